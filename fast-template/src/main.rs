@@ -0,0 +1,193 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Creates a new project from the template repository without cloning it first.
+//!
+//! The template is downloaded at a tag, or at its default branch, and bootstrapped into the
+//! given directory by its own `cargo x bootstrap --out`, so that the bootstrap always matches
+//! the template version.
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::ExitCode;
+
+/// The GitHub repository of the template.
+const DEFAULT_REPOSITORY: &str = "fast/template";
+
+const USAGE: &str = "\
+Usage: fast-template <PATH> [--tag <TAG>] [--repository <OWNER/NAME>] [-- <BOOTSTRAP OPTIONS>...]
+
+Creates a new project in PATH from the template at TAG, or at its default branch. The options
+after `--` are passed to `cargo x bootstrap`, e.g., `-- --project-name my-project`.";
+
+/// The command line of the bootstrapper.
+#[derive(Debug, PartialEq)]
+struct Args {
+    path: PathBuf,
+    tag: Option<String>,
+    repository: String,
+    bootstrap_args: Vec<String>,
+}
+
+fn main() -> ExitCode {
+    let args = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(args)) => args,
+        Ok(None) => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("error: {err}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+    match create_project(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Parses the command line, returning `None` when help is requested.
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Option<Args>, String> {
+    let mut args = args.into_iter();
+    let mut path = None;
+    let mut tag = None;
+    let mut repository = DEFAULT_REPOSITORY.to_owned();
+    let mut bootstrap_args = vec![];
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--tag" => tag = Some(args.next().ok_or("--tag needs a value")?),
+            "--repository" => repository = args.next().ok_or("--repository needs a value")?,
+            "--" => bootstrap_args.extend(args.by_ref()),
+            _ if arg.starts_with('-') => return Err(format!("unknown option '{arg}'")),
+            _ if path.is_none() => path = Some(PathBuf::from(arg)),
+            _ => return Err(format!("unexpected argument '{arg}'")),
+        }
+    }
+    let path = path.ok_or("the project PATH is required")?;
+    Ok(Some(Args {
+        path,
+        tag,
+        repository,
+        bootstrap_args,
+    }))
+}
+
+/// Downloads the template into a temporary directory and bootstraps it into the project path,
+/// removing the download afterwards.
+fn create_project(args: &Args) -> Result<(), String> {
+    let out = std::path::absolute(&args.path)
+        .map_err(|err| format!("invalid path {}: {err}", args.path.display()))?;
+    if std::fs::read_dir(&out).is_ok_and(|mut entries| entries.next().is_some()) {
+        return Err(format!("{} is not empty", out.display()));
+    }
+    let work = std::env::temp_dir().join(format!("fast-template-{}", std::process::id()));
+    let result = download_and_bootstrap(args, &out, &work);
+    let _ = std::fs::remove_dir_all(&work);
+    result
+}
+
+fn download_and_bootstrap(args: &Args, out: &Path, work: &Path) -> Result<(), String> {
+    std::fs::create_dir_all(work)
+        .map_err(|err| format!("failed to create {}: {err}", work.display()))?;
+    let reference = match &args.tag {
+        Some(tag) => format!("refs/tags/{tag}"),
+        None => "HEAD".to_owned(),
+    };
+    let url = format!(
+        "https://github.com/{}/archive/{reference}.tar.gz",
+        args.repository
+    );
+    println!("Downloading {url}...");
+    let archive = work.join("template.tar.gz");
+    run(Command::new("curl")
+        .args([
+            "--fail",
+            "--silent",
+            "--show-error",
+            "--location",
+            "--output",
+        ])
+        .arg(&archive)
+        .arg(&url))?;
+    run(Command::new("tar")
+        .arg("-xzf")
+        .arg(&archive)
+        .arg("-C")
+        .arg(work))?;
+
+    // the archive holds a single directory named after the repository and reference
+    let source = std::fs::read_dir(work)
+        .map_err(|err| format!("failed to read {}: {err}", work.display()))?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .find(|path| path.is_dir())
+        .ok_or_else(|| format!("{url} holds no template"))?;
+    // the bootstrap lists the template files with git
+    run(Command::new("git")
+        .args(["init", "--quiet"])
+        .current_dir(&source))?;
+    run(Command::new("cargo")
+        .args(["x", "bootstrap", "--out"])
+        .arg(out)
+        .args(&args.bootstrap_args)
+        .current_dir(&source))
+}
+
+fn run(cmd: &mut Command) -> Result<(), String> {
+    let status = cmd
+        .status()
+        .map_err(|err| format!("failed to run {cmd:?}: {err}"))?;
+    if !status.success() {
+        return Err(format!("{cmd:?} failed: {status}"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_args() {
+        let parse = |args: &[&str]| parse_args(args.iter().map(|arg| arg.to_string()));
+        assert_eq!(
+            parse(&[
+                "demo",
+                "--tag",
+                "v0.1.0",
+                "--",
+                "--project-name",
+                "demo",
+                "--yes"
+            ]),
+            Ok(Some(Args {
+                path: PathBuf::from("demo"),
+                tag: Some("v0.1.0".to_owned()),
+                repository: DEFAULT_REPOSITORY.to_owned(),
+                bootstrap_args: vec!["--project-name".into(), "demo".into(), "--yes".into()],
+            }))
+        );
+        assert_eq!(parse(&["--help"]), Ok(None));
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["demo", "--tag"]).is_err());
+        assert!(parse(&["demo", "--force"]).is_err());
+        assert!(parse(&["demo", "other"]).is_err());
+    }
+}
@@ -13,6 +13,9 @@
 // limitations under the License.
 
 //! A template library.
+//!
+//! `${projectName}` is developed at <https://github.com/fast/template>, where issues and pull
+//! requests are welcome.
 
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![deny(missing_docs)]
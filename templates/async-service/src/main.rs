@@ -0,0 +1,43 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A template library.
+//!
+//! `${projectName}` is developed at <https://github.com/fast/template>, where issues and pull
+//! requests are welcome.
+
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpListener;
+
+/// The address the service listens on.
+const ADDRESS: &str = "127.0.0.1:${port}";
+
+#[tokio::main]
+async fn main() -> std::io::Result<()> {
+    let listener = TcpListener::bind(ADDRESS).await?;
+    println!("listening on {ADDRESS}");
+    loop {
+        let (mut socket, _) = listener.accept().await?;
+        // echo back whatever the client sends until it disconnects
+        tokio::spawn(async move {
+            let mut buf = [0; 1024];
+            while let Ok(n @ 1..) = socket.read(&mut buf).await {
+                if socket.write_all(&buf[..n]).await.is_err() {
+                    break;
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A template library.
+//!
+//! `${projectName}` is developed at <https://github.com/fast/template>, where issues and pull
+//! requests are welcome.
+//!
+//! The library is built as a `cdylib` exporting a C API, declared by the header in `include/`
+//! that `cargo x cbindgen` generates from the exported functions.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+// the exported functions are unmangled, which only the C API may do
+#![allow(unsafe_code)]
+
+use std::ffi::CStr;
+use std::ffi::c_char;
+
+/// The greeting of the library.
+static GREETING: &CStr = c"Hello, world!";
+
+/// Returns the sum of `a` and `b`, wrapping around on overflow.
+#[unsafe(no_mangle)]
+pub extern "C" fn template_add(a: u32, b: u32) -> u32 {
+    a.wrapping_add(b)
+}
+
+/// Returns the greeting of the library, a static NUL-terminated string the caller must not free.
+#[unsafe(no_mangle)]
+pub extern "C" fn template_greeting() -> *const c_char {
+    GREETING.as_ptr()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add() {
+        assert_eq!(template_add(1, 2), 3);
+        assert_eq!(template_add(u32::MAX, 1), 0);
+    }
+
+    #[test]
+    fn test_greeting() {
+        assert_eq!(template_greeting(), GREETING.as_ptr());
+        assert_eq!(GREETING.to_str(), Ok("Hello, world!"));
+    }
+}
@@ -0,0 +1,89 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A template library.
+//!
+//! `${projectName}` is developed at <https://github.com/fast/template>, where issues and pull
+//! requests are welcome.
+//!
+//! The crate is `#![no_std]`: its core API needs neither the standard library nor an allocator.
+//! The `alloc` feature adds the allocating API, and the `std` feature, enabled by default, the
+//! API using the standard library.
+
+#![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::fmt;
+
+/// Writes the greeting of `name` to `out`, without allocating.
+pub fn write_greeting(out: &mut impl fmt::Write, name: &str) -> fmt::Result {
+    write!(out, "Hello, {name}!")
+}
+
+/// Returns the greeting of `name`.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub fn greeting(name: &str) -> alloc::string::String {
+    alloc::format!("Hello, {name}!")
+}
+
+/// A placeholder function.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn hello() {
+    std::println!("Hello, world!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fixed-capacity buffer, like the output of a target without an allocator.
+    struct Buffer {
+        bytes: [u8; 32],
+        len: usize,
+    }
+
+    impl fmt::Write for Buffer {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let end = self.len + s.len();
+            let bytes = self.bytes.get_mut(self.len..end).ok_or(fmt::Error)?;
+            bytes.copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_greeting() {
+        let mut buffer = Buffer {
+            bytes: [0; 32],
+            len: 0,
+        };
+        write_greeting(&mut buffer, "world").unwrap();
+        assert_eq!(&buffer.bytes[..buffer.len], b"Hello, world!");
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_greeting() {
+        assert_eq!(greeting("world"), "Hello, world!");
+    }
+}
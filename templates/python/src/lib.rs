@@ -0,0 +1,48 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A template library.
+//!
+//! `${projectName}` is developed at <https://github.com/fast/template>, where issues and pull
+//! requests are welcome.
+//!
+//! The library is a Python extension module, which `cargo x maturin develop` builds and installs
+//! into the current virtualenv, to be imported under the name of the library.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+
+use pyo3::prelude::*;
+
+/// Returns the greeting of `name`.
+#[pyfunction]
+pub fn greet(name: &str) -> String {
+    format!("Hello, {name}!")
+}
+
+/// The Python module of the library.
+#[pymodule]
+fn template(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(greet, module)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_greet() {
+        assert_eq!(greet("world"), "Hello, world!");
+    }
+}
@@ -0,0 +1,54 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A template library.
+//!
+//! `${projectName}` is developed at <https://github.com/fast/template>, where issues and pull
+//! requests are welcome.
+//!
+//! The library is built for `wasm32-unknown-unknown`, exporting its functions to JavaScript with
+//! wasm-bindgen; `cargo x wasm build` packages it for npm into `pkg/`.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+
+use wasm_bindgen::prelude::*;
+
+/// Returns the sum of `a` and `b`, wrapping around on overflow.
+#[wasm_bindgen]
+pub fn add(a: u32, b: u32) -> u32 {
+    a.wrapping_add(b)
+}
+
+/// Returns the greeting of `name`.
+#[wasm_bindgen]
+pub fn greet(name: &str) -> String {
+    format!("Hello, {name}!")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add() {
+        assert_eq!(add(1, 2), 3);
+        assert_eq!(add(u32::MAX, 1), 0);
+    }
+
+    #[test]
+    fn test_greet() {
+        assert_eq!(greet("world"), "Hello, world!");
+    }
+}
@@ -12,6 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-pub fn bootstrap(_cleanup: bool) {
-    println!("\nThis project has already been bootstrapped!");
+//! Tests of the bindings in a headless browser, run by `cargo x wasm test`.
+
+#![cfg(target_arch = "wasm32")]
+
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn test_greet() {
+    assert_eq!(template::greet("wasm"), "Hello, wasm!");
 }
@@ -0,0 +1,472 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Records the plan and the steps of a bootstrap, to resume it after an interruption, roll it
+//! back on failure, or undo it later.
+
+use std::error::Error;
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde_json::Value;
+use serde_json::json;
+
+use crate::FileChange;
+use crate::Progress;
+use crate::Task;
+use crate::create_symlink;
+
+/// The journal at the project root, which `bootstrap --undo` replays backwards.
+pub const JOURNAL_FILE: &str = ".bootstrap-journal.json";
+
+/// What a path held before the bootstrap changed it.
+pub enum Original {
+    Missing,
+    File(String),
+    Symlink(PathBuf),
+}
+
+impl Original {
+    /// Reads what `file` holds now, without following a symlink.
+    pub fn read(file: &Path) -> std::io::Result<Original> {
+        match std::fs::symlink_metadata(file) {
+            Err(_) => Ok(Original::Missing),
+            Ok(metadata) if metadata.is_symlink() => {
+                Ok(Original::Symlink(std::fs::read_link(file)?))
+            }
+            Ok(_) => Ok(Original::File(std::fs::read_to_string(file)?)),
+        }
+    }
+
+    /// Puts the original back at `file`, replacing whatever is there now.
+    fn restore(&self, file: &Path) -> Result<(), Box<dyn Error>> {
+        if std::fs::symlink_metadata(file).is_ok() {
+            std::fs::remove_file(file)?;
+        }
+        // the directory of a removed file may be gone with it
+        if let (Original::File(_) | Original::Symlink(_), Some(parent)) = (self, file.parent()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        match self {
+            Original::Missing => {}
+            Original::File(content) => std::fs::write(file, content)?,
+            Original::Symlink(target) => create_symlink(target, file)?,
+        }
+        Ok(())
+    }
+}
+
+/// A step of the bootstrap, with paths relative to the project root.
+pub enum Step {
+    /// A file written, linked, or removed, and what it held before.
+    Change { file: String, original: Original },
+    /// A directory renamed.
+    Rename { from: String, to: String },
+}
+
+/// A bootstrap of the project at `root`: the changes it plans, in order, and the steps taken so
+/// far, saved after each one so that an interrupted bootstrap can be resumed or undone.
+///
+/// The `n`-th change is recorded as the `n`-th step before it is applied, and the directory
+/// renames follow, each recorded once done.
+pub struct Journal {
+    root: PathBuf,
+    pub changes: Vec<FileChange>,
+    /// The directories renamed, from and to.
+    pub renames: Vec<(String, String)>,
+    steps: Vec<Step>,
+    complete: bool,
+}
+
+impl Journal {
+    /// Starts the journal of a bootstrap, saving its plan.
+    pub fn start(
+        root: &Path,
+        changes: Vec<FileChange>,
+        renames: Vec<(String, String)>,
+    ) -> Result<Journal, Box<dyn Error>> {
+        let journal = Journal {
+            root: root.to_owned(),
+            changes,
+            renames,
+            steps: vec![],
+            complete: false,
+        };
+        journal.save()?;
+        Ok(journal)
+    }
+
+    /// Returns whether `root` has a journal, of a bootstrap either interrupted or complete.
+    pub fn exists(root: &Path) -> bool {
+        root.join(JOURNAL_FILE).exists()
+    }
+
+    /// Loads the journal of the bootstrap of `root`.
+    pub fn load(root: &Path) -> Result<Journal, Box<dyn Error>> {
+        let path = root.join(JOURNAL_FILE);
+        let content = std::fs::read_to_string(&path)
+            .map_err(|err| format!("no bootstrap journal at {}: {err}", path.display()))?;
+        let doc: Value = serde_json::from_str(&content)?;
+        let malformed = || format!("invalid {JOURNAL_FILE}: malformed journal");
+        let list = |key: &str| doc[key].as_array().ok_or_else(malformed);
+        let changes = list("changes")?
+            .iter()
+            .map(parse_change)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(malformed)?;
+        let steps = list("steps")?
+            .iter()
+            .map(parse_step)
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(malformed)?;
+        let renames = list("renames")?
+            .iter()
+            .map(|rename| {
+                let (from, to) = (rename["from"].as_str()?, rename["to"].as_str()?);
+                Some((from.to_owned(), to.to_owned()))
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(malformed)?;
+        Ok(Journal {
+            root: root.to_owned(),
+            changes,
+            renames,
+            steps,
+            complete: doc["complete"].as_bool().ok_or_else(malformed)?,
+        })
+    }
+
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// Records `step` and saves the journal.
+    pub fn record(&mut self, step: Step) -> Result<(), Box<dyn Error>> {
+        self.steps.push(step);
+        self.save()
+    }
+
+    /// Marks the bootstrap as complete, so that it is no longer resumed.
+    pub fn finish(&mut self) -> Result<(), Box<dyn Error>> {
+        self.complete = true;
+        self.save()
+    }
+
+    fn save(&self) -> Result<(), Box<dyn Error>> {
+        let changes: Vec<Value> = self.changes.iter().map(render_change).collect();
+        let steps: Vec<Value> = self.steps.iter().map(render_step).collect();
+        let renames: Vec<Value> = self
+            .renames
+            .iter()
+            .map(|(from, to)| json!({ "from": from, "to": to }))
+            .collect();
+        let doc = json!({
+            "changes": changes,
+            "renames": renames,
+            "steps": steps,
+            "complete": self.complete,
+        });
+        std::fs::write(
+            self.root.join(JOURNAL_FILE),
+            serde_json::to_string_pretty(&doc)?,
+        )?;
+        Ok(())
+    }
+
+    /// Takes the steps not taken yet, recording each before taking it, and marks the bootstrap
+    /// complete, or else undoes every step taken and returns the error of the one that failed.
+    pub fn resume(&mut self, progress: &mut dyn Progress) -> Result<(), Box<dyn Error>> {
+        match self.take_steps(progress) {
+            Ok(()) => self.finish(),
+            Err(err) => {
+                if !self.steps.is_empty() {
+                    progress.task(Task::RollBack);
+                }
+                let _ = self.undo(progress);
+                Err(err)
+            }
+        }
+    }
+
+    fn take_steps(&mut self, progress: &mut dyn Progress) -> Result<(), Box<dyn Error>> {
+        // the last recorded change may have been interrupted before it was applied, which is safe
+        // to apply again
+        let applied = self.steps.len().saturating_sub(1);
+        for (index, change) in self.changes.clone().iter().enumerate().skip(applied) {
+            let file = self.root.join(change.file());
+            progress.task(Task::Update(&file));
+            let recorded = if index < self.steps.len() {
+                Ok(())
+            } else {
+                Original::read(&file)
+                    .map_err(Into::into)
+                    .and_then(|original| {
+                        self.record(Step::Change {
+                            file: change.file().to_owned(),
+                            original,
+                        })
+                    })
+            };
+            let result = recorded.and_then(|()| change.apply(&self.root));
+            progress.done(&result);
+            result?;
+        }
+        for (from, to) in self.renames.clone() {
+            let renamed = self
+                .steps
+                .iter()
+                .any(|step| matches!(step, Step::Rename { from: dir, .. } if *dir == from));
+            if !renamed {
+                progress.task(Task::Rename {
+                    from: &from,
+                    to: &to,
+                });
+                let result = self.rename_dir(from.clone(), to.clone());
+                progress.done(&result);
+                result?;
+            }
+        }
+        Ok(())
+    }
+
+    fn rename_dir(&mut self, from: String, to: String) -> Result<(), Box<dyn Error>> {
+        let target_dir = self.root.join(&to);
+        if target_dir.exists() {
+            return Err(format!("Directory '{to}' already exists").into());
+        }
+        std::fs::rename(self.root.join(&from), target_dir)?;
+        self.record(Step::Rename { from, to })
+    }
+
+    /// Reverts the recorded steps, latest first, and removes the journal once all succeeded.
+    pub fn undo(&self, progress: &mut dyn Progress) -> Result<(), Box<dyn Error>> {
+        let mut failed = 0;
+        for step in self.steps.iter().rev() {
+            let result = match step {
+                Step::Change { file, original } => {
+                    let file = self.root.join(file);
+                    progress.task(Task::Restore(&file));
+                    original.restore(&file).map(|()| {
+                        if let Original::Missing = original {
+                            remove_empty_dirs(&self.root, &file);
+                        }
+                    })
+                }
+                Step::Rename { from, to } => {
+                    progress.task(Task::RenameBack { from, to });
+                    std::fs::rename(self.root.join(to), self.root.join(from)).map_err(Into::into)
+                }
+            };
+            progress.done(&result);
+            if result.is_err() {
+                failed += 1;
+            }
+        }
+        if failed > 0 {
+            return Err(format!("{failed} step(s) could not be undone").into());
+        }
+        let journal = self.root.join(JOURNAL_FILE);
+        if journal.exists() {
+            std::fs::remove_file(journal)?;
+        }
+        Ok(())
+    }
+}
+
+/// Removes the directories between `root` and `file` that were left empty, e.g., created by the
+/// bootstrap for a file it wrote, or emptied by the files it removed.
+pub fn remove_empty_dirs(root: &Path, file: &Path) {
+    let mut dir = file.parent();
+    while let Some(path) = dir.filter(|path| *path != root) {
+        // only an empty directory is removed
+        if std::fs::remove_dir(path).is_err() {
+            break;
+        }
+        dir = path.parent();
+    }
+}
+
+fn render_change(change: &FileChange) -> Value {
+    match change {
+        FileChange::Write { file, content } => json!({ "write": file, "content": content }),
+        FileChange::Symlink { file, target } => json!({ "symlink": file, "target": target }),
+        FileChange::Remove { file } => json!({ "remove": file }),
+    }
+}
+
+fn parse_change(change: &Value) -> Option<FileChange> {
+    let field = |key: &str| change[key].as_str().map(str::to_owned);
+    if let Some(file) = field("write") {
+        Some(FileChange::Write {
+            file,
+            content: field("content")?,
+        })
+    } else if let Some(file) = field("symlink") {
+        Some(FileChange::Symlink {
+            file,
+            target: field("target")?,
+        })
+    } else {
+        Some(FileChange::Remove {
+            file: field("remove")?,
+        })
+    }
+}
+
+fn render_step(step: &Step) -> Value {
+    match step {
+        Step::Change { file, original } => {
+            let original = match original {
+                Original::Missing => Value::Null,
+                Original::File(content) => json!({ "content": content }),
+                Original::Symlink(target) => json!({ "symlink": target.to_string_lossy() }),
+            };
+            json!({ "file": file, "original": original })
+        }
+        Step::Rename { from, to } => json!({ "rename": from, "to": to }),
+    }
+}
+
+fn parse_step(step: &Value) -> Option<Step> {
+    if let Some(from) = step["rename"].as_str() {
+        let to = step["to"].as_str()?;
+        return Some(Step::Rename {
+            from: from.to_owned(),
+            to: to.to_owned(),
+        });
+    }
+    let file = step["file"].as_str()?.to_owned();
+    let original = &step["original"];
+    let original = if original.is_null() {
+        Original::Missing
+    } else if let Some(content) = original["content"].as_str() {
+        Original::File(content.to_owned())
+    } else {
+        Original::Symlink(PathBuf::from(original["symlink"].as_str()?))
+    };
+    Some(Step::Change { file, original })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Silent;
+
+    #[test]
+    fn test_journal_undo() {
+        let root = std::env::temp_dir().join(format!("xtask-journal-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("demo")).unwrap();
+        std::fs::write(root.join("README.md"), "# demo\n").unwrap();
+        std::fs::write(root.join("LICENSE-MIT"), "MIT License\n").unwrap();
+
+        let changes = vec![
+            FileChange::Write {
+                file: "README.md".to_owned(),
+                content: "# demo\n".to_owned(),
+            },
+            FileChange::Write {
+                file: "LICENSE-MIT".to_owned(),
+                content: "MIT License\n".to_owned(),
+            },
+        ];
+        let renames = vec![("template".to_owned(), "demo".to_owned())];
+        let mut journal = Journal::start(&root, changes, renames).unwrap();
+        let steps = [
+            Step::Change {
+                file: "README.md".to_owned(),
+                original: Original::File("# template\n".to_owned()),
+            },
+            Step::Change {
+                file: "LICENSE-MIT".to_owned(),
+                original: Original::Missing,
+            },
+            Step::Rename {
+                from: "template".to_owned(),
+                to: "demo".to_owned(),
+            },
+        ];
+        for step in steps {
+            journal.record(step).unwrap();
+        }
+
+        // the saved journal is what an undo in a later run replays
+        let journal = Journal::load(&root).unwrap();
+        assert_eq!(journal.steps().len(), 3);
+        assert_eq!(journal.changes[1].file(), "LICENSE-MIT");
+        assert_eq!(
+            journal.renames,
+            [("template".to_owned(), "demo".to_owned())]
+        );
+        assert!(!journal.is_complete());
+        journal.undo(&mut Silent).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(root.join("README.md")).unwrap(),
+            "# template\n"
+        );
+        assert!(!root.join("LICENSE-MIT").exists());
+        assert!(root.join("template").is_dir());
+        assert!(!root.join(JOURNAL_FILE).exists());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_journal_resume() {
+        let root = std::env::temp_dir().join(format!("xtask-resume-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("template")).unwrap();
+        std::fs::write(root.join("Cargo.toml"), "[workspace]\n").unwrap();
+
+        let changes = vec![
+            FileChange::Write {
+                file: "Cargo.toml".to_owned(),
+                content: "[workspace]\nmembers = [\"demo\"]\n".to_owned(),
+            },
+            FileChange::Write {
+                file: "LICENSE-MIT".to_owned(),
+                content: "MIT License\n".to_owned(),
+            },
+        ];
+        let renames = vec![("template".to_owned(), "demo".to_owned())];
+        let mut journal = Journal::start(&root, changes, renames).unwrap();
+        // interrupted after recording the first change, before writing it
+        journal
+            .record(Step::Change {
+                file: "Cargo.toml".to_owned(),
+                original: Original::File("[workspace]\n".to_owned()),
+            })
+            .unwrap();
+
+        let mut journal = Journal::load(&root).unwrap();
+        journal.resume(&mut Silent).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            "[workspace]\nmembers = [\"demo\"]\n"
+        );
+        assert!(root.join("LICENSE-MIT").exists());
+        assert!(root.join("demo").is_dir());
+        assert_eq!(journal.steps().len(), 3);
+        assert!(Journal::load(&root).unwrap().is_complete());
+
+        Journal::load(&root).unwrap().undo(&mut Silent).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            "[workspace]\n"
+        );
+        assert!(root.join("template").is_dir());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,154 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The engine of `cargo x bootstrap`: renaming a crate across the spellings of its name, and
+//! applying the file changes and directory renames of a [`BootstrapPlan`] step by step under a
+//! [`Journal`], so that an interrupted bootstrap is resumed, a failed one rolled back, and a
+//! finished one undone.
+//!
+//! The xtask plans the bootstrap of the template with it, and a fork of the template adds its own
+//! steps to the plan the same way:
+//!
+//! ```no_run
+//! use xtask_bootstrap::BootstrapPlan;
+//! use xtask_bootstrap::FileChange;
+//! use xtask_bootstrap::Silent;
+//!
+//! let mut plan = BootstrapPlan::new("path/to/project");
+//! plan.add_change(FileChange::Write {
+//!     file: "NOTICE".to_owned(),
+//!     content: "Copyright 2026 Octocat\n".to_owned(),
+//! })
+//! .add_rename("template", "demo");
+//! for step in plan.preview() {
+//!     println!("{step}");
+//! }
+//! plan.apply(&mut Silent).unwrap();
+//! ```
+
+use std::error::Error;
+use std::path::Path;
+
+pub mod journal;
+mod plan;
+pub mod rename;
+
+pub use journal::Journal;
+pub use plan::BootstrapPlan;
+
+/// A file written, linked, or removed by the bootstrap, relative to the project root.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FileChange {
+    Write { file: String, content: String },
+    Symlink { file: String, target: String },
+    Remove { file: String },
+}
+
+impl FileChange {
+    pub fn file(&self) -> &str {
+        match self {
+            FileChange::Write { file, .. }
+            | FileChange::Symlink { file, .. }
+            | FileChange::Remove { file } => file,
+        }
+    }
+
+    /// The action of the change, as the JSON summary names it.
+    pub fn action(&self) -> &'static str {
+        match self {
+            FileChange::Write { .. } => "write",
+            FileChange::Symlink { .. } => "symlink",
+            FileChange::Remove { .. } => "remove",
+        }
+    }
+
+    pub fn describe(&self) -> String {
+        match self {
+            FileChange::Write { file, .. } => format!("write {file}"),
+            FileChange::Symlink { file, target } => format!("link {file} -> {target}"),
+            FileChange::Remove { file } => format!("remove {file}"),
+        }
+    }
+
+    /// Applies the change to the project at `root`, which may be done again with the same
+    /// result.
+    pub fn apply(&self, root: &Path) -> Result<(), Box<dyn Error>> {
+        let file = root.join(self.file());
+        // never write through a symlink that is being replaced
+        let replaced = match self {
+            FileChange::Write { .. } => file.is_symlink(),
+            FileChange::Symlink { .. } | FileChange::Remove { .. } => {
+                std::fs::symlink_metadata(&file).is_ok()
+            }
+        };
+        if replaced {
+            std::fs::remove_file(&file)?;
+        }
+        if let (FileChange::Write { .. } | FileChange::Symlink { .. }, Some(parent)) =
+            (self, file.parent())
+        {
+            std::fs::create_dir_all(parent)?;
+        }
+        match self {
+            FileChange::Write { content, .. } => std::fs::write(&file, content)?,
+            FileChange::Symlink { target, .. } => create_symlink(Path::new(target), &file)?,
+            // a directory left empty goes with its last file, e.g., the GitHub workflows
+            FileChange::Remove { .. } => journal::remove_empty_dirs(root, &file),
+        }
+        Ok(())
+    }
+}
+
+/// A step of a bootstrap being taken or undone, as reported to a [`Progress`].
+#[derive(Debug)]
+pub enum Task<'a> {
+    /// A file of the plan is written, linked, or removed.
+    Update(&'a Path),
+    /// A directory of the plan is renamed.
+    Rename { from: &'a str, to: &'a str },
+    /// A file is restored by an undo.
+    Restore(&'a Path),
+    /// A directory is renamed back by an undo.
+    RenameBack { from: &'a str, to: &'a str },
+    /// The steps taken are undone after one failed, which has no result of its own.
+    RollBack,
+}
+
+/// Reports the progress of a bootstrap, where every task but [`Task::RollBack`] is followed by
+/// its result.
+pub trait Progress {
+    fn task(&mut self, task: Task<'_>);
+
+    fn done(&mut self, result: &Result<(), Box<dyn Error>>);
+}
+
+/// Reports nothing.
+pub struct Silent;
+
+impl Progress for Silent {
+    fn task(&mut self, _: Task<'_>) {}
+
+    fn done(&mut self, _: &Result<(), Box<dyn Error>>) {}
+}
+
+#[cfg(unix)]
+pub fn create_symlink(target: &Path, link: &Path) -> std::io::Result<()> {
+    std::os::unix::fs::symlink(target, link)
+}
+
+/// Copies the file `target` points to instead, since symlinks need privileges elsewhere.
+#[cfg(not(unix))]
+pub fn create_symlink(target: &Path, link: &Path) -> std::io::Result<()> {
+    std::fs::copy(link.parent().unwrap().join(target), link).map(|_| ())
+}
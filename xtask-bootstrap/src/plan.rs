@@ -0,0 +1,134 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error::Error;
+use std::path::PathBuf;
+
+use crate::FileChange;
+use crate::Journal;
+use crate::Progress;
+
+/// The file changes and directory renames bootstrapping the project at a root, added step by step
+/// and applied at once under a [`Journal`].
+#[derive(Clone, Debug)]
+pub struct BootstrapPlan {
+    root: PathBuf,
+    changes: Vec<FileChange>,
+    renames: Vec<(String, String)>,
+}
+
+impl BootstrapPlan {
+    /// Starts an empty plan for the project at `root`.
+    pub fn new(root: impl Into<PathBuf>) -> BootstrapPlan {
+        BootstrapPlan {
+            root: root.into(),
+            changes: vec![],
+            renames: vec![],
+        }
+    }
+
+    /// Adds a file change, applied after those added before.
+    pub fn add_change(&mut self, change: FileChange) -> &mut Self {
+        self.changes.push(change);
+        self
+    }
+
+    /// Adds the rename of the directory `from` to `to`, relative to the root, which is taken
+    /// once the file changes are applied, in the order the renames were added.
+    pub fn add_rename(&mut self, from: impl Into<String>, to: impl Into<String>) -> &mut Self {
+        self.renames.push((from.into(), to.into()));
+        self
+    }
+
+    pub fn changes(&self) -> &[FileChange] {
+        &self.changes
+    }
+
+    pub fn renames(&self) -> &[(String, String)] {
+        &self.renames
+    }
+
+    /// Returns a line describing each step of the plan, in order, e.g., `write README.md` or
+    /// `rename template/ -> demo/`.
+    pub fn preview(&self) -> Vec<String> {
+        let renames = self
+            .renames
+            .iter()
+            .map(|(from, to)| format!("rename {from}/ -> {to}/"));
+        self.changes
+            .iter()
+            .map(FileChange::describe)
+            .chain(renames)
+            .collect()
+    }
+
+    /// Applies the plan, recording every step in the journal at the root before taking it, and
+    /// returns the complete journal, or else the error of the step that failed once the steps
+    /// taken are undone.
+    pub fn apply(self, progress: &mut dyn Progress) -> Result<Journal, Box<dyn Error>> {
+        let mut journal = Journal::start(&self.root, self.changes, self.renames)?;
+        journal.resume(progress)?;
+        Ok(journal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Silent;
+
+    #[test]
+    fn test_apply() {
+        let root = std::env::temp_dir().join(format!("xtask-plan-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("template/src")).unwrap();
+        std::fs::write(root.join("README.md"), "# template\n").unwrap();
+
+        let mut plan = BootstrapPlan::new(&root);
+        plan.add_change(FileChange::Write {
+            file: "README.md".to_owned(),
+            content: "# demo\n".to_owned(),
+        })
+        .add_change(FileChange::Write {
+            file: "template/src/lib.rs".to_owned(),
+            content: "//! A demo.\n".to_owned(),
+        })
+        .add_rename("template", "demo");
+        assert_eq!(
+            plan.preview(),
+            [
+                "write README.md",
+                "write template/src/lib.rs",
+                "rename template/ -> demo/",
+            ]
+        );
+        let journal = plan.clone().apply(&mut Silent).unwrap();
+        assert!(journal.is_complete());
+        assert_eq!(journal.steps().len(), 3);
+        assert_eq!(
+            std::fs::read_to_string(root.join("demo/src/lib.rs")).unwrap(),
+            "//! A demo.\n"
+        );
+
+        // a failed step rolls back those taken before it
+        journal.undo(&mut Silent).unwrap();
+        std::fs::create_dir_all(root.join("demo")).unwrap();
+        assert!(plan.apply(&mut Silent).is_err());
+        assert_eq!(
+            std::fs::read_to_string(root.join("README.md")).unwrap(),
+            "# template\n"
+        );
+        assert!(!root.join("template/src/lib.rs").exists());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
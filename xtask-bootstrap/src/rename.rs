@@ -0,0 +1,266 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Renames a crate across the case variants of its name, along with the workspace members
+//! prefixed with it.
+
+/// A workspace member named after a renamed crate, e.g., the template, and what it is renamed to.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrateRename {
+    pub package: String,
+    pub new_package: String,
+    /// The member directory, relative to the workspace root.
+    pub dir: String,
+    pub new_dir: String,
+}
+
+impl CrateRename {
+    /// Returns whether the member directory is renamed, which it is when named after the package.
+    pub fn renames_dir(&self) -> bool {
+        self.dir != self.new_dir
+    }
+}
+
+/// Returns the renames of the workspace `members`, given as their package names and directories
+/// relative to the workspace root, from the package `old` to `new` along with every `<old>-foo`
+/// package to `<new_prefix>-foo`, e.g., the template crate to the project.
+///
+/// A member directory is renamed the same way if its own name is one of those. The renames are
+/// sorted from the longest package name, which is the order the names are replaced in, so `old`
+/// comes last.
+pub fn prefixed_renames(
+    members: &[(String, String)],
+    old: &str,
+    new: &str,
+    new_prefix: &str,
+) -> Vec<CrateRename> {
+    let prefix = format!("{old}-");
+    let renamed = |name: &str| match name {
+        _ if name == old => Some(new.to_owned()),
+        _ => name
+            .strip_prefix(&prefix)
+            .filter(|rest| !rest.is_empty())
+            .map(|rest| format!("{new_prefix}-{rest}")),
+    };
+    let mut renames: Vec<CrateRename> = members
+        .iter()
+        .filter_map(|(package, dir)| {
+            let new_package = renamed(package)?;
+            let (parent, name) = dir.rsplit_once('/').unwrap_or(("", dir));
+            let new_dir = match renamed(name) {
+                Some(new_name) if parent.is_empty() => new_name,
+                Some(new_name) => format!("{parent}/{new_name}"),
+                None => dir.clone(),
+            };
+            Some(CrateRename {
+                package: package.clone(),
+                new_package,
+                dir: dir.clone(),
+                new_dir,
+            })
+        })
+        .collect();
+    renames.sort_by_key(|rename| std::cmp::Reverse(rename.package.len()));
+    renames
+}
+
+/// Renames every crate of `renames` in `content`, in order, so that the `template-*` members are
+/// renamed before `template_foo` would be renamed as a prefixed template name.
+pub fn rename_crates(content: &str, renames: &[CrateRename], rust: bool) -> String {
+    renames.iter().fold(content.to_owned(), |content, rename| {
+        rename_crate(&content, &rename.package, &rename.new_package, rust)
+    })
+}
+
+/// The spellings of a crate name, e.g., `my-proj`, `my_proj`, `MyProj` and `MY_PROJ`.
+struct Variants {
+    kebab: String,
+    snake: String,
+    pascal: String,
+    screaming: String,
+}
+
+impl Variants {
+    fn of(name: &str) -> Variants {
+        let words: Vec<String> = name
+            .split(['-', '_'])
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect();
+        let pascal = words
+            .iter()
+            .map(|word| {
+                let mut chars = word.chars();
+                chars.next().map_or_else(String::new, |first| {
+                    first.to_uppercase().chain(chars).collect()
+                })
+            })
+            .collect();
+        Variants {
+            kebab: words.join("-"),
+            snake: words.join("_"),
+            pascal,
+            screaming: words.join("_").to_uppercase(),
+        }
+    }
+}
+
+/// Replaces the crate name `old` with `new` in all its case variants.
+///
+/// A variant is only replaced where it starts a word, so `template_lib` becomes `my_proj_lib`
+/// while `templated`, `my_template`, `x-template-header` and `octocat/template` are left alone. A
+/// lowercase name is renamed to `new` as given, except where it is part of an identifier or path:
+/// before `_` or `::`, and outside of comments in Rust sources, where the snake case variant keeps
+/// it valid.
+pub fn rename_crate(content: &str, old: &str, new: &str, rust: bool) -> String {
+    let old = Variants::of(old);
+    let new_variants = Variants::of(new);
+    let lowercase_new = |after: &str, in_comment: bool| {
+        if (rust && !in_comment) || after.starts_with('_') || after.starts_with("::") {
+            new_variants.snake.as_str()
+        } else {
+            new
+        }
+    };
+
+    let mut out = String::with_capacity(content.len());
+    let mut rest = content;
+    let mut prev: Option<char> = None;
+    let mut in_comment = false;
+    while let Some(c) = rest.chars().next() {
+        let starts_word =
+            prev.is_none_or(|p| !(p.is_alphanumeric() || matches!(p, '-' | '_' | '/')));
+        let matched = starts_word
+            .then(|| {
+                [
+                    (&old.screaming, Case::Screaming),
+                    (&old.pascal, Case::Pascal),
+                    (&old.kebab, Case::Lower),
+                    (&old.snake, Case::Lower),
+                ]
+                .into_iter()
+                .find(|(variant, case)| {
+                    rest.strip_prefix(variant.as_str())
+                        .is_some_and(|after| case.ends_word(after.chars().next()))
+                })
+            })
+            .flatten();
+        match matched {
+            Some((variant, case)) => {
+                let after = &rest[variant.len()..];
+                out.push_str(match case {
+                    Case::Screaming => &new_variants.screaming,
+                    Case::Pascal => &new_variants.pascal,
+                    Case::Lower if variant.contains('-') => &new_variants.kebab,
+                    Case::Lower if variant.contains('_') => &new_variants.snake,
+                    Case::Lower => lowercase_new(after, in_comment),
+                });
+                prev = variant.chars().last();
+                rest = &rest[variant.len()..];
+            }
+            None => {
+                match c {
+                    '/' if prev == Some('/') => in_comment = true,
+                    '\n' => in_comment = false,
+                    _ => {}
+                }
+                out.push(c);
+                prev = Some(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    out
+}
+
+#[derive(Clone, Copy)]
+enum Case {
+    Screaming,
+    Pascal,
+    Lower,
+}
+
+impl Case {
+    /// Returns whether a variant followed by `next` ends a word, allowing `TemplateBuilder`,
+    /// `TEMPLATE_DIR` and `template_lib` but not `templated`.
+    fn ends_word(self, next: Option<char>) -> bool {
+        next.is_none_or(|next| match self {
+            Case::Screaming => !(next.is_uppercase() || next.is_ascii_digit()),
+            Case::Pascal | Case::Lower => !(next.is_lowercase() || next.is_ascii_digit()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rename_crate() {
+        let rename = |content: &str, rust: bool| rename_crate(content, "template", "my-proj", rust);
+
+        assert_eq!(rename("name = \"template\"", false), "name = \"my-proj\"");
+        assert_eq!(rename("template_lib = 1", false), "my_proj_lib = 1");
+        assert_eq!(rename("template-cli", false), "my-proj-cli");
+        assert_eq!(rename("use template::hello;", true), "use my_proj::hello;");
+        assert_eq!(
+            rename("//! A template library.\n/// template::hello();\n", true),
+            "//! A my-proj library.\n/// my_proj::hello();\n"
+        );
+        assert_eq!(
+            rename(
+                "struct TemplateBuilder; const TEMPLATE_DIR: &str = \"\";",
+                true
+            ),
+            "struct MyProjBuilder; const MY_PROJ_DIR: &str = \"\";"
+        );
+
+        // words that merely contain the name are left alone
+        for content in [
+            "templated",
+            "my_template",
+            "x-template-header",
+            "octocat/template",
+            "Templates",
+        ] {
+            assert_eq!(rename(content, false), content);
+        }
+
+        assert_eq!(
+            rename_crate(
+                "my-template and my_template",
+                "my-template",
+                "demo-app",
+                false
+            ),
+            "demo-app and demo_app"
+        );
+    }
+
+    #[test]
+    fn test_prefixed_renames() {
+        let members = [("demo", "demo"), ("demo-macros", "crates/macros")]
+            .map(|(package, dir)| (package.to_owned(), dir.to_owned()));
+        let renames = prefixed_renames(&members, "demo", "acme", "acme");
+        let summary: Vec<(&str, &str)> = renames
+            .iter()
+            .map(|rename| (rename.new_package.as_str(), rename.new_dir.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            [("acme-macros", "crates/macros"), ("acme", "acme")]
+        );
+        assert!(prefixed_renames(&members, "demo-cli", "acme-cli", "acme").is_empty());
+    }
+}
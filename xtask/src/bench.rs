@@ -0,0 +1,338 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Stdio;
+
+use super::find_cargo;
+use super::history;
+use super::run_command;
+use super::workspace_dir;
+use super::worktree;
+
+/// Two-sided 95% critical values of Student's t-distribution for 1 to 30 degrees of freedom.
+const T_CRITICAL_95: [f64; 30] = [
+    12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228, 2.201, 2.179, 2.160,
+    2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086, 2.080, 2.074, 2.069, 2.064, 2.060, 2.056,
+    2.052, 2.048, 2.045, 2.042,
+];
+
+/// The critical value for more than 30 degrees of freedom.
+const Z_CRITICAL_95: f64 = 1.960;
+
+/// The nanoseconds per iteration measured in each run, by benchmark name.
+type Samples = BTreeMap<String, Vec<f64>>;
+
+/// Where the baseline measurements come from.
+pub enum Baseline {
+    /// Build and run the benches at a git revision.
+    Revision(String),
+    /// Load measurements saved with `--save-baseline`.
+    File(PathBuf),
+}
+
+/// How to run and compare the benches.
+pub struct BenchOptions {
+    pub baseline: Option<Baseline>,
+    pub save_baseline: Option<PathBuf>,
+    pub runs: usize,
+    pub threshold: f64,
+    pub output: Option<PathBuf>,
+}
+
+/// Runs the workspace benches, optionally comparing them against a baseline.
+///
+/// Comparing requires the bench harnesses to print libtest's bencher format, e.g., criterion
+/// with `--output-format bencher`. The suite runs `runs` times on each side; a benchmark
+/// regresses if its slowdown exceeds `threshold` percent and Welch's t-test finds the difference
+/// significant at the 95% level. The markdown report is printed and written to `output` if given,
+/// and any regression fails the command.
+pub fn bench(options: BenchOptions) {
+    if options.baseline.is_none() && options.save_baseline.is_none() {
+        let mut cmd = find_cargo();
+        cmd.args(["bench", "--workspace"]);
+        run_command(cmd);
+        return;
+    }
+
+    let base = match &options.baseline {
+        Some(Baseline::Revision(revision)) => {
+            let work_dir = workspace_dir().join("target/xtask/bench");
+            let base_dir = work_dir.join("base");
+            worktree::add_worktree(&base_dir, revision);
+            let samples = collect_samples(&base_dir, Some(&work_dir.join("target")), options.runs);
+            worktree::remove_worktree(&base_dir);
+            Some((format!("`{revision}`"), samples))
+        }
+        Some(Baseline::File(file)) => {
+            let content = std::fs::read_to_string(file)
+                .unwrap_or_else(|err| panic!("failed to read {}: {err}", file.display()));
+            let samples = serde_json::from_str(&content).unwrap_or_else(|err| {
+                history::fail(&format!("invalid baseline {}: {err}", file.display()))
+            });
+            Some((format!("`{}`", file.display()), samples))
+        }
+        None => None,
+    };
+    let head = collect_samples(workspace_dir(), None, options.runs);
+
+    if let Some(file) = &options.save_baseline {
+        let content = serde_json::to_string_pretty(&head).unwrap();
+        std::fs::write(file, content + "\n").unwrap();
+        println!("Saved the baseline to {}.", file.display());
+    }
+
+    let Some((label, base)) = base else {
+        return;
+    };
+    let comparisons = compare(&base, &head, options.threshold);
+    let report = render_report(&label, options.threshold, &comparisons);
+    println!("{report}");
+    if let Some(output) = &options.output {
+        std::fs::write(output, &report).unwrap();
+        println!("Wrote the bench report to {}.", output.display());
+    }
+
+    let regressions = comparisons
+        .iter()
+        .filter(|c| c.verdict == Verdict::Regression)
+        .count();
+    if regressions > 0 {
+        eprintln!("\nFound {regressions} benchmark regression(s).");
+        history::exit(1);
+    }
+}
+
+/// Runs the benches of the workspace at `dir` `runs` times and collects their measurements.
+fn collect_samples(dir: &Path, target_dir: Option<&Path>, runs: usize) -> Samples {
+    let mut samples = Samples::new();
+    for run in 1..=runs {
+        let mut cmd = find_cargo();
+        // only the bench targets, since the libtest harness of a library or binary rejects the
+        // bencher output format
+        cmd.current_dir(dir)
+            .args(["bench", "--workspace", "--bench", "*", "--"])
+            .args(["--output-format", "bencher"])
+            .stderr(Stdio::inherit());
+        if let Some(target_dir) = target_dir {
+            cmd.env("CARGO_TARGET_DIR", target_dir);
+        }
+        println!("{cmd:?} (run {run}/{runs})");
+        let output = cmd.output().expect("failed to execute cargo bench");
+        if !output.status.success() {
+            history::fail(&format!("command failed: {}", output.status));
+        }
+        for (name, nanos) in parse_bencher(&String::from_utf8_lossy(&output.stdout)) {
+            samples.entry(name).or_default().push(nanos);
+        }
+    }
+    samples
+}
+
+/// Parses lines like `test name ... bench:      1,234 ns/iter (+/- 56)`.
+fn parse_bencher(output: &str) -> Vec<(String, f64)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (name, rest) = line.strip_prefix("test ")?.split_once(" ... bench:")?;
+            let nanos = rest.split_whitespace().next()?.replace(',', "");
+            Some((name.trim().to_owned(), nanos.parse().ok()?))
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Verdict {
+    Regression,
+    Improvement,
+    Unchanged,
+    Added,
+    Removed,
+}
+
+#[derive(Debug)]
+struct Comparison {
+    name: String,
+    base: Option<f64>,
+    head: Option<f64>,
+    verdict: Verdict,
+}
+
+fn compare(base: &Samples, head: &Samples, threshold: f64) -> Vec<Comparison> {
+    let names: BTreeSet<&String> = base.keys().chain(head.keys()).collect();
+    names
+        .into_iter()
+        .map(|name| {
+            let (base, head) = (base.get(name), head.get(name));
+            let verdict = match (base, head) {
+                (Some(base), Some(head)) => {
+                    let change = (mean(head) - mean(base)) * 100.0 / mean(base);
+                    if change.abs() <= threshold || !is_significant(base, head) {
+                        Verdict::Unchanged
+                    } else if change > 0.0 {
+                        Verdict::Regression
+                    } else {
+                        Verdict::Improvement
+                    }
+                }
+                (None, Some(_)) => Verdict::Added,
+                (Some(_), None) => Verdict::Removed,
+                (None, None) => unreachable!(),
+            };
+            Comparison {
+                name: name.clone(),
+                base: base.map(|s| mean(s)),
+                head: head.map(|s| mean(s)),
+                verdict,
+            }
+        })
+        .collect()
+}
+
+fn mean(samples: &[f64]) -> f64 {
+    samples.iter().sum::<f64>() / samples.len() as f64
+}
+
+fn variance(samples: &[f64]) -> f64 {
+    let mean = mean(samples);
+    let squares: f64 = samples.iter().map(|s| (s - mean).powi(2)).sum();
+    squares / (samples.len() - 1) as f64
+}
+
+/// Returns whether Welch's t-test rejects equal means at the 95% level.
+///
+/// Fewer than two samples on either side never count as significant.
+fn is_significant(base: &[f64], head: &[f64]) -> bool {
+    if base.len() < 2 || head.len() < 2 {
+        return false;
+    }
+    let a = variance(base) / base.len() as f64;
+    let b = variance(head) / head.len() as f64;
+    let delta = (mean(head) - mean(base)).abs();
+    if a + b == 0.0 {
+        return delta > 0.0;
+    }
+    let t = delta / (a + b).sqrt();
+    let df = (a + b).powi(2)
+        / (a.powi(2) / (base.len() - 1) as f64 + b.powi(2) / (head.len() - 1) as f64);
+    // round the degrees of freedom down, which errs on the side of larger critical values
+    let critical = T_CRITICAL_95
+        .get((df.floor() as usize).max(1) - 1)
+        .copied()
+        .unwrap_or(Z_CRITICAL_95);
+    t > critical
+}
+
+fn render_report(label: &str, threshold: f64, comparisons: &[Comparison]) -> String {
+    let mut out = String::new();
+    writeln!(out, "### Benchmarks compared to {label}").unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "Regressions are significant slowdowns above {threshold}%."
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "| Benchmark | Base (ns/iter) | Head (ns/iter) | Change | Verdict |"
+    )
+    .unwrap();
+    writeln!(out, "|:---|---:|---:|---:|:---|").unwrap();
+    for c in comparisons {
+        let format_nanos = |nanos: Option<f64>| nanos.map_or("-".to_owned(), |n| format!("{n:.0}"));
+        let change = match (c.base, c.head) {
+            (Some(base), Some(head)) => format!("{:+.2}%", (head - base) * 100.0 / base),
+            _ => "-".to_owned(),
+        };
+        let verdict = match c.verdict {
+            Verdict::Regression => "regression",
+            Verdict::Improvement => "improvement",
+            Verdict::Unchanged => "no change",
+            Verdict::Added => "added",
+            Verdict::Removed => "removed",
+        };
+        writeln!(
+            out,
+            "| `{}` | {} | {} | {change} | {verdict} |",
+            c.name,
+            format_nanos(c.base),
+            format_nanos(c.head),
+        )
+        .unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bencher() {
+        let output = "\nrunning 2 tests\ntest fib_20 ... bench:      31,234 ns/iter (+/- 1,042)\ntest parse::small ... bench:         12 ns/iter (+/- 0)\n\ntest result: ok.\n";
+        assert_eq!(
+            parse_bencher(output),
+            vec![
+                ("fib_20".to_owned(), 31234.0),
+                ("parse::small".to_owned(), 12.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        let samples = |values: &[(&str, &[f64])]| -> Samples {
+            values
+                .iter()
+                .map(|(name, s)| ((*name).to_owned(), s.to_vec()))
+                .collect()
+        };
+        let base = samples(&[
+            ("noisy", &[100.0, 140.0, 90.0, 130.0, 95.0]),
+            ("slower", &[100.0, 101.0, 99.0, 100.0, 100.0]),
+            ("faster", &[100.0, 101.0, 99.0, 100.0, 100.0]),
+            ("old", &[1.0]),
+        ]);
+        let head = samples(&[
+            ("noisy", &[120.0, 150.0, 100.0, 145.0, 110.0]),
+            ("slower", &[120.0, 121.0, 119.0, 120.0, 120.0]),
+            ("faster", &[80.0, 81.0, 79.0, 80.0, 80.0]),
+            ("new", &[1.0]),
+        ]);
+        let verdicts: Vec<(String, Verdict)> = compare(&base, &head, 5.0)
+            .into_iter()
+            .map(|c| (c.name, c.verdict))
+            .collect();
+        assert_eq!(
+            verdicts,
+            vec![
+                ("faster".to_owned(), Verdict::Improvement),
+                ("new".to_owned(), Verdict::Added),
+                ("noisy".to_owned(), Verdict::Unchanged),
+                ("old".to_owned(), Verdict::Removed),
+                ("slower".to_owned(), Verdict::Regression),
+            ]
+        );
+
+        let report = render_report("`origin/main`", 5.0, &compare(&base, &head, 5.0));
+        assert!(report.starts_with("### Benchmarks compared to `origin/main`\n"));
+        assert!(report.contains("| `slower` | 100 | 120 | +20.00% | regression |\n"));
+        assert!(report.contains("| `new` | - | 1 | - | added |\n"));
+    }
+}
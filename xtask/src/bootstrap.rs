@@ -12,89 +12,876 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::error::Error;
+use std::fmt::Write;
 use std::path::Path;
+use std::path::PathBuf;
 
 use colored::Colorize;
 use dialoguer::Confirm;
-use dialoguer::Input;
+use dialoguer::MultiSelect;
+use dialoguer::Select;
+use serde_json::json;
 use toml_edit::DocumentMut;
+use xtask_bootstrap::BootstrapPlan;
+use xtask_bootstrap::FileChange;
+use xtask_bootstrap::Journal;
+use xtask_bootstrap::Progress;
+use xtask_bootstrap::Task;
+use xtask_bootstrap::create_symlink;
+use xtask_bootstrap::journal;
+use xtask_bootstrap::journal::Step;
+use xtask_bootstrap::rename;
+use xtask_bootstrap::rename::CrateRename;
 
+/// Prints the progress like `print!`, to stderr while stdout carries the JSON summary.
+macro_rules! progress {
+    ($($arg:tt)*) => {
+        if summary::on_stdout() {
+            eprint!($($arg)*)
+        } else {
+            print!($($arg)*)
+        }
+    };
+}
+
+/// Prints the progress like `println!`, to stderr while stdout carries the JSON summary.
+macro_rules! progressln {
+    ($($arg:tt)*) => {
+        if summary::on_stdout() {
+            eprintln!($($arg)*)
+        } else {
+            println!($($arg)*)
+        }
+    };
+}
+
+/// Translates a message of the wizard into the selected language, then fills in its `{name}`
+/// placeholders like `format!` would, from the variable of that name or the value given for it.
+macro_rules! tr {
+    (@value $name:ident) => {
+        $name
+    };
+    (@value $name:ident = $value:expr) => {
+        $value
+    };
+    ($message:literal $(,)?) => {
+        i18n::tr($message).to_owned()
+    };
+    ($message:literal, $($name:ident $(= $value:expr)?),+ $(,)?) => {
+        i18n::tr($message)
+            $(.replace(concat!("{", stringify!($name), "}"), &tr!(@value $name $(= $value)?).to_string()))+
+    };
+}
+
+mod answers;
+mod audit;
+mod badges;
+mod benchmarks;
+mod choice;
+mod ci;
+mod cleanup;
+mod community;
+mod components;
+mod conflict;
+mod edits;
+mod errors;
+mod export;
+mod features;
+mod github;
+mod harness;
+mod i18n;
+mod kind;
+mod manifest;
+mod observability;
+mod owners;
+mod restricted_names;
+mod runtime;
+mod summary;
+mod tool_config;
+mod usage;
+mod variant;
+
+use answers::Answers;
+use answers::resolve_answers;
+use badges::BADGES;
+use badges::Badge;
+use benchmarks::Benchmarks;
+use ci::Ci;
+use community::COMMUNITY_FILES;
+use community::CommunityFile;
+use components::COMPONENTS;
+use components::Component;
+use conflict::Resolution;
+use edits::Edit;
+use edits::planned_edits;
+use errors::ErrorHandling;
+use features::FeatureFlags;
+use kind::KINDS;
+use kind::Kind;
+use observability::Observability;
+use runtime::Runtime;
+use usage::USAGE_FILES;
+use usage::UsageFile;
+
+use super::changelog;
+use super::find_cargo;
+use super::find_command;
+use super::history;
+use super::lock;
+use super::metadata;
+use super::remove_crate;
+use super::scan;
+use super::split;
+use super::tasks::make_shell_cmd;
 use super::workspace_dir;
 
-pub fn bootstrap(cleanup: bool) {
-    if cleanup {
+/// The name of the xtask package and its `.cargo/config.toml` alias shipped with the template.
+const DEFAULT_XTASK_ALIAS: &str = "x";
+
+/// The template crate, which the members named `template-*` are prefixed with.
+const TEMPLATE_CRATE: &str = "template";
+
+/// Files naming the template package as plain text, which are rewritten to the project name
+/// along with the Rust sources of the template crate; manifests are updated structurally.
+const PACKAGE_FILES: &[&str] = &["Cargo.lock"];
+
+/// The directories of examples, benches, and integration tests, whose Rust sources are renamed
+/// like those of the template crate wherever they are, since they may use it.
+const TARGET_DIRS: &[&str] = &["examples", "benches", "tests"];
+
+/// The GitHub Actions workflows, which name the template package in commands like
+/// `cargo test -p template` and in paths, and are renamed like the package files.
+const WORKFLOWS_DIR: &str = ".github/workflows/";
+
+/// Returns whether only the copyright notice of `file` is rewritten, since it is build output or
+/// one of the xtask sources, which define the placeholders themselves.
+fn is_skipped(file: &str) -> bool {
+    file.starts_with("target/") || scan::TOOLING_DIRS.iter().any(|dir| file.starts_with(dir))
+}
+
+/// Built-in cargo commands and their short forms, which an alias cannot shadow.
+const CARGO_BUILTIN_COMMANDS: &[&str] = &[
+    "add",
+    "b",
+    "bench",
+    "build",
+    "c",
+    "check",
+    "clean",
+    "clippy",
+    "config",
+    "d",
+    "doc",
+    "fetch",
+    "fix",
+    "fmt",
+    "generate-lockfile",
+    "help",
+    "info",
+    "init",
+    "install",
+    "locate-project",
+    "login",
+    "logout",
+    "metadata",
+    "new",
+    "owner",
+    "package",
+    "pkgid",
+    "publish",
+    "r",
+    "read-manifest",
+    "remove",
+    "report",
+    "run",
+    "rustc",
+    "rustdoc",
+    "search",
+    "t",
+    "test",
+    "tree",
+    "uninstall",
+    "update",
+    "vendor",
+    "verify-project",
+    "version",
+    "yank",
+];
+
+/// How to run the bootstrap, as given on the command line.
+pub struct BootstrapOptions {
+    pub cleanup: bool,
+    pub undo: bool,
+    pub allow_dirty: bool,
+    pub force: bool,
+    pub commit: bool,
+    pub reset_history: bool,
+    pub default_branch: String,
+    pub create_repo: bool,
+    pub out: Option<PathBuf>,
+    pub diff: bool,
+    pub dry_run: bool,
+    pub project_name: Option<String>,
+    pub github_account: Option<String>,
+    pub xtask_alias: Option<String>,
+    pub no_xtask: bool,
+    pub yes: bool,
+    pub config: Option<PathBuf>,
+    pub offline: bool,
+    pub no_verify: bool,
+    pub template: Option<String>,
+    pub placeholders: Vec<String>,
+    pub kind: Option<String>,
+    pub edition: Option<String>,
+    pub rust_version: Option<String>,
+    pub ci: Option<String>,
+    pub async_runtime: Option<String>,
+    pub error_handling: Option<String>,
+    pub feature_flags: Option<String>,
+    pub observability: Option<String>,
+    pub benchmarks: Option<String>,
+    pub usage_files: Option<String>,
+    pub without: Option<String>,
+    pub without_badges: Option<String>,
+    pub license: Option<String>,
+    pub copyright_holder: Option<String>,
+    pub community_files: Option<String>,
+    pub maintainer_contact: Option<String>,
+    pub security_email: Option<String>,
+    pub maintainers: Option<String>,
+    pub funding: Option<String>,
+    pub description: Option<String>,
+    pub homepage: Option<String>,
+    pub documentation: Option<String>,
+    pub authors: Option<String>,
+    pub keywords: Option<String>,
+    pub categories: Option<String>,
+    pub format: String,
+    pub summary_file: Option<PathBuf>,
+    pub log_file: Option<PathBuf>,
+    pub lang: Option<String>,
+}
+
+/// Bootstraps the project in place, or into `out` leaving this checkout untouched.
+///
+/// With `diff`, the changes are previewed and files or whole directories that were already
+/// customized by hand can be deselected before confirming. With `dry_run`, the changes are
+/// printed as unified diffs and nothing is written. Inputs given as options or in the `config`
+/// file are not prompted for, and with `yes` or `config` nothing is: missing inputs without a
+/// default fail the command. Unless `offline`, the availability of the project name on crates.io
+/// is checked before confirming, and the bootstrapped workspace is checked to build, unless
+/// `no_verify`. An in-place bootstrap requires a clean git working tree unless
+/// `allow_dirty`, and is committed afterwards with `commit` or once confirmed; `reset_history`
+/// replaces the template's history with a new repository on `default_branch` first, and
+/// `create_repo` creates the GitHub repository and pushes the commit to it. Every step of
+/// an in-place bootstrap is recorded in `.bootstrap-journal.json`, from which an interrupted
+/// bootstrap is resumed when run again, and which `undo` replays backwards to restore the template.
+/// With the `json` format or a `summary_file`, a summary of what was done is written to it or
+/// else stdout. Unless `dry_run`, the answers, file changes, and commands of the bootstrap are
+/// logged to `log_file`, or else `bootstrap.log` at the project root. The prompts and messages
+/// are in `lang`, or else the language of the locale.
+pub fn bootstrap(options: BootstrapOptions) {
+    i18n::select(options.lang.as_deref());
+    if options.format == "json" || options.summary_file.is_some() {
+        summary::start(options.summary_file.clone());
+    }
+    if !options.cleanup && !options.undo && !options.dry_run {
+        let root = options.out.as_deref().unwrap_or(workspace_dir());
+        audit::start(
+            options
+                .log_file
+                .clone()
+                .unwrap_or_else(|| root.join(audit::LOG_FILE)),
+        );
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        audit::record("started", format!("{args:?}"));
+        if let Ok(revision) = git(workspace_dir(), &["rev-parse", "HEAD"]) {
+            audit::record("template", revision.trim());
+        }
+    }
+    if options.cleanup {
         cleanup_bootstrap();
+    } else if options.undo {
+        undo_bootstrap();
     } else {
-        bootstrap_project();
+        bootstrap_project(&options);
     }
+    summary::finish("success");
+    audit::finish("success");
 }
 
-fn bootstrap_project() {
-    println!("\n{}", "🚀 Starting project bootstrap...".yellow().bold());
+/// The description of the template crate, in its manifest and crate docs.
+const TEMPLATE_DESCRIPTION: &str = "A template library.";
 
-    let project_name = get_valid_input(
-        "Enter your project name (e.g., my-awesome-project)",
-        parse_project_name,
-    );
-    let github_username = get_valid_input(
-        "Enter your GitHub username (e.g., tisonkun)",
-        parse_github_username,
+/// The tagline of the template README, replaced by the project description.
+const README_DESCRIPTION: &str =
+    "Use this repository as a GitHub template to quickly start a new Rust project.";
+
+/// A license the project can be bootstrapped with.
+struct License {
+    /// The value of `--license`.
+    id: &'static str,
+    /// The SPDX expression of the manifests, which also names the built-in license header.
+    spdx: &'static str,
+    /// The license as named in the README, which links to `url` as `[license-url]`.
+    readme_name: &'static str,
+    url: &'static str,
+}
+
+/// The supported licenses, starting with the license of the template.
+const LICENSES: &[License] = &[
+    License {
+        id: "apache-2.0",
+        spdx: "Apache-2.0",
+        readme_name: "[Apache License, Version 2.0][license-url]",
+        url: "https://www.apache.org/licenses/LICENSE-2.0",
+    },
+    License {
+        id: "mit",
+        spdx: "MIT",
+        readme_name: "the [MIT License][license-url]",
+        url: "https://opensource.org/licenses/MIT",
+    },
+    License {
+        id: "mit-or-apache-2.0",
+        spdx: "MIT OR Apache-2.0",
+        readme_name: "either the [MIT License](LICENSE-MIT) or the [Apache License, Version 2.0](LICENSE-APACHE), at your option",
+        url: "#license",
+    },
+    License {
+        id: "mpl-2.0",
+        spdx: "MPL-2.0",
+        readme_name: "[Mozilla Public License, Version 2.0][license-url]",
+        url: "https://www.mozilla.org/MPL/2.0/",
+    },
+];
+
+/// The MIT license text, with the `{year}` and `{owner}` of the copyright notice to fill in.
+const MIT_LICENSE: &str = include_str!("bootstrap/licenses/MIT.txt");
+
+const MPL_LICENSE: &str = include_str!("bootstrap/licenses/MPL-2.0.txt");
+
+/// The keys of a `bootstrap --config` file, which answer the bootstrap prompts.
+const CONFIG_KEYS: &[&str] = &[
+    "project-name",
+    "github-account",
+    "xtask-alias",
+    "template",
+    "kind",
+    "edition",
+    "rust-version",
+    "ci",
+    "async-runtime",
+    "error-handling",
+    "feature-flags",
+    "observability",
+    "benchmarks",
+    "usage-files",
+    "without",
+    "without-badges",
+    "description",
+    "homepage",
+    "documentation",
+    "license",
+    "copyright-holder",
+    "community-files",
+    "maintainer-contact",
+    "security-email",
+    "maintainers",
+    "funding",
+    "authors",
+    "keywords",
+    "categories",
+];
+
+/// The hooks a `--config` file may declare in its `[hooks]` table.
+const HOOK_KEYS: &[&str] = &["post_bootstrap"];
+
+/// The settings read from a `--config` file.
+#[derive(Default)]
+struct Config {
+    /// The answers to the prompts, by the name of their option.
+    answers: BTreeMap<String, String>,
+    /// The shell commands run in the bootstrapped project once every change is applied.
+    post_bootstrap: Vec<String>,
+    /// The values of the placeholders of the template variant, by key.
+    placeholders: BTreeMap<String, String>,
+}
+
+/// The editions the template's code compiles with, the default first, and the first Rust
+/// version supporting each.
+const EDITIONS: &[(&str, &str)] = &[("2024", "1.85.0"), ("2021", "1.56.0")];
+
+/// Bootstraps the project: resolves the answers, plans the changes and applies them once
+/// confirmed, then verifies that the workspace builds and publishes it.
+fn bootstrap_project(options: &BootstrapOptions) {
+    let out = options.out.as_deref();
+    if let Some(out) = out {
+        let is_empty = std::fs::read_dir(out).map_or(true, |mut entries| entries.next().is_none());
+        if !is_empty {
+            fail(&tr!(
+                "output directory '{out}' is not empty",
+                out = out.display()
+            ));
+        }
+    }
+
+    let config = match &options.config {
+        Some(path) => load_config(path).unwrap_or_else(|err| fail(&err)),
+        None => Config::default(),
+    };
+    let unattended = options.yes || options.config.is_some();
+    if out.is_none() && Journal::exists(workspace_dir()) {
+        resume_bootstrap(unattended);
+        return;
+    }
+    if out.is_some() && options.create_repo && !options.reset_history {
+        fail(&tr!(
+            "--create-repo with --out needs --reset-history to create a repository to push"
+        ));
+    }
+    if out.is_none() && !options.dry_run && !options.allow_dirty {
+        ensure_clean_tree(workspace_dir());
+    }
+
+    progressln!(
+        "\n{}",
+        tr!("🚀 Starting project bootstrap...").yellow().bold()
     );
+    let files =
+        workspace_files().unwrap_or_else(|err| fail(&tr!("failed to list files: {err}", err)));
+    let answers = resolve_answers(options, &config, &files, unattended);
+    let Answers {
+        project_name,
+        github_username,
+        xtask_alias,
+        ..
+    } = &answers;
 
-    let confirmation = Confirm::new()
-        .with_prompt(
-            format!("Bootstrap project '{project_name}' for user '{github_username}'?")
-                .blue()
-                .to_string(),
-        )
-        .default(false)
-        .interact()
-        .unwrap();
+    let crate_name = answers.kind.crate_name(project_name);
+    let crates = template_renames(&workspace_members(), project_name, &crate_name);
+    let mut new_packages = BTreeSet::new();
+    for rename in &crates {
+        if !new_packages.insert(&rename.new_package) {
+            fail(&tr!(
+                "more than one member would be renamed to '{package}'",
+                package = rename.new_package,
+            ));
+        }
+    }
+    let (mut edits, mut file_changes, mut renames) = planned_bootstrap(&files, &answers, &crates);
+    let names: Vec<String> = files.iter().map(|(file, _)| file.clone()).collect();
+    for (from, to) in &renames {
+        if conflict::existing_files(to, &names).is_empty() {
+            continue;
+        }
+        let Some(resolution) = resolve_rename_conflict(to, options.force, unattended) else {
+            progressln!("\n{}", tr!("Cancelled.").yellow());
+            summary::set("outcome", "cancelled");
+            audit::discard();
+            return;
+        };
+        let bootstrapped = |name: &str| bootstrapped_content(&files, &edits, name);
+        let changes =
+            conflict::planned_files(from, to, resolution, &names, &file_changes, bootstrapped);
+        add_changes(&mut file_changes, changes);
+    }
+    // a file the kind, a component, the harness removal or a rename conflict removes is not
+    // edited first
+    edits.retain(|edit| {
+        !file_changes
+            .iter()
+            .any(|change| matches!(change, FileChange::Remove { file } if *file == edit.file))
+    });
+    if options.diff {
+        select_changes(&mut edits, &file_changes, &mut renames);
+    }
+    if options.dry_run {
+        progressln!("\n{}", tr!("Dry run: no files will be written.").yellow());
+    }
+    // the changes are reviewed before confirming, unless they were already selected
+    if options.dry_run || !(unattended || options.diff) {
+        print_changes(out, &edits, &file_changes, &renames);
+    }
+    print_crate_name_status(project_name, options.offline);
+    print_github_status(github_username, project_name, options.offline);
+    if options.dry_run {
+        let edited = edits
+            .iter()
+            .filter(|edit| edit_diff(edit).is_some())
+            .map(|edit| FileChange::Write {
+                file: edit.file.clone(),
+                content: String::new(),
+            });
+        record_changes(&edited.chain(file_changes).collect::<Vec<_>>(), &renames);
+        summary::set("outcome", "dry-run");
+        return;
+    }
+
+    let prompt = tr!(
+        "Bootstrap project '{project_name}' for user '{github_username}' with `cargo {xtask_alias}`?",
+        project_name,
+        github_username,
+        xtask_alias,
+    );
+    let confirmation = unattended
+        || Confirm::new()
+            .with_prompt(prompt.blue().to_string())
+            .default(false)
+            .interact()
+            .unwrap();
+    audit::record("confirm", format!("{prompt} {confirmation}"));
 
     if !confirmation {
-        println!("\n{}", "Cancelled.".yellow());
+        progressln!("\n{}", tr!("Cancelled.").yellow());
+        summary::set("outcome", "cancelled");
+        audit::discard();
         return;
     }
 
-    println!("\n{}", "Bootstrapping...".cyan());
-    execute_bootstrap(&project_name, &github_username);
+    progressln!("\n{}", tr!("Bootstrapping...").cyan());
+    let root = match out {
+        Some(out) => {
+            print_task(tr!("Copying the template to {out}...", out = out.display()));
+            if print_update_result(copy_template(out)).is_err() {
+                remove_copy(out);
+                fail(&tr!("bootstrap failed; the partial copy was removed"));
+            }
+            out
+        }
+        None => workspace_dir(),
+    };
+    summary::set("root", root.display().to_string());
+    if execute_bootstrap(root, &edits, &file_changes, &renames).is_err() {
+        if let Some(out) = out {
+            remove_copy(out);
+        }
+        fail(&tr!("bootstrap failed; all changes were rolled back"));
+    }
+    if out.is_some() || !answers.xtask {
+        // a copy is undone by deleting it, and without the xtask there is nothing to undo with
+        let _ = std::fs::remove_file(root.join(journal::JOURNAL_FILE));
+    }
+    if !options.no_verify {
+        verify_bootstrap(root, out, &answers, options.offline);
+    }
+    // the hooks of the variant set the project up, before those of the config refine it
+    let hooks: Vec<String> = answers
+        .variant
+        .iter()
+        .flat_map(|variant| &variant.post_bootstrap)
+        .chain(&config.post_bootstrap)
+        .cloned()
+        .collect();
+    run_post_bootstrap_hooks(root, &hooks);
+    publish_project(root, options, &answers, unattended);
+    print_next_steps(out, &answers);
+}
 
-    println!("\n{}", "🎉 Bootstrap complete!".green().bold());
-    println!(
+/// Checks that the workspace bootstrapped in `root` builds, failing with how to revert it
+/// otherwise.
+fn verify_bootstrap(root: &Path, out: Option<&Path>, answers: &Answers, offline: bool) {
+    // the lockfile is renamed along with the packages, but lacks the dependencies the
+    // other kinds add and still has the xtask's
+    let locked = answers.kind == Kind::Lib
+        && answers.xtask
+        && answers.runtime == Runtime::None
+        && answers.errors == ErrorHandling::None
+        && answers.feature_flags == FeatureFlags::None
+        && answers.observability == Observability::None
+        && answers.benchmarks == Benchmarks::None
+        && answers.variant.is_none();
+    if print_update_result(verify_workspace(root, locked, offline)).is_err() {
+        let revert = match out {
+            Some(out) => tr!("remove {out}", out = out.display()),
+            None if !answers.xtask => tr!("revert it with git"),
+            None => tr!(
+                "revert it with `cargo {xtask_alias} bootstrap --undo`",
+                xtask_alias = answers.xtask_alias
+            ),
+        };
+        fail(&tr!(
+            "the bootstrapped workspace does not build; fix it, or {revert}",
+            revert
+        ));
+    }
+}
+
+/// Commits the project bootstrapped in `root` and points `origin` to its GitHub repository, as
+/// the options or the prompt ask, then creates the repository with `--create-repo`.
+fn publish_project(root: &Path, options: &BootstrapOptions, answers: &Answers, unattended: bool) {
+    let Answers {
+        project_name,
+        github_username,
+        ..
+    } = answers;
+    let origin = format!("git@github.com:{github_username}/{project_name}.git");
+    if options.reset_history {
+        reset_history(root, &options.default_branch);
+        commit_project(root, project_name, &origin);
+    } else if options.out.is_none() {
+        let commit = options.commit
+            || options.create_repo
+            || (!unattended
+                && Confirm::new()
+                    .with_prompt(
+                        tr!(
+                            "Commit the changes and set the origin remote to {origin}?",
+                            origin
+                        )
+                        .blue()
+                        .to_string(),
+                    )
+                    .default(true)
+                    .interact()
+                    .unwrap());
+        audit::record("confirm", format!("Commit the changes? {commit}"));
+        if commit {
+            commit_project(root, project_name, &origin);
+        }
+    }
+    if options.create_repo {
+        let repository = github::Repository {
+            account: github_username,
+            name: project_name,
+            description: &answers.description,
+            keywords: &answers.keywords,
+        };
+        github::create_repository(root, &repository);
+        summary::set(
+            "repository",
+            format!("https://github.com/{github_username}/{project_name}"),
+        );
+    }
+}
+
+/// Prints where the bootstrapped project is and what to do with it next.
+fn print_next_steps(out: Option<&Path>, answers: &Answers) {
+    progressln!("\n{}", tr!("🎉 Bootstrap complete!").green().bold());
+    if let Some(file) = audit::file() {
+        progressln!(
+            "   {}: {}",
+            tr!("The bootstrap is logged to").dimmed(),
+            file.display().to_string().cyan().bold(),
+        );
+    }
+    if let Some(out) = out {
+        progressln!(
+            "   {}: {}",
+            tr!("The project was created in").dimmed(),
+            out.display().to_string().cyan().bold(),
+        );
+    } else if answers.xtask {
+        progressln!(
+            "   {}: {}",
+            tr!("Revert it with").dimmed(),
+            format!("cargo {} bootstrap --undo", answers.xtask_alias)
+                .cyan()
+                .bold(),
+        );
+    }
+    // without the xtask, the template machinery is already gone
+    if !answers.xtask {
+        return;
+    }
+    if answers.license.id != LICENSES[0].id {
+        progressln!(
+            "   {}: {}",
+            tr!("Update the license headers with").dimmed(),
+            format!("cargo {} lint --fix", answers.xtask_alias)
+                .cyan()
+                .bold(),
+        );
+    }
+    progressln!(
         "   {}: {}",
-        "You can now delete this script".dimmed(),
-        "cargo x bootstrap --cleanup".cyan().bold(),
+        tr!("You can now delete this script").dimmed(),
+        format!("cargo {} bootstrap --cleanup", answers.xtask_alias)
+            .cyan()
+            .bold(),
+    );
+}
+
+/// Resumes an interrupted bootstrap from its journal, since planning it again against the files
+/// it already updated would go wrong.
+fn resume_bootstrap(unattended: bool) {
+    let root = workspace_dir();
+    let mut journal = Journal::load(root).unwrap_or_else(|err| fail(&err.to_string()));
+    if journal.is_complete() {
+        fail(&tr!(
+            "the project was already bootstrapped; undo it with `bootstrap --undo` to start over, or finish with `bootstrap --cleanup`"
+        ));
+    }
+
+    let total = journal.changes.len() + journal.renames.len();
+    progressln!(
+        "\n{}",
+        tr!(
+            "⏯️  Found an interrupted bootstrap, {done} of {total} step(s) done.",
+            done = journal.steps().len(),
+            total,
+        )
+        .yellow()
+        .bold()
+    );
+    let confirmation = unattended
+        || Confirm::new()
+            .with_prompt(tr!("Resume the remaining steps?").blue().to_string())
+            .default(true)
+            .interact()
+            .unwrap();
+    audit::record(
+        "confirm",
+        format!("Resume the remaining steps? {confirmation}"),
     );
+    if !confirmation {
+        progressln!(
+            "\n{} {}",
+            tr!("Cancelled.").yellow(),
+            tr!("Run `bootstrap --undo` to start over instead.")
+        );
+        summary::set("outcome", "cancelled");
+        audit::discard();
+        return;
+    }
+
+    progressln!("\n{}", tr!("Bootstrapping...").cyan());
+    if journal.resume(&mut Reporter).is_err() {
+        fail(&tr!("bootstrap failed; all changes were rolled back"));
+    }
+    report_journal(&journal);
+    progressln!("\n{}", tr!("🎉 Bootstrap complete!").green().bold());
+}
+
+fn undo_bootstrap() {
+    progressln!("\n{}", tr!("⏪ Undoing the bootstrap...").yellow().bold());
+    let journal = Journal::load(workspace_dir()).unwrap_or_else(|err| fail(&err.to_string()));
+    if let Err(err) = journal.undo(&mut Reporter) {
+        fail(&tr!("{err}; fix them and run the undo again", err));
+    }
+    progressln!("\n{}", tr!("⏪ Bootstrap undone!").green().bold());
 }
 
 fn cleanup_bootstrap() {
-    println!("\n{}", "🧹 Starting bootstrap cleanup...".yellow().bold());
+    progressln!("\n{}", "🧹 Starting bootstrap cleanup...".yellow().bold());
+    remove_journal();
     remove_ci_workflows();
-    override_bootstrap_file();
+    remove_placeholder_code();
+    remove_bootstrap_command();
+    move_engine_modules();
+    remove_bootstrap_members();
     cleanup_cargo_toml();
-    println!("\n{}", "🧹 Bootstrap cleanup complete!".green().bold());
+    if workspace_dir().join("Cargo.lock").exists() {
+        progressln!("Pruning Cargo.lock...");
+        lock::prune_lockfile(false);
+    }
+    progressln!("\n{}", "🧹 Bootstrap cleanup complete!".green().bold());
+}
+
+/// Removes the journal, since the bootstrap can no longer be undone without this script.
+fn remove_journal() {
+    let journal = workspace_dir().join(journal::JOURNAL_FILE);
+    if journal.exists() {
+        progressln!("Removing the bootstrap journal...");
+        std::fs::remove_file(journal).unwrap();
+    }
 }
 
 fn remove_ci_workflows() {
+    // a project bootstrapped for another CI system has no workflows left
+    if !workspace_dir().join(WORKFLOWS_DIR).exists() {
+        return;
+    }
     let ci_bootstrap = workspace_dir().join(".github/workflows/ci-bootstrap.yml");
     if ci_bootstrap.exists() {
-        println!("Removing CI Bootstrap workflows...");
+        progressln!("Removing CI Bootstrap workflows...");
         std::fs::remove_file(ci_bootstrap).unwrap();
     } else {
-        panic!("Broken bootstrap cleanup state: '.github/workflows/ci-bootstrap.yml' not found");
+        fail("Broken bootstrap cleanup state: '.github/workflows/ci-bootstrap.yml' not found");
     }
 }
 
-fn override_bootstrap_file() {
-    let old_bootstrap_file = workspace_dir().join("xtask/src/bootstrap.rs");
-    let new_bootstrap_file = workspace_dir().join("xtask/src/bootstrap-done.rs");
-    if new_bootstrap_file.exists() {
-        println!("Overriding bootstrap file...");
-        std::fs::rename(new_bootstrap_file, old_bootstrap_file).unwrap();
-    } else {
-        panic!("Broken bootstrap cleanup state: 'bootstrap-done.rs' not found");
+/// Removes the members only the bootstrap uses: the `fast-template` bootstrapper, which creates
+/// projects from the template and is of no use to them, and the `xtask-bootstrap` engine.
+fn remove_bootstrap_members() {
+    let cargo_toml = workspace_dir().join("Cargo.toml");
+    let content = std::fs::read_to_string(&cargo_toml).unwrap();
+    let mut doc = content.parse::<DocumentMut>().unwrap();
+    for member in cleanup::BOOTSTRAP_MEMBERS {
+        let dir = workspace_dir().join(member);
+        if !dir.exists() {
+            continue;
+        }
+        progressln!("Removing the {member} member...");
+        std::fs::remove_dir_all(dir).unwrap();
+        remove_crate::update_workspace_manifest(&mut doc, member, member);
+    }
+    std::fs::write(&cargo_toml, doc.to_string()).unwrap();
+}
+
+/// Moves the modules of the `xtask-bootstrap` engine that the xtask keeps using into its sources,
+/// so that the engine goes with the other bootstrap members.
+fn move_engine_modules() {
+    let engine = workspace_dir().join("xtask-bootstrap/src");
+    if !engine.exists() {
+        return;
+    }
+    progressln!("Moving the rename engine into the xtask...");
+    let src = workspace_dir().join("xtask/src");
+    for module in cleanup::ENGINE_MODULES {
+        let file = format!("{module}.rs");
+        std::fs::rename(engine.join(&file), src.join(&file)).unwrap();
+    }
+    let main_rs = src.join("main.rs");
+    let content = std::fs::read_to_string(&main_rs).unwrap();
+    std::fs::write(&main_rs, cleanup::declare_engine_modules(&content)).unwrap();
+    let mut files = vec![];
+    metadata::collect_sources(&src, &[], &mut files);
+    for path in files {
+        let content = std::fs::read_to_string(&path).unwrap();
+        let updated = cleanup::import_engine_modules(&content);
+        if updated != content {
+            std::fs::write(&path, updated).unwrap();
+        }
+    }
+}
+
+/// Removes the template's placeholder function from the Rust sources of the project, along with
+/// the calls the generated binaries make to it.
+fn remove_placeholder_code() {
+    let mut files = vec![];
+    // the xtask sources spell out the placeholder code they remove
+    metadata::collect_sources(workspace_dir(), &[".git", "target", "xtask"], &mut files);
+    for path in files {
+        let content = std::fs::read_to_string(&path).unwrap();
+        let updated = cleanup::remove_placeholder_code(&content);
+        if updated != content {
+            let file = path.strip_prefix(workspace_dir()).unwrap_or(&path);
+            progressln!("Removing the placeholder code of {}...", file.display());
+            std::fs::write(&path, updated).unwrap();
+        }
+    }
+}
+
+/// Removes the bootstrap command from the xtask, with this script and the modules only it uses.
+fn remove_bootstrap_command() {
+    let main_rs = workspace_dir().join("xtask/src/main.rs");
+    let content = std::fs::read_to_string(&main_rs).unwrap();
+    let updated = cleanup::remove_bootstrap_command(&content).unwrap_or_else(|err| {
+        fail(&format!(
+            "Broken bootstrap cleanup state: 'xtask/src/main.rs': {err}"
+        ))
+    });
+    progressln!("Removing the bootstrap command...");
+    std::fs::write(&main_rs, updated).unwrap();
+    let src = workspace_dir().join("xtask/src");
+    for module in cleanup::BOOTSTRAP_MODULES {
+        std::fs::remove_file(src.join(format!("{module}.rs"))).unwrap();
     }
+    std::fs::remove_dir_all(src.join("bootstrap")).unwrap();
 }
 
 fn cleanup_cargo_toml() {
@@ -103,13 +890,23 @@ fn cleanup_cargo_toml() {
     let content = std::fs::read_to_string(&cargo_toml).unwrap();
     let mut doc = content.parse::<DocumentMut>().unwrap();
     if let Some(dependencies) = doc.get_mut("dependencies").and_then(|d| d.as_table_mut()) {
-        println!("Removing unnecessary dependencies...");
-        dependencies.remove("toml_edit");
+        progressln!("Removing unnecessary dependencies...");
         dependencies.remove("colored");
         dependencies.remove("dialoguer");
+        dependencies.remove("xtask-bootstrap");
+        // only the bootstrap snapshots its output
+        if let Some(dev_dependencies) = doc
+            .get_mut("dev-dependencies")
+            .and_then(|d| d.as_table_mut())
+        {
+            dev_dependencies.remove("insta");
+            if dev_dependencies.is_empty() {
+                doc.remove("dev-dependencies");
+            }
+        }
         std::fs::write(&cargo_toml, doc.to_string()).unwrap();
     } else {
-        panic!("Broken bootstrap cleanup state: 'dependencies' section not found");
+        fail("Broken bootstrap cleanup state: 'dependencies' section not found");
     }
 }
 
@@ -124,17 +921,17 @@ pub fn parse_project_name(name: &str) -> Result<String, String> {
     let name = name.trim();
 
     if name.is_empty() {
-        return Err("project name cannot be empty".into());
+        return Err(tr!("project name cannot be empty"));
     }
 
     let mut chars = name.chars();
     if let Some(ch) = chars.next() {
         if ch.is_ascii_digit() {
-            return Err(format!("the name cannot start with a digit: '{}'", ch));
+            return Err(tr!("the name cannot start with a digit: '{ch}'", ch));
         }
         if !(ch.is_ascii_alphabetic() || ch == '_') {
-            return Err(format!(
-                "the first character must be a letter or `_`, found: '{}'",
+            return Err(tr!(
+                "the first character must be a letter or `_`, found: '{ch}'",
                 ch
             ));
         }
@@ -142,167 +939,2910 @@ pub fn parse_project_name(name: &str) -> Result<String, String> {
 
     for ch in chars {
         if !(ch.is_ascii_alphanumeric() || ch == '-' || ch == '_') {
-            return Err(format!(
-                "invalid character '{}': only letters, numbers, `-`, or `_` are allowed",
+            return Err(tr!(
+                "invalid character '{ch}': only letters, numbers, `-`, or `_` are allowed",
                 ch
             ));
         }
     }
 
+    restricted_names::check(name)?;
     Ok(name.to_owned())
 }
 
+/// Returns a valid project name close to the rejected `name`, if there is one: its words in
+/// lowercase and joined by `-`, with a leading number moved to the end, e.g., `My Project!`
+/// becomes `my-project` and `123-api` becomes `api-123`.
+pub fn suggest_project_name(name: &str) -> Option<String> {
+    let mut words: Vec<String> = name
+        .split(|ch: char| !ch.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect();
+    let leading_numbers = words
+        .iter()
+        .take_while(|word| word.starts_with(|ch: char| ch.is_ascii_digit()))
+        .count();
+    words.rotate_left(leading_numbers);
+
+    let mut suggestion = words.join("-");
+    suggestion.truncate(restricted_names::MAX_NAME_LENGTH);
+    let mut suggestion = suggestion.trim_end_matches('-').to_owned();
+    if restricted_names::check(&suggestion).is_err() {
+        suggestion.push_str("-rs");
+    }
+    (suggestion != name.trim() && parse_project_name(&suggestion).is_ok()).then_some(suggestion)
+}
+
+/// Offers the suggestion for the rejected project `name`, which a single keypress accepts, or
+/// adds it to the error `e` when unattended.
+fn offer_project_name(name: &str, e: String, unattended: bool) -> Result<String, String> {
+    let Some(suggestion) = suggest_project_name(name) else {
+        return Err(e);
+    };
+    if unattended {
+        return Err(tr!("{e}; did you mean '{suggestion}'?", e, suggestion));
+    }
+    let accepted = Confirm::new()
+        .with_prompt(
+            tr!("{e}; use '{suggestion}' instead?", e, suggestion)
+                .blue()
+                .to_string(),
+        )
+        .default(true)
+        .interact()
+        .unwrap();
+    if accepted { Ok(suggestion) } else { Err(e) }
+}
+
+/// Validates a GitHub user or organization name: at most 39 letters, digits, or single hyphens,
+/// neither starting nor ending with a hyphen.
 pub fn parse_github_username(account_name: &str) -> Result<String, String> {
     let account_name = account_name.trim();
     if account_name.is_empty() {
-        return Err("GitHub account name cannot be empty".into());
+        return Err(tr!("GitHub account name cannot be empty"));
+    }
+    if account_name.len() > 39 {
+        return Err(tr!(
+            "GitHub account names are at most 39 characters, found {count}",
+            count = account_name.len()
+        ));
+    }
+    if let Some(ch) = account_name
+        .chars()
+        .find(|ch| !(ch.is_ascii_alphanumeric() || *ch == '-'))
+    {
+        return Err(tr!(
+            "invalid character '{ch}': only letters, numbers, or `-` are allowed",
+            ch
+        ));
+    }
+    if account_name.starts_with('-') || account_name.ends_with('-') {
+        return Err(tr!("GitHub account names cannot start or end with `-`"));
+    }
+    if account_name.contains("--") {
+        return Err(tr!("GitHub account names cannot contain consecutive `-`"));
     }
     Ok(account_name.to_owned())
 }
 
-fn get_valid_input<F>(prompt: &str, validator: F) -> String
-where
-    F: Fn(&str) -> Result<String, String>,
-{
-    loop {
-        let input: String = Input::new().with_prompt(prompt).interact_text().unwrap();
-        match validator(&input) {
-            Ok(value) => return value,
-            Err(e) => eprintln!("{}", format!("ERROR: {e}").red()),
-        }
+/// Validates the alias used to invoke the xtask binary, which doubles as its package name.
+pub fn parse_xtask_alias(alias: &str) -> Result<String, String> {
+    let alias = parse_project_name(alias)?;
+    if CARGO_BUILTIN_COMMANDS.contains(&alias.as_str()) {
+        return Err(tr!(
+            "'{alias}' is a built-in cargo command and cannot be used as an alias",
+            alias
+        ));
     }
+    Ok(alias)
 }
 
-fn execute_bootstrap(project_name: &str, github_username: &str) {
-    update_readme(project_name, github_username);
-    update_root_cargo_toml(project_name, github_username);
-    update_template_cargo_toml(project_name);
-    update_semantic_yml(project_name, github_username);
-    update_cargo_lock(project_name);
-    update_project_dir(project_name);
+/// Validates a license, given as its `--license` value or SPDX expression.
+pub fn parse_license(license: &str) -> Result<String, String> {
+    let license = license.trim();
+    LICENSES
+        .iter()
+        .find(|l| l.id.eq_ignore_ascii_case(license) || l.spdx.eq_ignore_ascii_case(license))
+        .map(|l| l.id.to_owned())
+        .ok_or_else(|| {
+            tr!(
+                "unknown license '{license}', expected one of {expected}",
+                license,
+                expected = license_ids().join(", "),
+            )
+        })
 }
 
-fn replace_in_file(file: &Path, old: &str, new: &str) -> Result<(), Box<dyn Error>> {
-    let content = std::fs::read_to_string(file)?;
+/// Validates a project kind, given as its `--kind` value.
+pub fn parse_kind(kind: &str) -> Result<String, String> {
+    let kind = kind.trim();
+    Kind::from_id(kind)
+        .map(|kind| kind.id().to_owned())
+        .ok_or_else(|| {
+            tr!(
+                "unknown project kind '{kind}', expected one of {expected}",
+                kind,
+                expected = kind_ids().join(", "),
+            )
+        })
+}
 
-    if !content.contains(old) {
-        return Ok(());
+/// Validates a template variant, one of `variants` unless given as `name@git-ref` to read it from
+/// another revision, or `none`.
+pub fn parse_template(template: &str, variants: &[String]) -> Result<String, String> {
+    let template = template.trim();
+    if template.eq_ignore_ascii_case("none") {
+        return Ok("none".to_owned());
+    }
+    let (name, git_ref) = match template.split_once('@') {
+        Some((name, git_ref)) => (name, Some(git_ref)),
+        None => (template, None),
+    };
+    let is_name = !name.is_empty()
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_');
+    // a variant of another revision is only known once read from it
+    let is_known = match git_ref {
+        Some(git_ref) => !git_ref.is_empty(),
+        None => variants.iter().any(|variant| variant == name),
+    };
+    if !is_name || !is_known {
+        return Err(tr!(
+            "unknown template variant '{name}', expected one of {expected} or none",
+            name,
+            expected = variants.join(", "),
+        ));
     }
-    let content = content.replace(old, new);
+    Ok(template.to_owned())
+}
 
-    std::fs::write(file, content)?;
-    Ok(())
+/// Validates a Rust edition the template's code compiles with.
+pub fn parse_edition(edition: &str) -> Result<String, String> {
+    let edition = edition.trim();
+    if edition_ids().contains(&edition) {
+        Ok(edition.to_owned())
+    } else {
+        Err(tr!(
+            "unsupported edition '{edition}', expected one of {expected}",
+            edition,
+            expected = edition_ids().join(", "),
+        ))
+    }
 }
 
-fn print_task(task: impl AsRef<str>) {
-    print!("{:.<60}", task.as_ref());
+/// Validates a `rust-version` of at least `minimum`, given as `major.minor` or
+/// `major.minor.patch`.
+pub fn parse_rust_version(version: &str, minimum: &str) -> Result<String, String> {
+    let version = version.trim();
+    let parsed = parse_version(version).ok_or_else(|| {
+        tr!(
+            "invalid Rust version '{version}', expected e.g. '1.85' or '1.85.0'",
+            version
+        )
+    })?;
+    if parse_version(minimum).is_some_and(|minimum| parsed < minimum) {
+        return Err(tr!(
+            "Rust {version} is older than {minimum}, which the template and edition need",
+            version,
+            minimum
+        ));
+    }
+    Ok(version.to_owned())
 }
 
-fn print_update_result(result: Result<(), Box<dyn Error>>) {
-    match result {
-        Ok(_) => println!("{}", "[OK]".green()),
-        Err(e) => println!("{}", format!("[ERROR] {e}").red()),
+/// Parses a `major.minor` or `major.minor.patch` version, with a missing patch as 0.
+fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let parts = version
+        .split('.')
+        .map(|part| part.parse().ok())
+        .collect::<Option<Vec<u64>>>()?;
+    match parts[..] {
+        [major, minor] => Some((major, minor, 0)),
+        [major, minor, patch] => Some((major, minor, patch)),
+        _ => None,
     }
 }
 
-fn update_readme(project_name: &str, github_username: &str) {
-    let file = workspace_dir().join("README.md");
-    print_task(format!("Updating {}...", file.display()));
-    let result = replace_in_file(
-        &file,
-        "fast/template",
-        &format!("{}/{}", github_username, project_name),
-    )
-    .and_then(|_| replace_in_file(&file, "${projectName}", project_name));
-    print_update_result(result);
+/// Validates a comma-separated list of usage files, given as their `--usage-files` values, or
+/// `none`.
+pub fn parse_usage_files(files: &str) -> Result<String, String> {
+    if files.trim().eq_ignore_ascii_case("none") {
+        return Ok(String::new());
+    }
+    let mut ids = vec![];
+    for id in split_list(files) {
+        let usage = UsageFile::from_id(&id).ok_or_else(|| {
+            tr!(
+                "unknown usage file '{id}', expected one of {expected} or none",
+                id,
+                expected = usage_file_ids().join(", "),
+            )
+        })?;
+        ids.push(usage.id);
+    }
+    Ok(ids.join(", "))
 }
 
-fn update_root_cargo_toml(project_name: &str, github_username: &str) {
-    let file = workspace_dir().join("Cargo.toml");
-    print_task(format!("Updating {}...", file.display()));
-    let result = replace_in_file(
-        &file,
-        "fast/template",
-        &format!("{}/{}", github_username, project_name),
-    )
-    .and_then(|_| replace_in_file(&file, "template", project_name));
+/// Validates a comma-separated list of optional components, given as their `--without` values.
+pub fn parse_components(components: &str) -> Result<String, String> {
+    let mut ids = vec![];
+    for id in split_list(components) {
+        let component = Component::from_id(&id).ok_or_else(|| {
+            tr!(
+                "unknown component '{id}', expected one of {expected}",
+                id,
+                expected = component_ids().join(", "),
+            )
+        })?;
+        ids.push(component.id);
+    }
+    Ok(ids.join(", "))
+}
 
-    print_update_result(result);
+/// Validates a comma-separated list of community health files, given as their
+/// `--community-files` values, or `none`.
+pub fn parse_community_files(files: &str) -> Result<String, String> {
+    if files.trim().eq_ignore_ascii_case("none") {
+        return Ok(String::new());
+    }
+    let mut ids = vec![];
+    for id in split_list(files) {
+        let file = CommunityFile::from_id(&id).ok_or_else(|| {
+            tr!(
+                "unknown community health file '{id}', expected one of {expected} or none",
+                id,
+                expected = community_file_ids().join(", "),
+            )
+        })?;
+        ids.push(file.id);
+    }
+    Ok(ids.join(", "))
 }
 
-fn update_template_cargo_toml(project_name: &str) {
-    let file = workspace_dir().join("template/Cargo.toml");
-    print_task(format!("Updating {}...", file.display()));
-    let result = replace_in_file(&file, "template", project_name);
-    print_update_result(result);
+/// Validates where conduct violations are reported, an email address or a URL.
+pub fn parse_maintainer_contact(contact: &str) -> Result<String, String> {
+    let contact = contact.trim();
+    if contact.is_empty() {
+        return Err(tr!("maintainer contact cannot be empty"));
+    }
+    if contact.contains(char::is_whitespace) {
+        return Err(tr!("maintainer contact must be an email address or a URL"));
+    }
+    Ok(contact.to_owned())
 }
 
-fn update_semantic_yml(project_name: &str, github_username: &str) {
-    let file = workspace_dir().join(".github/semantic.yml");
-    print_task(format!("Updating {}...", file.display()));
-    let result = replace_in_file(
-        &file,
-        "fast/template",
-        &format!("{}/{}", github_username, project_name),
-    );
-    print_update_result(result);
+/// Validates a comma-separated list of README badges, which may be empty.
+pub fn parse_badges(badges: &str) -> Result<String, String> {
+    let mut ids = vec![];
+    for id in split_list(badges) {
+        let badge = Badge::from_id(&id).ok_or_else(|| {
+            tr!(
+                "unknown badge '{id}', expected one of {expected}",
+                id,
+                expected = badge_ids().join(", "),
+            )
+        })?;
+        ids.push(badge.id());
+    }
+    Ok(ids.join(", "))
+}
+
+/// Validates the email address where vulnerabilities are reported, which may be empty.
+pub fn parse_security_email(email: &str) -> Result<String, String> {
+    let email = email.trim();
+    if email.is_empty() {
+        return Ok(String::new());
+    }
+    let valid = email.split_once('@').is_some_and(|(local, domain)| {
+        !local.is_empty() && domain.contains('.') && !domain.contains('@')
+    });
+    if !valid || email.contains(char::is_whitespace) {
+        return Err(tr!("'{email}' is not an email address", email));
+    }
+    Ok(email.to_owned())
 }
 
-fn update_cargo_lock(project_name: &str) {
-    let file = workspace_dir().join("Cargo.lock");
-    print_task(format!("Updating {}...", file.display()));
-    let result = replace_in_file(&file, "template", project_name);
-    print_update_result(result);
+/// Validates a comma-separated list of GitHub handles, of users or of `org/team` teams, with or
+/// without their leading `@`.
+pub fn parse_maintainers(maintainers: &str) -> Result<String, String> {
+    let mut handles = vec![];
+    for handle in split_list(maintainers) {
+        let handle = handle.strip_prefix('@').unwrap_or(&handle);
+        let parsed = match handle.split_once('/') {
+            Some((org, team)) => {
+                let valid_team = !team.is_empty()
+                    && team
+                        .chars()
+                        .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_'));
+                if !valid_team {
+                    return Err(tr!("invalid team '{handle}'", handle));
+                }
+                format!("{}/{team}", parse_github_username(org)?)
+            }
+            None => parse_github_username(handle)?,
+        };
+        handles.push(parsed);
+    }
+    if handles.is_empty() {
+        return Err(tr!("at least one maintainer is required"));
+    }
+    Ok(handles.join(", "))
 }
 
-fn update_project_dir(project_name: &str) {
-    print_task(format!(
-        "Renaming directory \"template\" to \"{project_name}\" ..."
-    ));
-    let template_dir = Path::new(env!("CARGO_WORKSPACE_DIR")).join("template");
-    let target_dir = Path::new(env!("CARGO_WORKSPACE_DIR")).join(project_name);
-    let result = if target_dir.exists() {
-        Err(format!("Directory '{project_name}' already exists").into())
-    } else {
-        std::fs::rename(template_dir, target_dir).map_err(|e| e.into())
+/// Validates a comma-separated list of sponsorship accounts as `platform:account`, where the
+/// platform is a key of `FUNDING.yml`, e.g., `github:octocat` or `custom:https://example.com`.
+pub fn parse_funding(funding: &str) -> Result<String, String> {
+    let mut accounts: Vec<(String, String)> = vec![];
+    for item in split_list(funding) {
+        let Some((platform, account)) = item.split_once(':') else {
+            return Err(tr!("expected platform:account, found '{item}'", item));
+        };
+        let (platform, account) = (platform.trim().to_ascii_lowercase(), account.trim());
+        if !owners::FUNDING_PLATFORMS.contains(&platform.as_str()) {
+            return Err(tr!(
+                "unknown funding platform '{platform}', expected one of {expected}",
+                platform,
+                expected = owners::FUNDING_PLATFORMS.join(", "),
+            ));
+        }
+        if account.is_empty() {
+            return Err(tr!("the {platform} account cannot be empty", platform));
+        }
+        let listed = owners::LIST_PLATFORMS.contains(&platform.as_str());
+        if !listed && accounts.iter().any(|(p, _)| *p == platform) {
+            return Err(tr!("{platform} takes a single account", platform));
+        }
+        accounts.push((platform, account.to_owned()));
+    }
+    let items: Vec<String> = accounts
+        .iter()
+        .map(|(platform, account)| format!("{platform}:{account}"))
+        .collect();
+    Ok(items.join(", "))
+}
+
+/// Validates the copyright holder named in the license headers.
+pub fn parse_copyright_holder(holder: &str) -> Result<String, String> {
+    let holder = holder.trim();
+    if holder.is_empty() {
+        return Err(tr!("copyright holder cannot be empty"));
+    }
+    if holder.contains(['\n', '\r']) {
+        return Err(tr!("copyright holder must be a single line"));
+    }
+    Ok(holder.to_owned())
+}
+
+/// Validates the one-line description of the project.
+pub fn parse_description(description: &str) -> Result<String, String> {
+    let description = description.trim();
+    if description.is_empty() {
+        return Err(tr!("description cannot be empty"));
+    }
+    if description.contains(['\n', '\r']) {
+        return Err(tr!("description must be a single line"));
+    }
+    Ok(description.to_owned())
+}
+
+/// Validates a homepage or documentation URL, which must be an `http` or `https` URL.
+pub fn parse_url(url: &str) -> Result<String, String> {
+    let url = url.trim();
+    let Some(rest) = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+    else {
+        return Err(tr!("URL must start with https:// or http://"));
     };
-    print_update_result(result);
+    if rest.is_empty() || url.contains(char::is_whitespace) {
+        return Err(tr!("'{url}' is not a valid URL", url));
+    }
+    Ok(url.to_owned())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Validates a comma-separated list of authors, e.g., `Jane Doe <jane@example.com>`.
+pub fn parse_authors(authors: &str) -> Result<String, String> {
+    let authors = split_list(authors);
+    if authors.iter().any(|author| author.contains(['\n', '\r'])) {
+        return Err(tr!("authors must be on a single line"));
+    }
+    Ok(authors.join(", "))
+}
 
-    #[test]
-    fn test_parse_project_name() {
-        // valid names
-        assert_eq!(parse_project_name("myproject"), Ok("myproject".into()));
-        assert_eq!(parse_project_name("my-project"), Ok("my-project".into()));
-        assert_eq!(parse_project_name("my_project"), Ok("my_project".into()));
-        assert_eq!(parse_project_name("project123"), Ok("project123".into()));
-        assert_eq!(parse_project_name("_private"), Ok("_private".into()));
-        assert_eq!(parse_project_name("MyProject"), Ok("MyProject".into()));
-        assert_eq!(parse_project_name("  myproject  "), Ok("myproject".into()));
+/// Validates a comma-separated list of keywords by the rules of crates.io: at most five, each at
+/// most 20 characters of letters, numbers, `_`, `-`, or `+`, starting with a letter or number.
+pub fn parse_keywords(keywords: &str) -> Result<String, String> {
+    let keywords = split_list(keywords);
+    if keywords.len() > 5 {
+        return Err(tr!(
+            "at most 5 keywords are allowed, found {count}",
+            count = keywords.len()
+        ));
+    }
+    for keyword in &keywords {
+        if keyword.len() > 20 {
+            return Err(tr!(
+                "keyword '{keyword}' is longer than 20 characters",
+                keyword
+            ));
+        }
+        let mut chars = keyword.chars();
+        let valid = chars.next().is_some_and(|ch| ch.is_ascii_alphanumeric())
+            && chars.all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '_' | '-' | '+'));
+        if !valid {
+            return Err(tr!(
+                "invalid keyword '{keyword}': only letters, numbers, `_`, `-`, or `+` are allowed, starting with a letter or number",
+                keyword
+            ));
+        }
+    }
+    Ok(keywords.join(", "))
+}
 
-        // invalid names
-        assert!(parse_project_name("").is_err());
-        assert!(parse_project_name("   ").is_err());
-        assert!(parse_project_name("123project").is_err());
-        assert!(parse_project_name("-project").is_err());
-        assert!(parse_project_name("my@project").is_err());
-        assert!(parse_project_name("my project").is_err());
-        assert!(parse_project_name("my.project").is_err());
+/// Validates a comma-separated list of at most five crates.io category slugs, e.g.,
+/// `development-tools::testing`.
+///
+/// See also: <https://crates.io/category_slugs>
+pub fn parse_categories(categories: &str) -> Result<String, String> {
+    let categories = split_list(categories);
+    if categories.len() > 5 {
+        return Err(tr!(
+            "at most 5 categories are allowed, found {count}",
+            count = categories.len()
+        ));
+    }
+    for category in &categories {
+        let valid = category.split("::").all(|slug| {
+            !slug.is_empty()
+                && slug
+                    .chars()
+                    .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '-')
+        });
+        if !valid {
+            return Err(tr!(
+                "invalid category '{category}': expected a slug like `development-tools::testing`",
+                category
+            ));
+        }
     }
+    Ok(categories.join(", "))
+}
 
-    #[test]
-    fn test_parse_github_username() {
-        // valid accounts
-        assert_eq!(parse_github_username("my-user"), Ok("my-user".into()));
-        assert_eq!(parse_github_username("my-org"), Ok("my-org".into()));
-        assert_eq!(parse_github_username("  my-user  "), Ok("my-user".into()));
+/// Splits a comma-separated list, dropping empty items.
+fn split_list(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
 
-        // invalid accounts
-        assert!(parse_github_username("").is_err());
-        assert!(parse_github_username("   ").is_err());
+fn kind_ids() -> Vec<&'static str> {
+    KINDS.iter().map(|kind| kind.id()).collect()
+}
+
+fn edition_ids() -> Vec<&'static str> {
+    EDITIONS.iter().map(|(id, _)| *id).collect()
+}
+
+fn usage_file_ids() -> Vec<&'static str> {
+    USAGE_FILES.iter().map(|usage| usage.id).collect()
+}
+
+fn community_file_ids() -> Vec<&'static str> {
+    COMMUNITY_FILES.iter().map(|file| file.id).collect()
+}
+
+fn badge_ids() -> Vec<&'static str> {
+    BADGES.iter().map(|badge| badge.id()).collect()
+}
+
+fn component_ids() -> Vec<&'static str> {
+    COMPONENTS.iter().map(|component| component.id).collect()
+}
+
+fn license_ids() -> Vec<&'static str> {
+    LICENSES.iter().map(|l| l.id).collect()
+}
+
+fn find_license(id: &str) -> &'static License {
+    LICENSES.iter().find(|l| l.id == id).unwrap()
+}
+
+/// Loads the answers of a `bootstrap --config` file, by key.
+fn load_config(path: &Path) -> Result<Config, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    parse_config(&content).map_err(|err| format!("invalid {}: {err}", path.display()))
+}
+
+fn parse_config(content: &str) -> Result<Config, String> {
+    let doc: DocumentMut = content.parse().map_err(|err| format!("{err}"))?;
+    let mut config = Config::default();
+    for (key, item) in doc.iter() {
+        if key == "hooks" {
+            config.post_bootstrap = parse_hooks(item)?;
+            continue;
+        }
+        if key == "placeholders" {
+            config.placeholders = parse_placeholders(item)?;
+            continue;
+        }
+        if !CONFIG_KEYS.contains(&key) {
+            return Err(format!(
+                "unknown key '{key}', expected one of {CONFIG_KEYS:?}, [hooks] or [placeholders]"
+            ));
+        }
+        // lists are given as arrays of strings, or as comma-separated strings like their options
+        let value = match item.as_array() {
+            Some(array) => string_array(array).map(|values| values.join(", ")),
+            None => item.as_str().map(str::to_owned),
+        };
+        let value =
+            value.ok_or_else(|| format!("'{key}' must be a string or an array of strings"))?;
+        config.answers.insert(key.to_owned(), value);
+    }
+    Ok(config)
+}
+
+/// Parses the `[hooks]` table, returning the `post_bootstrap` commands.
+fn parse_hooks(item: &toml_edit::Item) -> Result<Vec<String>, String> {
+    let hooks = item
+        .as_table_like()
+        .ok_or("'hooks' must be a table".to_owned())?;
+    let mut post_bootstrap = vec![];
+    for (key, item) in hooks.iter() {
+        if !HOOK_KEYS.contains(&key) {
+            return Err(format!(
+                "unknown hook '{key}', expected one of {HOOK_KEYS:?}"
+            ));
+        }
+        post_bootstrap = item
+            .as_array()
+            .and_then(string_array)
+            .ok_or_else(|| format!("'hooks.{key}' must be an array of commands"))?;
+    }
+    Ok(post_bootstrap)
+}
+
+/// Parses the `[placeholders]` table, returning the values of the variant placeholders by key.
+fn parse_placeholders(item: &toml_edit::Item) -> Result<BTreeMap<String, String>, String> {
+    let placeholders = item
+        .as_table_like()
+        .ok_or("'placeholders' must be a table".to_owned())?;
+    placeholders
+        .iter()
+        .map(|(key, item)| {
+            let value = item
+                .as_str()
+                .ok_or_else(|| format!("'placeholders.{key}' must be a string"))?;
+            Ok((key.to_owned(), value.to_owned()))
+        })
+        .collect()
+}
+
+fn string_array(array: &toml_edit::Array) -> Option<Vec<String>> {
+    array
+        .iter()
+        .map(|value| value.as_str().map(str::to_owned))
+        .collect()
+}
+
+/// Checks that the bootstrapped workspace at `root` builds, so that a botched rename shows now
+/// rather than at the first build.
+fn verify_workspace(root: &Path, locked: bool, offline: bool) -> Result<(), Box<dyn Error>> {
+    print_task(tr!("Checking the workspace builds..."));
+    let mut cmd = find_cargo();
+    cmd.current_dir(root)
+        .args(["check", "--workspace", "--quiet"]);
+    if locked {
+        cmd.arg("--locked");
+    }
+    if offline {
+        cmd.arg("--offline");
+    }
+    audit::command(&cmd);
+    let output = cmd.output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("{cmd:?} failed:\n{}", stderr.trim_end()).into());
+    }
+    Ok(())
+}
+
+/// Runs the post-bootstrap `hooks` in `root` in order, stopping at the first that fails, which
+/// fails the command but keeps the bootstrap, since it is complete.
+fn run_post_bootstrap_hooks(root: &Path, hooks: &[String]) {
+    for hook in hooks {
+        progressln!("\n{}", tr!("Running `{hook}`...", hook).cyan());
+        let mut cmd = make_shell_cmd(hook);
+        cmd.current_dir(root);
+        // the output of a hook is progress too
+        if summary::on_stdout() {
+            cmd.stdout(std::io::stderr());
+        }
+        audit::command(&cmd);
+        let error = match cmd.status() {
+            Ok(status) if status.success() => None,
+            Ok(status) => Some(tr!("`{hook}` failed: {status}", hook, status)),
+            Err(err) => Some(tr!("failed to run `{hook}`: {err}", hook, err)),
+        };
+        if let Some(error) = error {
+            fail(&tr!(
+                "{error}; the bootstrap itself is complete, run the remaining hooks by hand",
+                error
+            ));
+        }
+        summary::push("hooks", hook.as_str());
+    }
+}
+
+/// Returns how to resolve the conflict of a directory renamed to the existing `to`: overwriting it
+/// with `force`, or as chosen from a menu, or `None` to abort. Unattended, the conflict fails
+/// the bootstrap without `force`.
+fn resolve_rename_conflict(to: &str, force: bool, unattended: bool) -> Option<Resolution> {
+    let resolution = if force {
+        Some(Resolution::Overwrite)
+    } else if unattended {
+        fail(&tr!(
+            "directory '{to}' already exists; pass --force to overwrite it",
+            to
+        ))
+    } else {
+        let mut items: Vec<&str> = conflict::RESOLUTIONS
+            .iter()
+            .map(|(_, item)| i18n::tr(item))
+            .collect();
+        items.push(i18n::tr("Abort the bootstrap"));
+        let selected = Select::new()
+            .with_prompt(
+                tr!("Directory '{to}' already exists", to)
+                    .blue()
+                    .to_string(),
+            )
+            .items(&items)
+            .default(items.len() - 1)
+            .interact()
+            .unwrap();
+        conflict::RESOLUTIONS
+            .get(selected)
+            .map(|(resolution, _)| *resolution)
+    };
+    audit::record("conflict", format!("{to}/: {resolution:?}"));
+    resolution
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("{}", tr!("ERROR: {message}", message).red());
+    summary::push("errors", message);
+    summary::finish("failure");
+    audit::record("error", message);
+    audit::finish("failure");
+    history::exit(1)
+}
+
+/// Applies the edits, the file changes and the directory renames, recording them as the plan of
+/// a new journal at `root` and undoing every step taken if any fails.
+fn execute_bootstrap(
+    root: &Path,
+    edits: &[Edit],
+    file_changes: &[FileChange],
+    renames: &[(String, String)],
+) -> Result<(), Box<dyn Error>> {
+    let mut plan = BootstrapPlan::new(root);
+    for edit in edits {
+        let content = std::fs::read_to_string(root.join(&edit.file))?;
+        let updated = updated_content(&content, edit);
+        if updated != content {
+            plan.add_change(FileChange::Write {
+                file: edit.file.clone(),
+                content: updated,
+            });
+        }
+    }
+    for change in file_changes {
+        plan.add_change(change.clone());
+    }
+    for (from, to) in renames {
+        plan.add_rename(from, to);
+    }
+    let journal = plan.apply(&mut Reporter)?;
+    report_journal(&journal);
+    Ok(())
+}
+
+/// Records the steps of the complete `journal` and tells how many files they updated.
+fn report_journal(journal: &Journal) {
+    record_changes(&journal.changes, &journal.renames);
+    let files = journal
+        .steps()
+        .iter()
+        .filter(|step| matches!(step, Step::Change { .. }))
+        .count();
+    progressln!("\n{}", tr!("{files} file(s) updated.", files));
+}
+
+/// Records the file changes and directory renames in the JSON summary and the audit log.
+fn record_changes(changes: &[FileChange], renames: &[(String, String)]) {
+    for change in changes {
+        summary::push(
+            "files",
+            json!({ "file": change.file(), "action": change.action() }),
+        );
+        audit::record(change.action(), change.file());
+    }
+    for (from, to) in renames {
+        summary::push("renames", json!({ "from": from, "to": to }));
+        audit::record("rename", format!("{from}/ -> {to}/"));
+    }
+}
+
+fn updated_content(content: &str, edit: &Edit) -> String {
+    let mut content =
+        rename::rename_crates(content, &edit.rename_crates, edit.file.ends_with(".rs"));
+    if let Some(manifest) = &edit.manifest {
+        content = manifest::update_manifest(&content, manifest);
+    }
+    if let Some(config) = &edit.tool_config {
+        content = tool_config::update_tool_config(&edit.file, &content, config);
+    }
+    edit.replacements
+        .iter()
+        .fold(content, |content, (old, new)| content.replace(old, new))
+}
+
+/// Removes everything copied into `out`, which was empty before the bootstrap.
+fn remove_copy(out: &Path) {
+    let Ok(entries) = std::fs::read_dir(out) else {
+        return;
+    };
+    for entry in entries {
+        let path = entry.unwrap().path();
+        let result = if path.is_dir() && !path.is_symlink() {
+            std::fs::remove_dir_all(&path)
+        } else {
+            std::fs::remove_file(&path)
+        };
+        if let Err(err) = result {
+            eprintln!("failed to remove {}: {err}", path.display());
+        }
+    }
+}
+
+/// Returns the file edits, file changes, and directory renames bootstrapping the workspace
+/// `files` with the `answers`, renaming the members named after the template as `crates`.
+///
+/// The files of the template variant are bootstrapped along with those of the template they
+/// replace or join, then written as a whole.
+fn planned_bootstrap(
+    files: &[(String, String)],
+    answers: &Answers,
+    crates: &[CrateRename],
+) -> (Vec<Edit>, Vec<FileChange>, Vec<(String, String)>) {
+    let workspace_names: Vec<String> = files.iter().map(|(file, _)| file.clone()).collect();
+    let overlaid = variant::overlaid_files(files, answers.variant.as_ref());
+    let files = overlaid.as_slice();
+    let mut edits = planned_edits(files, answers, crates);
+    let mut file_changes = planned_license_files(files, answers);
+    file_changes.extend(planned_kind_files(files, &edits, answers));
+    file_changes.extend(planned_error_files(files, &edits, answers));
+    file_changes.extend(planned_bench_files(files, &edits, answers));
+    file_changes.extend(planned_usage_files(files, &edits, answers, crates));
+    file_changes.extend(planned_ci_files(files, &edits, answers));
+    if !answers.xtask {
+        let bootstrapped = |name: &str| bootstrapped_content(files, &edits, name);
+        let names: Vec<String> = files.iter().map(|(file, _)| file.clone()).collect();
+        for change in &mut file_changes {
+            if let FileChange::Write { file, content } = change {
+                if file == ".gitlab-ci.yml" {
+                    *content = harness::plain_commands(content);
+                }
+            }
+        }
+        add_changes(
+            &mut file_changes,
+            harness::planned_files(&names, bootstrapped),
+        );
+    }
+    file_changes.extend(planned_community_files(files, answers));
+    file_changes.extend(planned_owner_files(files, &edits, answers));
+    file_changes.push(FileChange::Write {
+        file: "CHANGELOG.md".to_owned(),
+        content: initial_changelog(&format!(
+            "{}/{}",
+            answers.github_username, answers.project_name
+        )),
+    });
+    // the release workflow may already be removed with the CI system
+    add_changes(&mut file_changes, planned_component_files(files, answers));
+    // the variant takes precedence over the kind, and its files do not exist yet to be edited
+    let bootstrapped = |name: &str| bootstrapped_content(files, &edits, name);
+    let variant_changes =
+        variant::planned_files(answers.variant.as_ref(), &workspace_names, bootstrapped);
+    let is_variant_file = |file: &str| variant_changes.iter().any(|change| change.file() == file);
+    file_changes.retain(|change| !is_variant_file(change.file()));
+    edits.retain(|edit| !is_variant_file(&edit.file));
+    file_changes.extend(variant_changes);
+    let mut renames: Vec<(String, String)> = crates
+        .iter()
+        .filter(|rename| rename.renames_dir())
+        .map(|rename| (rename.dir.clone(), rename.new_dir.clone()))
+        .collect();
+    // a directory nested in another is renamed first
+    renames.sort_by_key(|(dir, _)| std::cmp::Reverse(dir.matches('/').count()));
+    (edits, file_changes, renames)
+}
+
+/// Returns the renames of the workspace `members`, given as their package names and directories
+/// relative to the workspace root, that are named after the template.
+///
+/// The `template` package becomes `crate_name`, and every `template-foo` package becomes
+/// `<project_name>-foo`.
+fn template_renames(
+    members: &[(String, String)],
+    project_name: &str,
+    crate_name: &str,
+) -> Vec<CrateRename> {
+    rename::prefixed_renames(members, TEMPLATE_CRATE, crate_name, project_name)
+}
+
+/// Returns the package names and directories of the workspace members, relative to the workspace
+/// root, as reported by `cargo metadata`.
+fn workspace_members() -> Vec<(String, String)> {
+    let metadata = metadata::cargo_metadata(true);
+    let root = Path::new(metadata["workspace_root"].as_str().unwrap_or_default());
+    metadata::workspace_packages(&metadata)
+        .into_iter()
+        .filter_map(|package| {
+            let manifest = Path::new(package["manifest_path"].as_str()?);
+            let dir = manifest.parent()?.strip_prefix(root).ok()?;
+            let dir = dir.to_string_lossy().replace('\\', "/");
+            Some((package["name"].as_str()?.to_owned(), dir))
+        })
+        .collect()
+}
+
+/// Returns the version of the template crate, which the members generated for the project kind
+/// start at.
+fn template_version(files: &[(String, String)]) -> String {
+    files
+        .iter()
+        .find(|(file, _)| file == "template/Cargo.toml")
+        .and_then(|(_, content)| content.parse::<DocumentMut>().ok())
+        .and_then(|doc| {
+            let version = doc.get("package")?.get("version")?.as_str()?;
+            Some(version.to_owned())
+        })
+        .unwrap_or_else(|| "0.0.1".to_owned())
+}
+
+/// Returns the `[workspace.package]` field `key` of the workspace `manifest`, e.g., its edition.
+fn workspace_package_field(manifest: &str, key: &str) -> Option<String> {
+    let doc = manifest.parse::<DocumentMut>().ok()?;
+    let value = doc.get("workspace")?.get("package")?.get(key)?.as_str()?;
+    Some(value.to_owned())
+}
+
+/// Returns the files generated for the project kind, from the template crate as bootstrapped.
+fn planned_kind_files(
+    files: &[(String, String)],
+    edits: &[Edit],
+    answers: &Answers,
+) -> Vec<FileChange> {
+    let bootstrapped = |name: &str| bootstrapped_content(files, edits, name);
+    let mut changes = kind::planned_files(
+        answers.kind,
+        &answers.project_name,
+        &answers.description,
+        &template_version(files),
+        &bootstrapped("template/src/lib.rs"),
+        &bootstrapped("template/Cargo.toml"),
+        answers.runtime,
+        answers.observability,
+    );
+    // a binary is released with its builds, unless the release workflow is left out
+    let release = format!("{WORKFLOWS_DIR}release.yml");
+    let released = answers.kind == Kind::Bin
+        && answers.ci == Ci::GitHub
+        && !answers.removed_components.iter().any(|c| c.id == "release")
+        && !files.iter().any(|(file, _)| *file == release);
+    if released {
+        let workflow = bootstrapped(&format!("{WORKFLOWS_DIR}ci.yml"));
+        changes.push(FileChange::Write {
+            file: release,
+            content: kind::release_workflow(&answers.project_name, &workflow),
+        });
+    }
+    changes
+}
+
+/// Returns the error module of the library, starting with the license header of its `lib.rs` as
+/// bootstrapped, unless a variant removes the library.
+fn planned_error_files(
+    files: &[(String, String)],
+    edits: &[Edit],
+    answers: &Answers,
+) -> Vec<FileChange> {
+    let lib_rs = "template/src/lib.rs";
+    let removed = answers
+        .variant
+        .as_ref()
+        .is_some_and(|variant| variant.removed.iter().any(|file| file == lib_rs));
+    let crate_path = answers
+        .kind
+        .crate_name(&answers.project_name)
+        .replace('-', "_");
+    match answers.errors.render_module(&crate_path) {
+        Some(module) if !removed => {
+            let lib_rs = bootstrapped_content(files, edits, lib_rs);
+            vec![FileChange::Write {
+                file: "template/src/error.rs".to_owned(),
+                content: format!("{}{module}", split::leading_header(&lib_rs, "//")),
+            }]
+        }
+        _ => vec![],
+    }
+}
+
+/// Returns the bench harness of the template crate, starting with the license header of its
+/// `lib.rs` as bootstrapped.
+fn planned_bench_files(
+    files: &[(String, String)],
+    edits: &[Edit],
+    answers: &Answers,
+) -> Vec<FileChange> {
+    let Some(bench) = answers.benchmarks.render_bench() else {
+        return vec![];
+    };
+    let lib_rs = bootstrapped_content(files, edits, "template/src/lib.rs");
+    vec![FileChange::Write {
+        file: format!("template/benches/{}.rs", benchmarks::BENCH_NAME),
+        content: format!("{}{bench}", split::leading_header(&lib_rs, "//")),
+    }]
+}
+
+/// Returns the files using the public API of the library, starting with the license header of
+/// its `lib.rs` as bootstrapped.
+fn planned_usage_files(
+    files: &[(String, String)],
+    edits: &[Edit],
+    answers: &Answers,
+    crates: &[CrateRename],
+) -> Vec<FileChange> {
+    let names: Vec<String> = files.iter().map(|(file, _)| file.clone()).collect();
+    let lib_rs = bootstrapped_content(files, edits, "template/src/lib.rs");
+    usage::planned_files(
+        &answers.usage_files,
+        &names,
+        &split::leading_header(&lib_rs, "//"),
+        crates,
+    )
+}
+
+/// Returns the files removed and generated for the CI system, from the main workflow as
+/// bootstrapped.
+fn planned_ci_files(
+    files: &[(String, String)],
+    edits: &[Edit],
+    answers: &Answers,
+) -> Vec<FileChange> {
+    let names: Vec<String> = files.iter().map(|(file, _)| file.clone()).collect();
+    let workflow = bootstrapped_content(files, edits, &format!("{WORKFLOWS_DIR}ci.yml"));
+    ci::planned_files(answers.ci, &names, &workflow, &answers.xtask_alias)
+}
+
+/// Returns the code owners and funding files, the latter starting with the license header of
+/// its `.github/` siblings as bootstrapped.
+fn planned_owner_files(
+    files: &[(String, String)],
+    edits: &[Edit],
+    answers: &Answers,
+) -> Vec<FileChange> {
+    let names: Vec<String> = files.iter().map(|(file, _)| file.clone()).collect();
+    let semantic = bootstrapped_content(files, edits, ".github/semantic.yml");
+    owners::planned_files(
+        &names,
+        &answers.maintainers,
+        &answers.funding,
+        &split::leading_header(&semantic, "#"),
+    )
+}
+
+/// Returns a changelog without releases in the format `cargo x changelog release` maintains,
+/// whose `[Unreleased]` link lists the commits of the GitHub `repository`, as `account/name`.
+fn initial_changelog(repository: &str) -> String {
+    format!(
+        "# Changelog
+
+All notable changes to this project will be documented in this file.
+
+The format is based on [Keep a Changelog](https://keepachangelog.com/en/1.1.0/),
+and this project adheres to [Semantic Versioning](https://semver.org/spec/v2.0.0.html).
+
+## [Unreleased]
+
+[Unreleased]: https://github.com/{repository}/commits/HEAD
+"
+    )
+}
+
+/// Returns the community health files written with the project's contacts.
+fn planned_community_files(files: &[(String, String)], answers: &Answers) -> Vec<FileChange> {
+    let names: Vec<String> = files.iter().map(|(file, _)| file.clone()).collect();
+    let repository = format!("{}/{}", answers.github_username, answers.project_name);
+    let contacts = community::Contacts {
+        project_name: &answers.project_name,
+        repository: &repository,
+        xtask_alias: &answers.xtask_alias,
+        maintainer_contact: &answers.maintainer_contact,
+        security_email: &answers.security_email,
+    };
+    community::planned_files(&answers.community_files, &names, &contacts)
+}
+
+/// Adds the `changes` to `file_changes`, except those of files a change is already planned for.
+fn add_changes(file_changes: &mut Vec<FileChange>, changes: Vec<FileChange>) {
+    for change in changes {
+        if !file_changes
+            .iter()
+            .any(|planned| planned.file() == change.file())
+        {
+            file_changes.push(change);
+        }
+    }
+}
+
+/// Returns the files of the optional components removed from the template.
+fn planned_component_files(files: &[(String, String)], answers: &Answers) -> Vec<FileChange> {
+    let names: Vec<String> = files.iter().map(|(file, _)| file.clone()).collect();
+    components::planned_files(&answers.removed_components, &names)
+}
+
+/// Returns the content of the workspace file `name` once `edits` are applied, or an empty
+/// string if there is no such file.
+fn bootstrapped_content(files: &[(String, String)], edits: &[Edit], name: &str) -> String {
+    let content = files
+        .iter()
+        .find(|(file, _)| file == name)
+        .map_or("", |(_, content)| content.as_str());
+    match edits.iter().find(|edit| edit.file == name) {
+        Some(edit) => updated_content(content, edit),
+        None => content.to_owned(),
+    }
+}
+
+/// Returns the current year in UTC.
+fn current_year() -> u64 {
+    changelog::civil_date(changelog::current_days()).0
+}
+
+/// Returns the changes to the license files for the chosen license, whose MIT copyright notice
+/// names the copyright holder and the current year.
+fn planned_license_files(files: &[(String, String)], answers: &Answers) -> Vec<FileChange> {
+    let content = |name: &str| {
+        files
+            .iter()
+            .find(|(file, _)| file == name)
+            .map(|(_, content)| content.as_str())
+    };
+    let mit = MIT_LICENSE
+        .replace("{year}", &answers.year.to_string())
+        .replace("{owner}", &answers.copyright_holder);
+    let write = |file: &str, content: &str| FileChange::Write {
+        file: file.to_owned(),
+        content: content.to_owned(),
+    };
+
+    match answers.license.id {
+        "mit" => vec![write("LICENSE", &mit)],
+        "mpl-2.0" => vec![write("LICENSE", MPL_LICENSE)],
+        "mit-or-apache-2.0" => {
+            let mut changes = vec![];
+            // the Apache license text is the one the template ships with
+            if let Some(apache) = content("LICENSE") {
+                changes.push(write("LICENSE-APACHE", apache));
+            }
+            changes.push(write("LICENSE-MIT", &mit));
+            for file in ["LICENSE", "template/LICENSE"] {
+                changes.push(FileChange::Remove {
+                    file: file.to_owned(),
+                });
+            }
+            for name in ["LICENSE-APACHE", "LICENSE-MIT"] {
+                changes.push(FileChange::Symlink {
+                    file: format!("template/{name}"),
+                    target: format!("../{name}"),
+                });
+            }
+            changes
+        }
+        _ => vec![],
+    }
+}
+
+/// Returns the paths and contents of the text files in this checkout that are not ignored.
+fn workspace_files() -> Result<Vec<(String, String)>, Box<dyn Error>> {
+    let mut files = vec![];
+    for file in list_files()? {
+        let path = workspace_dir().join(&file);
+        let is_regular = std::fs::symlink_metadata(&path).is_ok_and(|m| m.is_file());
+        if !is_regular {
+            continue;
+        }
+        // skip binary or otherwise non-UTF-8 files
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            files.push((file, content));
+        }
+    }
+    // the lockfile is usually ignored, but still names the template package
+    let lockfile = "Cargo.lock";
+    if !files.iter().any(|(file, _)| file == lockfile) {
+        if let Ok(content) = std::fs::read_to_string(workspace_dir().join(lockfile)) {
+            files.push((lockfile.to_owned(), content));
+        }
+    }
+    Ok(files)
+}
+
+/// Fails unless the git working tree at `root` is clean, so that the bootstrap can be reviewed
+/// and committed on its own.
+fn ensure_clean_tree(root: &Path) {
+    let status = git(root, &["status", "--porcelain"])
+        .unwrap_or_else(|err| fail(&tr!("failed to check the working tree: {err}", err)));
+    // the log of an earlier attempt is left behind on purpose
+    let status: Vec<&str> = status
+        .lines()
+        .filter(|line| line.get(3..) != Some(audit::LOG_FILE))
+        .collect();
+    let status = status.join("\n");
+    if !status.is_empty() {
+        fail(&tr!(
+            "the working tree has uncommitted changes; commit or stash them, or pass --allow-dirty\n{status}",
+            status
+        ));
+    }
+}
+
+/// Commits the bootstrapped project at `root` and points the `origin` remote to `origin`.
+///
+/// Failures are reported but not fatal, since the bootstrap itself is complete.
+fn commit_project(root: &Path, project_name: &str, origin: &str) {
+    let message = format!("chore: initialize project as {project_name}");
+    print_task(tr!("Committing the changes..."));
+    let committed = print_update_result(
+        git(root, &["add", "-A"])
+            .and_then(|_| git(root, &["commit", "-q", "-m", &message]))
+            .map(drop),
+    );
+    if committed.is_ok() {
+        summary::set("commit", message);
+    }
+    print_task(tr!("Setting the origin remote..."));
+    let action = match git(root, &["remote", "get-url", "origin"]) {
+        Ok(_) => "set-url",
+        Err(_) => "add",
+    };
+    if print_update_result(git(root, &["remote", action, "origin", origin]).map(drop)).is_ok() {
+        summary::set("origin", origin);
+    }
+}
+
+/// Replaces the git history of `root`, if any, with an empty repository on `branch`.
+fn reset_history(root: &Path, branch: &str) {
+    print_task(tr!("Resetting the git history..."));
+    let git_dir = root.join(".git");
+    // `.git` is a file in worktrees and submodules
+    let removed = if git_dir.is_dir() {
+        std::fs::remove_dir_all(&git_dir)
+    } else if git_dir.exists() {
+        std::fs::remove_file(&git_dir)
+    } else {
+        Ok(())
+    };
+    let result = removed
+        .map_err(Into::into)
+        .and_then(|()| git(root, &["init", "-q", "-b", branch]).map(drop));
+    if print_update_result(result).is_err() {
+        fail(&tr!(
+            "failed to reset the git history; run `git init` to start a new one"
+        ));
+    }
+}
+
+/// Runs git in `root`, returning its output or its error message.
+fn git(root: &Path, args: &[&str]) -> Result<String, Box<dyn Error>> {
+    let mut cmd = find_command("git");
+    cmd.current_dir(root).args(args);
+    audit::command(&cmd);
+    let output = cmd.output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("{cmd:?} failed: {}", stderr.trim()).into());
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+/// Lists the tracked and untracked but not ignored files of this checkout, recursively.
+///
+/// Outside a git checkout, e.g., in a project bootstrapped with `--out` or an unpacked archive,
+/// every file is listed except those in `.git` and `target`.
+fn list_files() -> Result<Vec<String>, Box<dyn Error>> {
+    let root = workspace_dir();
+    if git(root, &["rev-parse", "--is-inside-work-tree"]).is_err() {
+        let mut files = vec![];
+        walk_files(root, root, &mut files)?;
+        files.retain(|file| file != audit::LOG_FILE);
+        files.sort();
+        return Ok(files);
+    }
+    let mut cmd = find_command("git");
+    cmd.args([
+        "ls-files",
+        "-z",
+        "--cached",
+        "--others",
+        "--exclude-standard",
+    ]);
+    audit::command(&cmd);
+    let output = cmd.output()?;
+    if !output.status.success() {
+        return Err(format!("{cmd:?} failed: {}", output.status).into());
+    }
+    // the log of earlier attempts is not part of the template
+    Ok(String::from_utf8(output.stdout)?
+        .split('\0')
+        .filter(|file| !file.is_empty() && *file != audit::LOG_FILE)
+        .map(str::to_owned)
+        .collect())
+}
+
+/// Collects the files under `dir` as paths relative to `root`, except in `.git` and `target`.
+fn walk_files(root: &Path, dir: &Path, files: &mut Vec<String>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if entry.file_type()?.is_dir() {
+            if entry.file_name() != ".git" && entry.file_name() != "target" {
+                walk_files(root, &path, files)?;
+            }
+        } else if let Ok(file) = path.strip_prefix(root) {
+            files.push(file.to_string_lossy().replace('\\', "/"));
+        }
+    }
+    Ok(())
+}
+
+/// Prints the diff of each file, then lets the user deselect files or whole
+/// directories from a tree; deselecting a renamed directory also keeps it from being renamed.
+fn select_changes(
+    edits: &mut Vec<Edit>,
+    file_changes: &[FileChange],
+    renames: &mut Vec<(String, String)>,
+) {
+    let dirs: Vec<String> = renames.iter().map(|(from, _)| format!("{from}/")).collect();
+    let mut files = vec![];
+    for edit in edits.iter() {
+        if let Some(diff) = edit_diff(edit) {
+            print_diff(&diff);
+            files.push(edit.file.as_str());
+        }
+    }
+    print_file_changes(file_changes);
+    if !renames.is_empty() {
+        progressln!();
+    }
+    for ((from, to), dir) in renames.iter().zip(&dirs) {
+        progressln!(
+            "{}",
+            tr!("{from}/ will be renamed to {to}/", from, to).bold()
+        );
+        if !files.iter().any(|file| file.starts_with(dir.as_str())) {
+            files.push(dir);
+        }
+    }
+
+    let items = tree_items(&files);
+    let labels: Vec<String> = items
+        .iter()
+        .map(|path| {
+            let label = tree_label(path);
+            match renames.iter().zip(&dirs).find(|(_, dir)| *dir == path) {
+                Some(((_, to), _)) => tr!("{label} (renamed to {to}/)", label, to),
+                None => label,
+            }
+        })
+        .collect();
+    let selected = MultiSelect::new()
+        .with_prompt(
+            tr!("Select the files to update (space toggles, enter confirms)")
+                .blue()
+                .to_string(),
+        )
+        .items(&labels)
+        .defaults(&vec![true; items.len()])
+        .interact()
+        .unwrap();
+    let deselected: Vec<String> = items
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| !selected.contains(i))
+        .map(|(_, path)| path)
+        .collect();
+    audit::record("deselected", format!("{deselected:?}"));
+
+    let files: Vec<String> = files.into_iter().map(str::to_owned).collect();
+    edits.retain(|edit| files.contains(&edit.file) && !is_deselected(&edit.file, &deselected));
+    renames.retain(|(from, _)| !is_deselected(&format!("{from}/"), &deselected));
+}
+
+/// Prints the crates.io page of the project name and whether the name is available there.
+fn print_crate_name_status(project_name: &str, offline: bool) {
+    let url = format!("https://crates.io/crates/{project_name}");
+    progress!("\n{} {} ", "crates.io:".dimmed(), url.cyan());
+    if offline {
+        progressln!("{}", tr!("[NOT CHECKED]").yellow());
+        return;
+    }
+    match search_crates(project_name) {
+        Ok(response) => {
+            let (taken, similar) = crate_name_status(project_name, &response);
+            if taken {
+                progressln!("{}", tr!("[TAKEN]").red().bold());
+            } else {
+                progressln!("{}", tr!("[AVAILABLE]").green().bold());
+            }
+            if !similar.is_empty() {
+                progressln!(
+                    "   {}: {}",
+                    tr!("Similar existing crates").dimmed(),
+                    similar.join(", ").yellow(),
+                );
+            }
+        }
+        Err(err) => progressln!("{}", tr!("[UNKNOWN] {err}", err).yellow()),
+    }
+}
+
+/// Queries the crates.io search API for crates named like `name`.
+fn search_crates(name: &str) -> Result<serde_json::Value, Box<dyn Error>> {
+    let url = format!("https://crates.io/api/v1/crates?q={name}&per_page=20");
+    match http_get(&url)? {
+        (200, body) => Ok(serde_json::from_slice(&body)?),
+        (status, _) => Err(format!("crates.io responded with HTTP {status}").into()),
+    }
+}
+
+/// Prints whether the GitHub account exists, and warns if the repository already does.
+fn print_github_status(account: &str, project_name: &str, offline: bool) {
+    let url = format!("https://github.com/{account}/{project_name}");
+    progress!("{} {} ", tr!("GitHub:").dimmed(), url.cyan());
+    if offline {
+        progressln!("{}", tr!("[NOT CHECKED]").yellow());
+        return;
+    }
+    let status = |path: &str| http_get(&format!("https://api.github.com/{path}")).map(|r| r.0);
+    match status(&format!("users/{account}")) {
+        Ok(404) => progressln!(
+            "{}",
+            tr!("[ACCOUNT '{account}' NOT FOUND]", account).red().bold()
+        ),
+        Ok(200) => match status(&format!("repos/{account}/{project_name}")) {
+            Ok(200) => {
+                progressln!("{}", tr!("[EXISTS]").yellow().bold());
+                progressln!(
+                    "   {}",
+                    tr!("Make sure it is the repository created from this template.").dimmed()
+                );
+            }
+            Ok(404) => progressln!("{}", tr!("[AVAILABLE]").green().bold()),
+            Ok(code) => progressln!("{}", tr!("[UNKNOWN] HTTP {code}", code).yellow()),
+            Err(err) => progressln!("{}", tr!("[UNKNOWN] {err}", err).yellow()),
+        },
+        Ok(code) => progressln!("{}", tr!("[UNKNOWN] HTTP {code}", code).yellow()),
+        Err(err) => progressln!("{}", tr!("[UNKNOWN] {err}", err).yellow()),
+    }
+}
+
+/// Fetches `url` with curl, returning the HTTP status and the body.
+fn http_get(url: &str) -> Result<(u16, Vec<u8>), Box<dyn Error>> {
+    let mut cmd = find_command("curl");
+    cmd.args(["--silent", "--show-error", "--max-time", "10"])
+        // crates.io and the GitHub API reject requests without a user agent
+        .args(["--user-agent", "xtask-bootstrap"])
+        .args(["--write-out", "\n%{http_code}"])
+        .arg(url);
+    audit::command(&cmd);
+    let output = cmd.output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("could not reach {url}: {}", stderr.trim()).into());
+    }
+    let mut body = output.stdout;
+    let split = body.iter().rposition(|b| *b == b'\n').unwrap_or(0);
+    let status = String::from_utf8_lossy(&body[split..]).trim().parse()?;
+    body.truncate(split);
+    Ok((status, body))
+}
+
+/// Returns whether `name` is taken on crates.io, where `-` and `_` are interchangeable and case
+/// is ignored, and the names of search results that differ from it by at most two edits.
+fn crate_name_status(name: &str, response: &serde_json::Value) -> (bool, Vec<String>) {
+    let normalize = |name: &str| name.to_lowercase().replace('-', "_");
+    let wanted = normalize(name);
+    let mut taken = false;
+    let mut similar = vec![];
+    let crates = response["crates"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    for krate in crates {
+        let Some(other) = krate["name"].as_str() else {
+            continue;
+        };
+        let normalized = normalize(other);
+        if normalized == wanted {
+            taken = true;
+        } else if edit_distance(&normalized, &wanted) <= 2 {
+            similar.push(other.to_owned());
+        }
+    }
+    (taken, similar)
+}
+
+/// Returns the Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != *cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+    row[b.len()]
+}
+
+/// Prints the diff of every edit, the license file changes, and the directory renames, followed
+/// by a summary like `git diff --stat`, without writing anything.
+fn print_changes(
+    out: Option<&Path>,
+    edits: &[Edit],
+    file_changes: &[FileChange],
+    renames: &[(String, String)],
+) {
+    if let Some(out) = out {
+        progressln!(
+            "\n{}",
+            format!("copy the template to {}", out.display()).bold()
+        );
+    }
+    let (mut files, mut insertions, mut deletions) = (file_changes.len(), 0, 0);
+    for edit in edits {
+        if let Some(diff) = edit_diff(edit) {
+            print_diff(&diff);
+            let (added, removed) = diff_stat(&diff);
+            files += 1;
+            insertions += added;
+            deletions += removed;
+        }
+    }
+    print_file_changes(file_changes);
+    if !renames.is_empty() {
+        progressln!();
+    }
+    for (from, to) in renames {
+        progressln!("{}", format!("rename {from}/ -> {to}/").bold());
+    }
+    progressln!(
+        "\n{}",
+        tr!(
+            "{files} file(s) changed, {insertions} insertion(s)(+), {deletions} deletion(s)(-)",
+            files,
+            insertions = insertions.to_string().green(),
+            deletions = deletions.to_string().red(),
+        )
+    );
+}
+
+/// Returns the unified diff of `edit` against this checkout, or `None` if nothing changes.
+fn edit_diff(edit: &Edit) -> Option<String> {
+    let content = std::fs::read_to_string(workspace_dir().join(&edit.file)).unwrap_or_default();
+    let updated = updated_content(&content, edit);
+    unified_diff(&edit.file, &content, &updated)
+}
+
+/// Returns the number of added and removed lines of a unified diff.
+fn diff_stat(diff: &str) -> (usize, usize) {
+    // skip the `---` and `+++` file headers
+    let lines = || diff.lines().skip(2);
+    (
+        lines().filter(|line| line.starts_with('+')).count(),
+        lines().filter(|line| line.starts_with('-')).count(),
+    )
+}
+
+fn print_file_changes(file_changes: &[FileChange]) {
+    if !file_changes.is_empty() {
+        progressln!();
+    }
+    for change in file_changes {
+        progressln!("{}", change.describe().bold());
+    }
+}
+
+fn print_diff(diff: &str) {
+    progressln!();
+    for line in diff.lines() {
+        if line.starts_with("---") || line.starts_with("+++") {
+            progressln!("{}", line.bold());
+        } else if line.starts_with("@@") {
+            progressln!("{}", line.cyan());
+        } else if line.starts_with('-') {
+            progressln!("{}", line.red());
+        } else if line.starts_with('+') {
+            progressln!("{}", line.green());
+        } else {
+            progressln!("{line}");
+        }
+    }
+}
+
+/// Renders a unified diff with three lines of context between `old` and `new`.
+fn unified_diff(path: &str, old: &str, new: &str) -> Option<String> {
+    const CONTEXT: usize = 3;
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    let lines = diff_lines(&old, &new);
+    let changed: Vec<usize> = (0..lines.len()).filter(|&i| lines[i].0 != ' ').collect();
+    if changed.is_empty() {
+        return None;
+    }
+
+    // group changes whose context overlaps into the same hunk
+    let mut hunks: Vec<(usize, usize)> = vec![];
+    for &i in &changed {
+        match hunks.last_mut() {
+            Some((_, end)) if i <= *end + 2 * CONTEXT => *end = i,
+            _ => hunks.push((i, i)),
+        }
+    }
+
+    // the lines of the old or new side among `lines`, which lack the other side's changes
+    let count = |lines: &[(char, &str)], side: char| {
+        lines
+            .iter()
+            .filter(|(tag, _)| *tag == ' ' || *tag == side)
+            .count()
+    };
+    let mut diff = format!("--- a/{path}\n+++ b/{path}\n");
+    for (first, last) in hunks {
+        let start = first.saturating_sub(CONTEXT);
+        let end = (last + CONTEXT).min(lines.len() - 1);
+        let (before, hunk) = (&lines[..start], &lines[start..=end]);
+        writeln!(
+            diff,
+            "@@ -{},{} +{},{} @@",
+            count(before, '-') + 1,
+            count(hunk, '-'),
+            count(before, '+') + 1,
+            count(hunk, '+'),
+        )
+        .unwrap();
+        for (tag, line) in hunk {
+            writeln!(diff, "{tag}{line}").unwrap();
+        }
+    }
+    Some(diff)
+}
+
+/// Returns the lines of `old` and `new` tagged as kept (` `), removed (`-`), or added (`+`),
+/// along their longest common subsequence.
+fn diff_lines<'a>(old: &[&'a str], new: &[&'a str]) -> Vec<(char, &'a str)> {
+    // only the lines between the common prefix and suffix need the quadratic table
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let (old_mid, new_mid) = (
+        &old[prefix..old.len() - suffix],
+        &new[prefix..new.len() - suffix],
+    );
+
+    // lcs[i][j] is the length of the longest common subsequence of old_mid[i..] and new_mid[j..]
+    let mut lcs = vec![vec![0; new_mid.len() + 1]; old_mid.len() + 1];
+    for i in (0..old_mid.len()).rev() {
+        for j in (0..new_mid.len()).rev() {
+            lcs[i][j] = if old_mid[i] == new_mid[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines: Vec<(char, &str)> = old[..prefix].iter().map(|line| (' ', *line)).collect();
+    let (mut i, mut j) = (0, 0);
+    while i < old_mid.len() || j < new_mid.len() {
+        if i < old_mid.len() && j < new_mid.len() && old_mid[i] == new_mid[j] {
+            lines.push((' ', old_mid[i]));
+            i += 1;
+            j += 1;
+        } else if j == new_mid.len() || (i < old_mid.len() && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(('-', old_mid[i]));
+            i += 1;
+        } else {
+            lines.push(('+', new_mid[j]));
+            j += 1;
+        }
+    }
+    lines.extend(old[old.len() - suffix..].iter().map(|line| (' ', *line)));
+    lines
+}
+
+/// Returns `files` and their parent directories, which end with `/`, in tree order.
+fn tree_items(files: &[&str]) -> Vec<String> {
+    let mut items = BTreeSet::new();
+    for file in files {
+        for (i, _) in file.match_indices('/') {
+            items.insert(file[..=i].to_owned());
+        }
+        items.insert((*file).to_owned());
+    }
+    items.into_iter().collect()
+}
+
+/// Renders a tree item as its last component, indented by its depth.
+fn tree_label(path: &str) -> String {
+    let trimmed = path.trim_end_matches('/');
+    let depth = trimmed.matches('/').count();
+    let name = trimmed.rsplit('/').next().unwrap();
+    let suffix = if path.ends_with('/') { "/" } else { "" };
+    format!("{}{name}{suffix}", "  ".repeat(depth))
+}
+
+/// Returns whether `path` or one of its parent directories was deselected.
+fn is_deselected(path: &str, deselected: &[String]) -> bool {
+    deselected
+        .iter()
+        .any(|item| path == item || (item.ends_with('/') && path.starts_with(item.as_str())))
+}
+
+/// Exports the template for `cargo generate` into `out`, replacing an earlier export there, with
+/// the placeholders of cargo-generate in place of the names the bootstrap renames.
+pub fn export_template(out: &Path) {
+    if out.join(export::CARGO_GENERATE_FILE).exists() {
+        std::fs::remove_dir_all(out).unwrap();
+    } else if std::fs::read_dir(out).is_ok_and(|mut entries| entries.next().is_some()) {
+        fail(&tr!(
+            "output directory '{out}' is not empty",
+            out = out.display()
+        ));
+    }
+    let crates = export::crate_renames(&workspace_members());
+    let files = workspace_files().unwrap_or_else(|err| panic!("failed to list files: {err}"));
+    for (file, content) in &files {
+        let target = out.join(export::exported_path(file, &crates));
+        std::fs::create_dir_all(target.parent().unwrap()).unwrap();
+        std::fs::write(target, export::exported_content(file, content, &crates)).unwrap();
+    }
+    for file in list_files().unwrap_or_else(|err| panic!("failed to list files: {err}")) {
+        let source = workspace_dir().join(&file);
+        if source.is_symlink() {
+            let target = out.join(export::exported_path(&file, &crates));
+            std::fs::create_dir_all(target.parent().unwrap()).unwrap();
+            copy_symlink(&source, &target).unwrap();
+        }
+    }
+    std::fs::write(
+        out.join(export::CARGO_GENERATE_FILE),
+        export::CARGO_GENERATE_TOML,
+    )
+    .unwrap();
+    println!(
+        "Exported {} files for cargo-generate to {}",
+        files.len(),
+        out.display()
+    );
+}
+
+/// Copies the tracked and untracked but not ignored files of this checkout into `out`.
+fn copy_template(out: &Path) -> Result<(), Box<dyn Error>> {
+    for file in list_files()? {
+        let source = workspace_dir().join(&file);
+        // skip files deleted from the working tree
+        let Ok(metadata) = std::fs::symlink_metadata(&source) else {
+            continue;
+        };
+        if metadata.is_dir() {
+            continue;
+        }
+        let target = out.join(&file);
+        std::fs::create_dir_all(target.parent().unwrap())?;
+        if metadata.is_symlink() {
+            copy_symlink(&source, &target)?;
+        } else {
+            std::fs::copy(&source, &target)?;
+        }
+    }
+
+    // keep dependency versions when the lockfile is not tracked
+    let lockfile = workspace_dir().join("Cargo.lock");
+    if lockfile.exists() && !out.join("Cargo.lock").exists() {
+        std::fs::copy(lockfile, out.join("Cargo.lock"))?;
+    }
+    Ok(())
+}
+
+fn copy_symlink(source: &Path, target: &Path) -> std::io::Result<()> {
+    create_symlink(&std::fs::read_link(source)?, target)
+}
+
+fn print_task(task: impl AsRef<str>) {
+    progress!("{:.<60}", task.as_ref());
+}
+
+fn print_update_result(result: Result<(), Box<dyn Error>>) -> Result<(), Box<dyn Error>> {
+    Reporter.done(&result);
+    result
+}
+
+/// Reports the steps of the bootstrap like its other tasks.
+struct Reporter;
+
+impl Progress for Reporter {
+    fn task(&mut self, task: Task<'_>) {
+        match task {
+            Task::Update(file) => print_task(tr!("Updating {file}...", file = file.display())),
+            Task::Rename { from, to } => print_task(tr!(
+                "Renaming directory \"{from}\" to \"{to}\" ...",
+                from,
+                to
+            )),
+            Task::Restore(file) => print_task(tr!("Restoring {file}...", file = file.display())),
+            Task::RenameBack { from, to } => print_task(tr!(
+                "Renaming directory \"{to}\" back to \"{from}\"...",
+                from,
+                to
+            )),
+            Task::RollBack => progressln!("\n{}", tr!("Rolling back...").yellow()),
+        }
+    }
+
+    fn done(&mut self, result: &Result<(), Box<dyn Error>>) {
+        match result {
+            Ok(_) => progressln!("{}", tr!("[OK]").green()),
+            Err(e) => {
+                progressln!("{}", tr!("[ERROR] {e}", e).red());
+                summary::push("errors", e.to_string());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_project_name() {
+        // valid names
+        assert_eq!(parse_project_name("myproject"), Ok("myproject".into()));
+        assert_eq!(parse_project_name("my-project"), Ok("my-project".into()));
+        assert_eq!(parse_project_name("my_project"), Ok("my_project".into()));
+        assert_eq!(parse_project_name("project123"), Ok("project123".into()));
+        assert_eq!(parse_project_name("_private"), Ok("_private".into()));
+        assert_eq!(parse_project_name("MyProject"), Ok("MyProject".into()));
+        assert_eq!(parse_project_name("  myproject  "), Ok("myproject".into()));
+
+        // invalid names
+        assert!(parse_project_name("").is_err());
+        assert!(parse_project_name("   ").is_err());
+        assert!(parse_project_name("123project").is_err());
+        assert!(parse_project_name("-project").is_err());
+        assert!(parse_project_name("my@project").is_err());
+        assert!(parse_project_name("my project").is_err());
+        assert!(parse_project_name("my.project").is_err());
+
+        // restricted names
+        assert!(parse_project_name("test").is_err());
+        assert!(parse_project_name("self").is_err());
+        assert!(parse_project_name("con").is_err());
+        assert!(parse_project_name(&"a".repeat(65)).is_err());
+    }
+
+    #[test]
+    fn test_suggest_project_name() {
+        assert_eq!(
+            suggest_project_name("My Project!"),
+            Some("my-project".into())
+        );
+        assert_eq!(suggest_project_name("123-api"), Some("api-123".into()));
+        assert_eq!(suggest_project_name("3d engine"), Some("engine-3d".into()));
+        assert_eq!(
+            suggest_project_name("my.project"),
+            Some("my-project".into())
+        );
+        assert_eq!(suggest_project_name("self"), Some("self-rs".into()));
+        assert_eq!(
+            suggest_project_name("Proc Macro"),
+            Some("proc-macro-rs".into())
+        );
+        assert_eq!(
+            suggest_project_name(&"a ".repeat(40)).map(|s| s.len()),
+            Some(63)
+        );
+
+        // nothing to suggest
+        assert_eq!(suggest_project_name("my-project"), None);
+        assert_eq!(suggest_project_name("123"), None);
+        assert_eq!(suggest_project_name("!!!"), None);
+        assert_eq!(suggest_project_name(""), None);
+    }
+
+    #[test]
+    fn test_parse_config() {
+        let config = parse_config(
+            r#"
+project-name = "my-service"
+keywords = ["fast", "service"]
+
+[hooks]
+post_bootstrap = ["cargo x lint --fix", "./scripts/setup.sh"]
+
+[placeholders]
+port = "3000"
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.answers["project-name"], "my-service");
+        assert_eq!(config.answers["keywords"], "fast, service");
+        assert_eq!(
+            config.post_bootstrap,
+            ["cargo x lint --fix", "./scripts/setup.sh"]
+        );
+        assert_eq!(config.placeholders["port"], "3000");
+        assert!(parse_config("").unwrap().post_bootstrap.is_empty());
+        assert!(parse_config("[placeholders]\nport = 3000").is_err());
+
+        assert!(parse_config("name = \"my-service\"").is_err());
+        assert!(parse_config("hooks = \"cargo x lint\"").is_err());
+        assert!(parse_config("[hooks]\npre_bootstrap = [\"true\"]").is_err());
+        assert!(parse_config("[hooks]\npost_bootstrap = \"cargo x lint\"").is_err());
+    }
+
+    /// Returns the files of this checkout, or `None` once it is bootstrapped and the template
+    /// crate and its variants are gone.
+    fn template_files() -> Option<Vec<(String, String)>> {
+        let files = workspace_files().unwrap();
+        files
+            .iter()
+            .any(|(file, _)| file == "template/Cargo.toml")
+            .then_some(files)
+    }
+
+    #[test]
+    fn test_parse_template() {
+        let variants = ["async-service".to_owned()];
+        assert_eq!(
+            parse_template(" async-service ", &variants),
+            Ok("async-service".to_owned())
+        );
+        assert_eq!(
+            parse_template("async-service@origin/main", &[]),
+            Ok("async-service@origin/main".to_owned())
+        );
+        assert_eq!(parse_template("None", &variants), Ok("none".to_owned()));
+        assert!(parse_template("web-app", &variants).is_err());
+        assert!(parse_template("async-service@", &variants).is_err());
+        assert!(parse_template("../x@main", &variants).is_err());
+    }
+
+    /// Returns the post-bootstrap commands, the edits and the file changes of the bootstrap of
+    /// this workspace into the variant `name`, whose `${port}` placeholder is filled with 3000,
+    /// unless it is bootstrapped already.
+    fn planned_variant_bootstrap(name: &str) -> Option<(Vec<String>, Vec<Edit>, Vec<FileChange>)> {
+        let files = template_files()?;
+        let read = |path: &str| {
+            std::fs::read_to_string(workspace_dir().join(path)).map_err(|err| err.to_string())
+        };
+        let mut variant = variant::load_variant(name, read).unwrap();
+        variant.fill(&[("port".to_owned(), "3000".to_owned())]);
+        let post_bootstrap = variant.post_bootstrap.clone();
+        let mut answers = answers("x", None, "apache-2.0");
+        answers.variant = Some(variant);
+        let crates = template_crates(&answers);
+        let (edits, file_changes, _) = planned_bootstrap(&files, &answers, &crates);
+        Some((post_bootstrap, edits, file_changes))
+    }
+
+    #[test]
+    fn test_planned_bootstrap_with_variants() {
+        type Files<'a> = &'a [(&'a str, &'a str)];
+        let cases: [(&str, &[&str], Files, &[&str]); 5] = [
+            (
+                "async-service",
+                &["cargo fmt --all"],
+                &[
+                    ("template/Cargo.toml", "name = \"demo\""),
+                    ("template/Cargo.toml", "tokio = "),
+                    ("template/src/main.rs", "\"127.0.0.1:3000\""),
+                    ("template/src/main.rs", "`demo` is developed"),
+                ],
+                &[
+                    "template/src/lib.rs",
+                    "templates/async-service/variant.toml",
+                ],
+            ),
+            (
+                "no-std",
+                &[],
+                &[
+                    ("template/Cargo.toml", "name = \"demo\""),
+                    ("template/Cargo.toml", "default = [\"std\"]\n"),
+                    ("template/src/lib.rs", "\n#![no_std]\n"),
+                    ("template/src/lib.rs", "`demo` is developed"),
+                ],
+                &["templates/no-std/variant.toml"],
+            ),
+            (
+                "ffi",
+                &["cargo x cbindgen"],
+                &[
+                    (
+                        "template/Cargo.toml",
+                        "crate-type = [\"cdylib\", \"rlib\"]\n",
+                    ),
+                    ("template/cbindgen.toml", ""),
+                    ("template/src/lib.rs", "pub extern \"C\" fn demo_add("),
+                ],
+                &["templates/ffi/variant.toml"],
+            ),
+            (
+                "python",
+                &[],
+                &[
+                    ("template/Cargo.toml", "pyo3 = "),
+                    ("template/pyproject.toml", "name = \"demo\"\n"),
+                    ("template/src/lib.rs", "fn demo("),
+                ],
+                &["templates/python/variant.toml"],
+            ),
+            (
+                "wasm",
+                &[],
+                &[
+                    ("template/Cargo.toml", "wasm-bindgen = "),
+                    ("template/tests/web.rs", "demo::greet(\"wasm\")"),
+                ],
+                &["templates/wasm/variant.toml"],
+            ),
+        ];
+        for (name, commands, written, removed) in cases {
+            let Some((post_bootstrap, edits, file_changes)) = planned_variant_bootstrap(name)
+            else {
+                return;
+            };
+            assert_eq!(post_bootstrap, commands, "{name}");
+            for (file, fragment) in written {
+                let content = file_changes
+                    .iter()
+                    .find_map(|change| match change {
+                        FileChange::Write {
+                            file: path,
+                            content,
+                        } if path == file => Some(content),
+                        _ => None,
+                    })
+                    .unwrap_or_else(|| panic!("{name} does not write {file}"));
+                assert!(
+                    content.contains(fragment),
+                    "{name}: {file} lacks {fragment:?}:\n{content}"
+                );
+            }
+            for file in removed {
+                assert!(
+                    file_changes.iter().any(
+                        |change| matches!(change, FileChange::Remove { file: path } if path == file)
+                    ),
+                    "{name} does not remove {file}"
+                );
+            }
+            // the variant files are written as a whole, rather than edited in place
+            assert!(
+                !edits.iter().any(|edit| edit.file.starts_with("template/")),
+                "{name}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_community_inputs() {
+        assert_eq!(
+            parse_community_files("Security, contributing"),
+            Ok("security, contributing".to_owned())
+        );
+        assert_eq!(parse_community_files(" none "), Ok(String::new()));
+        assert!(parse_community_files("changelog").is_err());
+        assert_eq!(parse_badges("MSRV, ci"), Ok("msrv, ci".to_owned()));
+        assert_eq!(parse_badges(""), Ok(String::new()));
+        assert!(parse_badges("coverage").is_err());
+        assert_eq!(parse_security_email(""), Ok(String::new()));
+        assert!(parse_security_email("security@octo.dev").is_ok());
+        for email in ["security", "@octo.dev", "sec@octo", "a b@octo.dev"] {
+            assert!(parse_security_email(email).is_err(), "{email}");
+        }
+        assert!(parse_maintainer_contact("https://github.com/octo").is_ok());
+        assert!(parse_maintainer_contact("  ").is_err());
+        assert_eq!(
+            parse_maintainers("@octo, octo-org/core_team"),
+            Ok("octo, octo-org/core_team".to_owned())
+        );
+        assert!(parse_maintainers("").is_err());
+        assert!(parse_maintainers("octo/").is_err());
+        assert_eq!(
+            parse_funding("GitHub:octo, github:cat, custom:https://octo.dev"),
+            Ok("github:octo, github:cat, custom:https://octo.dev".to_owned())
+        );
+        assert_eq!(parse_funding(""), Ok(String::new()));
+        assert!(parse_funding("octo").is_err());
+        assert!(parse_funding("paypal:octo").is_err());
+        assert!(parse_funding("patreon:a, patreon:b").is_err());
+    }
+
+    #[test]
+    fn test_parse_github_username() {
+        // valid accounts
+        assert_eq!(parse_github_username("my-user"), Ok("my-user".into()));
+        assert_eq!(parse_github_username("my-org"), Ok("my-org".into()));
+        assert_eq!(parse_github_username("  my-user  "), Ok("my-user".into()));
+        assert_eq!(parse_github_username("a"), Ok("a".into()));
+        assert_eq!(parse_github_username(&"a".repeat(39)), Ok("a".repeat(39)));
+
+        // invalid accounts
+        assert!(parse_github_username("").is_err());
+        assert!(parse_github_username("   ").is_err());
+        assert!(parse_github_username(&"a".repeat(40)).is_err());
+        assert!(parse_github_username("-user").is_err());
+        assert!(parse_github_username("user-").is_err());
+        assert!(parse_github_username("my--user").is_err());
+        assert!(parse_github_username("my_user").is_err());
+        assert!(parse_github_username("my.user").is_err());
+    }
+
+    #[test]
+    fn test_select_changes_tree() {
+        let items = tree_items(&[
+            "README.md",
+            ".github/workflows/ci.yml",
+            ".github/semantic.yml",
+            "template/Cargo.toml",
+        ]);
+        assert_eq!(
+            items,
+            [
+                ".github/",
+                ".github/semantic.yml",
+                ".github/workflows/",
+                ".github/workflows/ci.yml",
+                "README.md",
+                "template/",
+                "template/Cargo.toml",
+            ]
+        );
+        assert_eq!(tree_label(".github/workflows/"), "  workflows/");
+        assert_eq!(tree_label(".github/workflows/ci.yml"), "    ci.yml");
+
+        let deselected = vec![".github/workflows/".to_owned(), "README.md".to_owned()];
+        assert!(is_deselected(".github/workflows/ci.yml", &deselected));
+        assert!(is_deselected("README.md", &deselected));
+        assert!(!is_deselected(".github/semantic.yml", &deselected));
+        assert!(!is_deselected("template/", &deselected));
+    }
+
+    #[test]
+    fn test_planned_edits() {
+        let files = vec![
+            (
+                "README.md".to_owned(),
+                "[fast/template](x) `cargo x lint`\n\n[crates-badge]: https://img.shields.io/crates/v/template.svg?style=flat\n[docs-url]: https://docs.rs/template\n[template-url]: https://example.com/template\n".to_owned(),
+            ),
+            (
+                ".github/workflows/ci.yml".to_owned(),
+                "# fast/template\nrun: cargo test -p template\nworking-directory: template/src\n".to_owned(),
+            ),
+            (
+                ".github/ISSUE_TEMPLATE/bug.md".to_owned(),
+                "Run `cargo x` and see ${projectName}.\n".to_owned(),
+            ),
+            (
+                ".cargo/config.toml".to_owned(),
+                "x = \"run --package x --\"\n".to_owned(),
+            ),
+            (
+                "template/Cargo.toml".to_owned(),
+                "[package]\nname = \"template\"\n".to_owned(),
+            ),
+            ("typos.toml".to_owned(), "[default]\n".to_owned()),
+        ];
+        assert_eq!(
+            updated_files(&files, &answers("dev", None, "apache-2.0")),
+            [
+                (
+                    "README.md",
+                    "[octocat/demo](x) `cargo dev lint`\n\n[crates-badge]: https://img.shields.io/crates/v/demo.svg?style=flat\n[docs-url]: https://docs.rs/demo\n[template-url]: https://example.com/template\n".to_owned()
+                ),
+                (
+                    ".github/workflows/ci.yml",
+                    "# octocat/demo\nrun: cargo test -p demo\nworking-directory: demo/src\n".to_owned()
+                ),
+                (
+                    ".github/ISSUE_TEMPLATE/bug.md",
+                    "Run `cargo dev` and see demo.\n".to_owned()
+                ),
+                (
+                    ".cargo/config.toml",
+                    "dev = \"run --package dev --\"\n".to_owned()
+                ),
+                (
+                    "template/Cargo.toml",
+                    "[package]\nname = \"demo\"\n\ndescription.workspace = true\ndocumentation.workspace = true\nhomepage.workspace = true\n".to_owned()
+                ),
+            ]
+        );
+        let answers = answers("x", None, "apache-2.0");
+        assert_eq!(
+            planned_edits(&files, &answers, &template_crates(&answers)).len(),
+            4
+        );
+    }
+
+    #[test]
+    fn test_planned_edits_with_config() {
+        let files = vec![
+            (
+                "Cargo.toml".to_owned(),
+                "[workspace]\nmembers = [\"template\"]\n\n[workspace.package]\nedition = \"2024\"\nlicense = \"Apache-2.0\"\n".to_owned(),
+            ),
+            (
+                "template/Cargo.toml".to_owned(),
+                "[package]\nname = \"template\"\ndescription = \"A template library.\"\n\nedition.workspace = true\nlicense.workspace = true\n".to_owned(),
+            ),
+            (
+                "template/src/lib.rs".to_owned(),
+                "//! A template library.\n".to_owned(),
+            ),
+            (
+                "licenserc.toml".to_owned(),
+                "headerPath = \"Apache-2.0.txt\"\n".to_owned(),
+            ),
+        ];
+        let mut answers = answers("x", Some("A \"fast\" service."), "mit");
+        answers.keywords = vec!["fast".to_owned(), "service".to_owned()];
+        answers.homepage = "https://demo.octo.dev".to_owned();
+        assert_eq!(
+            updated_files(&files, &answers),
+            [
+                (
+                    "Cargo.toml",
+                    "[workspace]\nmembers = [\"demo\"]\n\n[workspace.package]\ndescription = 'A \"fast\" service.'\ndocumentation = \"https://docs.rs/demo\"\nedition = \"2024\"\nhomepage = \"https://demo.octo.dev\"\nkeywords = [\"fast\", \"service\"]\nlicense = \"MIT\"\n".to_owned()
+                ),
+                (
+                    "template/Cargo.toml",
+                    "[package]\nname = \"demo\"\n\ndescription.workspace = true\ndocumentation.workspace = true\nedition.workspace = true\nhomepage.workspace = true\nkeywords.workspace = true\nlicense.workspace = true\n".to_owned()
+                ),
+                (
+                    "template/src/lib.rs",
+                    "//! A \"fast\" service.\n".to_owned()
+                ),
+                ("licenserc.toml", "headerPath = \"MIT.txt\"\n".to_owned()),
+            ]
+        );
+
+        assert_eq!(parse_keywords("fast, service,"), Ok("fast, service".into()));
+        assert!(parse_keywords("a,b,c,d,e,f").is_err());
+        assert!(parse_keywords("-fast").is_err());
+        assert_eq!(
+            parse_categories("development-tools::testing"),
+            Ok("development-tools::testing".into())
+        );
+        assert!(parse_categories("Development Tools").is_err());
+        assert_eq!(
+            parse_url(" https://demo.octo.dev "),
+            Ok("https://demo.octo.dev".into())
+        );
+        assert!(parse_url("demo.octo.dev").is_err());
+        assert!(parse_url("https://").is_err());
+        assert!(parse_url("https://demo.octo.dev/a b").is_err());
+    }
+
+    #[test]
+    fn test_planned_edits_workspace_kind() {
+        let files = vec![
+            (
+                "Cargo.toml".to_owned(),
+                "[workspace]\nmembers = [\"template\", \"xtask\"]\n".to_owned(),
+            ),
+            (
+                "template/Cargo.toml".to_owned(),
+                "[package]\nname = \"template\"\nversion = \"0.1.0\"\n".to_owned(),
+            ),
+            (
+                "template/src/lib.rs".to_owned(),
+                "//! A template library.\n//!\n//! `${projectName}` is developed at <https://github.com/fast/template>.\nuse template::hello;\n".to_owned(),
+            ),
+            (
+                "fuzz/tests/smoke.rs".to_owned(),
+                "use template::hello;\n".to_owned(),
+            ),
+        ];
+        let mut answers = answers("x", Some("A demo."), "apache-2.0");
+        answers.kind = Kind::Workspace;
+        assert_eq!(
+            updated_files(&files, &answers),
+            [
+                (
+                    "Cargo.toml",
+                    "[workspace]\nmembers = [\"demo-core\", \"demo-cli\", \"xtask\"]\n\n[workspace.dependencies]\ndemo-core = { path = \"demo-core\", version = \"0.1.0\" }\nclap = { version = \"4.6.1\", features = [\"derive\"] }\n".to_owned()
+                ),
+                (
+                    "template/Cargo.toml",
+                    "[package]\nname = \"demo-core\"\nversion = \"0.1.0\"\n\ndescription.workspace = true\ndocumentation.workspace = true\nhomepage.workspace = true\n".to_owned()
+                ),
+                (
+                    "template/src/lib.rs",
+                    "//! A demo.\n//!\n//! `demo` is developed at <https://github.com/octocat/demo>.\nuse demo_core::hello;\n".to_owned()
+                ),
+                ("fuzz/tests/smoke.rs", "use demo_core::hello;\n".to_owned()),
+            ]
+        );
+        let edits = planned_edits(&files, &answers, &template_crates(&answers));
+        let changes = planned_kind_files(&files, &edits, &answers);
+        let files: Vec<&str> = changes.iter().map(FileChange::file).collect();
+        assert_eq!(files, ["demo-cli/Cargo.toml", "demo-cli/src/main.rs"]);
+        assert_eq!(parse_kind("Workspace"), Ok("workspace".into()));
+        assert!(parse_kind("dylib").is_err());
+    }
+
+    #[test]
+    fn test_planned_edits_members() {
+        let files = vec![
+            (
+                "Cargo.toml".to_owned(),
+                "[workspace]\nmembers = [\"template\", \"crates/template-macros\"]\n\n[workspace.dependencies]\ntemplate-macros = { path = \"crates/template-macros\" }\n".to_owned(),
+            ),
+            (
+                "crates/template-macros/Cargo.toml".to_owned(),
+                "[package]\nname = \"template-macros\"\n".to_owned(),
+            ),
+            (
+                "template/src/lib.rs".to_owned(),
+                "pub use template_macros::derive;\n".to_owned(),
+            ),
+        ];
+        let answers = answers("x", None, "apache-2.0");
+        let members = [
+            ("template", "template"),
+            ("template-macros", "crates/template-macros"),
+        ]
+        .map(|(package, dir)| (package.to_owned(), dir.to_owned()));
+        let crates = template_renames(&members, "demo", "demo");
+        let edits = planned_edits(&files, &answers, &crates);
+        let updated: Vec<(&str, String)> = edits
+            .iter()
+            .map(|edit| {
+                let (file, content) = files.iter().find(|(f, _)| *f == edit.file).unwrap();
+                (file.as_str(), updated_content(content, edit))
+            })
+            .collect();
+        assert_eq!(
+            updated,
+            [
+                (
+                    "Cargo.toml",
+                    "[workspace]\nmembers = [\"demo\", \"crates/demo-macros\"]\n\n[workspace.dependencies]\ndemo-macros = { path = \"crates/demo-macros\" }\n".to_owned()
+                ),
+                (
+                    "crates/template-macros/Cargo.toml",
+                    "[package]\nname = \"demo-macros\"\n".to_owned()
+                ),
+                (
+                    "template/src/lib.rs",
+                    "pub use demo_macros::derive;\n".to_owned()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_execute_bootstrap() {
+        let root = std::env::temp_dir().join(format!("xtask-bootstrap-{}", std::process::id()));
+        let Some(files) = template_files() else {
+            return;
+        };
+        for (file, content) in &files {
+            let path = root.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        let mut answers = answers("x", Some("A demo."), "mit");
+        answers.kind = Kind::Workspace;
+        answers.documentation = "https://docs.rs/demo-core".to_owned();
+        let crates = template_crates(&answers);
+        let (edits, file_changes, renames) = planned_bootstrap(&files, &answers, &crates);
+        let result = execute_bootstrap(&root, &edits, &file_changes, &renames);
+
+        let mut tree = vec![];
+        walk_files(&root, &root, &mut tree).unwrap();
+        tree.sort();
+        // the xtask sources are left alone, and would only churn the snapshot
+        tree.retain(|file| !file.starts_with("xtask/src/"));
+        let mut snapshot = format!("# files\n{}\n", tree.join("\n"));
+        for file in [
+            "Cargo.toml",
+            "demo-core/Cargo.toml",
+            "demo-core/src/lib.rs",
+            "demo-cli/Cargo.toml",
+            "demo-cli/src/main.rs",
+        ] {
+            let content = std::fs::read_to_string(root.join(file)).unwrap_or_default();
+            snapshot.push_str(&format!("\n# {file}\n{content}"));
+        }
+        std::fs::remove_dir_all(&root).unwrap();
+        result.unwrap();
+        // the snapshot goes with the bootstrap sources at cleanup
+        insta::with_settings!({
+            snapshot_path => "bootstrap/snapshots",
+            prepend_module_to_snapshot => false,
+        }, {
+            insta::assert_snapshot!("execute_bootstrap", snapshot);
+        });
+    }
+
+    #[test]
+    fn test_bootstrap_leaves_no_placeholders() {
+        let root = std::env::temp_dir().join(format!("xtask-scan-{}", std::process::id()));
+        let Some(files) = template_files() else {
+            return;
+        };
+        for (file, content) in &files {
+            let path = root.join(file);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        let answers = answers("x", None, "apache-2.0");
+        let crates = template_crates(&answers);
+        let (edits, file_changes, renames) = planned_bootstrap(&files, &answers, &crates);
+        let result = execute_bootstrap(&root, &edits, &file_changes, &renames);
+        let leftovers = scan::leftover_placeholders(&root);
+        std::fs::remove_dir_all(&root).unwrap();
+        result.unwrap();
+        assert_eq!(leftovers, []);
+        assert_eq!(scan::JOURNAL_FILE, journal::JOURNAL_FILE);
+    }
+
+    fn answers(xtask_alias: &str, description: Option<&str>, license: &str) -> Answers {
+        Answers {
+            project_name: "demo".to_owned(),
+            github_username: "octocat".to_owned(),
+            xtask_alias: xtask_alias.to_owned(),
+            xtask: true,
+            kind: Kind::Lib,
+            variant: None,
+            edition: None,
+            rust_version: None,
+            ci: Ci::GitHub,
+            runtime: Runtime::None,
+            errors: ErrorHandling::None,
+            feature_flags: FeatureFlags::None,
+            observability: Observability::None,
+            benchmarks: Benchmarks::None,
+            usage_files: vec![],
+            removed_components: vec![],
+            badges: BADGES.to_vec(),
+            description: description.unwrap_or("A demo library.").to_owned(),
+            homepage: "https://github.com/octocat/demo".to_owned(),
+            documentation: "https://docs.rs/demo".to_owned(),
+            authors: vec![],
+            keywords: vec![],
+            categories: vec![],
+            license: find_license(license),
+            copyright_holder: "Demo Developers".to_owned(),
+            community_files: vec![],
+            maintainer_contact: String::new(),
+            security_email: String::new(),
+            maintainers: vec!["octocat".to_owned()],
+            funding: vec![],
+            year: 2027,
+        }
+    }
+
+    /// Returns the renames of a workspace whose only member named after the template is the
+    /// template crate.
+    fn template_crates(answers: &Answers) -> Vec<CrateRename> {
+        let members = [("template".to_owned(), "template".to_owned())];
+        let crate_name = answers.kind.crate_name(&answers.project_name);
+        template_renames(&members, &answers.project_name, &crate_name)
+    }
+
+    fn updated_files<'a>(
+        files: &'a [(String, String)],
+        answers: &Answers,
+    ) -> Vec<(&'a str, String)> {
+        planned_edits(files, answers, &template_crates(answers))
+            .iter()
+            .map(|edit| {
+                let (file, content) = files.iter().find(|(f, _)| *f == edit.file).unwrap();
+                (file.as_str(), updated_content(content, edit))
+            })
+            .collect()
+    }
+
+    /// Returns a workspace with the template crate alone, whose `src/lib.rs` starts with a
+    /// license header, the crate docs and a crate attribute.
+    fn template_workspace() -> Vec<(String, String)> {
+        [
+            ("Cargo.toml", "[workspace]\nmembers = [\"template\"]\n"),
+            (
+                "template/Cargo.toml",
+                "[package]\nname = \"template\"\nversion = \"0.1.0\"\n\n[dependencies]\n\n[lints]\nworkspace = true\n",
+            ),
+            (
+                "template/src/lib.rs",
+                "// Copyright\n\n//! requests are welcome.\n\n#![deny(missing_docs)]\n\npub fn hello() {\n    println!(\"Hello, world!\");\n}\n",
+            ),
+        ]
+        .map(|(file, content)| (file.to_owned(), content.to_owned()))
+        .to_vec()
+    }
+
+    /// Returns the content of every file the bootstrap of `files` with `answers` edits or
+    /// writes, by path.
+    fn bootstrapped_files(
+        files: &[(String, String)],
+        answers: &Answers,
+    ) -> BTreeMap<String, String> {
+        let (edits, changes, _) = planned_bootstrap(files, answers, &template_crates(answers));
+        let mut bootstrapped: BTreeMap<String, String> = edits
+            .iter()
+            .map(|edit| {
+                (
+                    edit.file.clone(),
+                    bootstrapped_content(files, &edits, &edit.file),
+                )
+            })
+            .collect();
+        for change in changes {
+            if let FileChange::Write { file, content } = change {
+                bootstrapped.insert(file, content);
+            }
+        }
+        bootstrapped
+    }
+
+    #[test]
+    fn test_planned_edits_edition() {
+        let files = vec![
+            (
+                "Cargo.toml".to_owned(),
+                "[workspace]\nmembers = [\"template\"]\n\n[workspace.package]\nedition = \"2024\"\nrust-version = \"1.85.0\"\n".to_owned(),
+            ),
+            (
+                "xtask/Cargo.toml".to_owned(),
+                "[package]\nname = \"x\"\n\nedition.workspace = true\nrust-version.workspace = true\n".to_owned(),
+            ),
+            (
+                "README.md".to_owned(),
+                "[![MSRV 1.85][msrv-badge]](https://www.whatrustisit.com)\n[msrv-badge]: https://img.shields.io/badge/MSRV-1.85-green?logo=rust\n\nThis crate's minimum supported `rustc` version is `1.85.0`, i.e., Rust 1.85.0 or newer.\n".to_owned(),
+            ),
+        ];
+        let mut answers = answers("x", None, "apache-2.0");
+        answers.edition = Some("2021".to_owned());
+        answers.rust_version = Some("1.88".to_owned());
+        answers.badges = vec![Badge::Msrv];
+        assert_eq!(
+            updated_files(&files, &answers),
+            [
+                (
+                    "Cargo.toml",
+                    "[workspace]\nmembers = [\"demo\"]\n\n[workspace.package]\ndescription = \"A demo library.\"\ndocumentation = \"https://docs.rs/demo\"\nedition = \"2021\"\nhomepage = \"https://github.com/octocat/demo\"\nrust-version = \"1.88\"\n".to_owned()
+                ),
+                (
+                    "xtask/Cargo.toml",
+                    "[package]\nname = \"x\"\n\nedition = \"2024\"\nrust-version.workspace = true\n".to_owned()
+                ),
+                (
+                    "README.md",
+                    "[![MSRV 1.88][msrv-badge]](https://www.whatrustisit.com)\n\n[msrv-badge]: https://img.shields.io/badge/MSRV-1.88-green?logo=rust\n[license-url]: https://www.apache.org/licenses/LICENSE-2.0\n\nThis crate's minimum supported `rustc` version is `1.88`, i.e., Rust 1.88 or newer.\n".to_owned()
+                ),
+            ]
+        );
+
+        assert_eq!(parse_edition(" 2021 "), Ok("2021".into()));
+        assert!(parse_edition("2018").is_err());
+        assert_eq!(parse_rust_version("1.88", "1.85.0"), Ok("1.88".into()));
+        assert_eq!(parse_rust_version("1.85.0", "1.85.0"), Ok("1.85.0".into()));
+        assert!(parse_rust_version("1.84.1", "1.85.0").is_err());
+        assert!(parse_rust_version("1.88-nightly", "1.85.0").is_err());
+        assert!(parse_rust_version("1", "1.85.0").is_err());
+    }
+
+    #[test]
+    fn test_template_renames() {
+        let members = [
+            ("template", "template"),
+            ("template-macros", "crates/template-macros"),
+            ("template-test-utils", "tests/utils"),
+            ("xtask", "xtask"),
+        ]
+        .map(|(package, dir)| (package.to_owned(), dir.to_owned()));
+        let renames = template_renames(&members, "demo", "demo-core");
+        let summary: Vec<(&str, &str)> = renames
+            .iter()
+            .map(|rename| (rename.new_package.as_str(), rename.new_dir.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("demo-test-utils", "tests/utils"),
+                ("demo-macros", "crates/demo-macros"),
+                ("demo-core", "demo-core"),
+            ]
+        );
+        assert!(!renames[0].renames_dir());
+
+        assert_eq!(
+            rename::rename_crates(
+                "use template_macros::derive;\nuse template::hello;\n",
+                &renames,
+                true
+            ),
+            "use demo_macros::derive;\nuse demo_core::hello;\n"
+        );
+    }
+
+    #[test]
+    fn test_planned_edits_choices() {
+        type Setup = fn(&mut Answers);
+        let cases: [(Setup, &[(&str, &str)]); 7] = [
+            (
+                |answers| answers.runtime = Runtime::Tokio,
+                &[
+                    (
+                        "Cargo.toml",
+                        "\n[workspace.dependencies]\ntokio = { version = \"1.53\", features = [\"macros\", \"rt-multi-thread\"] }\n",
+                    ),
+                    (
+                        "template/Cargo.toml",
+                        "\n[dependencies]\ntokio = { workspace = true }\n",
+                    ),
+                    (
+                        "template/src/lib.rs",
+                        "}\n\n#[cfg(test)]\nmod tests {\n    #[tokio::test]\n",
+                    ),
+                ],
+            ),
+            (
+                |answers| answers.benchmarks = Benchmarks::Criterion,
+                &[
+                    (
+                        "Cargo.toml",
+                        "\n[workspace.dependencies]\ncriterion = \"0.7.0\"\n",
+                    ),
+                    (
+                        "template/Cargo.toml",
+                        "\n[dev-dependencies]\ncriterion = { workspace = true }\n\n[[bench]]\nname = \"bench_main\"\nharness = false\n",
+                    ),
+                    (
+                        "template/benches/bench_main.rs",
+                        "// Copyright\n\n//! The benchmarks of the crate",
+                    ),
+                ],
+            ),
+            (
+                |answers| answers.errors = ErrorHandling::Thiserror,
+                &[
+                    (
+                        "Cargo.toml",
+                        "\n[workspace.dependencies]\nthiserror = \"2.0\"\n",
+                    ),
+                    (
+                        "template/Cargo.toml",
+                        "\n[dependencies]\nthiserror = { workspace = true }\n",
+                    ),
+                    (
+                        "template/src/lib.rs",
+                        "#![deny(missing_docs)]\n\nmod error;\n\npub use error::Error;\npub use error::Result;\n\npub fn hello() {",
+                    ),
+                    (
+                        "template/src/error.rs",
+                        "// Copyright\n\n//! The errors of the crate.\n",
+                    ),
+                    (
+                        "template/src/error.rs",
+                        "/// fn parse(input: &str) -> demo::Result<u32> {\n",
+                    ),
+                ],
+            ),
+            (
+                |answers| answers.observability = Observability::Tracing,
+                &[
+                    (
+                        "Cargo.toml",
+                        "\n[workspace.dependencies]\ntracing = \"0.1.44\"\ntracing-subscriber = { version = \"0.3.22\", features = [\"env-filter\"] }\n",
+                    ),
+                    (
+                        "template/Cargo.toml",
+                        "\n[dependencies]\ntracing = { workspace = true }\n\n[dev-dependencies]\ntracing-subscriber = { workspace = true }\n",
+                    ),
+                    (
+                        "template/src/lib.rs",
+                        "}\n\n/// Returns the greeting of `name`, in a span recording it.\n#[tracing::instrument]\n",
+                    ),
+                ],
+            ),
+            (
+                |answers| {
+                    answers.observability = Observability::Tracing;
+                    answers.kind = Kind::Bin;
+                },
+                &[
+                    (
+                        "template/Cargo.toml",
+                        "\n[dependencies]\nclap = { workspace = true }\ntracing = { workspace = true }\ntracing-subscriber = { workspace = true }\n\n[dev-dependencies]\nassert_cmd = { workspace = true }\n",
+                    ),
+                    ("template/src/main.rs", "    init_tracing();\n"),
+                ],
+            ),
+            (
+                |answers| answers.feature_flags = FeatureFlags::Serde,
+                &[
+                    (
+                        "Cargo.toml",
+                        "\n[workspace.dependencies]\nserde = { version = \"1.0.228\", features = [\"derive\"] }\nserde_json = \"1.0.149\"\n",
+                    ),
+                    (
+                        "template/Cargo.toml",
+                        "\n[features]\nserde = [\"dep:serde\", \"dep:serde_json\"]\n\n[dependencies]\nserde = { workspace = true, optional = true }\nserde_json = { workspace = true, optional = true }\n",
+                    ),
+                    (
+                        "template/src/lib.rs",
+                        "//! requests are welcome.\n//!\n//! # Features\n",
+                    ),
+                    (
+                        "template/src/lib.rs",
+                        "#[cfg_attr(docsrs, doc(cfg(feature = \"serde\")))]\n",
+                    ),
+                ],
+            ),
+            (
+                |answers| answers.kind = Kind::ProcMacro,
+                &[
+                    (
+                        "Cargo.toml",
+                        "members = [\"demo\", \"demo-macros\"]\n\n[workspace.dependencies]\ndemo-macros = { path = \"demo-macros\", version = \"0.1.0\" }\nquote = \"1.0.45\"\nsyn = \"2.0.117\"\ntrybuild = \"1.0.116\"\n",
+                    ),
+                    (
+                        "template/Cargo.toml",
+                        "\n[dependencies]\ndemo-macros = { workspace = true }\n\n[dev-dependencies]\ntrybuild = { workspace = true }\n",
+                    ),
+                    (
+                        "template/src/lib.rs",
+                        "#![deny(missing_docs)]\n\n/// Derives [`Hello`]",
+                    ),
+                ],
+            ),
+        ];
+        let files = template_workspace();
+        for (setup, expected) in cases {
+            let mut answers = answers("x", None, "apache-2.0");
+            setup(&mut answers);
+            let bootstrapped = bootstrapped_files(&files, &answers);
+            for (file, fragment) in expected {
+                let content = &bootstrapped[*file];
+                assert!(
+                    content.contains(fragment),
+                    "{file} lacks {fragment:?}:\n{content}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_planned_usage_files() {
+        let files = [(
+            "template/src/lib.rs".to_owned(),
+            "// Copyright\n\npub fn hello() {}\n".to_owned(),
+        )];
+        let mut answers = answers("demo", None, "apache-2.0");
+        answers.usage_files = vec![UsageFile::from_id("tests").unwrap()];
+        let crates = template_renames(
+            &[("template".to_owned(), "template".to_owned())],
+            "demo",
+            "demo",
+        );
+        let changes = planned_usage_files(&files, &[], &answers, &crates);
+        let [FileChange::Write { file, content }] = &changes[..] else {
+            panic!("expected the integration test");
+        };
+        assert_eq!(file, "template/tests/integration.rs");
+        assert!(content.starts_with("// Copyright\n\n//! Integration tests"));
+        assert!(content.contains("\n    demo::hello();\n"));
+
+        assert_eq!(
+            parse_usage_files("Examples, tests"),
+            Ok("examples, tests".to_owned())
+        );
+        assert_eq!(parse_usage_files("none"), Ok(String::new()));
+        assert!(parse_usage_files("benches").is_err());
+    }
+
+    #[test]
+    fn test_planned_edits_no_xtask() {
+        let files = vec![(
+            "Cargo.toml".to_owned(),
+            "[workspace]\nmembers = [\"fast-template\", \"template\", \"xtask\"]\n".to_owned(),
+        )];
+        let mut answers = answers("x", None, "apache-2.0");
+        answers.xtask = false;
+        assert_eq!(
+            updated_files(&files, &answers),
+            [(
+                "Cargo.toml",
+                "[workspace]\nmembers = [\"demo\"]\n".to_owned()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_planned_edits_copyright() {
+        let files = vec![
+            (
+                "licenserc.toml".to_owned(),
+                "# Copyright 2020 Template Authors\n[properties]\ncopyrightOwner = \"Template Authors\"\ninceptionYear = 2020\n".to_owned(),
+            ),
+            (
+                "xtask/src/main.rs".to_owned(),
+                "// Copyright 2020 Template Authors\nconst REPO: &str = \"fast/template\";\n"
+                    .to_owned(),
+            ),
+        ];
+        assert_eq!(
+            updated_files(&files, &answers("x", None, "apache-2.0")),
+            [
+                (
+                    "licenserc.toml",
+                    "# Copyright 2027 Demo Developers\n[properties]\ncopyrightOwner = \"Demo Developers\"\ninceptionYear = 2027\n".to_owned()
+                ),
+                (
+                    "xtask/src/main.rs",
+                    "// Copyright 2027 Demo Developers\nconst REPO: &str = \"fast/template\";\n"
+                        .to_owned()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_planned_license_files() {
+        let files = vec![("LICENSE".to_owned(), "Apache License\n".to_owned())];
+        assert!(planned_license_files(&files, &answers("x", None, "apache-2.0")).is_empty());
+
+        let changes = planned_license_files(&files, &answers("x", None, "mit-or-apache-2.0"));
+        let described: Vec<String> = changes.iter().map(FileChange::describe).collect();
+        assert_eq!(
+            described,
+            [
+                "write LICENSE-APACHE",
+                "write LICENSE-MIT",
+                "remove LICENSE",
+                "remove template/LICENSE",
+                "link template/LICENSE-APACHE -> ../LICENSE-APACHE",
+                "link template/LICENSE-MIT -> ../LICENSE-MIT",
+            ]
+        );
+        let FileChange::Write { content, .. } = &changes[1] else {
+            panic!("expected LICENSE-MIT to be written");
+        };
+        assert!(content.contains("Copyright (c) 2027 Demo Developers\n"));
+
+        assert_eq!(
+            parse_license("MIT OR Apache-2.0"),
+            Ok("mit-or-apache-2.0".into())
+        );
+        assert_eq!(parse_license(" MPL-2.0 "), Ok("mpl-2.0".into()));
+        assert!(parse_license("gpl-3.0").is_err());
+    }
+
+    #[test]
+    fn test_execute_bootstrap_rolls_back() {
+        let root = std::env::temp_dir().join(format!("xtask-bootstrap-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("template")).unwrap();
+        std::fs::create_dir_all(root.join("demo")).unwrap();
+        let manifest = "[workspace]\nmembers = [\"template\"]\n";
+        std::fs::write(root.join("Cargo.toml"), manifest).unwrap();
+
+        let files = vec![("Cargo.toml".to_owned(), manifest.to_owned())];
+        let answers = answers("x", None, "apache-2.0");
+        let edits = planned_edits(&files, &answers, &template_crates(&answers));
+        // the rename fails since `demo` exists, after Cargo.toml was rewritten
+        let file_changes = [FileChange::Write {
+            file: "LICENSE-MIT".to_owned(),
+            content: "MIT License\n".to_owned(),
+        }];
+        let renames = [("template".to_owned(), "demo".to_owned())];
+        assert!(execute_bootstrap(&root, &edits, &file_changes, &renames).is_err());
+        assert_eq!(
+            std::fs::read_to_string(root.join("Cargo.toml")).unwrap(),
+            manifest
+        );
+        assert!(!root.join("LICENSE-MIT").exists());
+        assert!(root.join("template").exists());
+        assert!(!root.join(journal::JOURNAL_FILE).exists());
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_crate_name_status() {
+        let response = serde_json::json!({
+            "crates": [
+                { "name": "my_proj", "exact_match": true },
+                { "name": "my-projs" },
+                { "name": "my-project-utils" },
+            ]
+        });
+        assert_eq!(
+            crate_name_status("my-proj", &response),
+            (true, vec!["my-projs".to_owned()])
+        );
+        assert_eq!(
+            crate_name_status("my-prof", &response),
+            (false, vec!["my_proj".to_owned(), "my-projs".to_owned()])
+        );
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_unified_diff() {
+        let old = "a\nb\ntemplate\nc\nd\ne\nf\ng\nh\ni\nj\ntemplate\n";
+        let new = old.replace("template", "demo");
+        assert_eq!(
+            unified_diff("Cargo.toml", old, &new).unwrap(),
+            "--- a/Cargo.toml\n+++ b/Cargo.toml\n@@ -1,6 +1,6 @@\n a\n b\n-template\n+demo\n c\n d\n e\n@@ -9,4 +9,4 @@\n h\n i\n j\n-template\n+demo\n"
+        );
+        assert_eq!(unified_diff("README.md", old, old), None);
+        assert_eq!(
+            diff_stat(&unified_diff("Cargo.toml", old, &new).unwrap()),
+            (2, 2)
+        );
+
+        let old =
+            "[package]\nname = \"demo\"\ndescription = \"A demo.\"\n\nedition.workspace = true\n";
+        let new = "[package]\nname = \"demo\"\n\ndescription.workspace = true\nedition.workspace = true\nkeywords.workspace = true\n";
+        assert_eq!(
+            unified_diff("Cargo.toml", old, new).unwrap(),
+            "--- a/Cargo.toml\n+++ b/Cargo.toml\n@@ -1,5 +1,6 @@\n [package]\n name = \"demo\"\n-description = \"A demo.\"\n \n+description.workspace = true\n edition.workspace = true\n+keywords.workspace = true\n"
+        );
+    }
+
+    #[test]
+    fn test_parse_xtask_alias() {
+        // valid aliases
+        assert_eq!(parse_xtask_alias("x"), Ok("x".into()));
+        assert_eq!(parse_xtask_alias("dev"), Ok("dev".into()));
+        assert_eq!(parse_xtask_alias(" z "), Ok("z".into()));
+
+        // invalid aliases
+        assert!(parse_xtask_alias("").is_err());
+        assert!(parse_xtask_alias("build").is_err());
+        assert!(parse_xtask_alias("t").is_err());
+        assert!(parse_xtask_alias("my alias").is_err());
     }
 }
@@ -0,0 +1,241 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The answers the project is bootstrapped with, resolved per concern from the options, then the
+//! `--config` file, then the prompts.
+
+mod choices;
+mod community;
+mod metadata;
+mod project;
+
+use colored::Colorize;
+use dialoguer::Input;
+use serde_json::json;
+
+use super::BootstrapOptions;
+use super::Config;
+use super::License;
+use super::audit;
+use super::badges::Badge;
+use super::benchmarks::Benchmarks;
+use super::choice::Choice;
+use super::ci::Ci;
+use super::community::CommunityFile;
+use super::components::Component;
+use super::current_year;
+use super::errors::ErrorHandling;
+use super::fail;
+use super::features::FeatureFlags;
+use super::i18n;
+use super::kind::Kind;
+use super::observability::Observability;
+use super::runtime::Runtime;
+use super::summary;
+use super::usage::UsageFile;
+use super::variant::Variant;
+
+/// The answers the project is bootstrapped with.
+pub struct Answers {
+    pub project_name: String,
+    pub github_username: String,
+    pub xtask_alias: String,
+    /// Whether the xtask harness is kept, or stripped with `--no-xtask`.
+    pub xtask: bool,
+    pub kind: Kind,
+    /// The variant of the template bootstrapped, with its placeholders filled in.
+    pub variant: Option<Variant>,
+    /// The edition, if it differs from the template's.
+    pub edition: Option<String>,
+    /// The minimum supported Rust version, if it differs from the template's.
+    pub rust_version: Option<String>,
+    pub ci: Ci,
+    /// The async runtime the generated crate starts with.
+    pub runtime: Runtime,
+    /// The error handling the library starts with.
+    pub errors: ErrorHandling,
+    /// The feature flags the library starts with.
+    pub feature_flags: FeatureFlags,
+    /// The observability the generated crate starts with.
+    pub observability: Observability,
+    /// The benchmarks the generated crate starts with.
+    pub benchmarks: Benchmarks,
+    /// The files using the public API of the library, unless the template has them.
+    pub usage_files: Vec<&'static UsageFile>,
+    /// The optional components removed from the template.
+    pub removed_components: Vec<&'static Component>,
+    /// The badges of the README's badge block.
+    pub badges: Vec<Badge>,
+    pub description: String,
+    pub homepage: String,
+    pub documentation: String,
+    pub authors: Vec<String>,
+    pub keywords: Vec<String>,
+    pub categories: Vec<String>,
+    pub license: &'static License,
+    pub copyright_holder: String,
+    /// The community health files written, unless the template has them.
+    pub community_files: Vec<&'static CommunityFile>,
+    /// Where conduct violations are reported, named in the code of conduct.
+    pub maintainer_contact: String,
+    /// Where vulnerabilities are reported, named in the security policy, or empty.
+    pub security_email: String,
+    /// The GitHub handles of the code owners.
+    pub maintainers: Vec<String>,
+    /// The sponsorship accounts, by `FUNDING.yml` platform.
+    pub funding: Vec<(String, String)>,
+    pub year: u64,
+}
+
+/// Resolves the answers the project is bootstrapped with from the options, then the `--config`
+/// file, then the prompts, and records them in the summary and the audit log.
+///
+/// The project is named first, since the defaults of its metadata and contacts derive from it.
+pub fn resolve_answers(
+    options: &BootstrapOptions,
+    config: &Config,
+    files: &[(String, String)],
+    unattended: bool,
+) -> Answers {
+    let project = project::resolve_project(options, config, unattended);
+    let choices = choices::resolve_choices(options, config, files, project.kind, unattended);
+    let metadata = metadata::resolve_metadata(options, config, &project, unattended);
+    let community =
+        community::resolve_community(options, config, &project.github_username, unattended);
+    let answers = Answers {
+        project_name: project.project_name,
+        github_username: project.github_username,
+        xtask_alias: project.xtask_alias,
+        xtask: !options.no_xtask,
+        kind: project.kind,
+        variant: choices.variant,
+        edition: (project.edition != project.template_edition).then_some(project.edition),
+        rust_version: (project.rust_version != project.template_rust_version)
+            .then_some(project.rust_version),
+        ci: choices.ci,
+        runtime: choices.runtime,
+        errors: choices.errors,
+        feature_flags: choices.feature_flags,
+        observability: choices.observability,
+        benchmarks: choices.benchmarks,
+        usage_files: choices.usage_files,
+        removed_components: choices.removed_components,
+        badges: choices.badges,
+        description: metadata.description,
+        homepage: metadata.homepage,
+        documentation: metadata.documentation,
+        authors: metadata.authors,
+        keywords: metadata.keywords,
+        categories: metadata.categories,
+        license: metadata.license,
+        copyright_holder: metadata.copyright_holder,
+        community_files: community.community_files,
+        maintainer_contact: community.maintainer_contact,
+        security_email: community.security_email,
+        maintainers: community.maintainers,
+        funding: community.funding,
+        year: current_year(),
+    };
+    let recorded = json!({
+        "name": answers.project_name,
+        "github_account": answers.github_username,
+        "xtask_alias": answers.xtask.then_some(&answers.xtask_alias),
+        "kind": answers.kind.id(),
+        "template": answers.variant.as_ref().map(|variant| &variant.name),
+        "edition": answers.edition.as_deref().unwrap_or(&project.template_edition),
+        "rust_version": answers.rust_version.as_deref().unwrap_or(&project.template_rust_version),
+        "ci": answers.ci.id(),
+        "async_runtime": answers.runtime.id(),
+        "error_handling": answers.errors.id(),
+        "feature_flags": answers.feature_flags.id(),
+        "observability": answers.observability.id(),
+        "benchmarks": answers.benchmarks.id(),
+        "usage_files": answers
+            .usage_files
+            .iter()
+            .map(|usage| usage.id)
+            .collect::<Vec<_>>(),
+        "without": answers
+            .removed_components
+            .iter()
+            .map(|component| component.id)
+            .collect::<Vec<_>>(),
+        "without_badges": choices.without_badges,
+        "license": answers.license.id,
+        "maintainers": answers.maintainers,
+        "community_files": answers
+            .community_files
+            .iter()
+            .map(|file| file.id)
+            .collect::<Vec<_>>(),
+        "description": answers.description,
+        "homepage": answers.homepage,
+        "documentation": answers.documentation,
+    });
+    audit::record("answers", &recorded);
+    summary::set("project", recorded);
+    answers
+}
+
+/// Returns the answer given as the `option`, or else under `key` in the `--config` file.
+fn given(option: &Option<String>, config: &Config, key: &str) -> Option<String> {
+    option.clone().or(config.answers.get(key).cloned())
+}
+
+/// Returns the input given as `flag`, its default with `yes`, or else prompts for it.
+pub fn resolve_input<F>(
+    value: Option<&str>,
+    yes: bool,
+    flag: &str,
+    prompt: &str,
+    default: Option<&str>,
+    validator: F,
+) -> String
+where
+    F: Fn(&str) -> Result<String, String>,
+{
+    let (value, source) = match (value, default) {
+        (Some(value), _) => (value, "given"),
+        (None, Some(default)) if yes => (default, "default"),
+        (None, None) if yes => fail(&tr!("{flag} is required with --yes or --config", flag)),
+        (None, _) => {
+            let value = get_valid_input(prompt, default, validator);
+            audit::record("answer", format!("{flag} = {value:?} (prompted)"));
+            return value;
+        }
+    };
+    let value = validator(value).unwrap_or_else(|e| fail(&tr!("invalid {flag}: {e}", flag, e)));
+    audit::record("answer", format!("{flag} = {value:?} ({source})"));
+    value
+}
+
+fn get_valid_input<F>(prompt: &str, default: Option<&str>, validator: F) -> String
+where
+    F: Fn(&str) -> Result<String, String>,
+{
+    loop {
+        let mut input = Input::<String>::new().with_prompt(prompt);
+        match default {
+            // an empty default marks an optional input
+            Some("") => input = input.allow_empty(true),
+            Some(default) => input = input.default(default.to_owned()),
+            None => {}
+        }
+        let input = input.interact_text().unwrap();
+        match validator(&input) {
+            Ok(value) => return value,
+            Err(e) => eprintln!("{}", tr!("ERROR: {message}", message = e).red()),
+        }
+    }
+}
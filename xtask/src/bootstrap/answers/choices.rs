@@ -0,0 +1,378 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The choices of what the project starts with: its CI, the code the crate is generated with,
+//! the template variant, and the components and badges of the template it keeps.
+
+use std::collections::BTreeMap;
+
+use colored::Colorize;
+use dialoguer::MultiSelect;
+
+use super::given;
+use super::resolve_input;
+use crate::bootstrap::BootstrapOptions;
+use crate::bootstrap::Config;
+use crate::bootstrap::badge_ids;
+use crate::bootstrap::badges::BADGES;
+use crate::bootstrap::badges::Badge;
+use crate::bootstrap::benchmarks::Benchmarks;
+use crate::bootstrap::choice::Choice;
+use crate::bootstrap::choice::choice_ids;
+use crate::bootstrap::choice::parse_choice;
+use crate::bootstrap::ci::Ci;
+use crate::bootstrap::components;
+use crate::bootstrap::components::Component;
+use crate::bootstrap::errors::ErrorHandling;
+use crate::bootstrap::fail;
+use crate::bootstrap::features::FeatureFlags;
+use crate::bootstrap::git;
+use crate::bootstrap::i18n;
+use crate::bootstrap::kind::Kind;
+use crate::bootstrap::observability::Observability;
+use crate::bootstrap::parse_badges;
+use crate::bootstrap::parse_components;
+use crate::bootstrap::parse_template;
+use crate::bootstrap::parse_usage_files;
+use crate::bootstrap::runtime::Runtime;
+use crate::bootstrap::split_list;
+use crate::bootstrap::usage::UsageFile;
+use crate::bootstrap::usage_file_ids;
+use crate::bootstrap::variant;
+use crate::bootstrap::variant::Variant;
+use crate::bootstrap::workspace_dir;
+
+/// What the project starts with, as chosen.
+pub struct Choices {
+    pub ci: Ci,
+    pub runtime: Runtime,
+    pub errors: ErrorHandling,
+    pub feature_flags: FeatureFlags,
+    pub observability: Observability,
+    pub benchmarks: Benchmarks,
+    pub usage_files: Vec<&'static UsageFile>,
+    pub variant: Option<Variant>,
+    pub removed_components: Vec<&'static Component>,
+    pub badges: Vec<Badge>,
+    /// The ids of the badges left out, as given.
+    pub without_badges: Vec<String>,
+}
+
+/// Resolves what the project of the `kind` starts with among the workspace `files`, failing on
+/// choices that contradict each other.
+pub fn resolve_choices(
+    options: &BootstrapOptions,
+    config: &Config,
+    files: &[(String, String)],
+    kind: Kind,
+    unattended: bool,
+) -> Choices {
+    let ci = resolve_input(
+        given(&options.ci, config, "ci").as_deref(),
+        unattended,
+        Ci::OPTION,
+        &tr!(
+            "Choose the CI system ({cis})",
+            cis = choice_ids::<Ci>().join(", ")
+        ),
+        Some(Ci::ALL[0].id()),
+        parse_choice::<Ci>,
+    );
+    let runtime = resolve_input(
+        given(&options.async_runtime, config, "async-runtime").as_deref(),
+        unattended,
+        Runtime::OPTION,
+        &tr!(
+            "Choose the async runtime ({runtimes})",
+            runtimes = choice_ids::<Runtime>().join(", ")
+        ),
+        Some(Runtime::ALL[0].id()),
+        parse_choice::<Runtime>,
+    );
+    let errors = resolve_input(
+        given(&options.error_handling, config, "error-handling").as_deref(),
+        unattended,
+        ErrorHandling::OPTION,
+        &tr!(
+            "Choose the error handling of the library ({errors})",
+            errors = choice_ids::<ErrorHandling>().join(", ")
+        ),
+        Some(ErrorHandling::ALL[0].id()),
+        parse_choice::<ErrorHandling>,
+    );
+    let feature_flags = resolve_input(
+        given(&options.feature_flags, config, "feature-flags").as_deref(),
+        unattended,
+        FeatureFlags::OPTION,
+        &tr!(
+            "Choose the feature flags of the library ({flags})",
+            flags = choice_ids::<FeatureFlags>().join(", ")
+        ),
+        Some(FeatureFlags::ALL[0].id()),
+        parse_choice::<FeatureFlags>,
+    );
+    let observability = resolve_input(
+        given(&options.observability, config, "observability").as_deref(),
+        unattended,
+        Observability::OPTION,
+        &tr!(
+            "Choose the observability ({observabilities})",
+            observabilities = choice_ids::<Observability>().join(", ")
+        ),
+        Some(Observability::ALL[0].id()),
+        parse_choice::<Observability>,
+    );
+    let benchmarks = resolve_input(
+        given(&options.benchmarks, config, "benchmarks").as_deref(),
+        unattended,
+        Benchmarks::OPTION,
+        &tr!(
+            "Choose the benchmarks ({benchmarks})",
+            benchmarks = choice_ids::<Benchmarks>().join(", ")
+        ),
+        Some(Benchmarks::ALL[0].id()),
+        parse_choice::<Benchmarks>,
+    );
+    let usage_files = resolve_input(
+        given(&options.usage_files, config, "usage-files").as_deref(),
+        unattended,
+        "--usage-files",
+        &tr!(
+            "Choose the files using the library to write ({usages}), comma-separated, or none",
+            usages = usage_file_ids().join(", ")
+        ),
+        Some("none"),
+        parse_usage_files,
+    );
+    let usage_files: Vec<&'static UsageFile> = split_list(&usage_files)
+        .iter()
+        .filter_map(|id| UsageFile::from_id(id))
+        .collect();
+    if kind == Kind::Bin && errors != ErrorHandling::None.id() {
+        fail(&tr!(
+            "--error-handling {errors} needs the library, which --kind bin replaces",
+            errors
+        ));
+    }
+    if kind == Kind::Bin && feature_flags != FeatureFlags::None.id() {
+        fail(&tr!(
+            "--feature-flags {flags} needs the library, which --kind bin replaces",
+            flags = feature_flags
+        ));
+    }
+    if kind == Kind::Bin && !usage_files.is_empty() {
+        fail(&tr!(
+            "--usage-files needs the library, which --kind bin replaces"
+        ));
+    }
+    let variant = resolve_variant(
+        given(&options.template, config, "template").as_deref(),
+        unattended,
+        files,
+    );
+    let variant = match variant {
+        Some(mut variant) => {
+            let values = resolve_placeholders(
+                &variant,
+                &options.placeholders,
+                &config.placeholders,
+                unattended,
+            );
+            variant.fill(&values);
+            Some(variant)
+        }
+        None if !options.placeholders.is_empty() => fail(&tr!(
+            "--placeholder needs a --template variant declaring it"
+        )),
+        None => None,
+    };
+    let removed_components = resolve_removed_components(
+        given(&options.without, config, "without").as_deref(),
+        unattended,
+        files,
+    );
+    if benchmarks != Benchmarks::None.id() && removed_components.iter().any(|c| c.id == "benches") {
+        fail(&tr!(
+            "--benchmarks {benchmarks} adds the benches that --without benches removes",
+            benchmarks
+        ));
+    }
+    if let Some(usage) = usage_files
+        .iter()
+        .find(|usage| removed_components.iter().any(|c| c.id == usage.id))
+    {
+        fail(&tr!(
+            "--usage-files {id} adds the {id} that --without {id} removes",
+            id = usage.id
+        ));
+    }
+    let without_badges = resolve_input(
+        given(&options.without_badges, config, "without-badges").as_deref(),
+        unattended,
+        "--without-badges",
+        &tr!(
+            "Choose the README badges to leave out ({badges}), comma-separated (optional)",
+            badges = badge_ids().join(", ")
+        ),
+        Some(""),
+        parse_badges,
+    );
+    let without_badges = split_list(&without_badges);
+    Choices {
+        ci: Ci::from_id(&ci).unwrap(),
+        runtime: Runtime::from_id(&runtime).unwrap(),
+        errors: ErrorHandling::from_id(&errors).unwrap(),
+        feature_flags: FeatureFlags::from_id(&feature_flags).unwrap(),
+        observability: Observability::from_id(&observability).unwrap(),
+        benchmarks: Benchmarks::from_id(&benchmarks).unwrap(),
+        usage_files,
+        variant,
+        removed_components,
+        badges: BADGES
+            .iter()
+            .copied()
+            .filter(|badge| !without_badges.contains(&badge.id().to_owned()))
+            .collect(),
+        without_badges,
+    }
+}
+
+/// Returns the optional components to remove: those given as `--without`, or else those of the
+/// workspace `files` deselected when prompted, unless `yes` keeps them all.
+fn resolve_removed_components(
+    without: Option<&str>,
+    yes: bool,
+    files: &[(String, String)],
+) -> Vec<&'static Component> {
+    if let Some(without) = without {
+        let ids =
+            parse_components(without).unwrap_or_else(|e| fail(&tr!("invalid --without: {e}", e)));
+        return split_list(&ids)
+            .iter()
+            .filter_map(|id| Component::from_id(id))
+            .collect();
+    }
+    let names: Vec<String> = files.iter().map(|(file, _)| file.clone()).collect();
+    let present = components::present(&names);
+    if yes || present.is_empty() {
+        return vec![];
+    }
+    let labels: Vec<String> = present
+        .iter()
+        .map(|component| format!("{} ({})", component.id, i18n::tr(component.description)))
+        .collect();
+    let kept = MultiSelect::new()
+        .with_prompt(
+            tr!("Select the optional components to keep (space toggles, enter confirms)")
+                .blue()
+                .to_string(),
+        )
+        .items(&labels)
+        .defaults(&vec![true; present.len()])
+        .interact()
+        .unwrap();
+    present
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| !kept.contains(i))
+        .map(|(_, component)| component)
+        .collect()
+}
+
+/// Returns the variant of the template given as `--template`, or else chosen among those of the
+/// workspace `files`, loaded from the working tree or from the git revision it names. Without
+/// variants, there is nothing to choose from and none is prompted for.
+fn resolve_variant(
+    template: Option<&str>,
+    yes: bool,
+    files: &[(String, String)],
+) -> Option<Variant> {
+    let names: Vec<String> = files.iter().map(|(file, _)| file.clone()).collect();
+    let variants = variant::variant_names(&names);
+    if template.is_none() && variants.is_empty() {
+        return None;
+    }
+    let template = resolve_input(
+        template,
+        yes,
+        "--template",
+        &tr!(
+            "Choose the template variant ({variants}), or none",
+            variants = variants.join(", ")
+        ),
+        Some("none"),
+        |template| parse_template(template, &variants),
+    );
+    if template == "none" {
+        return None;
+    }
+    let (name, git_ref) = match template.split_once('@') {
+        Some((name, git_ref)) => (name, Some(git_ref)),
+        None => (template.as_str(), None),
+    };
+    let read = |path: &str| match git_ref {
+        Some(git_ref) => git(workspace_dir(), &["show", &format!("{git_ref}:{path}")])
+            .map_err(|err| err.to_string()),
+        None => std::fs::read_to_string(workspace_dir().join(path))
+            .map_err(|err| format!("failed to read {path}: {err}")),
+    };
+    let variant = variant::load_variant(name, read)
+        .unwrap_or_else(|e| fail(&tr!("invalid {flag}: {e}", flag = "--template", e)));
+    Some(variant)
+}
+
+/// Returns the values of the placeholders of the `variant`, given as `--placeholder key=value`
+/// or in the `[placeholders]` table of the config, or else prompted for with the variant's
+/// prompts.
+fn resolve_placeholders(
+    variant: &Variant,
+    given: &[String],
+    config: &BTreeMap<String, String>,
+    yes: bool,
+) -> Vec<(String, String)> {
+    let mut values = config.clone();
+    for item in given {
+        let Some((key, value)) = item.split_once('=') else {
+            fail(&tr!(
+                "expected --placeholder key=value, found '{item}'",
+                item
+            ));
+        };
+        values.insert(key.trim().to_owned(), value.to_owned());
+    }
+    for key in values.keys() {
+        if !variant.placeholders.iter().any(|p| p.key == *key) {
+            fail(&tr!(
+                "unknown placeholder '{key}' of the {name} variant",
+                key,
+                name = variant.name,
+            ));
+        }
+    }
+    variant
+        .placeholders
+        .iter()
+        .map(|placeholder| {
+            let value = resolve_input(
+                values.get(&placeholder.key).map(String::as_str),
+                yes,
+                &format!("--placeholder {}", placeholder.key),
+                &placeholder.prompt,
+                placeholder.default.as_deref(),
+                |value| Ok(value.trim().to_owned()),
+            );
+            (placeholder.key.clone(), value)
+        })
+        .collect()
+}
@@ -0,0 +1,113 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The community of the project: the health files it is written with, where its maintainers are
+//! reached, and how it is sponsored.
+
+use super::given;
+use super::resolve_input;
+use crate::bootstrap::BootstrapOptions;
+use crate::bootstrap::Config;
+use crate::bootstrap::community::CommunityFile;
+use crate::bootstrap::community_file_ids;
+use crate::bootstrap::i18n;
+use crate::bootstrap::parse_community_files;
+use crate::bootstrap::parse_funding;
+use crate::bootstrap::parse_maintainer_contact;
+use crate::bootstrap::parse_maintainers;
+use crate::bootstrap::parse_security_email;
+use crate::bootstrap::split_list;
+
+/// The community health files written, and the contacts and sponsorship accounts they name.
+pub struct Community {
+    pub community_files: Vec<&'static CommunityFile>,
+    pub maintainer_contact: String,
+    pub security_email: String,
+    pub maintainers: Vec<String>,
+    pub funding: Vec<(String, String)>,
+}
+
+/// Resolves the community health files, then the contacts only those written name, then the code
+/// owners and sponsorship accounts, which default to the `github_username`.
+pub fn resolve_community(
+    options: &BootstrapOptions,
+    config: &Config,
+    github_username: &str,
+    unattended: bool,
+) -> Community {
+    let community_files = resolve_input(
+        given(&options.community_files, config, "community-files").as_deref(),
+        unattended,
+        "--community-files",
+        &tr!("Choose the community health files to write, comma-separated, or none"),
+        Some(&community_file_ids().join(", ")),
+        parse_community_files,
+    );
+    let community_files: Vec<&'static CommunityFile> = split_list(&community_files)
+        .iter()
+        .filter_map(|id| CommunityFile::from_id(id))
+        .collect();
+    let writes = |id: &str| community_files.iter().any(|file| file.id == id);
+    let maintainer_contact = match writes("code-of-conduct") {
+        true => resolve_input(
+            given(&options.maintainer_contact, config, "maintainer-contact").as_deref(),
+            unattended,
+            "--maintainer-contact",
+            &tr!("Enter the email or URL where conduct violations are reported"),
+            Some(&format!("https://github.com/{github_username}")),
+            parse_maintainer_contact,
+        ),
+        false => String::new(),
+    };
+    let security_email = match writes("security") {
+        true => resolve_input(
+            given(&options.security_email, config, "security-email").as_deref(),
+            unattended,
+            "--security-email",
+            &tr!(
+                "Enter the email where vulnerabilities are reported (optional, GitHub's private reporting otherwise)"
+            ),
+            Some(""),
+            parse_security_email,
+        ),
+        false => String::new(),
+    };
+    let maintainers = resolve_input(
+        given(&options.maintainers, config, "maintainers").as_deref(),
+        unattended,
+        "--maintainers",
+        &tr!("Enter the GitHub handles of the code owners, comma-separated"),
+        Some(github_username),
+        parse_maintainers,
+    );
+    let funding = resolve_input(
+        given(&options.funding, config, "funding").as_deref(),
+        unattended,
+        "--funding",
+        &tr!("Enter the sponsorship accounts as platform:account, comma-separated (optional)"),
+        Some(""),
+        parse_funding,
+    );
+    Community {
+        community_files,
+        maintainer_contact,
+        security_email,
+        maintainers: split_list(&maintainers),
+        funding: split_list(&funding)
+            .iter()
+            .filter_map(|item| item.split_once(':'))
+            .map(|(platform, account)| (platform.to_owned(), account.to_owned()))
+            .collect(),
+    }
+}
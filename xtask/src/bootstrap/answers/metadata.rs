@@ -0,0 +1,148 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The package metadata of the project, and the license it is published under.
+
+use xtask_bootstrap::rename;
+
+use super::given;
+use super::project::Project;
+use super::resolve_input;
+use crate::bootstrap::BootstrapOptions;
+use crate::bootstrap::Config;
+use crate::bootstrap::LICENSES;
+use crate::bootstrap::License;
+use crate::bootstrap::TEMPLATE_DESCRIPTION;
+use crate::bootstrap::find_license;
+use crate::bootstrap::i18n;
+use crate::bootstrap::license_ids;
+use crate::bootstrap::parse_authors;
+use crate::bootstrap::parse_categories;
+use crate::bootstrap::parse_copyright_holder;
+use crate::bootstrap::parse_description;
+use crate::bootstrap::parse_keywords;
+use crate::bootstrap::parse_license;
+use crate::bootstrap::parse_url;
+use crate::bootstrap::split_list;
+
+/// The metadata of the packages, and the license and copyright holder of the project.
+pub struct Metadata {
+    pub description: String,
+    pub homepage: String,
+    pub documentation: String,
+    pub authors: Vec<String>,
+    pub keywords: Vec<String>,
+    pub categories: Vec<String>,
+    pub license: &'static License,
+    pub copyright_holder: String,
+}
+
+/// Resolves the license and the package metadata of the `project`, which the defaults name.
+pub fn resolve_metadata(
+    options: &BootstrapOptions,
+    config: &Config,
+    project: &Project,
+    unattended: bool,
+) -> Metadata {
+    let Project {
+        project_name,
+        github_username,
+        ..
+    } = project;
+    let license = resolve_input(
+        given(&options.license, config, "license").as_deref(),
+        unattended,
+        "--license",
+        &tr!(
+            "Choose a license ({licenses})",
+            licenses = license_ids().join(", ")
+        ),
+        Some(LICENSES[0].id),
+        parse_license,
+    );
+    let description = resolve_input(
+        given(&options.description, config, "description").as_deref(),
+        unattended,
+        "--description",
+        &tr!("Enter a one-line description of the project"),
+        Some(&rename::rename_crate(
+            TEMPLATE_DESCRIPTION,
+            "template",
+            project_name,
+            false,
+        )),
+        parse_description,
+    );
+    let homepage = resolve_input(
+        given(&options.homepage, config, "homepage").as_deref(),
+        unattended,
+        "--homepage",
+        &tr!("Enter the homepage URL of the project"),
+        Some(&format!(
+            "https://github.com/{github_username}/{project_name}"
+        )),
+        parse_url,
+    );
+    let crate_name = project.kind.crate_name(project_name);
+    let documentation = resolve_input(
+        given(&options.documentation, config, "documentation").as_deref(),
+        unattended,
+        "--documentation",
+        &tr!("Enter the documentation URL of the project"),
+        Some(&format!("https://docs.rs/{crate_name}")),
+        parse_url,
+    );
+    let authors = resolve_input(
+        given(&options.authors, config, "authors").as_deref(),
+        unattended,
+        "--authors",
+        &tr!("Enter the authors, comma-separated (optional)"),
+        Some(""),
+        parse_authors,
+    );
+    let keywords = resolve_input(
+        given(&options.keywords, config, "keywords").as_deref(),
+        unattended,
+        "--keywords",
+        &tr!("Enter up to 5 crates.io keywords, comma-separated (optional)"),
+        Some(""),
+        parse_keywords,
+    );
+    let categories = resolve_input(
+        given(&options.categories, config, "categories").as_deref(),
+        unattended,
+        "--categories",
+        &tr!("Enter up to 5 crates.io category slugs, comma-separated (optional)"),
+        Some(""),
+        parse_categories,
+    );
+    let copyright_holder = resolve_input(
+        given(&options.copyright_holder, config, "copyright-holder").as_deref(),
+        unattended,
+        "--copyright-holder",
+        &tr!("Enter the copyright holder of the license headers"),
+        Some(github_username),
+        parse_copyright_holder,
+    );
+    Metadata {
+        description,
+        homepage,
+        documentation,
+        authors: split_list(&authors),
+        keywords: split_list(&keywords),
+        categories: split_list(&categories),
+        license: find_license(&license),
+        copyright_holder,
+    }
+}
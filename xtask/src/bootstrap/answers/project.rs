@@ -0,0 +1,130 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The identity of the project, and the toolchain it builds with.
+
+use super::given;
+use super::resolve_input;
+use crate::bootstrap::BootstrapOptions;
+use crate::bootstrap::Config;
+use crate::bootstrap::DEFAULT_XTASK_ALIAS;
+use crate::bootstrap::EDITIONS;
+use crate::bootstrap::edition_ids;
+use crate::bootstrap::i18n;
+use crate::bootstrap::kind::KINDS;
+use crate::bootstrap::kind::Kind;
+use crate::bootstrap::kind_ids;
+use crate::bootstrap::offer_project_name;
+use crate::bootstrap::parse_edition;
+use crate::bootstrap::parse_github_username;
+use crate::bootstrap::parse_kind;
+use crate::bootstrap::parse_project_name;
+use crate::bootstrap::parse_rust_version;
+use crate::bootstrap::parse_version;
+use crate::bootstrap::parse_xtask_alias;
+use crate::bootstrap::workspace_dir;
+use crate::bootstrap::workspace_package_field;
+
+/// The name, owner, and kind of the project, and its toolchain along with the template's.
+pub struct Project {
+    pub project_name: String,
+    pub github_username: String,
+    pub xtask_alias: String,
+    pub kind: Kind,
+    pub edition: String,
+    pub template_edition: String,
+    pub rust_version: String,
+    pub template_rust_version: String,
+}
+
+/// Resolves the name, owner, and kind of the project, then the edition and MSRV it builds with.
+pub fn resolve_project(options: &BootstrapOptions, config: &Config, unattended: bool) -> Project {
+    let project_name = resolve_input(
+        given(&options.project_name, config, "project-name").as_deref(),
+        unattended,
+        "--project-name",
+        &tr!("Enter your project name (e.g., my-awesome-project)"),
+        None,
+        |name| parse_project_name(name).or_else(|e| offer_project_name(name, e, unattended)),
+    );
+    let github_username = resolve_input(
+        given(&options.github_account, config, "github-account").as_deref(),
+        unattended,
+        "--github-account",
+        &tr!("Enter your GitHub username (e.g., tisonkun)"),
+        None,
+        parse_github_username,
+    );
+    let xtask_alias = resolve_input(
+        given(&options.xtask_alias, config, "xtask-alias").as_deref(),
+        unattended || options.no_xtask,
+        "--xtask-alias",
+        &tr!("Enter the xtask command alias (e.g., x for `cargo x`)"),
+        Some(DEFAULT_XTASK_ALIAS),
+        parse_xtask_alias,
+    );
+    let kind = resolve_input(
+        given(&options.kind, config, "kind").as_deref(),
+        unattended,
+        "--kind",
+        &tr!(
+            "Choose the project kind ({kinds})",
+            kinds = kind_ids().join(", ")
+        ),
+        Some(KINDS[0].id()),
+        parse_kind,
+    );
+    let workspace_manifest =
+        std::fs::read_to_string(workspace_dir().join("Cargo.toml")).unwrap_or_default();
+    let template_edition = workspace_package_field(&workspace_manifest, "edition")
+        .unwrap_or_else(|| EDITIONS[0].0.to_owned());
+    let edition = resolve_input(
+        given(&options.edition, config, "edition").as_deref(),
+        unattended,
+        "--edition",
+        &tr!(
+            "Choose the Rust edition ({editions})",
+            editions = edition_ids().join(", ")
+        ),
+        Some(&template_edition),
+        parse_edition,
+    );
+    // the whole workspace, xtask included, is tested with the MSRV
+    let template_rust_version = workspace_package_field(&workspace_manifest, "rust-version")
+        .unwrap_or_else(|| EDITIONS[0].1.to_owned());
+    let edition_rust_version = EDITIONS.iter().find(|(id, _)| *id == edition).unwrap().1;
+    let minimum_rust_version = [template_rust_version.as_str(), edition_rust_version]
+        .into_iter()
+        .max_by_key(|version| parse_version(version))
+        .unwrap()
+        .to_owned();
+    let rust_version = resolve_input(
+        given(&options.rust_version, config, "rust-version").as_deref(),
+        unattended,
+        "--rust-version",
+        &tr!("Enter the minimum supported Rust version"),
+        Some(&minimum_rust_version),
+        |version| parse_rust_version(version, &minimum_rust_version),
+    );
+    Project {
+        project_name,
+        github_username,
+        xtask_alias,
+        kind: Kind::from_id(&kind).unwrap(),
+        edition,
+        template_edition,
+        rust_version,
+        template_rust_version,
+    }
+}
@@ -0,0 +1,129 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The audit log of the bootstrap, which tells how the project was generated long after the
+//! fact: every answer, confirmation, file change, and command run, one timestamped line each.
+//!
+//! The log is kept in memory and appended to `bootstrap.log` at the project root, or the
+//! `--log-file`, once the bootstrap succeeds or fails, so a cancelled or dry run leaves nothing
+//! behind.
+
+use std::fmt::Display;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use super::changelog;
+
+/// The name of the log at the project root, unless `--log-file` is given.
+pub const LOG_FILE: &str = "bootstrap.log";
+
+/// The log being recorded, if the bootstrap is not a dry run.
+static AUDIT: Mutex<Option<AuditLog>> = Mutex::new(None);
+
+struct AuditLog {
+    file: PathBuf,
+    lines: Vec<String>,
+}
+
+/// Starts recording the log, which [`finish`] appends to `file`.
+pub fn start(file: PathBuf) {
+    *AUDIT.lock().unwrap() = Some(AuditLog {
+        file,
+        lines: vec![],
+    });
+}
+
+/// Records an event of the given `kind`, such as `answer` or `command`, if the log is being
+/// recorded.
+pub fn record(kind: &str, detail: impl Display) {
+    if let Some(log) = AUDIT.lock().unwrap().as_mut() {
+        log.lines
+            .push(format_line(current_secs(), kind, &detail.to_string()));
+    }
+}
+
+/// Records that `cmd` is run.
+pub fn command(cmd: &Command) {
+    record("command", format!("{cmd:?}"));
+}
+
+/// Returns the file the log is appended to, if it is being recorded.
+pub fn file() -> Option<PathBuf> {
+    AUDIT.lock().unwrap().as_ref().map(|log| log.file.clone())
+}
+
+/// Stops recording the log without writing it.
+pub fn discard() {
+    AUDIT.lock().unwrap().take();
+}
+
+/// Appends the log with the given `outcome` to its file and stops recording it.
+///
+/// Nothing is written if the file's directory is gone, as for a copy removed after a failure.
+pub fn finish(outcome: &str) {
+    record("outcome", outcome);
+    let Some(log) = AUDIT.lock().unwrap().take() else {
+        return;
+    };
+    let gone = |dir: &Path| !dir.as_os_str().is_empty() && !dir.exists();
+    if log.file.parent().is_some_and(gone) {
+        return;
+    }
+    let result = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&log.file)
+        .and_then(|mut file| file.write_all(log.lines.concat().as_bytes()));
+    if let Err(err) = result {
+        eprintln!("failed to write the log to {}: {err}", log.file.display());
+    }
+}
+
+fn current_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Formats a line of the log, whose timestamp is the UTC time `secs` after 1970-01-01 in the
+/// RFC 3339 format. Line breaks in `detail` are escaped to keep one event per line.
+fn format_line(secs: u64, kind: &str, detail: &str) -> String {
+    let (year, month, day) = changelog::civil_date(secs / 86_400);
+    let (hours, minutes, seconds) = (secs % 86_400 / 3600, secs % 3600 / 60, secs % 60);
+    format!(
+        "{year}-{month:02}-{day:02}T{hours:02}:{minutes:02}:{seconds:02}Z {kind} {}\n",
+        detail.replace('\n', "\\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_line() {
+        assert_eq!(
+            format_line(1_792_145_045, "answer", "--project-name = \"demo\""),
+            "2026-10-16T10:04:05Z answer --project-name = \"demo\"\n"
+        );
+        assert_eq!(
+            format_line(0, "error", "failed:\nno such file"),
+            "1970-01-01T00:00:00Z error failed:\\nno such file\n"
+        );
+    }
+}
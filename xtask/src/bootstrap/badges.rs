@@ -0,0 +1,228 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The badge block of the README, generated for the coordinates of the new project in place of
+//! the template's badges.
+
+use super::Ci;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Badge {
+    Crates,
+    Docs,
+    Msrv,
+    License,
+    Ci,
+}
+
+/// The badges of the block, in order.
+pub const BADGES: &[Badge] = &[
+    Badge::Crates,
+    Badge::Docs,
+    Badge::Msrv,
+    Badge::License,
+    Badge::Ci,
+];
+
+impl Badge {
+    /// The value of `--without-badges`.
+    pub fn id(self) -> &'static str {
+        match self {
+            Badge::Crates => "crates",
+            Badge::Docs => "docs",
+            Badge::Msrv => "msrv",
+            Badge::License => "license",
+            Badge::Ci => "ci",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Badge> {
+        BADGES
+            .iter()
+            .copied()
+            .find(|badge| badge.id().eq_ignore_ascii_case(id))
+    }
+}
+
+/// The coordinates of the project the badges point to.
+pub struct Coordinates<'a> {
+    pub crate_name: &'a str,
+    /// The GitHub repository, as `account/name`.
+    pub repository: &'a str,
+    pub documentation: &'a str,
+    /// The minimum supported Rust version, if the workspace declares one.
+    pub rust_version: Option<&'a str>,
+    /// The SPDX expression of the license, and the URL of its text.
+    pub license: (&'a str, &'a str),
+    pub ci: Ci,
+}
+
+/// Returns the block of the `badges`, one per line, followed by the definitions of their links,
+/// or an empty string without any; the MSRV badge needs a Rust version, and the CI badge a CI.
+///
+/// The license link is defined even without its badge, since the license section of the README
+/// links to it.
+pub fn badge_block(badges: &[Badge], coordinates: &Coordinates) -> String {
+    let crate_name = coordinates.crate_name;
+    let mut lines = vec![];
+    let mut links = vec![];
+    for badge in BADGES {
+        if !badges.contains(badge) {
+            if *badge == Badge::License {
+                links.push(format!("[license-url]: {}", coordinates.license.1));
+            }
+            continue;
+        }
+        match badge {
+            Badge::Crates => {
+                lines.push("[![Crates.io][crates-badge]][crates-url]".to_owned());
+                links.extend([
+                    format!("[crates-badge]: https://img.shields.io/crates/v/{crate_name}.svg"),
+                    format!("[crates-url]: https://crates.io/crates/{crate_name}"),
+                ]);
+            }
+            Badge::Docs => {
+                lines.push("[![Documentation][docs-badge]][docs-url]".to_owned());
+                links.extend([
+                    format!("[docs-badge]: https://img.shields.io/docsrs/{crate_name}"),
+                    format!("[docs-url]: {}", coordinates.documentation),
+                ]);
+            }
+            Badge::Msrv => {
+                let Some(rust_version) = coordinates.rust_version else {
+                    continue;
+                };
+                let short = rust_version.splitn(3, '.').take(2).collect::<Vec<_>>();
+                let short = short.join(".");
+                lines.push(format!(
+                    "[![MSRV {short}][msrv-badge]](https://www.whatrustisit.com)"
+                ));
+                links.push(format!(
+                    "[msrv-badge]: https://img.shields.io/badge/MSRV-{short}-green?logo=rust"
+                ));
+            }
+            Badge::License => {
+                let (spdx, url) = coordinates.license;
+                lines.push(format!("[![{spdx} licensed][license-badge]][license-url]"));
+                links.extend([
+                    format!("[license-badge]: https://img.shields.io/crates/l/{crate_name}"),
+                    format!("[license-url]: {url}"),
+                ]);
+            }
+            Badge::Ci => {
+                let Some((badge, url)) = coordinates.ci.badge(coordinates.repository) else {
+                    continue;
+                };
+                lines.push("[![Build Status][actions-badge]][actions-url]".to_owned());
+                links.extend([
+                    format!("[actions-badge]: {badge}"),
+                    format!("[actions-url]: {url}"),
+                ]);
+            }
+        }
+    }
+    match (lines.is_empty(), links.is_empty()) {
+        (true, true) => String::new(),
+        (true, false) => format!("{}\n", links.join("\n")),
+        _ => format!("{}\n\n{}\n", lines.join("\n"), links.join("\n")),
+    }
+}
+
+/// Returns the badge block of a README `content`, from its first badge through the link
+/// definitions after it, along with the blank lines that follow, or `None` without badges.
+pub fn find_badge_block(content: &str) -> Option<&str> {
+    let start = content
+        .lines()
+        .scan(0, |offset, line| {
+            let start = *offset;
+            *offset += line.len() + 1;
+            Some((start, line))
+        })
+        .find(|(_, line)| line.starts_with("[!["))
+        .map(|(start, _)| start)?;
+    let mut end = start;
+    for line in content[start..].split_inclusive('\n') {
+        let trimmed = line.trim_end();
+        let is_badge = trimmed.starts_with("[![");
+        let is_link = trimmed.starts_with('[') && trimmed.contains("]: ");
+        if !(is_badge || is_link || trimmed.is_empty()) {
+            break;
+        }
+        end += line.len();
+    }
+    Some(&content[start..end])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_badge_block() {
+        let coordinates = Coordinates {
+            crate_name: "demo",
+            repository: "octo/demo",
+            documentation: "https://docs.rs/demo",
+            rust_version: Some("1.88.0"),
+            license: ("MIT", "https://opensource.org/licenses/MIT"),
+            ci: Ci::GitLab,
+        };
+        assert_eq!(
+            badge_block(BADGES, &coordinates),
+            "[![Crates.io][crates-badge]][crates-url]
+[![Documentation][docs-badge]][docs-url]
+[![MSRV 1.88][msrv-badge]](https://www.whatrustisit.com)
+[![MIT licensed][license-badge]][license-url]
+[![Build Status][actions-badge]][actions-url]
+
+[crates-badge]: https://img.shields.io/crates/v/demo.svg
+[crates-url]: https://crates.io/crates/demo
+[docs-badge]: https://img.shields.io/docsrs/demo
+[docs-url]: https://docs.rs/demo
+[msrv-badge]: https://img.shields.io/badge/MSRV-1.88-green?logo=rust
+[license-badge]: https://img.shields.io/crates/l/demo
+[license-url]: https://opensource.org/licenses/MIT
+[actions-badge]: https://gitlab.com/octo/demo/badges/main/pipeline.svg
+[actions-url]: https://gitlab.com/octo/demo/-/pipelines
+"
+        );
+        let coordinates = Coordinates {
+            ci: Ci::None,
+            ..coordinates
+        };
+        assert_eq!(
+            badge_block(&[Badge::Docs, Badge::Ci], &coordinates),
+            "[![Documentation][docs-badge]][docs-url]\n\n[docs-badge]: https://img.shields.io/docsrs/demo\n[docs-url]: https://docs.rs/demo\n[license-url]: https://opensource.org/licenses/MIT\n"
+        );
+        assert_eq!(
+            badge_block(&[Badge::Ci], &coordinates),
+            "[license-url]: https://opensource.org/licenses/MIT\n"
+        );
+
+        assert_eq!(Badge::from_id("MSRV"), Some(Badge::Msrv));
+        assert_eq!(Badge::from_id("coverage"), None);
+    }
+
+    #[test]
+    fn test_find_badge_block() {
+        let readme = "# Demo\n\n[![Crates.io][crates-badge]][crates-url]\n\n[crates-badge]: https://img.shields.io/crates/v/demo.svg\n[crates-url]: https://crates.io/crates/demo\n\nA demo.\n\n[![Star][star-badge]](x)\n";
+        assert_eq!(
+            find_badge_block(readme),
+            Some(
+                "[![Crates.io][crates-badge]][crates-url]\n\n[crates-badge]: https://img.shields.io/crates/v/demo.svg\n[crates-url]: https://crates.io/crates/demo\n\n"
+            )
+        );
+        assert_eq!(find_badge_block("# Demo\n\nA demo.\n"), None);
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The benchmarks the generated crate can start with: a criterion harness in
+//! `benches/bench_main.rs`, declared as a `[[bench]]` target, which `cargo x bench` runs and
+//! compares against a baseline.
+
+use toml_edit::Value;
+
+use super::choice::Choice;
+use super::choice::inherited;
+
+/// The criterion requirement of the generated crate, the latest supporting the template's MSRV.
+const CRITERION_VERSION: &str = "0.7.0";
+
+/// The name of the bench target.
+pub const BENCH_NAME: &str = "bench_main";
+
+/// The criterion harness, which measures a standalone function so that it runs for every kind.
+const CRITERION_BENCH: &str = r#"//! The benchmarks of the crate, run with `cargo x bench`.
+
+use std::hint::black_box;
+
+use criterion::Criterion;
+use criterion::criterion_group;
+use criterion::criterion_main;
+
+fn bench_greeting(c: &mut Criterion) {
+    c.bench_function("greeting", |b| {
+        b.iter(|| format!("Hello, {}!", black_box("world")))
+    });
+}
+
+criterion_group!(benches, bench_greeting);
+criterion_main!(benches);
+"#;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Benchmarks {
+    None,
+    Criterion,
+}
+
+impl Choice for Benchmarks {
+    const OPTION: &'static str = "--benchmarks";
+
+    /// The supported benchmarks, starting with the default of none.
+    const ALL: &'static [Benchmarks] = &[Benchmarks::None, Benchmarks::Criterion];
+
+    fn id(self) -> &'static str {
+        match self {
+            Benchmarks::None => "none",
+            Benchmarks::Criterion => "criterion",
+        }
+    }
+
+    fn workspace_dependencies(self) -> Vec<(String, Value)> {
+        match self {
+            Benchmarks::None => vec![],
+            Benchmarks::Criterion => vec![(self.id().to_owned(), CRITERION_VERSION.into())],
+        }
+    }
+
+    fn crate_dev_dependencies(self) -> Vec<(String, Value)> {
+        match self {
+            Benchmarks::None => vec![],
+            Benchmarks::Criterion => vec![inherited(self.id())],
+        }
+    }
+}
+
+impl Benchmarks {
+    /// Returns the bench targets added to the manifest of the template crate.
+    pub fn benches(self) -> Vec<String> {
+        match self {
+            Benchmarks::None => vec![],
+            Benchmarks::Criterion => vec![BENCH_NAME.to_owned()],
+        }
+    }
+
+    /// Returns the harness of the bench target, or `None` without benchmarks.
+    pub fn render_bench(self) -> Option<&'static str> {
+        match self {
+            Benchmarks::None => None,
+            Benchmarks::Criterion => Some(CRITERION_BENCH),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_benchmarks_scaffolding() {
+        assert!(Benchmarks::None.workspace_dependencies().is_empty());
+        assert!(Benchmarks::None.benches().is_empty());
+        assert_eq!(Benchmarks::None.render_bench(), None);
+        let [(name, dependency)] = &Benchmarks::Criterion.workspace_dependencies()[..] else {
+            panic!("expected the criterion dependency");
+        };
+        assert_eq!(name, "criterion");
+        assert_eq!(dependency.to_string(), "\"0.7.0\"");
+        assert_eq!(Benchmarks::Criterion.benches(), ["bench_main"]);
+        assert!(
+            Benchmarks::Criterion
+                .render_bench()
+                .unwrap()
+                .contains("criterion_group!(benches, bench_greeting);\n")
+        );
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The options picking one of a fixed set of choices, such as the CI system or the async runtime
+//! the generated crate starts with, along with the dependencies each choice adds.
+
+use toml_edit::Value;
+
+use super::i18n;
+
+/// A choice of an option such as `--async-runtime`.
+pub trait Choice: Copy + 'static {
+    /// The option picking the choice, e.g., `--async-runtime`.
+    const OPTION: &'static str;
+
+    /// The supported choices, starting with the default.
+    const ALL: &'static [Self];
+
+    /// The value of the option.
+    fn id(self) -> &'static str;
+
+    fn from_id(id: &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|choice| choice.id().eq_ignore_ascii_case(id))
+    }
+
+    /// Returns the dependencies added to `[workspace.dependencies]`.
+    fn workspace_dependencies(self) -> Vec<(String, Value)> {
+        vec![]
+    }
+
+    /// Returns the dependencies added to the manifest of the generated crate, inherited from the
+    /// workspace.
+    fn crate_dependencies(self) -> Vec<(String, Value)> {
+        vec![]
+    }
+
+    /// Returns the dev-dependencies added to the manifest of the generated crate, inherited from
+    /// the workspace.
+    fn crate_dev_dependencies(self) -> Vec<(String, Value)> {
+        vec![]
+    }
+}
+
+/// Returns the values of the option picking a `T`, starting with the default.
+pub fn choice_ids<T: Choice>() -> Vec<&'static str> {
+    T::ALL.iter().map(|choice| choice.id()).collect()
+}
+
+/// Validates a choice, given as its option value.
+pub fn parse_choice<T: Choice>(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    T::from_id(value)
+        .map(|choice| choice.id().to_owned())
+        .ok_or_else(|| {
+            tr!(
+                "unknown value '{value}', expected one of {expected}",
+                value,
+                expected = choice_ids::<T>().join(", "),
+            )
+        })
+}
+
+/// Returns the dependency `name` inherited from the workspace, as the choices add them.
+pub fn inherited(name: &str) -> (String, Value) {
+    (name.to_owned(), Value::from_iter([("workspace", true)]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bootstrap::Benchmarks;
+    use crate::bootstrap::Ci;
+    use crate::bootstrap::ErrorHandling;
+    use crate::bootstrap::FeatureFlags;
+    use crate::bootstrap::Observability;
+    use crate::bootstrap::Runtime;
+
+    /// Checks that the choices of `T` parse case-insensitively, ignoring surrounding whitespace,
+    /// and that the error on `unknown` lists them.
+    fn check_parse<T: Choice>(unknown: &str) {
+        let ids = choice_ids::<T>();
+        for id in &ids {
+            let value = format!(" {} ", id.to_uppercase());
+            assert_eq!(parse_choice::<T>(&value), Ok(id.to_string()));
+        }
+        let err = parse_choice::<T>(unknown).unwrap_err();
+        assert!(err.contains(&ids.join(", ")), "{err}");
+    }
+
+    #[test]
+    fn test_parse_choice() {
+        check_parse::<Ci>("travis");
+        check_parse::<Runtime>("smol");
+        check_parse::<ErrorHandling>("anyhow");
+        check_parse::<FeatureFlags>("rayon");
+        check_parse::<Observability>("log");
+        check_parse::<Benchmarks>("divan");
+    }
+}
@@ -0,0 +1,190 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The CI systems the project is bootstrapped for: the GitHub Actions workflows the template
+//! ships, a GitLab CI pipeline running the same jobs, or none at all.
+
+use super::FileChange;
+use super::WORKFLOWS_DIR;
+use super::choice::Choice;
+use crate::split;
+
+/// The GitLab CI pipeline, mirroring the check and test jobs of the GitHub workflow; `cargo x`
+/// is replaced by the xtask alias of the project.
+const GITLAB_CI: &str = r#"workflow:
+  rules:
+    - if: $CI_PIPELINE_SOURCE == "merge_request_event"
+    - if: $CI_COMMIT_BRANCH == $CI_DEFAULT_BRANCH
+
+stages:
+  - check
+  - test
+
+default:
+  image: rust:latest
+  cache:
+    key: $CI_JOB_NAME
+    paths:
+      - target/
+
+check:
+  stage: check
+  script:
+    - cargo x lint
+    - cargo x unsafe-report
+    - cargo x cbindgen --check
+
+build:
+  stage: check
+  script:
+    - cargo x build
+
+test:
+  stage: test
+  parallel:
+    matrix:
+      - RUST_VERSION: [msrv, stable]
+  script:
+    # the MSRV is read from the workspace manifest, as the GitHub workflow does
+    - |
+      if [ "$RUST_VERSION" = msrv ]; then
+        RUST_VERSION=$(sed -n 's/^rust-version = "\(.*\)"/\1/p' Cargo.toml)
+      fi
+      echo "Rust: $RUST_VERSION"
+    - rm rust-toolchain.toml
+    - rustup toolchain install "$RUST_VERSION" --profile minimal
+    - rustup default "$RUST_VERSION"
+    - cargo x test --no-capture
+"#;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Ci {
+    GitHub,
+    GitLab,
+    None,
+}
+
+impl Choice for Ci {
+    const OPTION: &'static str = "--ci";
+
+    /// The supported CI systems, starting with the one of the template.
+    const ALL: &'static [Ci] = &[Ci::GitHub, Ci::GitLab, Ci::None];
+
+    fn id(self) -> &'static str {
+        match self {
+            Ci::GitHub => "github",
+            Ci::GitLab => "gitlab",
+            Ci::None => "none",
+        }
+    }
+}
+
+impl Ci {
+    /// Returns the URLs of the README's build badge image and of the pipelines it links to for
+    /// the `repository`, or `None` without CI.
+    pub fn badge(self, repository: &str) -> Option<(String, String)> {
+        match self {
+            Ci::GitHub => Some((
+                format!("https://github.com/{repository}/workflows/CI/badge.svg"),
+                format!("https://github.com/{repository}/actions?query=workflow%3ACI"),
+            )),
+            Ci::GitLab => Some((
+                format!("https://gitlab.com/{repository}/badges/main/pipeline.svg"),
+                format!("https://gitlab.com/{repository}/-/pipelines"),
+            )),
+            Ci::None => None,
+        }
+    }
+}
+
+/// Returns the files written or removed to run `ci` instead of the GitHub workflows, among the
+/// workspace `files`.
+///
+/// `workflow` is the main workflow as bootstrapped, whose license header the GitLab pipeline
+/// starts with, and `xtask_alias` the alias its jobs run the xtask with.
+pub fn planned_files(
+    ci: Ci,
+    files: &[String],
+    workflow: &str,
+    xtask_alias: &str,
+) -> Vec<FileChange> {
+    if ci == Ci::GitHub {
+        return vec![];
+    }
+    let mut changes: Vec<FileChange> = files
+        .iter()
+        .filter(|file| file.starts_with(WORKFLOWS_DIR))
+        .map(|file| FileChange::Remove { file: file.clone() })
+        .collect();
+    if ci == Ci::GitLab {
+        changes.push(FileChange::Write {
+            file: ".gitlab-ci.yml".to_owned(),
+            content: format!(
+                "{}{}",
+                split::leading_header(workflow, "#"),
+                GITLAB_CI.replace("cargo x ", &format!("cargo {xtask_alias} "))
+            ),
+        });
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_planned_files() {
+        let files = [
+            ".github/semantic.yml".to_owned(),
+            ".github/workflows/ci.yml".to_owned(),
+            "README.md".to_owned(),
+        ];
+        let workflow = "# Copyright 2026 Octocat\n\nname: CI\n";
+        assert!(planned_files(Ci::GitHub, &files, workflow, "x").is_empty());
+
+        let changes = planned_files(Ci::None, &files, workflow, "x");
+        let described: Vec<String> = changes.iter().map(FileChange::describe).collect();
+        assert_eq!(described, ["remove .github/workflows/ci.yml"]);
+
+        let changes = planned_files(Ci::GitLab, &files, workflow, "xt");
+        let [
+            FileChange::Remove { file: removed },
+            FileChange::Write { file, content },
+        ] = &changes[..]
+        else {
+            panic!("expected the workflow removed and the pipeline written");
+        };
+        assert_eq!(removed, ".github/workflows/ci.yml");
+        assert_eq!(file, ".gitlab-ci.yml");
+        assert!(content.starts_with("# Copyright 2026 Octocat\n\nworkflow:\n"));
+        assert!(content.contains("    - cargo xt test --no-capture\n"));
+        assert!(!content.contains("cargo x "));
+
+        assert_eq!(Ci::from_id("GitLab"), Some(Ci::GitLab));
+        assert_eq!(Ci::from_id("travis"), None);
+    }
+
+    #[test]
+    fn test_badge() {
+        assert_eq!(
+            Ci::GitLab.badge("octo/demo"),
+            Some((
+                "https://gitlab.com/octo/demo/badges/main/pipeline.svg".to_owned(),
+                "https://gitlab.com/octo/demo/-/pipelines".to_owned()
+            ))
+        );
+        assert_eq!(Ci::None.badge("octo/demo"), None);
+    }
+}
@@ -0,0 +1,314 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The text patches of `bootstrap --cleanup`, which remove the bootstrap command from the xtask
+//! and the placeholder code from the project, leaving no trace of the template machinery.
+
+/// The modules of the xtask only the bootstrap command uses; `scan` stays for `cargo x scan`.
+pub const BOOTSTRAP_MODULES: &[&str] = &["bootstrap"];
+
+/// The workspace members only the bootstrap uses, as their directories named after their packages.
+pub const BOOTSTRAP_MEMBERS: &[&str] = &["fast-template", "xtask-bootstrap"];
+
+/// The modules of the `xtask-bootstrap` engine that `cargo x rename-crate` renames crates with,
+/// which are moved into the xtask before the engine is removed.
+pub const ENGINE_MODULES: &[&str] = &["rename"];
+
+/// The prefix of the imports from the engine, which become imports from the xtask once its modules
+/// are moved there.
+const ENGINE_IMPORT: &str = "use xtask_bootstrap::";
+
+/// The items of the xtask `main.rs` declaring the bootstrap command, removed along with their
+/// attributes.
+const BOOTSTRAP_ITEMS: &[&str] = &[
+    "struct CommandBootstrap {",
+    "impl CommandBootstrap {",
+    "struct CommandExportTemplate {",
+    "impl CommandExportTemplate {",
+];
+
+/// The lines of the xtask `main.rs` naming the bootstrap command, as trimmed.
+const BOOTSTRAP_LINES: &[&str] = &[
+    "SubCommand::Bootstrap(cmd) => cmd.run(),",
+    "SubCommand::ExportTemplate(cmd) => cmd.run(),",
+    "Bootstrap(Box<CommandBootstrap>),",
+    "ExportTemplate(CommandExportTemplate),",
+];
+
+/// The placeholder function of the template crate.
+const PLACEHOLDER_FN: &str = "/// A placeholder function.
+pub fn hello() {
+    println!(\"Hello, world!\");
+}
+";
+
+/// Returns the xtask `main.rs` without the bootstrap command, or an error naming the first part
+/// of it that was not found, e.g., because it was already changed by hand.
+pub fn remove_bootstrap_command(main_rs: &str) -> Result<String, String> {
+    let mut lines: Vec<&str> = main_rs.split_inclusive('\n').collect();
+    for module in BOOTSTRAP_MODULES {
+        let declaration = format!("mod {module};");
+        remove_line(&mut lines, |line| line.trim_end() == declaration)
+            .ok_or_else(|| format!("`{declaration}` not found"))?;
+    }
+    for expected in BOOTSTRAP_LINES {
+        let index = remove_line(&mut lines, |line| line.trim() == *expected)
+            .ok_or_else(|| format!("`{expected}` not found"))?;
+        remove_attributes(&mut lines, index);
+    }
+    for item in BOOTSTRAP_ITEMS {
+        let start = lines
+            .iter()
+            .position(|line| line.trim_end() == *item)
+            .ok_or_else(|| format!("`{item}` not found"))?;
+        let end = lines[start..]
+            .iter()
+            .position(|line| line.trim_end() == "}")
+            .map(|i| start + i + 1)
+            .ok_or_else(|| format!("the end of `{item}` not found"))?;
+        // the blank line separating the item from the next one goes with it
+        let end = match lines.get(end) {
+            Some(line) if line.trim().is_empty() => end + 1,
+            _ => end,
+        };
+        lines.drain(start..end);
+        remove_attributes(&mut lines, start);
+    }
+    Ok(lines.concat())
+}
+
+/// Returns the xtask `main.rs` declaring the engine modules moved into the xtask, in order.
+pub fn declare_engine_modules(main_rs: &str) -> String {
+    let mut lines: Vec<String> = main_rs.split_inclusive('\n').map(str::to_owned).collect();
+    for module in ENGINE_MODULES {
+        insert_sorted(&mut lines, "mod ", format!("mod {module};\n"));
+    }
+    lines.concat()
+}
+
+/// Returns the Rust `source` of the xtask importing the engine modules from the xtask rather than
+/// from the engine.
+pub fn import_engine_modules(source: &str) -> String {
+    let mut lines: Vec<String> = source.split_inclusive('\n').map(str::to_owned).collect();
+    let Some(start) = lines
+        .iter()
+        .position(|line| line.starts_with(ENGINE_IMPORT))
+    else {
+        return source.to_owned();
+    };
+    let imports: Vec<String> = lines
+        .iter()
+        .filter_map(|line| line.strip_prefix(ENGINE_IMPORT))
+        .map(|path| format!("use super::{path}"))
+        .collect();
+    lines.retain(|line| !line.starts_with(ENGINE_IMPORT));
+    // a group of imports from the engine alone leaves its blank line behind
+    if start > 0 && lines[start - 1] == "\n" && lines.get(start).is_some_and(|line| line == "\n") {
+        lines.remove(start);
+    }
+    for import in imports {
+        insert_sorted(&mut lines, "use super::", import);
+    }
+    lines.concat()
+}
+
+/// Inserts `line` into the first run of lines starting with `prefix`, before the first one sorting
+/// after it as rustfmt sorts them.
+fn insert_sorted(lines: &mut Vec<String>, prefix: &str, line: String) {
+    let start = lines
+        .iter()
+        .position(|existing| existing.starts_with(prefix))
+        .unwrap_or(lines.len());
+    let key = |line: &str| line.trim_end().trim_end_matches(';').to_owned();
+    let index = lines[start..]
+        .iter()
+        .position(|existing| !existing.starts_with(prefix) || key(existing) > key(&line))
+        .map_or(lines.len(), |i| start + i);
+    lines.insert(index, line);
+}
+
+/// Removes the first line matching `matches`, returning its index.
+fn remove_line(lines: &mut Vec<&str>, matches: impl Fn(&str) -> bool) -> Option<usize> {
+    let index = lines.iter().position(|line| matches(line))?;
+    lines.remove(index);
+    Some(index)
+}
+
+/// Removes the attributes of the item that started at `index` before it was removed.
+fn remove_attributes(lines: &mut Vec<&str>, mut index: usize) {
+    while index > 0 && lines[index - 1].trim_start().starts_with("#[") {
+        index -= 1;
+        lines.remove(index);
+    }
+}
+
+/// Returns the Rust `source` of a project crate without the template's placeholder code: the
+/// `hello` function, the calls to it, and the greeting a generated `main` prints.
+///
+/// A block left empty, like the arm of the generated `hello` subcommand, is closed on its first
+/// line as rustfmt would.
+pub fn remove_placeholder_code(source: &str) -> String {
+    let source = source.replace(&format!("\n{PLACEHOLDER_FN}"), "");
+    let mut lines: Vec<String> = vec![];
+    for line in source.split_inclusive('\n') {
+        let trimmed = line.trim();
+        let is_call = trimmed
+            .strip_suffix("::hello();")
+            .is_some_and(|path| path.chars().all(|ch| ch.is_alphanumeric() || ch == '_'));
+        if is_call || trimmed == "println!(\"Hello, world!\");" {
+            continue;
+        }
+        match lines.last_mut() {
+            Some(last) if last.ends_with(" {\n") && trimmed == "}" => {
+                last.truncate(last.len() - 1);
+                last.push_str("}\n");
+            }
+            _ => lines.push(line.to_owned()),
+        }
+    }
+    lines.concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remove_bootstrap_command() {
+        let main_rs = r#"mod bench;
+mod bootstrap;
+mod scan;
+
+impl Command {
+    fn run(self) {
+        match self.sub {
+            SubCommand::Bench(cmd) => cmd.run(),
+            SubCommand::Bootstrap(cmd) => cmd.run(),
+            SubCommand::ExportTemplate(cmd) => cmd.run(),
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum SubCommand {
+    #[clap(about = "Run the benches.")]
+    Bench(CommandBench),
+    #[clap(about = "Bootstrap a new project from this template.")]
+    Bootstrap(Box<CommandBootstrap>),
+    #[clap(about = "Export the template for `cargo generate`.")]
+    ExportTemplate(CommandExportTemplate),
+}
+
+#[derive(Parser)]
+struct CommandBootstrap {
+    #[arg(long)]
+    cleanup: bool,
+}
+
+impl CommandBootstrap {
+    fn run(self) {
+        bootstrap::bootstrap();
+    }
+}
+
+#[derive(Parser)]
+struct CommandExportTemplate {
+    #[arg(long)]
+    out: Option<PathBuf>,
+}
+
+impl CommandExportTemplate {
+    fn run(self) {
+        bootstrap::export_template();
+    }
+}
+
+#[derive(Parser)]
+struct CommandBench {}
+"#;
+        assert_eq!(
+            remove_bootstrap_command(main_rs).unwrap(),
+            r#"mod bench;
+mod scan;
+
+impl Command {
+    fn run(self) {
+        match self.sub {
+            SubCommand::Bench(cmd) => cmd.run(),
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum SubCommand {
+    #[clap(about = "Run the benches.")]
+    Bench(CommandBench),
+}
+
+#[derive(Parser)]
+struct CommandBench {}
+"#
+        );
+        assert_eq!(
+            remove_bootstrap_command("mod bench;\n"),
+            Err("`mod bootstrap;` not found".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_move_engine_modules() {
+        assert_eq!(
+            declare_engine_modules("mod readme;\nmod rename_crate;\nmod scan;\n\nfn main() {}\n"),
+            "mod readme;\nmod rename;\nmod rename_crate;\nmod scan;\n\nfn main() {}\n"
+        );
+        assert_eq!(
+            import_engine_modules(
+                "use std::path::Path;\n\nuse toml_edit::DocumentMut;\nuse xtask_bootstrap::rename;\nuse xtask_bootstrap::rename::CrateRename;\n\nuse super::history;\nuse super::workspace_dir;\n"
+            ),
+            "use std::path::Path;\n\nuse toml_edit::DocumentMut;\n\nuse super::history;\nuse super::rename;\nuse super::rename::CrateRename;\nuse super::workspace_dir;\n"
+        );
+        assert_eq!(
+            import_engine_modules(
+                "use std::path::Path;\n\nuse xtask_bootstrap::rename;\n\nuse super::workspace_dir;\n"
+            ),
+            "use std::path::Path;\n\nuse super::rename;\nuse super::workspace_dir;\n"
+        );
+        assert_eq!(
+            import_engine_modules("use super::scan;\n"),
+            "use super::scan;\n"
+        );
+    }
+
+    #[test]
+    fn test_remove_placeholder_code() {
+        assert_eq!(
+            remove_placeholder_code(&format!(
+                "//! A demo library.\n\n#![deny(missing_docs)]\n\n{PLACEHOLDER_FN}"
+            )),
+            "//! A demo library.\n\n#![deny(missing_docs)]\n"
+        );
+        assert_eq!(
+            remove_placeholder_code(
+                "fn main() {\n    Args::parse();\n    demo_core::hello();\n}\n\nfn hello() {}\n"
+            ),
+            "fn main() {\n    Args::parse();\n}\n\nfn hello() {}\n"
+        );
+        assert_eq!(
+            remove_placeholder_code(
+                "    match args.command {\n        Command::Hello => {\n            println!(\"Hello, world!\");\n        }\n    }\n"
+            ),
+            "    match args.command {\n        Command::Hello => {}\n    }\n"
+        );
+    }
+}
@@ -0,0 +1,158 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The community health files GitHub links from the repository: the contributing guide, the code
+//! of conduct, and the security policy, written with the project's name and contacts.
+
+use super::FileChange;
+
+/// A community health file written at the workspace root.
+pub struct CommunityFile {
+    /// The value of `--community-files`.
+    pub id: &'static str,
+    pub file: &'static str,
+    /// The content, with `{project}`, `{repository}`, `{xtask}`, `{contact}` and `{report}` to
+    /// fill in.
+    content: &'static str,
+}
+
+/// The community health files, in the order they are written.
+pub const COMMUNITY_FILES: &[CommunityFile] = &[
+    CommunityFile {
+        id: "contributing",
+        file: "CONTRIBUTING.md",
+        content: include_str!("community/CONTRIBUTING.md"),
+    },
+    CommunityFile {
+        id: "code-of-conduct",
+        file: "CODE_OF_CONDUCT.md",
+        content: include_str!("community/CODE_OF_CONDUCT.md"),
+    },
+    CommunityFile {
+        id: "security",
+        file: "SECURITY.md",
+        content: include_str!("community/SECURITY.md"),
+    },
+];
+
+impl CommunityFile {
+    pub fn from_id(id: &str) -> Option<&'static CommunityFile> {
+        COMMUNITY_FILES
+            .iter()
+            .find(|file| file.id.eq_ignore_ascii_case(id))
+    }
+}
+
+/// The values filled into the community health files.
+pub struct Contacts<'a> {
+    pub project_name: &'a str,
+    /// The GitHub repository, as `account/name`.
+    pub repository: &'a str,
+    pub xtask_alias: &'a str,
+    /// Where conduct violations are reported, an email address or a URL.
+    pub maintainer_contact: &'a str,
+    /// Where vulnerabilities are reported, or empty for GitHub's private vulnerability reporting.
+    pub security_email: &'a str,
+}
+
+/// Returns the `selected` community health files written with the `contacts`, except those
+/// already among the workspace `files`, which the template's maintainers wrote themselves.
+pub fn planned_files(
+    selected: &[&CommunityFile],
+    files: &[String],
+    contacts: &Contacts,
+) -> Vec<FileChange> {
+    let report = match contacts.security_email {
+        "" => format!(
+            "Instead, report them privately through [GitHub's private vulnerability reporting](https://github.com/{}/security/advisories/new).",
+            contacts.repository
+        ),
+        email => format!("Instead, report them by email to <{email}>."),
+    };
+    let contact = match contacts.maintainer_contact {
+        contact if contact.contains('@') && !contact.contains("://") => format!("<{contact}>"),
+        contact => contact.to_owned(),
+    };
+    selected
+        .iter()
+        .filter(|community_file| !files.iter().any(|file| file == community_file.file))
+        .map(|community_file| FileChange::Write {
+            file: community_file.file.to_owned(),
+            content: community_file
+                .content
+                .replace("{project}", contacts.project_name)
+                .replace("{repository}", contacts.repository)
+                .replace("{xtask}", contacts.xtask_alias)
+                .replace("{contact}", &contact)
+                .replace("{report}", &report),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_planned_files() {
+        let contacts = Contacts {
+            project_name: "demo",
+            repository: "octo/demo",
+            xtask_alias: "xt",
+            maintainer_contact: "conduct@octo.dev",
+            security_email: "",
+        };
+        let files = ["README.md".to_owned(), "SECURITY.md".to_owned()];
+        let changes = planned_files(
+            &COMMUNITY_FILES.iter().collect::<Vec<_>>(),
+            &files,
+            &contacts,
+        );
+        let [
+            FileChange::Write {
+                file: contributing,
+                content: guide,
+            },
+            FileChange::Write {
+                file: code_of_conduct,
+                content: conduct,
+            },
+        ] = &changes[..]
+        else {
+            panic!("expected the contributing guide and the code of conduct written");
+        };
+        assert_eq!(contributing, "CONTRIBUTING.md");
+        assert!(guide.starts_with("# Contributing to demo\n"));
+        assert!(guide.contains("cargo xt test\n"));
+        assert_eq!(code_of_conduct, "CODE_OF_CONDUCT.md");
+        assert!(conduct.contains("at <conduct@octo.dev>."));
+
+        let security = CommunityFile::from_id("security").unwrap();
+        let changes = planned_files(&[security], &[], &contacts);
+        let [FileChange::Write { content, .. }] = &changes[..] else {
+            panic!("expected the security policy written");
+        };
+        assert!(content.contains("https://github.com/octo/demo/security/advisories/new"));
+        let contacts = Contacts {
+            security_email: "security@octo.dev",
+            ..contacts
+        };
+        let changes = planned_files(&[security], &[], &contacts);
+        let [FileChange::Write { content, .. }] = &changes[..] else {
+            panic!("expected the security policy written");
+        };
+        assert!(content.contains("by email to <security@octo.dev>."));
+        assert!(!content.contains('{'));
+    }
+}
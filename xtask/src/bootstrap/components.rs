@@ -0,0 +1,152 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The optional scaffolding a project may go without, such as benchmarks or fuzz targets, which
+//! the bootstrap removes along with the manifest sections declaring it.
+
+use super::FileChange;
+
+/// A piece of optional scaffolding.
+pub struct Component {
+    /// The value of `--without`.
+    pub id: &'static str,
+    pub description: &'static str,
+    /// The directories of the component in any crate, e.g., `benches` of `template/benches/`.
+    dirs: &'static [&'static str],
+    /// The files and directories of the component, from the workspace root.
+    paths: &'static [&'static str],
+    /// The arrays of target tables declaring the component in a manifest, e.g., `[[bench]]`.
+    pub targets: &'static [&'static str],
+    /// The workspace members or exclusions of the component.
+    pub members: &'static [&'static str],
+}
+
+/// The optional components, in the order they are offered.
+pub const COMPONENTS: &[Component] = &[
+    Component {
+        id: "benches",
+        description: "benchmarks in benches/",
+        dirs: &["benches"],
+        paths: &[],
+        targets: &["bench"],
+        members: &[],
+    },
+    Component {
+        id: "examples",
+        description: "examples in examples/",
+        dirs: &["examples"],
+        paths: &[],
+        targets: &["example"],
+        members: &[],
+    },
+    Component {
+        id: "fuzz",
+        description: "cargo-fuzz targets in fuzz/",
+        dirs: &[],
+        paths: &["fuzz/"],
+        targets: &[],
+        members: &["fuzz"],
+    },
+    Component {
+        id: "docs",
+        description: "the mdBook in docs/",
+        dirs: &[],
+        paths: &["docs/"],
+        targets: &[],
+        members: &[],
+    },
+    Component {
+        id: "release",
+        description: "the release workflow",
+        dirs: &[],
+        paths: &[".github/workflows/release.yml"],
+        targets: &[],
+        members: &[],
+    },
+];
+
+impl Component {
+    pub fn from_id(id: &str) -> Option<&'static Component> {
+        COMPONENTS
+            .iter()
+            .find(|component| component.id.eq_ignore_ascii_case(id))
+    }
+
+    /// Returns whether the workspace `file` belongs to the component.
+    fn contains(&self, file: &str) -> bool {
+        let in_path = |path: &&str| match path.ends_with('/') {
+            true => file.starts_with(path),
+            false => file == *path,
+        };
+        let mut parents = file.split('/').rev().skip(1);
+        self.paths.iter().any(in_path) || parents.any(|dir| self.dirs.contains(&dir))
+    }
+}
+
+/// Returns the components with files among the workspace `files`.
+pub fn present(files: &[String]) -> Vec<&'static Component> {
+    COMPONENTS
+        .iter()
+        .filter(|component| files.iter().any(|file| component.contains(file)))
+        .collect()
+}
+
+/// Returns the files removed to drop the `removed` components, among the workspace `files`.
+pub fn planned_files(removed: &[&Component], files: &[String]) -> Vec<FileChange> {
+    files
+        .iter()
+        .filter(|file| removed.iter().any(|component| component.contains(file)))
+        .map(|file| FileChange::Remove { file: file.clone() })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_planned_files() {
+        let files = [
+            ".github/workflows/ci.yml",
+            ".github/workflows/release.yml",
+            "Cargo.toml",
+            "docs/book.toml",
+            "docs/src/SUMMARY.md",
+            "fuzz/fuzz_targets/parse.rs",
+            "template/benches/parse.rs",
+            "template/src/benches.rs",
+            "xtask/src/docs.rs",
+        ]
+        .map(str::to_owned);
+        let ids = |components: Vec<&Component>| -> Vec<&str> {
+            components.iter().map(|component| component.id).collect()
+        };
+        assert_eq!(ids(present(&files)), ["benches", "fuzz", "docs", "release"]);
+
+        let removed = ["benches", "docs", "release"].map(|id| Component::from_id(id).unwrap());
+        let changes = planned_files(&removed, &files);
+        let described: Vec<String> = changes.iter().map(FileChange::describe).collect();
+        assert_eq!(
+            described,
+            [
+                "remove .github/workflows/release.yml",
+                "remove docs/book.toml",
+                "remove docs/src/SUMMARY.md",
+                "remove template/benches/parse.rs",
+            ]
+        );
+        assert!(Component::from_id("Fuzz").is_some());
+        assert!(Component::from_id("tests").is_none());
+    }
+}
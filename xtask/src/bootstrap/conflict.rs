@@ -0,0 +1,144 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Resolves a directory rename whose target already exists, e.g., a `demo/` directory next to the
+//! `template/` crate renamed to it, by planning file changes that clear the way for the rename.
+//!
+//! The changes are journaled like any other, so a resolved conflict is resumed and undone along
+//! with the rest of the bootstrap.
+
+use super::FileChange;
+
+/// How the existing target of a directory rename is dealt with.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Resolution {
+    /// Removes the files of the target, which the renamed directory replaces.
+    Overwrite,
+    /// Keeps the files of the target the renamed directory does not have, moving them into it
+    /// before the rename, while the others are replaced.
+    Merge,
+}
+
+/// The choices of the conflict menu, in order, after which comes aborting.
+pub const RESOLUTIONS: &[(Resolution, &str)] = &[
+    (Resolution::Overwrite, "Overwrite it, removing its files"),
+    (
+        Resolution::Merge,
+        "Merge into it, keeping the files the template does not have",
+    ),
+];
+
+/// Returns the files of the directory `to` among the workspace `files`, which are in the way of
+/// renaming another directory to it.
+pub fn existing_files<'a>(to: &str, files: &'a [String]) -> Vec<&'a String> {
+    let prefix = format!("{to}/");
+    files
+        .iter()
+        .filter(|file| file.starts_with(&prefix))
+        .collect()
+}
+
+/// Returns the changes emptying the target `to` of the rename of `from` as `resolution` says,
+/// given the workspace `files`, the `planned` changes, and the `bootstrapped` content of a file.
+pub fn planned_files(
+    from: &str,
+    to: &str,
+    resolution: Resolution,
+    files: &[String],
+    planned: &[FileChange],
+    bootstrapped: impl Fn(&str) -> String,
+) -> Vec<FileChange> {
+    let from_prefix = format!("{from}/");
+    // the files the renamed directory will hold, which win over those of the target
+    let template_files: Vec<&str> = files
+        .iter()
+        .map(String::as_str)
+        .chain(planned.iter().filter_map(|change| match change {
+            FileChange::Remove { .. } => None,
+            change => Some(change.file()),
+        }))
+        .filter_map(|file| file.strip_prefix(&from_prefix))
+        .collect();
+    let to_prefix = format!("{to}/");
+    let mut changes = vec![];
+    for file in existing_files(to, files) {
+        let relative = &file[to_prefix.len()..];
+        if resolution == Resolution::Merge && !template_files.contains(&relative) {
+            changes.push(FileChange::Write {
+                file: format!("{from_prefix}{relative}"),
+                content: bootstrapped(file),
+            });
+        }
+        changes.push(FileChange::Remove { file: file.clone() });
+    }
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_planned_files() {
+        let files = [
+            "demo/Cargo.toml",
+            "demo/notes.md",
+            "template/Cargo.toml",
+            "template/src/lib.rs",
+        ]
+        .map(str::to_owned);
+        let planned = [FileChange::Write {
+            file: "template/src/main.rs".to_owned(),
+            content: String::new(),
+        }];
+        let bootstrapped = |file: &str| format!("bootstrapped {file}");
+        let describe = |resolution| {
+            planned_files(
+                "template",
+                "demo",
+                resolution,
+                &files,
+                &planned,
+                bootstrapped,
+            )
+            .iter()
+            .map(FileChange::describe)
+            .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            describe(Resolution::Overwrite),
+            ["remove demo/Cargo.toml", "remove demo/notes.md"]
+        );
+        assert_eq!(
+            describe(Resolution::Merge),
+            [
+                "remove demo/Cargo.toml",
+                "write template/notes.md",
+                "remove demo/notes.md",
+            ]
+        );
+        let changes = planned_files(
+            "template",
+            "demo",
+            Resolution::Merge,
+            &files,
+            &planned,
+            bootstrapped,
+        );
+        let FileChange::Write { content, .. } = &changes[1] else {
+            panic!("expected the kept file moved");
+        };
+        assert_eq!(content, "bootstrapped demo/notes.md");
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The file edits of the bootstrap, planned per concern: the sources written from the answers,
+//! the manifests, the Markdown badges, and the copyright notices, along with the renames of the
+//! template crates, the placeholders, and the xtask alias rewritten in every file.
+
+mod copyright;
+mod manifests;
+mod markdown;
+mod sources;
+
+use xtask_bootstrap::rename;
+use xtask_bootstrap::rename::CrateRename;
+
+use super::Answers;
+use super::DEFAULT_XTASK_ALIAS;
+use super::PACKAGE_FILES;
+use super::TARGET_DIRS;
+use super::WORKFLOWS_DIR;
+use super::badges;
+use super::is_skipped;
+use super::manifest;
+use super::scan;
+use super::tool_config;
+
+/// A file rewritten by the bootstrap, as the renames of the template crates, then the structured
+/// update of a manifest, then text replacements applied in order.
+pub struct Edit {
+    pub file: String,
+    pub replacements: Vec<(String, String)>,
+    pub rename_crates: Vec<CrateRename>,
+    pub manifest: Option<manifest::ManifestEdit>,
+    pub tool_config: Option<tool_config::ToolConfigEdit>,
+}
+
+/// A text replacement of the files it is scoped to, or of every file if the scope is empty, as
+/// the scope, the old text, and the new text.
+type Replacement<'a> = (&'a [&'a str], String, String);
+
+/// Returns the file edits of the bootstrap for the workspace `files`, as paths and contents, and
+/// the members named after the template, as `crates`.
+///
+/// Placeholders and `cargo x` invocations are rewritten in every file mentioning them, while the
+/// template package names are only rewritten in the manifests, with toml_edit, the lockfile, the
+/// sources of the renamed members, the workflows, the crates.io and docs.rs badges of Markdown
+/// files, and the lint tool configs.
+pub fn planned_edits(
+    files: &[(String, String)],
+    answers: &Answers,
+    crates: &[CrateRename],
+) -> Vec<Edit> {
+    let project_name = answers.project_name.as_str();
+    let xtask_alias = answers.xtask_alias.as_str();
+    let repository = format!("{}/{project_name}", answers.github_username);
+    let renamed = |text: &str, rust: bool| rename::rename_crates(text, crates, rust);
+    let workspace_manifest = files
+        .iter()
+        .find(|(file, _)| file == "Cargo.toml")
+        .map_or("", |(_, content)| content.as_str());
+    let manifest_files: Vec<&str> = files
+        .iter()
+        .map(|(file, _)| file.as_str())
+        .filter(|file| *file == "Cargo.toml" || file.ends_with("/Cargo.toml"))
+        .collect();
+
+    let alias_replacements = alias_replacements(xtask_alias);
+    let mut answer_replacements = sources::source_replacements(answers, crates);
+    answer_replacements.extend(manifests::metadata_replacements(
+        workspace_manifest,
+        &manifest_files,
+        answers,
+    ));
+    let copyright_replacements = copyright::copyright_replacements(files, answers);
+    let package_fields = manifests::package_fields(answers);
+    let badge_block = markdown::badge_block(workspace_manifest, answers);
+    let tool_config = tool_config_edit(answers, crates);
+
+    let mut edits = vec![];
+    for (file, content) in files {
+        let is_rust = file.ends_with(".rs");
+        // the examples, benches, and integration tests of any member may use the template crate
+        let is_crate_source = is_rust
+            && (crates
+                .iter()
+                .any(|rename| file.starts_with(&format!("{}/", rename.dir)))
+                || file
+                    .split('/')
+                    .rev()
+                    .skip(1)
+                    .any(|dir| TARGET_DIRS.contains(&dir)));
+        let is_workflow =
+            file.starts_with(WORKFLOWS_DIR) && (file.ends_with(".yml") || file.ends_with(".yaml"));
+        let skipped = is_skipped(file);
+        let mut content = content.clone();
+        if !skipped
+            && (PACKAGE_FILES.contains(&file.as_str()) || is_crate_source || is_workflow)
+            && content.contains("template")
+        {
+            edits.push(Edit {
+                file: file.to_owned(),
+                replacements: vec![],
+                rename_crates: crates.to_vec(),
+                manifest: None,
+                tool_config: None,
+            });
+            content = renamed(&content, is_rust);
+        }
+        if !skipped && (file == "Cargo.toml" || file.ends_with("/Cargo.toml")) {
+            let manifest = manifests::manifest_edit(file, files, answers, crates, &package_fields);
+            let updated = manifest::update_manifest(&content, &manifest);
+            if updated != content {
+                edit_for(&mut edits, file).manifest = Some(manifest);
+                content = updated;
+            }
+        }
+        if !skipped && tool_config::is_tool_config(file) {
+            let updated = tool_config::update_tool_config(file, &content, &tool_config);
+            if updated != content {
+                edit_for(&mut edits, file).tool_config = Some(tool_config.clone());
+                content = updated;
+            }
+        }
+        for placeholder in scan::PLACEHOLDERS {
+            if !skipped && content.contains(placeholder) {
+                let new = match *placeholder {
+                    "fast/template" => repository.as_str(),
+                    _ => project_name,
+                };
+                add_replacement(&mut edits, file, placeholder, new);
+                content = content.replace(placeholder, new);
+            }
+        }
+        if !skipped && file == "README.md" {
+            if let Some(block) = badges::find_badge_block(&content) {
+                let new = match badge_block.as_str() {
+                    "" => String::new(),
+                    badges => format!("{badges}\n"),
+                };
+                add_replacement(&mut edits, file, block, &new);
+                content = content.replacen(block, &new, 1);
+            }
+        }
+        if !skipped && file.ends_with(".md") {
+            for (old, new) in markdown::badge_replacements(&content, project_name) {
+                add_replacement(&mut edits, file, &old, &new);
+            }
+        }
+        let alias_replacements = match xtask_alias {
+            DEFAULT_XTASK_ALIAS => &[][..],
+            _ => &alias_replacements[..],
+        };
+        let replacements: Vec<_> = if skipped {
+            copyright_replacements.iter().collect()
+        } else {
+            alias_replacements
+                .iter()
+                .chain(&answer_replacements)
+                .chain(&copyright_replacements)
+                .collect()
+        };
+        for (scope, old, new) in replacements {
+            let in_scope = scope.is_empty() || scope.contains(&file.as_str());
+            if in_scope && old != new && content.contains(old.as_str()) {
+                add_replacement(&mut edits, file, old, new);
+            }
+        }
+    }
+    edits
+}
+
+/// Returns the replacements of the default xtask alias with `xtask_alias`.
+///
+/// The package name and cargo alias are only rewritten in their own files, while `cargo x` is
+/// rewritten everywhere, both as `cargo x <command>` and as a bare `cargo x` in inline code.
+fn alias_replacements(xtask_alias: &str) -> [Replacement<'static>; 4] {
+    [
+        (
+            &["Cargo.lock"],
+            format!("name = \"{DEFAULT_XTASK_ALIAS}\""),
+            format!("name = \"{xtask_alias}\""),
+        ),
+        (
+            &[".cargo/config.toml"],
+            format!("{DEFAULT_XTASK_ALIAS} = \"run --package {DEFAULT_XTASK_ALIAS} --\""),
+            format!("{xtask_alias} = \"run --package {xtask_alias} --\""),
+        ),
+        (
+            &[],
+            format!("cargo {DEFAULT_XTASK_ALIAS} "),
+            format!("cargo {xtask_alias} "),
+        ),
+        (
+            &[],
+            format!("cargo {DEFAULT_XTASK_ALIAS}`"),
+            format!("cargo {xtask_alias}`"),
+        ),
+    ]
+}
+
+/// Returns the update of the lint tool configs, which must accept the project name and license,
+/// and the crate directories renamed as `crates`.
+fn tool_config_edit(answers: &Answers, crates: &[CrateRename]) -> tool_config::ToolConfigEdit {
+    let mut words: Vec<String> = vec![];
+    for name in [&answers.project_name, &answers.github_username] {
+        for word in name.split(['-', '_']).map(str::to_lowercase) {
+            if word.len() > 1 && !words.contains(&word) {
+                words.push(word);
+            }
+        }
+    }
+    tool_config::ToolConfigEdit {
+        dirs: crates
+            .iter()
+            .filter(|rename| rename.renames_dir())
+            .map(|rename| (rename.dir.clone(), rename.new_dir.clone()))
+            .collect(),
+        words,
+        licenses: answers
+            .license
+            .spdx
+            .split(" OR ")
+            .map(str::to_owned)
+            .collect(),
+        github_account: answers.github_username.clone(),
+    }
+}
+
+fn add_replacement(edits: &mut Vec<Edit>, file: &str, old: &str, new: &str) {
+    let replacement = (old.to_owned(), new.to_owned());
+    let edit = edit_for(edits, file);
+    if !edit.replacements.contains(&replacement) {
+        edit.replacements.push(replacement);
+    }
+}
+
+/// Returns the edit of `file`, adding an empty one if there is none yet.
+fn edit_for<'a>(edits: &'a mut Vec<Edit>, file: &str) -> &'a mut Edit {
+    match edits.iter().position(|edit| edit.file == file) {
+        Some(i) => &mut edits[i],
+        None => {
+            edits.push(Edit {
+                file: file.to_owned(),
+                replacements: vec![],
+                rename_crates: vec![],
+                manifest: None,
+                tool_config: None,
+            });
+            edits.last_mut().unwrap()
+        }
+    }
+}
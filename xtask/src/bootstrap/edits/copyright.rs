@@ -0,0 +1,64 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The edits of the copyright notices, which name the copyright holder and year of the answers
+//! instead of the template's.
+
+use toml_edit::DocumentMut;
+
+use super::Replacement;
+use crate::bootstrap::Answers;
+
+/// Returns the replacements of the copyright notice of the template, as recorded in the
+/// properties of `licenserc.toml` in `files`, which the headers of every file carry.
+pub fn copyright_replacements(
+    files: &[(String, String)],
+    answers: &Answers,
+) -> Vec<Replacement<'static>> {
+    let Some((year, owner)) = template_copyright(files) else {
+        return vec![];
+    };
+    let holder = answers.copyright_holder.as_str();
+    vec![
+        (
+            &[],
+            format!("Copyright {year} {owner}"),
+            format!("Copyright {} {holder}", answers.year),
+        ),
+        (
+            &["licenserc.toml"],
+            format!(
+                "copyrightOwner = {}",
+                toml_edit::Value::from(owner.as_str())
+            ),
+            format!("copyrightOwner = {}", toml_edit::Value::from(holder)),
+        ),
+        (
+            &["licenserc.toml"],
+            format!("inceptionYear = {year}"),
+            format!("inceptionYear = {}", answers.year),
+        ),
+    ]
+}
+
+/// Returns the year and owner of the template's copyright notice from the `[properties]` of
+/// `licenserc.toml` in `files`.
+fn template_copyright(files: &[(String, String)]) -> Option<(String, String)> {
+    let (_, content) = files.iter().find(|(file, _)| file == "licenserc.toml")?;
+    let doc = content.parse::<DocumentMut>().ok()?;
+    let properties = doc.get("properties")?;
+    let year = properties.get("inceptionYear")?.as_integer()?;
+    let owner = properties.get("copyrightOwner")?.as_str()?;
+    Some((year.to_string(), owner.to_owned()))
+}
@@ -0,0 +1,226 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The edits of the manifests: the package metadata from the answers, the members and
+//! dependencies the answers add, and the text the edition, MSRV, and license are stated in.
+
+use xtask_bootstrap::rename::CrateRename;
+
+use super::Replacement;
+use crate::bootstrap::Answers;
+use crate::bootstrap::LICENSES;
+use crate::bootstrap::choice::Choice;
+use crate::bootstrap::harness;
+use crate::bootstrap::kind::Kind;
+use crate::bootstrap::manifest::ManifestEdit;
+use crate::bootstrap::template_version;
+use crate::bootstrap::variant::Variant;
+use crate::bootstrap::workspace_package_field;
+
+/// The manifests whose package metadata is set from the answers: the workspace manifest, which
+/// declares it in `[workspace.package]`, and the template crate, which inherits it.
+const METADATA_MANIFESTS: &[&str] = &["Cargo.toml", "template/Cargo.toml"];
+
+/// Returns the package metadata set from the answers, which goes into the manifests with
+/// toml_edit, unlike the text replacements.
+pub fn package_fields(answers: &Answers) -> Vec<(String, toml_edit::Value)> {
+    let list = |items: &[String]| toml_edit::Value::from_iter(items.iter().map(String::as_str));
+    let mut fields: Vec<(String, toml_edit::Value)> = [
+        ("description", &answers.description),
+        ("homepage", &answers.homepage),
+        ("documentation", &answers.documentation),
+    ]
+    .into_iter()
+    .map(|(key, value)| (key.to_owned(), value.as_str().into()))
+    .collect();
+    for (key, items) in [
+        ("authors", &answers.authors),
+        ("keywords", &answers.keywords),
+        ("categories", &answers.categories),
+    ] {
+        if !items.is_empty() {
+            fields.push((key.to_owned(), list(items)));
+        }
+    }
+    if let Some(edition) = &answers.edition {
+        fields.push(("edition".to_owned(), edition.as_str().into()));
+    }
+    if let Some(rust_version) = &answers.rust_version {
+        fields.push(("rust-version".to_owned(), rust_version.as_str().into()));
+    }
+    fields
+}
+
+/// Returns the replacements of the edition, MSRV, and license of the template where they are
+/// stated as text, given the `workspace_manifest` of the template and its `manifests`.
+pub fn metadata_replacements<'a>(
+    workspace_manifest: &str,
+    manifests: &'a [&'a str],
+    answers: &Answers,
+) -> Vec<Replacement<'a>> {
+    let mut replacements: Vec<Replacement> = vec![];
+    // the code of xtask needs the edition of the template
+    if answers.edition.is_some() {
+        if let Some(template_edition) = workspace_package_field(workspace_manifest, "edition") {
+            replacements.push((
+                &["xtask/Cargo.toml"],
+                "edition.workspace = true".to_owned(),
+                format!("edition = \"{template_edition}\""),
+            ));
+        }
+    }
+    // the CI tests the version in the manifest, so only the README states it
+    if let Some(rust_version) = &answers.rust_version {
+        if let Some(template_rust_version) =
+            workspace_package_field(workspace_manifest, "rust-version")
+        {
+            replacements.extend([
+                (
+                    &["README.md"][..],
+                    format!("`{template_rust_version}`"),
+                    format!("`{rust_version}`"),
+                ),
+                (
+                    &["README.md"],
+                    format!("Rust {template_rust_version}"),
+                    format!("Rust {rust_version}"),
+                ),
+            ]);
+        }
+    }
+    let (template_license, license) = (&LICENSES[0], answers.license);
+    if license.id != template_license.id {
+        replacements.extend([
+            (
+                manifests,
+                format!("license = \"{}\"", template_license.spdx),
+                format!("license = \"{}\"", license.spdx),
+            ),
+            (
+                &["licenserc.toml"],
+                format!("headerPath = \"{}.txt\"", template_license.spdx),
+                format!("headerPath = \"{}.txt\"", license.spdx),
+            ),
+            (
+                &["README.md"],
+                format!("licensed under {}.", template_license.readme_name),
+                format!("licensed under {}.", license.readme_name),
+            ),
+        ]);
+    }
+    replacements
+}
+
+/// Returns the structured update of the manifest `file` among the workspace `files`, renaming the
+/// members named after the template as `crates` and setting the `package_fields` where declared.
+pub fn manifest_edit(
+    file: &str,
+    files: &[(String, String)],
+    answers: &Answers,
+    crates: &[CrateRename],
+    package_fields: &[(String, toml_edit::Value)],
+) -> ManifestEdit {
+    let project_name = answers.project_name.as_str();
+    let removed_components = &answers.removed_components;
+    ManifestEdit {
+        crates: crates.to_vec(),
+        package_name: match file {
+            "xtask/Cargo.toml" => Some(answers.xtask_alias.clone()),
+            _ => crates
+                .iter()
+                .find(|rename| file == format!("{}/Cargo.toml", rename.dir))
+                .map(|rename| rename.new_package.clone()),
+        },
+        repository: format!(
+            "https://github.com/{}/{project_name}",
+            answers.github_username
+        ),
+        package_fields: match METADATA_MANIFESTS.contains(&file) {
+            true => package_fields.to_vec(),
+            false => vec![],
+        },
+        members: match file {
+            "Cargo.toml" => answers.kind.members(project_name),
+            _ => vec![],
+        },
+        dependencies: match file {
+            "Cargo.toml" => [
+                answers
+                    .kind
+                    .workspace_dependencies(project_name, &template_version(files)),
+                answers.runtime.workspace_dependencies(),
+                answers.errors.workspace_dependencies(),
+                answers.feature_flags.workspace_dependencies(),
+                answers.observability.workspace_dependencies(),
+                answers.benchmarks.workspace_dependencies(),
+                answers
+                    .variant
+                    .as_ref()
+                    .map(Variant::workspace_dependencies)
+                    .unwrap_or_default(),
+            ]
+            .concat(),
+            "template/Cargo.toml" => [
+                answers.kind.crate_dependencies(project_name),
+                answers.runtime.crate_dependencies(),
+                answers.errors.crate_dependencies(),
+                answers.feature_flags.crate_dependencies(),
+                answers.observability.crate_dependencies(),
+                // a binary initializes the subscriber its example test also uses
+                match answers.kind {
+                    Kind::Bin => answers.observability.binary_dependencies(),
+                    Kind::Lib | Kind::Workspace | Kind::ProcMacro => vec![],
+                },
+            ]
+            .concat(),
+            _ => vec![],
+        },
+        features: match file {
+            "template/Cargo.toml" => answers.feature_flags.crate_features(),
+            _ => vec![],
+        },
+        dev_dependencies: match file {
+            "template/Cargo.toml" => [
+                answers.kind.crate_dev_dependencies(),
+                match answers.kind {
+                    Kind::Bin => vec![],
+                    Kind::Lib | Kind::Workspace | Kind::ProcMacro => {
+                        answers.observability.library_dev_dependencies()
+                    }
+                },
+                answers.benchmarks.crate_dev_dependencies(),
+            ]
+            .concat(),
+            _ => vec![],
+        },
+        benches: match file {
+            "template/Cargo.toml" => answers.benchmarks.benches(),
+            _ => vec![],
+        },
+        removed_members: removed_components
+            .iter()
+            .flat_map(|component| component.members)
+            .chain(match answers.xtask {
+                true => &[][..],
+                false => harness::HARNESS_MEMBERS,
+            })
+            .map(|member| member.to_string())
+            .collect(),
+        removed_targets: removed_components
+            .iter()
+            .flat_map(|component| component.targets)
+            .map(|target| target.to_string())
+            .collect(),
+    }
+}
@@ -0,0 +1,80 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The edits of the Markdown files: the badge block of the README, and the badge and link
+//! definitions naming the template crate.
+
+use crate::bootstrap::Answers;
+use crate::bootstrap::badges;
+use crate::bootstrap::workspace_package_field;
+
+/// The hosts of README badges and their links whose paths name the crate, e.g.,
+/// `https://docs.rs/template`.
+const BADGE_HOSTS: &[&str] = &["crates.io", "docs.rs", "img.shields.io"];
+
+/// Returns the badge block of the README, which points to the new crate, repository, and docs,
+/// and states the MSRV of the answers or else of the template's `workspace_manifest`.
+pub fn badge_block(workspace_manifest: &str, answers: &Answers) -> String {
+    let crate_name = answers.kind.crate_name(&answers.project_name);
+    let repository = format!("{}/{}", answers.github_username, answers.project_name);
+    let rust_version = answers
+        .rust_version
+        .clone()
+        .or_else(|| workspace_package_field(workspace_manifest, "rust-version"));
+    badges::badge_block(
+        &answers.badges,
+        &badges::Coordinates {
+            crate_name: &crate_name,
+            repository: &repository,
+            documentation: &answers.documentation,
+            rust_version: rust_version.as_deref(),
+            license: (answers.license.spdx, answers.license.url),
+            ci: answers.ci,
+        },
+    )
+}
+
+/// Returns the replacements of the Markdown link definitions, e.g.,
+/// `[docs-url]: https://docs.rs/template`, whose badge or link URL names the template crate.
+pub fn badge_replacements(content: &str, project_name: &str) -> Vec<(String, String)> {
+    let mut replacements = vec![];
+    for line in content.lines() {
+        let Some((label, url)) = line.split_once("]: https://") else {
+            continue;
+        };
+        let Some((host, path)) = url.split_once('/') else {
+            continue;
+        };
+        if !label.starts_with('[') || !BADGE_HOSTS.contains(&host) {
+            continue;
+        }
+        let path: Vec<String> = path
+            .split('/')
+            .map(|segment| {
+                // keep an extension like `.svg` or a query like `?style=flat`
+                let (name, rest) =
+                    segment.split_at(segment.find(['.', '?']).unwrap_or(segment.len()));
+                match name {
+                    "template" => format!("{project_name}{rest}"),
+                    _ => segment.to_owned(),
+                }
+            })
+            .collect();
+        let new = format!("{label}]: https://{host}/{}", path.join("/"));
+        if new != line {
+            replacements.push((line.to_owned(), new));
+        }
+    }
+    replacements
+}
@@ -0,0 +1,110 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The replacements writing the answers into the library and the README: the description, and
+//! the code the library starts with.
+
+use xtask_bootstrap::rename;
+use xtask_bootstrap::rename::CrateRename;
+
+use super::Replacement;
+use crate::bootstrap::Answers;
+use crate::bootstrap::README_DESCRIPTION;
+use crate::bootstrap::TEMPLATE_DESCRIPTION;
+use crate::bootstrap::errors;
+use crate::bootstrap::errors::ErrorHandling;
+use crate::bootstrap::features::FeatureFlags;
+use crate::bootstrap::kind::Kind;
+
+/// The end of the placeholder function of the library, which the example code follows.
+const HELLO_END: &str = "    println!(\"Hello, world!\");\n}\n";
+
+/// The end of the crate attributes of the library, which the declarations follow.
+const ATTRIBUTES_END: &str = "#![deny(missing_docs)]\n";
+
+/// Returns the replacements of the description of the library and the README, and of the code
+/// the library starts with, in the template crate renamed as `crates`.
+pub fn source_replacements(answers: &Answers, crates: &[CrateRename]) -> Vec<Replacement<'static>> {
+    // the description replaces the template's own, matched after the crate rename
+    let description = &answers.description;
+    let mut replacements: Vec<Replacement> = vec![
+        (
+            &["template/src/lib.rs"],
+            rename::rename_crates(&format!("//! {TEMPLATE_DESCRIPTION}"), crates, true),
+            format!("//! {description}"),
+        ),
+        (
+            &["README.md"],
+            README_DESCRIPTION.to_owned(),
+            description.clone(),
+        ),
+    ];
+
+    // the example test of the runtime follows the placeholder function of the library
+    let runtime_tests = answers.runtime.render_tests();
+    if !runtime_tests.is_empty() {
+        replacements.push((
+            &["template/src/lib.rs"],
+            HELLO_END.to_owned(),
+            format!("{HELLO_END}{runtime_tests}"),
+        ));
+    }
+
+    // so does the instrumented example of the observability, in a library
+    let example = answers.observability.render_example();
+    if !example.is_empty() {
+        replacements.push((
+            &["template/src/lib.rs"],
+            HELLO_END.to_owned(),
+            format!("{HELLO_END}{example}"),
+        ));
+    }
+
+    // so does the type gated on the features, which the crate docs list
+    if answers.feature_flags != FeatureFlags::None {
+        replacements.push((
+            &["template/src/lib.rs"],
+            HELLO_END.to_owned(),
+            format!("{HELLO_END}{}", answers.feature_flags.render_example()),
+        ));
+        let intro_end = "//! requests are welcome.\n";
+        replacements.push((
+            &["template/src/lib.rs"],
+            intro_end.to_owned(),
+            format!("{intro_end}{}", answers.feature_flags.render_docs()),
+        ));
+    }
+
+    // the facade of a proc-macro project re-exports its macros after the crate attributes
+    if answers.kind == Kind::ProcMacro {
+        replacements.push((
+            &["template/src/lib.rs"],
+            ATTRIBUTES_END.to_owned(),
+            format!(
+                "{ATTRIBUTES_END}{}",
+                answers.kind.render_facade(&answers.project_name)
+            ),
+        ));
+    }
+
+    // the error module is declared after the crate attributes of the library
+    if answers.errors != ErrorHandling::None {
+        replacements.push((
+            &["template/src/lib.rs"],
+            ATTRIBUTES_END.to_owned(),
+            format!("{ATTRIBUTES_END}{}", errors::LIB_DECLARATIONS),
+        ));
+    }
+    replacements
+}
@@ -0,0 +1,139 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The error handling the generated library can start with: a `src/error.rs` module declaring
+//! the crate's `Error` enum and `Result` alias, re-exported at the crate root.
+
+use toml_edit::Value;
+
+use super::choice::Choice;
+use super::choice::inherited;
+
+/// The thiserror requirement of the generated crate.
+const THISERROR_VERSION: &str = "2.0";
+
+/// The declarations of the error module, inserted after the crate attributes of `src/lib.rs`.
+pub const LIB_DECLARATIONS: &str = "
+mod error;
+
+pub use error::Error;
+pub use error::Result;
+";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ErrorHandling {
+    None,
+    Thiserror,
+}
+
+impl Choice for ErrorHandling {
+    const OPTION: &'static str = "--error-handling";
+
+    /// The supported error handling, starting with the default of none.
+    const ALL: &'static [ErrorHandling] = &[ErrorHandling::None, ErrorHandling::Thiserror];
+
+    fn id(self) -> &'static str {
+        match self {
+            ErrorHandling::None => "none",
+            ErrorHandling::Thiserror => "thiserror",
+        }
+    }
+
+    fn workspace_dependencies(self) -> Vec<(String, Value)> {
+        match self {
+            ErrorHandling::None => vec![],
+            ErrorHandling::Thiserror => vec![(self.id().to_owned(), THISERROR_VERSION.into())],
+        }
+    }
+
+    fn crate_dependencies(self) -> Vec<(String, Value)> {
+        match self {
+            ErrorHandling::None => vec![],
+            ErrorHandling::Thiserror => vec![inherited(self.id())],
+        }
+    }
+}
+
+impl ErrorHandling {
+    /// Returns the `src/error.rs` of the library `crate_path`, as used in its doctests, or
+    /// `None` without error handling.
+    pub fn render_module(self, crate_path: &str) -> Option<String> {
+        match self {
+            ErrorHandling::None => None,
+            ErrorHandling::Thiserror => Some(format!(
+                r#"//! The errors of the crate.
+
+/// The error of every fallible operation of the crate.
+///
+/// ```
+/// use std::io;
+///
+/// let err = {crate_path}::Error::from(io::Error::other("disk full"));
+/// assert_eq!(err.to_string(), "I/O error: disk full");
+/// ```
+#[derive(Debug, thiserror::Error)]
+#[non_exhaustive]
+pub enum Error {{
+    /// An I/O operation failed.
+    #[error("I/O error: {{0}}")]
+    Io(#[from] std::io::Error),
+    /// Any other failure, described by its message.
+    #[error("{{0}}")]
+    Message(String),
+}}
+
+/// A result whose error defaults to the crate's [`Error`].
+///
+/// ```
+/// fn parse(input: &str) -> {crate_path}::Result<u32> {{
+///     input
+///         .parse()
+///         .map_err(|_| {crate_path}::Error::Message(format!("not a number: {{input}}")))
+/// }}
+///
+/// assert_eq!(parse("42").unwrap(), 42);
+/// assert_eq!(
+///     parse("forty-two").unwrap_err().to_string(),
+///     "not a number: forty-two"
+/// );
+/// ```
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+"#
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_handling_scaffolding() {
+        assert!(ErrorHandling::None.workspace_dependencies().is_empty());
+        assert_eq!(ErrorHandling::None.render_module("demo"), None);
+        let [(name, dependency)] = &ErrorHandling::Thiserror.workspace_dependencies()[..] else {
+            panic!("expected the thiserror dependency");
+        };
+        assert_eq!(name, "thiserror");
+        assert_eq!(dependency.to_string(), "\"2.0\"");
+        let module = ErrorHandling::Thiserror.render_module("demo_core").unwrap();
+        assert!(
+            module.contains(
+                "/// let err = demo_core::Error::from(io::Error::other(\"disk full\"));\n"
+            )
+        );
+        assert!(module.contains("    #[error(\"I/O error: {0}\")]\n"));
+    }
+}
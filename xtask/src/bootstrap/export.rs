@@ -0,0 +1,217 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The export of the template for `cargo generate`, whose placeholders are derived from the
+//! names the bootstrap renames, so that both start from the same files.
+//!
+//! The template crates are renamed to a stand-in project name first, as the bootstrap would
+//! rename them, and each spelling of that name is then replaced by its cargo-generate
+//! placeholder.
+
+use xtask_bootstrap::rename;
+use xtask_bootstrap::rename::CrateRename;
+
+use super::PACKAGE_FILES;
+use super::README_DESCRIPTION;
+use super::TARGET_DIRS;
+use super::TEMPLATE_DESCRIPTION;
+use super::WORKFLOWS_DIR;
+use super::is_skipped;
+use super::manifest;
+use super::manifest::ManifestEdit;
+use super::template_renames;
+
+/// The file declaring the placeholders of a cargo-generate template.
+pub const CARGO_GENERATE_FILE: &str = "cargo-generate.toml";
+
+/// The placeholders prompted for by `cargo generate`, besides its built-in `project-name`.
+pub const CARGO_GENERATE_TOML: &str = r#"[template]
+cargo_generate_version = ">=0.18.0"
+
+[placeholders.github-account]
+type = "string"
+prompt = "Enter your GitHub username (e.g., tisonkun)"
+regex = "^[A-Za-z0-9](?:[A-Za-z0-9]|-[A-Za-z0-9]){0,38}$"
+"#;
+
+/// The project name the template crates are renamed to before their spellings are replaced.
+const STAND_IN: &str = "xtask-export-stand-in";
+
+/// The spellings of the stand-in project name, and the placeholders replacing them.
+const SPELLINGS: &[(&str, &str)] = &[
+    (
+        "XTASK_EXPORT_STAND_IN",
+        "{{project-name | shouty_snake_case}}",
+    ),
+    ("XtaskExportStandIn", "{{project-name | pascal_case}}"),
+    ("xtask_export_stand_in", "{{crate_name}}"),
+    ("xtask-export-stand-in", "{{project-name}}"),
+];
+
+/// The placeholders of the bootstrap, and those of cargo-generate replacing them.
+const PLACEHOLDERS: &[(&str, &str)] = &[
+    ("fast/template", "{{github-account}}/{{project-name}}"),
+    ("${projectName}", "{{project-name}}"),
+];
+
+/// Returns the renames of the workspace `members` named after the template to the stand-in.
+pub fn crate_renames(members: &[(String, String)]) -> Vec<CrateRename> {
+    template_renames(members, STAND_IN, STAND_IN)
+}
+
+/// Returns the path of `file` in the export, under the renamed directories of the `crates`.
+pub fn exported_path(file: &str, crates: &[CrateRename]) -> String {
+    let path = crates
+        .iter()
+        .filter(|rename| rename.renames_dir())
+        .find_map(|rename| {
+            let rest = file.strip_prefix(&format!("{}/", rename.dir))?;
+            Some(format!("{}/{rest}", rename.new_dir))
+        })
+        .unwrap_or_else(|| file.to_owned());
+    with_placeholders(&path)
+}
+
+/// Returns the content of `file` in the export, where the text cargo-generate would take for its
+/// own template syntax, like `${{ matrix.os }}` in a workflow, is escaped.
+///
+/// The crates are renamed in the same files the bootstrap renames them in, and the description
+/// of the template becomes the one the bootstrap defaults to.
+pub fn exported_content(file: &str, content: &str, crates: &[CrateRename]) -> String {
+    let escape = |content: &str| {
+        content
+            .replace("{{", "{{ \"{{\" }}")
+            .replace("{%", "{{ \"{%\" }}")
+    };
+    if is_skipped(file) {
+        return escape(content);
+    }
+    let is_rust = file.ends_with(".rs");
+    let is_crate_source = is_rust
+        && (crates
+            .iter()
+            .any(|rename| file.starts_with(&format!("{}/", rename.dir)))
+            || file
+                .split('/')
+                .rev()
+                .skip(1)
+                .any(|dir| TARGET_DIRS.contains(&dir)));
+    let is_workflow = file.starts_with(WORKFLOWS_DIR);
+    let content = if file == "Cargo.toml" || file.ends_with("/Cargo.toml") {
+        let manifest = ManifestEdit {
+            crates: crates.to_vec(),
+            package_name: crates
+                .iter()
+                .find(|rename| file == format!("{}/Cargo.toml", rename.dir))
+                .map(|rename| rename.new_package.clone()),
+            repository: manifest::TEMPLATE_REPOSITORY.to_owned(),
+            package_fields: vec![],
+            members: vec![],
+            dependencies: vec![],
+            features: vec![],
+            dev_dependencies: vec![],
+            benches: vec![],
+            removed_members: vec![],
+            removed_targets: vec![],
+        };
+        manifest::update_manifest(content, &manifest)
+    } else if is_crate_source || is_workflow || PACKAGE_FILES.contains(&file) {
+        rename::rename_crates(content, crates, is_rust)
+    } else {
+        content.to_owned()
+    };
+    let description = rename::rename_crate(TEMPLATE_DESCRIPTION, "template", STAND_IN, false);
+    let replacements = [
+        (TEMPLATE_DESCRIPTION, description.as_str()),
+        (README_DESCRIPTION, description.as_str()),
+    ];
+    let content = replacements
+        .iter()
+        .chain(PLACEHOLDERS)
+        .fold(escape(&content), |content, (old, new)| {
+            content.replace(old, new)
+        });
+    with_placeholders(&content)
+}
+
+/// Replaces the spellings of the stand-in project name with their placeholders.
+fn with_placeholders(text: &str) -> String {
+    SPELLINGS
+        .iter()
+        .fold(text.to_owned(), |text, (old, new)| text.replace(old, new))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exported_files() {
+        let crates = crate_renames(&[
+            ("template".to_owned(), "template".to_owned()),
+            ("template-macros".to_owned(), "macros".to_owned()),
+            ("x".to_owned(), "xtask".to_owned()),
+        ]);
+        assert_eq!(
+            exported_path("template/src/lib.rs", &crates),
+            "{{project-name}}/src/lib.rs"
+        );
+        assert_eq!(
+            exported_path("macros/src/lib.rs", &crates),
+            "macros/src/lib.rs"
+        );
+
+        assert_eq!(
+            exported_content(
+                "template/src/lib.rs",
+                "//! `${projectName}` at fast/template.\nuse template_macros::Template;\n",
+                &crates
+            ),
+            "//! `{{project-name}}` at {{github-account}}/{{project-name}}.\nuse {{crate_name}}_macros::{{project-name | pascal_case}};\n"
+        );
+        assert_eq!(
+            exported_content(
+                "Cargo.toml",
+                "[workspace]\nmembers = [\"template\", \"macros\"]\n\n[workspace.metadata.release]\ntag-name = \"v{{version}}\"\n\n[workspace.dependencies]\ntemplate-macros = { path = \"macros\" }\n",
+                &crates
+            ),
+            "[workspace]\nmembers = [\"{{project-name}}\", \"macros\"]\n\n[workspace.metadata.release]\ntag-name = \"v{{ \"{{\" }}version}}\"\n\n[workspace.dependencies]\n{{project-name}}-macros = { path = \"macros\" }\n"
+        );
+        assert_eq!(
+            exported_content(
+                "template/Cargo.toml",
+                "[package]\nname = \"template\"\ndescription = \"A template library.\"\n",
+                &crates
+            ),
+            "[package]\nname = \"{{project-name}}\"\ndescription = \"A {{project-name}} library.\"\n"
+        );
+        assert_eq!(
+            exported_content(
+                ".github/workflows/ci.yml",
+                "runs-on: ${{ matrix.os }}\nrun: cargo test -p template\n",
+                &crates
+            ),
+            "runs-on: ${{ \"{{\" }} matrix.os }}\nrun: cargo test -p {{project-name}}\n"
+        );
+        // the xtask defines the placeholders of the bootstrap itself
+        assert_eq!(
+            exported_content(
+                "xtask/src/bootstrap.rs",
+                "const PLACEHOLDER: &str = \"${projectName}\";\n",
+                &crates
+            ),
+            "const PLACEHOLDER: &str = \"${projectName}\";\n"
+        );
+    }
+}
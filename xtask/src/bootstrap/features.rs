@@ -0,0 +1,206 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The feature flags the generated library can start with, following the feature-gating
+//! conventions: an optional `serde` feature enabling optional dependencies, code gated on it and
+//! labeled on docs.rs, which builds with all features, and the features listed in the crate docs.
+//!
+//! A crate with features its default leaves out is tested both with and without them by
+//! `cargo x test`.
+
+use toml_edit::Value;
+
+use super::choice::Choice;
+
+/// The serde requirement of the generated crate.
+const SERDE_VERSION: &str = "1.0.228";
+
+/// The serde_json requirement of the generated crate.
+const SERDE_JSON_VERSION: &str = "1.0.149";
+
+/// The list of the features, following the introduction of the crate docs.
+const SERDE_DOCS: &str = "//!
+//! # Features
+//!
+//! - `serde`: (de)serializes the public types with [serde](https://serde.rs), and converts them
+//!   to and from JSON.
+";
+
+/// The type gated on the feature, following the placeholder function of the library, and its
+/// tests.
+const SERDE_EXAMPLE: &str = r##"
+/// The greeting of a name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Greeting {
+    /// The name greeted.
+    pub name: String,
+}
+
+impl std::fmt::Display for Greeting {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Hello, {}!", self.name)
+    }
+}
+
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+impl Greeting {
+    /// Returns the greeting as JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("a greeting serializes to JSON")
+    }
+
+    /// Parses a greeting from JSON.
+    pub fn from_json(json: &str) -> serde_json::Result<Greeting> {
+        serde_json::from_str(json)
+    }
+}
+
+#[cfg(test)]
+mod greeting_tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let greeting = Greeting {
+            name: "world".to_owned(),
+        };
+        assert_eq!(greeting.to_string(), "Hello, world!");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json() {
+        let greeting = Greeting {
+            name: "world".to_owned(),
+        };
+        assert_eq!(greeting.to_json(), r#"{"name":"world"}"#);
+        assert_eq!(Greeting::from_json(&greeting.to_json()).unwrap(), greeting);
+    }
+}
+"##;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeatureFlags {
+    None,
+    Serde,
+}
+
+impl Choice for FeatureFlags {
+    const OPTION: &'static str = "--feature-flags";
+
+    /// The supported feature flags, starting with the default of none.
+    const ALL: &'static [FeatureFlags] = &[FeatureFlags::None, FeatureFlags::Serde];
+
+    fn id(self) -> &'static str {
+        match self {
+            FeatureFlags::None => "none",
+            FeatureFlags::Serde => "serde",
+        }
+    }
+
+    /// Returns the dependencies on serde, with the `derive` feature the gated type uses.
+    fn workspace_dependencies(self) -> Vec<(String, Value)> {
+        match self {
+            FeatureFlags::None => vec![],
+            FeatureFlags::Serde => {
+                let mut serde = toml_edit::InlineTable::new();
+                serde.insert("version", SERDE_VERSION.into());
+                serde.insert("features", Value::from_iter(["derive"]));
+                vec![
+                    ("serde".to_owned(), Value::InlineTable(serde)),
+                    ("serde_json".to_owned(), SERDE_JSON_VERSION.into()),
+                ]
+            }
+        }
+    }
+
+    /// Returns the optional dependencies of the library, inherited from the workspace.
+    fn crate_dependencies(self) -> Vec<(String, Value)> {
+        match self {
+            FeatureFlags::None => vec![],
+            FeatureFlags::Serde => ["serde", "serde_json"]
+                .iter()
+                .map(|name| {
+                    let dependency = Value::from_iter([("workspace", true), ("optional", true)]);
+                    (name.to_string(), dependency)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl FeatureFlags {
+    /// Returns the features added to the `[features]` of the library, each enabling the
+    /// optional dependencies it gates.
+    pub fn crate_features(self) -> Vec<(String, Value)> {
+        match self {
+            FeatureFlags::None => vec![],
+            FeatureFlags::Serde => vec![(
+                self.id().to_owned(),
+                Value::from_iter(["dep:serde", "dep:serde_json"]),
+            )],
+        }
+    }
+
+    /// Returns the list of the features in the crate docs, or an empty string without features.
+    pub fn render_docs(self) -> &'static str {
+        match self {
+            FeatureFlags::None => "",
+            FeatureFlags::Serde => SERDE_DOCS,
+        }
+    }
+
+    /// Returns the type gated on the features and its tests, or an empty string without
+    /// features.
+    pub fn render_example(self) -> &'static str {
+        match self {
+            FeatureFlags::None => "",
+            FeatureFlags::Serde => SERDE_EXAMPLE,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_feature_flags_scaffolding() {
+        assert!(FeatureFlags::None.workspace_dependencies().is_empty());
+        assert!(FeatureFlags::None.crate_features().is_empty());
+        assert!(FeatureFlags::None.render_example().is_empty());
+        let [(name, dependency), _] = &FeatureFlags::Serde.workspace_dependencies()[..] else {
+            panic!("expected the serde dependencies");
+        };
+        assert_eq!(name, "serde");
+        assert_eq!(
+            dependency.to_string(),
+            "{ version = \"1.0.228\", features = [\"derive\"] }"
+        );
+        let [(_, dependency), _] = &FeatureFlags::Serde.crate_dependencies()[..] else {
+            panic!("expected the optional dependencies");
+        };
+        assert_eq!(
+            dependency.to_string(),
+            "{ workspace = true, optional = true }"
+        );
+        let [(name, feature)] = &FeatureFlags::Serde.crate_features()[..] else {
+            panic!("expected the serde feature");
+        };
+        assert_eq!(name, "serde");
+        assert_eq!(feature.to_string(), "[\"dep:serde\", \"dep:serde_json\"]");
+    }
+}
@@ -0,0 +1,196 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Creates the GitHub repository of the bootstrapped project, with the GitHub CLI or the REST API.
+
+use std::error::Error;
+use std::path::Path;
+use std::process::Stdio;
+
+use serde_json::Value;
+use serde_json::json;
+
+use super::audit;
+use super::git;
+use super::print_task;
+use super::print_update_result;
+use crate::env;
+use crate::find_command;
+
+/// The GitHub repository to create.
+pub struct Repository<'a> {
+    pub account: &'a str,
+    pub name: &'a str,
+    pub description: &'a str,
+    /// The crates.io keywords, which become the repository topics.
+    pub keywords: &'a [String],
+}
+
+/// Creates the public repository, sets its topics and pushes the commit of `root` to `origin`.
+///
+/// The GitHub CLI is used when installed, so that its login applies, and otherwise the REST API
+/// with the `GITHUB_TOKEN` from the environment or the dotenv files. Failures are reported but
+/// not fatal, since the project itself is bootstrapped.
+pub fn create_repository(root: &Path, repository: &Repository) {
+    let full_name = format!("{}/{}", repository.account, repository.name);
+    let topics = github_topics(repository.keywords);
+    let token = std::env::var("GITHUB_TOKEN")
+        .ok()
+        .or_else(|| env::dotenv_vars().get("GITHUB_TOKEN").cloned());
+    let client = match token {
+        _ if which::which("gh").is_ok() => Client::Cli,
+        Some(token) => Client::Api(token),
+        None => Client::Missing,
+    };
+
+    print_task(format!("Creating the GitHub repository {full_name}..."));
+    let created = match &client {
+        Client::Cli => gh(&[
+            "repo",
+            "create",
+            &full_name,
+            "--public",
+            "--description",
+            repository.description,
+        ]),
+        Client::Api(token) => create_with_api(token, repository),
+        Client::Missing => {
+            Err("install the GitHub CLI or set GITHUB_TOKEN to create the repository".into())
+        }
+    };
+    if print_update_result(created).is_err() {
+        return;
+    }
+
+    if !topics.is_empty() {
+        print_task("Setting the repository topics...");
+        let result = match &client {
+            Client::Cli => gh(&["repo", "edit", &full_name, "--add-topic", &topics.join(",")]),
+            Client::Api(token) => api(
+                token,
+                "PUT",
+                &format!("repos/{full_name}/topics"),
+                &json!({ "names": topics }),
+            )
+            .map(drop),
+            Client::Missing => unreachable!("the repository was created"),
+        };
+        let _ = print_update_result(result);
+    }
+
+    print_task("Pushing the initial commit...");
+    let _ = print_update_result(git(root, &["push", "--quiet", "-u", "origin", "HEAD"]).map(drop));
+}
+
+/// How the GitHub repository is created: with the GitHub CLI, or with a token for the REST API.
+enum Client {
+    Cli,
+    Api(String),
+    Missing,
+}
+
+/// Converts crates.io keywords to GitHub topics, which are lowercase and only allow `-` besides
+/// letters and digits.
+fn github_topics(keywords: &[String]) -> Vec<String> {
+    let mut topics: Vec<String> = vec![];
+    for keyword in keywords {
+        let topic: String = keyword
+            .to_lowercase()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .collect();
+        let topic = topic.trim_matches('-').to_owned();
+        if !topic.is_empty() && !topics.contains(&topic) {
+            topics.push(topic);
+        }
+    }
+    topics
+}
+
+fn gh(args: &[&str]) -> Result<(), Box<dyn Error>> {
+    let mut cmd = find_command("gh");
+    cmd.args(args);
+    audit::command(&cmd);
+    let output = cmd.output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("{cmd:?} failed: {}", stderr.trim()).into());
+    }
+    Ok(())
+}
+
+/// Creates the repository under the account of the token's user, or under an organization.
+fn create_with_api(token: &str, repository: &Repository) -> Result<(), Box<dyn Error>> {
+    let user = api(token, "GET", "user", &Value::Null)?;
+    let path = if user["login"].as_str() == Some(repository.account) {
+        "user/repos".to_owned()
+    } else {
+        format!("orgs/{}/repos", repository.account)
+    };
+    let body = json!({
+        "name": repository.name,
+        "description": repository.description,
+    });
+    api(token, "POST", &path, &body).map(drop)
+}
+
+/// Sends a GitHub REST API request with curl, returning the response body.
+///
+/// The token is passed on stdin so that it does not show up in the process list.
+fn api(token: &str, method: &str, path: &str, body: &Value) -> Result<Value, Box<dyn Error>> {
+    let mut cmd = find_command("curl");
+    cmd.args(["--silent", "--show-error", "--max-time", "30"])
+        .args(["--request", method])
+        .args(["--user-agent", "xtask-bootstrap"])
+        .args(["--header", "Accept: application/vnd.github+json"])
+        .args(["--header", "@-"])
+        .args(["--write-out", "\n%{http_code}"]);
+    if !body.is_null() {
+        cmd.args(["--data", &body.to_string()]);
+    }
+    cmd.arg(format!("https://api.github.com/{path}"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    audit::command(&cmd);
+    let mut child = cmd.spawn()?;
+    let header = format!("Authorization: Bearer {token}\n");
+    std::io::Write::write_all(&mut child.stdin.take().unwrap(), header.as_bytes())?;
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!("could not reach the GitHub API: {}", stderr.trim()).into());
+    }
+
+    let stdout = String::from_utf8(output.stdout)?;
+    let (body, status) = stdout.rsplit_once('\n').unwrap_or(("", &stdout));
+    let status: u16 = status.trim().parse()?;
+    let body: Value = serde_json::from_str(body).unwrap_or(Value::Null);
+    if !(200..300).contains(&status) {
+        let message = body["message"].as_str().unwrap_or("unexpected response");
+        return Err(format!("{method} {path} failed with HTTP {status}: {message}").into());
+    }
+    Ok(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_github_topics() {
+        let keywords = ["CLI", "no_std", "c++", "cli", "+"].map(str::to_owned);
+        assert_eq!(github_topics(&keywords), ["cli", "no-std", "c"]);
+    }
+}
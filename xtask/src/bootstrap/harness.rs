@@ -0,0 +1,213 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Strips the xtask harness for `--no-xtask`, leaving a plain Cargo workspace whose CI runs
+//! cargo directly.
+
+use toml_edit::DocumentMut;
+use toml_edit::Item;
+
+use super::DEFAULT_XTASK_ALIAS;
+use super::FileChange;
+use super::WORKFLOWS_DIR;
+
+/// The directories and files of the harness and of the template machinery running on it.
+const HARNESS_PATHS: &[&str] = &[
+    "xtask/",
+    "xtask-bootstrap/",
+    "fast-template/",
+    "xtask.toml",
+    ".github/workflows/ci-bootstrap.yml",
+    ".github/workflows/mutants.yml",
+];
+
+/// The workspace members of the harness.
+pub const HARNESS_MEMBERS: &[&str] = &["xtask", "xtask-bootstrap", "fast-template"];
+
+const CARGO_CONFIG: &str = ".cargo/config.toml";
+
+/// The jobs of the GitHub workflow that only run xtask commands without a cargo equivalent.
+const XTASK_JOBS: &[&str] = &["nightly", "wasm", "bench"];
+
+/// The section of the agent instructions pointing to the xtask.
+const AGENTS_SECTION: &str = "## Cargo XTask
+
+Use `cargo x` as the source of truth for repository workflows.
+
+- Run `cargo x --help` before choosing build, test, lint, or formatting commands.
+- Run `cargo x <command> --help` for command-specific behavior.
+
+";
+
+/// Returns the files removed and rewritten to drop the harness, among the workspace `files`,
+/// from the `bootstrapped` content of the rewritten ones.
+pub fn planned_files(files: &[String], bootstrapped: impl Fn(&str) -> String) -> Vec<FileChange> {
+    let mut changes: Vec<FileChange> = files
+        .iter()
+        .filter(|file| {
+            HARNESS_PATHS.iter().any(|path| match path.ends_with('/') {
+                true => file.starts_with(path),
+                false => file == path,
+            })
+        })
+        .map(|file| FileChange::Remove { file: file.clone() })
+        .collect();
+    let has = |name: &str| files.iter().any(|file| file == name);
+    if has(CARGO_CONFIG) {
+        changes.push(match cargo_config(&bootstrapped(CARGO_CONFIG)) {
+            Some(content) => FileChange::Write {
+                file: CARGO_CONFIG.to_owned(),
+                content,
+            },
+            None => FileChange::Remove {
+                file: CARGO_CONFIG.to_owned(),
+            },
+        });
+    }
+    let workflow = format!("{WORKFLOWS_DIR}ci.yml");
+    if has(&workflow) {
+        let mut content = bootstrapped(&workflow);
+        for job in XTASK_JOBS {
+            content = remove_job(&content, job);
+        }
+        changes.push(FileChange::Write {
+            file: workflow,
+            content: plain_commands(&content),
+        });
+    }
+    if has("AGENTS.md") {
+        let content = bootstrapped("AGENTS.md");
+        if content.contains(AGENTS_SECTION) {
+            changes.push(FileChange::Write {
+                file: "AGENTS.md".to_owned(),
+                content: content.replace(AGENTS_SECTION, ""),
+            });
+        }
+    }
+    changes
+}
+
+/// Returns the cargo config without the xtask alias and the workspace directory the xtask reads,
+/// or `None` if nothing else is left.
+fn cargo_config(content: &str) -> Option<String> {
+    let Ok(mut doc) = content.parse::<DocumentMut>() else {
+        return Some(content.to_owned());
+    };
+    for (table, key) in [
+        ("alias", DEFAULT_XTASK_ALIAS),
+        ("env", "CARGO_WORKSPACE_DIR"),
+    ] {
+        if let Some(entries) = doc.get_mut(table).and_then(Item::as_table_like_mut) {
+            entries.remove(key);
+            if entries.is_empty() {
+                doc.remove(table);
+            }
+        }
+    }
+    (!doc.as_table().is_empty()).then(|| doc.to_string())
+}
+
+/// Removes the `job` of a GitHub workflow, up to the next job or top-level key.
+fn remove_job(workflow: &str, job: &str) -> String {
+    let lines: Vec<&str> = workflow.split_inclusive('\n').collect();
+    let header = format!("  {job}:");
+    let Some(start) = lines.iter().position(|line| line.trim_end() == header) else {
+        return workflow.to_owned();
+    };
+    let end = lines[start + 1..]
+        .iter()
+        .position(|line| {
+            let indent = line.len() - line.trim_start_matches(' ').len();
+            !line.trim().is_empty() && indent <= 2 && !line.trim_start().starts_with('#')
+        })
+        .map_or(lines.len(), |i| start + 1 + i);
+    lines[..start].concat() + &lines[end..].concat()
+}
+
+/// Replaces the `cargo x` commands of a CI config by their cargo equivalents, dropping those
+/// without one, such as `cargo x unsafe-report`.
+pub fn plain_commands(config: &str) -> String {
+    let xtask = format!("cargo {DEFAULT_XTASK_ALIAS} ");
+    let mut content = String::new();
+    for line in config.split_inclusive('\n') {
+        let Some(index) = line.find(&xtask) else {
+            content.push_str(line);
+            continue;
+        };
+        let prefix = &line[..index];
+        let commands: &[&str] = match line[index + xtask.len()..].split_whitespace().next() {
+            Some("lint") => &[
+                "cargo fmt --all --check",
+                "cargo clippy --workspace --all-targets -- -D warnings",
+            ],
+            Some("build") => &["cargo build --workspace --all-targets"],
+            Some("test") => &["cargo test --workspace -- --nocapture"],
+            _ => &[],
+        };
+        for command in commands {
+            content.push_str(&format!("{prefix}{command}\n"));
+        }
+    }
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_planned_files() {
+        let files = [
+            ".cargo/config.toml",
+            ".github/workflows/ci-bootstrap.yml",
+            ".github/workflows/ci.yml",
+            "Cargo.toml",
+            "fast-template/src/main.rs",
+            "template/src/lib.rs",
+            "xtask/src/main.rs",
+            "xtask.toml",
+        ]
+        .map(str::to_owned);
+        let config = "[alias]\nx = \"run --package x --\"\n\n[env]\nCARGO_WORKSPACE_DIR = { value = \"\", relative = true }\n";
+        let workflow = "jobs:\n  check:\n    steps:\n      - run: cargo x lint\n      - run: cargo x unsafe-report\n\n  nightly:\n    # informational\n    steps:\n      - run: cargo x lint --nightly\n\n  test:\n    steps:\n      - name: Run unit tests\n        run: cargo x test --no-capture\n";
+        let bootstrapped = |file: &str| match file {
+            ".cargo/config.toml" => config.to_owned(),
+            _ => workflow.to_owned(),
+        };
+        let changes = planned_files(&files, bootstrapped);
+        let described: Vec<String> = changes.iter().map(FileChange::describe).collect();
+        assert_eq!(
+            described[..5],
+            [
+                "remove .github/workflows/ci-bootstrap.yml",
+                "remove fast-template/src/main.rs",
+                "remove xtask/src/main.rs",
+                "remove xtask.toml",
+                "remove .cargo/config.toml",
+            ]
+        );
+        let FileChange::Write { content, .. } = &changes[5] else {
+            panic!("expected the workflow rewritten");
+        };
+        assert_eq!(
+            content,
+            "jobs:\n  check:\n    steps:\n      - run: cargo fmt --all --check\n      - run: cargo clippy --workspace --all-targets -- -D warnings\n\n  test:\n    steps:\n      - name: Run unit tests\n        run: cargo test --workspace -- --nocapture\n"
+        );
+
+        assert_eq!(
+            cargo_config("[alias]\nx = \"run --package x --\"\nxt = \"nextest run\"\n").as_deref(),
+            Some("[alias]\nxt = \"nextest run\"\n")
+        );
+    }
+}
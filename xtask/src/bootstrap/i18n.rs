@@ -0,0 +1,698 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The languages of the bootstrap wizard, whose prompts, preview panels, and error messages are
+//! written in English and looked up in a translation table for the others.
+//!
+//! The language is `--lang`, or else the locale of `LC_ALL`, `LC_MESSAGES`, or `LANG`, falling
+//! back to English. Only what is shown to the user is translated: the audit log, the JSON summary,
+//! and the generated files stay in English.
+
+use std::sync::OnceLock;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Lang {
+    En,
+    ZhCn,
+}
+
+/// The language of the wizard, English until [`select`] is called.
+static LANG: OnceLock<Lang> = OnceLock::new();
+
+impl Lang {
+    /// Parses a `--lang` value or a POSIX locale such as `zh_CN.UTF-8`, or returns `None` for an
+    /// unsupported language.
+    fn from_locale(locale: &str) -> Option<Self> {
+        let language = locale.split(['_', '-', '.', '@']).next()?;
+        match language.to_ascii_lowercase().as_str() {
+            "en" | "c" | "posix" => Some(Lang::En),
+            "zh" => Some(Lang::ZhCn),
+            _ => None,
+        }
+    }
+}
+
+/// Selects the language of the wizard from `--lang`, or else the locale of the environment.
+pub fn select(lang: Option<&str>) {
+    let locale = |name: &str| std::env::var(name).ok().filter(|value| !value.is_empty());
+    let lang = detect(lang, locale);
+    let _ = LANG.set(lang);
+}
+
+/// Returns the language given as `lang`, or else that of the first locale variable set, as
+/// `locale` reads them.
+fn detect(lang: Option<&str>, locale: impl Fn(&str) -> Option<String>) -> Lang {
+    let locale = lang.map(str::to_owned).or_else(|| {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .into_iter()
+            .find_map(&locale)
+    });
+    locale
+        .as_deref()
+        .and_then(Lang::from_locale)
+        .unwrap_or(Lang::En)
+}
+
+/// Returns the translation of the English `message` into the selected language, or `message`
+/// itself if it is English or the message is not translated.
+pub fn tr(message: &'static str) -> &'static str {
+    let table = match LANG.get().copied().unwrap_or(Lang::En) {
+        Lang::En => return message,
+        Lang::ZhCn => ZH_CN,
+    };
+    table
+        .iter()
+        .find(|(en, _)| *en == message)
+        .map_or(message, |(_, translated)| translated)
+}
+
+/// The Simplified Chinese messages, keyed by their English form.
+const ZH_CN: &[(&str, &str)] = &[
+    (
+        "--create-repo with --out needs --reset-history to create a repository to push",
+        "--create-repo 与 --out 一起使用时需要 --reset-history，才能创建可推送的仓库",
+    ),
+    ("🚀 Starting project bootstrap...", "🚀 开始初始化项目..."),
+    (
+        "Enter your project name (e.g., my-awesome-project)",
+        "输入项目名称（例如 my-awesome-project）",
+    ),
+    (
+        "Enter your GitHub username (e.g., tisonkun)",
+        "输入你的 GitHub 用户名（例如 tisonkun）",
+    ),
+    (
+        "Enter the xtask command alias (e.g., x for `cargo x`)",
+        "输入 xtask 命令别名（例如 x 对应 `cargo x`）",
+    ),
+    (
+        "Choose the project kind ({kinds})",
+        "选择项目类型（{kinds}）",
+    ),
+    (
+        "Choose the Rust edition ({editions})",
+        "选择 Rust edition（{editions}）",
+    ),
+    (
+        "Enter the minimum supported Rust version",
+        "输入支持的最低 Rust 版本",
+    ),
+    ("Choose the CI system ({cis})", "选择 CI 系统（{cis}）"),
+    (
+        "Choose the async runtime ({runtimes})",
+        "选择异步运行时（{runtimes}）",
+    ),
+    (
+        "Choose the files using the library to write ({usages}), comma-separated, or none",
+        "选择要生成的库用法文件（{usages}），以逗号分隔，或 none",
+    ),
+    (
+        "--usage-files needs the library, which --kind bin replaces",
+        "--usage-files 需要库，而 --kind bin 会将其替换",
+    ),
+    (
+        "--usage-files {id} adds the {id} that --without {id} removes",
+        "--usage-files {id} 会添加 --without {id} 所移除的 {id}",
+    ),
+    (
+        "Choose the benchmarks ({benchmarks})",
+        "选择基准测试方案（{benchmarks}）",
+    ),
+    (
+        "--benchmarks {benchmarks} adds the benches that --without benches removes",
+        "--benchmarks {benchmarks} 会添加 --without benches 所移除的基准测试",
+    ),
+    (
+        "Choose the observability ({observabilities})",
+        "选择可观测性方案（{observabilities}）",
+    ),
+    (
+        "Choose the feature flags of the library ({flags})",
+        "选择库的特性开关（{flags}）",
+    ),
+    (
+        "--feature-flags {flags} needs the library, which --kind bin replaces",
+        "--feature-flags {flags} 需要库，而 --kind bin 会将其替换",
+    ),
+    (
+        "Choose the error handling of the library ({errors})",
+        "选择库的错误处理方式（{errors}）",
+    ),
+    (
+        "--error-handling {errors} needs the library, which --kind bin replaces",
+        "--error-handling {errors} 需要库，而 --kind bin 会将其替换",
+    ),
+    (
+        "Choose the template variant ({variants}), or none",
+        "选择模板变体（{variants}），或 none",
+    ),
+    (
+        "--placeholder needs a --template variant declaring it",
+        "--placeholder 需要声明该占位符的 --template 变体",
+    ),
+    (
+        "expected --placeholder key=value, found '{item}'",
+        "应为 --placeholder 键=值，实际为 '{item}'",
+    ),
+    (
+        "unknown placeholder '{key}' of the {name} variant",
+        "{name} 变体中没有占位符 '{key}'",
+    ),
+    (
+        "Choose the README badges to leave out ({badges}), comma-separated (optional)",
+        "选择 README 中不需要的徽章（{badges}），以逗号分隔（可选）",
+    ),
+    ("failed to list files: {err}", "列出文件失败：{err}"),
+    ("Choose a license ({licenses})", "选择许可证（{licenses}）"),
+    (
+        "Enter a one-line description of the project",
+        "输入一行项目描述",
+    ),
+    (
+        "Enter the homepage URL of the project",
+        "输入项目主页的 URL",
+    ),
+    (
+        "Enter the documentation URL of the project",
+        "输入项目文档的 URL",
+    ),
+    (
+        "Enter the authors, comma-separated (optional)",
+        "输入作者，以逗号分隔（可选）",
+    ),
+    (
+        "Enter up to 5 crates.io keywords, comma-separated (optional)",
+        "输入最多 5 个 crates.io 关键词，以逗号分隔（可选）",
+    ),
+    (
+        "Enter up to 5 crates.io category slugs, comma-separated (optional)",
+        "输入最多 5 个 crates.io 分类标识，以逗号分隔（可选）",
+    ),
+    (
+        "Enter the copyright holder of the license headers",
+        "输入许可证头部的版权所有者",
+    ),
+    (
+        "Choose the community health files to write, comma-separated, or none",
+        "选择要写入的社区健康文件，以逗号分隔，或 none",
+    ),
+    (
+        "Enter the email or URL where conduct violations are reported",
+        "输入举报行为准则违规的邮箱或网址",
+    ),
+    (
+        "Enter the email where vulnerabilities are reported (optional, GitHub's private reporting otherwise)",
+        "输入报告安全漏洞的邮箱（可选，否则使用 GitHub 私密报告）",
+    ),
+    (
+        "Enter the GitHub handles of the code owners, comma-separated",
+        "输入代码所有者的 GitHub 账号，以逗号分隔",
+    ),
+    (
+        "Enter the sponsorship accounts as platform:account, comma-separated (optional)",
+        "以 平台:账号 的形式输入赞助账号，以逗号分隔（可选）",
+    ),
+    (
+        "more than one member would be renamed to '{package}'",
+        "多个成员将被重命名为 '{package}'",
+    ),
+    ("Cancelled.", "已取消。"),
+    (
+        "Dry run: no files will be written.",
+        "试运行：不会写入任何文件。",
+    ),
+    (
+        "Bootstrap project '{project_name}' for user '{github_username}' with `cargo {xtask_alias}`?",
+        "为用户 '{github_username}' 初始化项目 '{project_name}'，并使用 `cargo {xtask_alias}`？",
+    ),
+    ("Bootstrapping...", "正在初始化..."),
+    (
+        "Copying the template to {out}...",
+        "正在将模板复制到 {out}...",
+    ),
+    (
+        "output directory '{out}' is not empty",
+        "输出目录 '{out}' 不为空",
+    ),
+    (
+        "bootstrap failed; the partial copy was removed",
+        "初始化失败；已删除不完整的副本",
+    ),
+    (
+        "bootstrap failed; all changes were rolled back",
+        "初始化失败；所有更改均已回滚",
+    ),
+    ("remove {out}", "删除 {out}"),
+    ("revert it with git", "使用 git 还原"),
+    (
+        "revert it with `cargo {xtask_alias} bootstrap --undo`",
+        "使用 `cargo {xtask_alias} bootstrap --undo` 还原",
+    ),
+    (
+        "the bootstrapped workspace does not build; fix it, or {revert}",
+        "初始化后的工作区无法构建；请修复，或{revert}",
+    ),
+    (
+        "Commit the changes and set the origin remote to {origin}?",
+        "提交更改并将 origin 远程仓库设置为 {origin}？",
+    ),
+    ("🎉 Bootstrap complete!", "🎉 初始化完成！"),
+    ("The bootstrap is logged to", "初始化日志位于"),
+    ("The project was created in", "项目创建于"),
+    ("Revert it with", "还原命令"),
+    ("Update the license headers with", "更新许可证头部的命令"),
+    ("You can now delete this script", "现在可以删除此脚本"),
+    (
+        "the project was already bootstrapped; undo it with `bootstrap --undo` to start over, or finish with `bootstrap --cleanup`",
+        "项目已初始化；使用 `bootstrap --undo` 撤销以重新开始，或使用 `bootstrap --cleanup` 完成",
+    ),
+    (
+        "⏯️  Found an interrupted bootstrap, {done} of {total} step(s) done.",
+        "⏯️  发现一次中断的初始化，已完成 {done}/{total} 步。",
+    ),
+    ("Resume the remaining steps?", "继续执行剩余步骤？"),
+    (
+        "Run `bootstrap --undo` to start over instead.",
+        "如需重新开始，请运行 `bootstrap --undo`。",
+    ),
+    ("⏪ Undoing the bootstrap...", "⏪ 正在撤销初始化..."),
+    (
+        "{err}; fix them and run the undo again",
+        "{err}；请修复后再次运行撤销",
+    ),
+    ("⏪ Bootstrap undone!", "⏪ 初始化已撤销！"),
+    ("project name cannot be empty", "项目名称不能为空"),
+    (
+        "the name cannot start with a digit: '{ch}'",
+        "名称不能以数字开头：'{ch}'",
+    ),
+    (
+        "the first character must be a letter or `_`, found: '{ch}'",
+        "首字符必须是字母或 `_`，实际为：'{ch}'",
+    ),
+    (
+        "invalid character '{ch}': only letters, numbers, `-`, or `_` are allowed",
+        "无效字符 '{ch}'：只允许字母、数字、`-` 或 `_`",
+    ),
+    (
+        "{e}; did you mean '{suggestion}'?",
+        "{e}；你是指 '{suggestion}' 吗？",
+    ),
+    (
+        "{e}; use '{suggestion}' instead?",
+        "{e}；改用 '{suggestion}'？",
+    ),
+    (
+        "GitHub account name cannot be empty",
+        "GitHub 账号名不能为空",
+    ),
+    (
+        "GitHub account names are at most 39 characters, found {count}",
+        "GitHub 账号名最多 39 个字符，实际为 {count} 个",
+    ),
+    (
+        "invalid character '{ch}': only letters, numbers, or `-` are allowed",
+        "无效字符 '{ch}'：只允许字母、数字或 `-`",
+    ),
+    (
+        "GitHub account names cannot start or end with `-`",
+        "GitHub 账号名不能以 `-` 开头或结尾",
+    ),
+    (
+        "GitHub account names cannot contain consecutive `-`",
+        "GitHub 账号名不能包含连续的 `-`",
+    ),
+    (
+        "'{alias}' is a built-in cargo command and cannot be used as an alias",
+        "'{alias}' 是 cargo 内置命令，不能用作别名",
+    ),
+    (
+        "unknown license '{license}', expected one of {expected}",
+        "未知的许可证 '{license}'，应为以下之一：{expected}",
+    ),
+    (
+        "unknown value '{value}', expected one of {expected}",
+        "未知的取值 '{value}'，应为以下之一：{expected}",
+    ),
+    (
+        "unknown project kind '{kind}', expected one of {expected}",
+        "未知的项目类型 '{kind}'，应为以下之一：{expected}",
+    ),
+    (
+        "unsupported edition '{edition}', expected one of {expected}",
+        "不支持的 edition '{edition}'，应为以下之一：{expected}",
+    ),
+    (
+        "unknown template variant '{name}', expected one of {expected} or none",
+        "未知的模板变体 '{name}'，应为以下之一：{expected} 或 none",
+    ),
+    (
+        "invalid Rust version '{version}', expected e.g. '1.85' or '1.85.0'",
+        "无效的 Rust 版本 '{version}'，应为例如 '1.85' 或 '1.85.0'",
+    ),
+    (
+        "Rust {version} is older than {minimum}, which the template and edition need",
+        "Rust {version} 低于模板和 edition 所需的 {minimum}",
+    ),
+    (
+        "unknown usage file '{id}', expected one of {expected} or none",
+        "未知的用法文件 '{id}'，应为以下之一：{expected} 或 none",
+    ),
+    (
+        "unknown component '{id}', expected one of {expected}",
+        "未知的组件 '{id}'，应为以下之一：{expected}",
+    ),
+    (
+        "unknown community health file '{id}', expected one of {expected} or none",
+        "未知的社区健康文件 '{id}'，应为以下之一：{expected} 或 none",
+    ),
+    (
+        "unknown badge '{id}', expected one of {expected}",
+        "未知的徽章 '{id}'，应为以下之一：{expected}",
+    ),
+    (
+        "maintainer contact cannot be empty",
+        "维护者联系方式不能为空",
+    ),
+    (
+        "maintainer contact must be an email address or a URL",
+        "维护者联系方式必须是邮箱地址或网址",
+    ),
+    (
+        "'{email}' is not an email address",
+        "'{email}' 不是邮箱地址",
+    ),
+    ("invalid team '{handle}'", "无效的团队 '{handle}'"),
+    ("at least one maintainer is required", "至少需要一名维护者"),
+    (
+        "expected platform:account, found '{item}'",
+        "应为 平台:账号，实际为 '{item}'",
+    ),
+    (
+        "unknown funding platform '{platform}', expected one of {expected}",
+        "未知的赞助平台 '{platform}'，应为以下之一：{expected}",
+    ),
+    (
+        "the {platform} account cannot be empty",
+        "{platform} 账号不能为空",
+    ),
+    (
+        "{platform} takes a single account",
+        "{platform} 只能填写一个账号",
+    ),
+    ("copyright holder cannot be empty", "版权所有者不能为空"),
+    (
+        "copyright holder must be a single line",
+        "版权所有者必须是单行",
+    ),
+    ("description cannot be empty", "描述不能为空"),
+    ("description must be a single line", "描述必须是单行"),
+    (
+        "URL must start with https:// or http://",
+        "URL 必须以 https:// 或 http:// 开头",
+    ),
+    ("'{url}' is not a valid URL", "'{url}' 不是有效的 URL"),
+    ("authors must be on a single line", "作者必须在同一行"),
+    (
+        "at most 5 keywords are allowed, found {count}",
+        "最多允许 5 个关键词，实际为 {count} 个",
+    ),
+    (
+        "keyword '{keyword}' is longer than 20 characters",
+        "关键词 '{keyword}' 超过 20 个字符",
+    ),
+    (
+        "invalid keyword '{keyword}': only letters, numbers, `_`, `-`, or `+` are allowed, starting with a letter or number",
+        "无效的关键词 '{keyword}'：只允许字母、数字、`_`、`-` 或 `+`，且须以字母或数字开头",
+    ),
+    (
+        "at most 5 categories are allowed, found {count}",
+        "最多允许 5 个分类，实际为 {count} 个",
+    ),
+    (
+        "invalid category '{category}': expected a slug like `development-tools::testing`",
+        "无效的分类 '{category}'：应为类似 `development-tools::testing` 的标识",
+    ),
+    (
+        "Checking the workspace builds...",
+        "正在检查工作区能否构建...",
+    ),
+    ("Running `{hook}`...", "正在运行 `{hook}`..."),
+    ("`{hook}` failed: {status}", "`{hook}` 失败：{status}"),
+    ("failed to run `{hook}`: {err}", "无法运行 `{hook}`：{err}"),
+    (
+        "{error}; the bootstrap itself is complete, run the remaining hooks by hand",
+        "{error}；初始化本身已完成，请手动运行剩余的钩子",
+    ),
+    ("invalid --without: {e}", "无效的 --without：{e}"),
+    (
+        "Select the optional components to keep (space toggles, enter confirms)",
+        "选择要保留的可选组件（空格切换，回车确认）",
+    ),
+    ("benchmarks in benches/", "benches/ 中的基准测试"),
+    ("examples in examples/", "examples/ 中的示例"),
+    ("cargo-fuzz targets in fuzz/", "fuzz/ 中的 cargo-fuzz 目标"),
+    ("the mdBook in docs/", "docs/ 中的 mdBook"),
+    ("the release workflow", "发布工作流"),
+    (
+        "directory '{to}' already exists; pass --force to overwrite it",
+        "目录 '{to}' 已存在；传入 --force 以覆盖它",
+    ),
+    ("Overwrite it, removing its files", "覆盖它，删除其中的文件"),
+    (
+        "Merge into it, keeping the files the template does not have",
+        "合并进去，保留模板中没有的文件",
+    ),
+    ("Abort the bootstrap", "中止初始化"),
+    ("Directory '{to}' already exists", "目录 '{to}' 已存在"),
+    (
+        "{flag} is required with --yes or --config",
+        "使用 --yes 或 --config 时必须提供 {flag}",
+    ),
+    ("invalid {flag}: {e}", "无效的 {flag}：{e}"),
+    ("ERROR: {message}", "错误：{message}"),
+    ("Updating {file}...", "正在更新 {file}..."),
+    ("{files} file(s) updated.", "已更新 {files} 个文件。"),
+    ("Rolling back...", "正在回滚..."),
+    ("Restoring {file}...", "正在恢复 {file}..."),
+    (
+        "Renaming directory \"{to}\" back to \"{from}\"...",
+        "正在将目录 \"{to}\" 重命名回 \"{from}\"...",
+    ),
+    (
+        "failed to check the working tree: {err}",
+        "检查工作区失败：{err}",
+    ),
+    (
+        "the working tree has uncommitted changes; commit or stash them, or pass --allow-dirty\n{status}",
+        "工作区有未提交的更改；请提交或暂存，或传入 --allow-dirty\n{status}",
+    ),
+    ("Committing the changes...", "正在提交更改..."),
+    (
+        "Setting the origin remote...",
+        "正在设置 origin 远程仓库...",
+    ),
+    ("Resetting the git history...", "正在重置 git 历史..."),
+    (
+        "failed to reset the git history; run `git init` to start a new one",
+        "重置 git 历史失败；请运行 `git init` 重新开始",
+    ),
+    (
+        "Select the files to update (space toggles, enter confirms)",
+        "选择要更新的文件（空格切换，回车确认）",
+    ),
+    ("{label} (renamed to {to}/)", "{label}（重命名为 {to}/）"),
+    (
+        "{from}/ will be renamed to {to}/",
+        "{from}/ 将被重命名为 {to}/",
+    ),
+    ("[NOT CHECKED]", "[未检查]"),
+    ("[TAKEN]", "[已被占用]"),
+    ("[AVAILABLE]", "[可用]"),
+    ("Similar existing crates", "已有的相似 crate"),
+    ("[UNKNOWN] {err}", "[未知] {err}"),
+    ("GitHub:", "GitHub："),
+    (
+        "[ACCOUNT '{account}' NOT FOUND]",
+        "[未找到账号 '{account}']",
+    ),
+    ("[EXISTS]", "[已存在]"),
+    (
+        "Make sure it is the repository created from this template.",
+        "请确认它是从此模板创建的仓库。",
+    ),
+    ("[UNKNOWN] HTTP {code}", "[未知] HTTP {code}"),
+    (
+        "{files} file(s) changed, {insertions} insertion(s)(+), {deletions} deletion(s)(-)",
+        "{files} 个文件已更改，{insertions} 行插入(+)，{deletions} 行删除(-)",
+    ),
+    ("[OK]", "[成功]"),
+    ("[ERROR] {e}", "[错误] {e}"),
+    (
+        "Renaming directory \"{from}\" to \"{to}\" ...",
+        "正在将目录 \"{from}\" 重命名为 \"{to}\" ...",
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the `{name}` placeholders of `message`, sorted.
+    fn placeholders(message: &str) -> Vec<&str> {
+        let mut names: Vec<&str> = message
+            .split('{')
+            .skip(1)
+            .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+            .collect();
+        names.sort_unstable();
+        names
+    }
+
+    /// Returns the messages the wizard translates, as the literals of `tr!` and `i18n::tr` in
+    /// `source`.
+    fn messages(source: &str) -> Vec<String> {
+        let calls = source
+            .match_indices("tr!(")
+            .filter(|(index, _)| {
+                // not another macro ending in `tr!`, such as `include_str!`
+                !source[..*index].ends_with(|ch: char| ch.is_alphanumeric() || ch == '_')
+            })
+            .chain(source.match_indices("i18n::tr("))
+            .map(|(index, call)| &source[index + call.len()..]);
+        let mut messages = vec![];
+        for rest in calls {
+            let Some(rest) = rest.trim_start().strip_prefix('"') else {
+                continue;
+            };
+            let mut message = String::new();
+            let mut chars = rest.chars();
+            while let Some(ch) = chars.next() {
+                match ch {
+                    '"' => break,
+                    '\\' => match chars.next() {
+                        Some('n') => message.push('\n'),
+                        Some(ch) => message.push(ch),
+                        None => break,
+                    },
+                    ch => message.push(ch),
+                }
+            }
+            messages.push(message);
+        }
+        messages
+    }
+
+    /// Returns the arguments of every call to `function` in `source`, split at the top-level
+    /// commas and trimmed.
+    fn call_arguments<'a>(source: &'a str, function: &str) -> Vec<Vec<&'a str>> {
+        let mut calls = vec![];
+        for (index, call) in source.match_indices(&format!("{function}(")) {
+            let rest = &source[index + call.len()..];
+            let mut arguments = vec![];
+            let (mut depth, mut start) = (0, 0);
+            let (mut in_string, mut escaped) = (false, false);
+            for (i, ch) in rest.char_indices() {
+                if in_string {
+                    match ch {
+                        _ if escaped => escaped = false,
+                        '\\' => escaped = true,
+                        '"' => in_string = false,
+                        _ => {}
+                    }
+                    continue;
+                }
+                match ch {
+                    '"' => in_string = true,
+                    '(' | '[' | '{' => depth += 1,
+                    ')' | ']' | '}' if depth > 0 => depth -= 1,
+                    ')' => {
+                        arguments.push(rest[start..i].trim());
+                        break;
+                    }
+                    ',' if depth == 0 => {
+                        arguments.push(rest[start..i].trim());
+                        start = i + 1;
+                    }
+                    _ => {}
+                }
+            }
+            calls.push(arguments);
+        }
+        calls
+    }
+
+    #[test]
+    fn test_translations() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(var, _)| *var == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert_eq!(detect(None, env(&[])), Lang::En);
+        assert_eq!(detect(None, env(&[("LANG", "zh_CN.UTF-8")])), Lang::ZhCn);
+        assert_eq!(
+            detect(None, env(&[("LC_ALL", "C"), ("LANG", "zh_CN.UTF-8")])),
+            Lang::En
+        );
+        assert_eq!(detect(Some("zh-CN"), env(&[("LANG", "C")])), Lang::ZhCn);
+        assert_eq!(detect(None, env(&[("LANG", "fr_FR.UTF-8")])), Lang::En);
+        assert_eq!(Lang::from_locale("en"), Some(Lang::En));
+        assert_eq!(Lang::from_locale("zh-CN"), Some(Lang::ZhCn));
+
+        for (i, (en, zh)) in ZH_CN.iter().enumerate() {
+            assert_eq!(placeholders(en), placeholders(zh), "{en}");
+            assert!(!ZH_CN[..i].iter().any(|(other, _)| other == en), "{en}");
+        }
+        let mut sources = vec![
+            include_str!("../bootstrap.rs").to_owned(),
+            include_str!("choice.rs").to_owned(),
+            include_str!("answers.rs").to_owned(),
+            include_str!("answers/choices.rs").to_owned(),
+            include_str!("answers/community.rs").to_owned(),
+            include_str!("answers/metadata.rs").to_owned(),
+            include_str!("answers/project.rs").to_owned(),
+        ];
+        sources.extend(
+            super::super::components::COMPONENTS
+                .iter()
+                .map(|c| format!("i18n::tr(\"{}\")", c.description)),
+        );
+        sources.extend(
+            super::super::conflict::RESOLUTIONS
+                .iter()
+                .map(|(_, item)| format!("i18n::tr(\"{item}\")")),
+        );
+        // a prompt given as a bare literal would never be translated
+        let prompts: Vec<_> = sources
+            .iter()
+            .flat_map(|source| call_arguments(source, "resolve_input"))
+            .collect();
+        assert!(!prompts.is_empty());
+        for arguments in prompts {
+            let prompt = arguments.get(3).copied().unwrap_or_default();
+            assert!(!prompt.starts_with('"'), "untranslated prompt: {prompt}");
+        }
+        for source in &sources {
+            for message in messages(source) {
+                assert!(
+                    ZH_CN.iter().any(|(en, _)| *en == message),
+                    "untranslated: {message:?}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,751 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The kinds of project the template is bootstrapped as: the library it ships, a binary with a
+//! clap command-line interface, a workspace of a core library and a CLI depending on it, or a
+//! library re-exporting the derive macro of a proc-macro member.
+
+use toml_edit::DocumentMut;
+use toml_edit::Item;
+use toml_edit::Value;
+
+use super::FileChange;
+use super::Observability;
+use super::Runtime;
+use super::choice::Choice;
+use crate::split;
+
+/// The clap requirement of the generated command-line interfaces, as xtask uses it.
+const CLAP_VERSION: &str = "4.6.1";
+
+/// The assert_cmd requirement of the smoke test of a binary.
+const ASSERT_CMD_VERSION: &str = "2.2";
+
+/// The syn requirement of a proc-macro crate.
+const SYN_VERSION: &str = "2.0.117";
+
+/// The quote requirement of a proc-macro crate.
+const QUOTE_VERSION: &str = "1.0.45";
+
+/// The trybuild requirement of the UI tests of a proc-macro crate, the latest supporting the
+/// template's MSRV.
+const TRYBUILD_VERSION: &str = "1.0.116";
+
+/// The workflow of a binary, which uploads its builds to the GitHub release of a version tag,
+/// as `BINARY` names it.
+pub const RELEASE_WORKFLOW: &str = r#"name: Release
+on:
+  push:
+    tags: [ 'v*' ]
+
+permissions:
+  contents: write
+
+env:
+  BINARY: template
+
+jobs:
+  release:
+    name: Create the release
+    runs-on: ubuntu-24.04
+    steps:
+      - uses: actions/checkout@v7
+      - env:
+          GH_TOKEN: ${{ github.token }}
+        run: gh release create "$GITHUB_REF_NAME" --verify-tag --generate-notes
+
+  upload:
+    name: Upload ${{ matrix.target }}
+    needs: release
+    runs-on: ${{ matrix.os }}
+    strategy:
+      fail-fast: false
+      matrix:
+        include:
+          - { os: ubuntu-24.04, target: x86_64-unknown-linux-gnu }
+          - { os: ubuntu-24.04-arm, target: aarch64-unknown-linux-gnu }
+          - { os: macos-15, target: aarch64-apple-darwin }
+          - { os: windows-2025, target: x86_64-pc-windows-msvc }
+    steps:
+      - uses: actions/checkout@v7
+      - name: Install toolchain
+        uses: dtolnay/rust-toolchain@stable
+        with:
+          targets: ${{ matrix.target }}
+      - uses: Swatinem/rust-cache@v2
+      - run: cargo build --release --package "$BINARY" --target ${{ matrix.target }}
+        shell: bash
+      - name: Upload the binary
+        shell: bash
+        env:
+          GH_TOKEN: ${{ github.token }}
+        run: |
+          dir="target/${{ matrix.target }}/release"
+          archive="$BINARY-$GITHUB_REF_NAME-${{ matrix.target }}"
+          if [ "$RUNNER_OS" = Windows ]; then
+            7z a "$archive.zip" "./$dir/$BINARY.exe"
+            gh release upload "$GITHUB_REF_NAME" "$archive.zip"
+          else
+            tar czf "$archive.tar.gz" -C "$dir" "$BINARY"
+            gh release upload "$GITHUB_REF_NAME" "$archive.tar.gz"
+          fi
+"#;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    Lib,
+    Bin,
+    Workspace,
+    ProcMacro,
+}
+
+/// The supported kinds, starting with the kind of the template.
+pub const KINDS: &[Kind] = &[Kind::Lib, Kind::Bin, Kind::Workspace, Kind::ProcMacro];
+
+impl Kind {
+    /// The value of `--kind`.
+    pub fn id(self) -> &'static str {
+        match self {
+            Kind::Lib => "lib",
+            Kind::Bin => "bin",
+            Kind::Workspace => "workspace",
+            Kind::ProcMacro => "proc-macro",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Kind> {
+        KINDS
+            .iter()
+            .copied()
+            .find(|kind| kind.id().eq_ignore_ascii_case(id))
+    }
+
+    /// Returns the name the template crate and its directory are renamed to: the project name,
+    /// or `<name>-core` in a workspace, whose CLI member is the one named after the project.
+    pub fn crate_name(self, project_name: &str) -> String {
+        match self {
+            Kind::Workspace => format!("{project_name}-core"),
+            Kind::Lib | Kind::Bin | Kind::ProcMacro => project_name.to_owned(),
+        }
+    }
+
+    /// Returns the members added to the workspace after the template crate.
+    pub fn members(self, project_name: &str) -> Vec<String> {
+        match self {
+            Kind::Workspace => vec![format!("{project_name}-cli")],
+            Kind::ProcMacro => vec![format!("{project_name}-macros")],
+            Kind::Lib | Kind::Bin => vec![],
+        }
+    }
+
+    /// Returns the dependencies added to `[workspace.dependencies]`, which the members inherit:
+    /// those of the command-line interface, the core crate of a workspace or the macros of a
+    /// proc-macro project at the `version` of the template crate, and what they are built and
+    /// tested with.
+    pub fn workspace_dependencies(self, project_name: &str, version: &str) -> Vec<(String, Value)> {
+        match self {
+            Kind::Lib => vec![],
+            Kind::Bin => vec![
+                ("assert_cmd".to_owned(), ASSERT_CMD_VERSION.into()),
+                ("clap".to_owned(), clap_dependency()),
+            ],
+            Kind::Workspace => {
+                let core = self.crate_name(project_name);
+                let dependency = Value::from_iter([("path", core.as_str()), ("version", version)]);
+                vec![(core, dependency), ("clap".to_owned(), clap_dependency())]
+            }
+            Kind::ProcMacro => {
+                let macros = format!("{project_name}-macros");
+                let dependency =
+                    Value::from_iter([("path", macros.as_str()), ("version", version)]);
+                vec![
+                    (macros, dependency),
+                    ("quote".to_owned(), QUOTE_VERSION.into()),
+                    ("syn".to_owned(), SYN_VERSION.into()),
+                    ("trybuild".to_owned(), TRYBUILD_VERSION.into()),
+                ]
+            }
+        }
+    }
+
+    /// Returns the dependencies added to the manifest of the template crate, which re-exports
+    /// the macros of a proc-macro project.
+    pub fn crate_dependencies(self, project_name: &str) -> Vec<(String, Value)> {
+        match self {
+            Kind::Bin => vec![("clap".to_owned(), inherited())],
+            Kind::ProcMacro => vec![(format!("{project_name}-macros"), inherited())],
+            Kind::Lib | Kind::Workspace => vec![],
+        }
+    }
+
+    /// Returns the dev-dependencies added to the manifest of the template crate, which the smoke
+    /// test of a binary runs it with, and the UI tests of a proc-macro project compile the
+    /// macros with.
+    pub fn crate_dev_dependencies(self) -> Vec<(String, Value)> {
+        match self {
+            Kind::Bin => vec![("assert_cmd".to_owned(), inherited())],
+            Kind::ProcMacro => vec![("trybuild".to_owned(), inherited())],
+            Kind::Lib | Kind::Workspace => vec![],
+        }
+    }
+
+    /// Returns the declarations inserted after the crate attributes of the library, which
+    /// re-export the derive macro of a proc-macro project along with the trait it implements,
+    /// or an empty string for the other kinds.
+    pub fn render_facade(self, project_name: &str) -> String {
+        match self {
+            Kind::ProcMacro => {
+                let crate_path = project_name.replace('-', "_");
+                format!(
+                    r#"
+/// Derives [`Hello`], greeting by the name of the type.
+///
+/// ```
+/// use {crate_path}::Hello;
+///
+/// #[derive(Hello)]
+/// struct World;
+///
+/// assert_eq!(World::hello(), "Hello, World!");
+/// ```
+pub use {crate_path}_macros::Hello;
+
+/// A type greeting by its name.
+pub trait Hello {{
+    /// Returns the greeting of the type.
+    fn hello() -> String;
+}}
+"#
+                )
+            }
+            Kind::Lib | Kind::Bin | Kind::Workspace => String::new(),
+        }
+    }
+}
+
+/// Returns a dependency inherited from `[workspace.dependencies]`.
+fn inherited() -> Value {
+    Value::from_iter([("workspace", true)])
+}
+
+fn clap_dependency() -> Value {
+    let mut clap = toml_edit::InlineTable::new();
+    clap.insert("version", CLAP_VERSION.into());
+    clap.insert("features", Value::from_iter(["derive"]));
+    Value::InlineTable(clap)
+}
+
+/// Returns the files written or removed to make the template crate a project of `kind`.
+///
+/// `lib_rs` and `manifest` are the template crate's `src/lib.rs` and `Cargo.toml` as
+/// bootstrapped, whose license headers the generated files start with. The binary replaces the
+/// library of the template crate, with a smoke test running it, and the CLI of a workspace is a
+/// new `<name>-cli` member at the `version` of the template crate. With a `runtime`, their
+/// `main` is async, and with `observability`, it initializes a tracing subscriber. The macros of
+/// a proc-macro project are a new `<name>-macros` member, whose UI tests the template crate
+/// runs.
+pub fn planned_files(
+    kind: Kind,
+    project_name: &str,
+    description: &str,
+    version: &str,
+    lib_rs: &str,
+    manifest: &str,
+    runtime: Runtime,
+    observability: Observability,
+) -> Vec<FileChange> {
+    let rust_header = split::leading_header(lib_rs, "//");
+    match kind {
+        Kind::Lib => vec![],
+        Kind::Bin => vec![
+            FileChange::Write {
+                file: "template/src/main.rs".to_owned(),
+                content: format!(
+                    "{rust_header}{}",
+                    render_main(description, None, runtime, observability)
+                ),
+            },
+            FileChange::Remove {
+                file: "template/src/lib.rs".to_owned(),
+            },
+            FileChange::Write {
+                file: "template/tests/cli.rs".to_owned(),
+                content: format!("{rust_header}{SMOKE_TEST}"),
+            },
+        ],
+        Kind::Workspace => {
+            let core = kind.crate_name(project_name);
+            let cli = format!("{project_name}-cli");
+            let mut cli_manifest: DocumentMut =
+                split::render_cli_manifest(project_name, &core, version)
+                    .parse()
+                    .unwrap();
+            cli_manifest["dependencies"]["clap"] = Item::Value(inherited());
+            let dependencies = [
+                runtime.crate_dependencies(),
+                observability.binary_dependencies(),
+            ];
+            for (name, dependency) in dependencies.into_iter().flatten() {
+                cli_manifest["dependencies"][&name] = Item::Value(dependency);
+            }
+            let core_path = core.replace('-', "_");
+            vec![
+                FileChange::Write {
+                    file: format!("{cli}/Cargo.toml"),
+                    content: format!("{}{cli_manifest}", split::leading_header(manifest, "#")),
+                },
+                FileChange::Write {
+                    file: format!("{cli}/src/main.rs"),
+                    content: format!(
+                        "{rust_header}{}",
+                        render_main(description, Some(&core_path), runtime, observability)
+                    ),
+                },
+            ]
+        }
+        Kind::ProcMacro => {
+            let macros = format!("{project_name}-macros");
+            let crate_path = project_name.replace('-', "_");
+            let fail_rs = format!("{rust_header}{}", render_ui_fail(&crate_path));
+            let stderr = render_ui_stderr(&fail_rs);
+            vec![
+                FileChange::Write {
+                    file: format!("{macros}/Cargo.toml"),
+                    content: format!(
+                        "{}{}",
+                        split::leading_header(manifest, "#"),
+                        render_macros_manifest(project_name, version)
+                    ),
+                },
+                FileChange::Write {
+                    file: format!("{macros}/src/lib.rs"),
+                    content: format!("{rust_header}{}", render_macros(project_name)),
+                },
+                FileChange::Write {
+                    file: "template/tests/ui.rs".to_owned(),
+                    content: format!("{rust_header}{UI_TEST}"),
+                },
+                FileChange::Write {
+                    file: "template/tests/ui/pass/derive.rs".to_owned(),
+                    content: format!("{rust_header}{}", render_ui_pass(&crate_path)),
+                },
+                FileChange::Write {
+                    file: "template/tests/ui/fail/union.rs".to_owned(),
+                    content: fail_rs,
+                },
+                FileChange::Write {
+                    file: "template/tests/ui/fail/union.stderr".to_owned(),
+                    content: stderr,
+                },
+            ]
+        }
+    }
+}
+
+/// The UI tests of the macros, compiling the code they accept and checking the errors they
+/// report against the `.stderr` files, which `TRYBUILD=overwrite` updates.
+const UI_TEST: &str = r#"//! UI tests of the macros, checking the code they accept and the errors they report.
+
+#[test]
+fn test_ui() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/pass/*.rs");
+    t.compile_fail("tests/ui/fail/*.rs");
+}
+"#;
+
+/// Renders the manifest of the proc-macro member, at the `version` of the template crate.
+fn render_macros_manifest(project_name: &str, version: &str) -> String {
+    format!(
+        r#"[package]
+name = "{project_name}-macros"
+version = "{version}"
+description = "The procedural macros of {project_name}, re-exported by the `{project_name}` crate."
+
+edition.workspace = true
+homepage.workspace = true
+license.workspace = true
+readme.workspace = true
+repository.workspace = true
+rust-version.workspace = true
+
+[lib]
+proc-macro = true
+
+[dependencies]
+quote.workspace = true
+syn.workspace = true
+
+[lints]
+workspace = true
+"#
+    )
+}
+
+/// Renders the derive macro of the proc-macro member, implementing the trait of the template
+/// crate, which re-exports it.
+fn render_macros(project_name: &str) -> String {
+    let crate_path = project_name.replace('-', "_");
+    format!(
+        r#"//! The procedural macros of {project_name}, to be used through the `{project_name}` crate,
+//! which re-exports them.
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::Data;
+use syn::DeriveInput;
+use syn::parse_macro_input;
+
+/// Derives `{crate_path}::Hello`, greeting by the name of the type.
+#[proc_macro_derive(Hello)]
+pub fn derive_hello(input: TokenStream) -> TokenStream {{
+    let input = parse_macro_input!(input as DeriveInput);
+    if let Data::Union(data) = &input.data {{
+        let message = "`#[derive(Hello)]` does not support unions";
+        return syn::Error::new_spanned(data.union_token, message)
+            .to_compile_error()
+            .into();
+    }}
+    let name = &input.ident;
+    let greeting = format!("Hello, {{name}}!");
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    quote! {{
+        impl #impl_generics ::{crate_path}::Hello for #name #ty_generics #where_clause {{
+            fn hello() -> ::std::string::String {{
+                ::std::string::String::from(#greeting)
+            }}
+        }}
+    }}
+    .into()
+}}
+"#
+    )
+}
+
+/// Renders the UI test of the code the derive macro accepts.
+fn render_ui_pass(crate_path: &str) -> String {
+    format!(
+        r#"use {crate_path}::Hello;
+
+#[derive(Hello)]
+struct World;
+
+#[derive(Hello)]
+enum Planet<T> {{
+    Earth(T),
+}}
+
+fn main() {{
+    assert_eq!(World::hello(), "Hello, World!");
+    assert_eq!(Planet::<u8>::hello(), "Hello, Planet!");
+}}
+"#
+    )
+}
+
+/// Renders the UI test of the error the derive macro reports.
+fn render_ui_fail(crate_path: &str) -> String {
+    format!(
+        r#"use {crate_path}::Hello;
+
+#[derive(Hello)]
+union Number {{
+    int: u32,
+    float: f32,
+}}
+
+fn main() {{}}
+"#
+    )
+}
+
+/// Renders the error expected of the UI test `fail_rs`, pointing at its `union` keyword.
+fn render_ui_stderr(fail_rs: &str) -> String {
+    let line = fail_rs
+        .lines()
+        .position(|line| line.starts_with("union "))
+        .map_or(0, |index| index + 1);
+    let gutter = " ".repeat(line.to_string().len());
+    format!(
+        "error: `#[derive(Hello)]` does not support unions\n{gutter}--> tests/ui/fail/union.rs:{line}:1\n{gutter} |\n{line} | union Number {{\n{gutter} | ^^^^^\n"
+    )
+}
+
+/// The smoke test of a binary, checking that it runs and reports the version of its package.
+const SMOKE_TEST: &str = r#"//! Smoke tests running the binary.
+
+use assert_cmd::Command;
+
+#[test]
+fn test_version() {
+    let name = env!("CARGO_PKG_NAME");
+    Command::cargo_bin(name)
+        .unwrap()
+        .arg("--version")
+        .assert()
+        .success()
+        .stdout(format!("{name} {}\n", env!("CARGO_PKG_VERSION")));
+}
+
+#[test]
+fn test_hello() {
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg("hello")
+        .assert()
+        .success();
+}
+"#;
+
+/// Returns the release workflow of the binary `project_name`, starting with the license header
+/// of the main `workflow`.
+pub fn release_workflow(project_name: &str, workflow: &str) -> String {
+    format!(
+        "{}{}",
+        split::leading_header(workflow, "#"),
+        RELEASE_WORKFLOW.replace("BINARY: template", &format!("BINARY: {project_name}"))
+    )
+}
+
+/// Renders a `main.rs` parsing its arguments and subcommand with clap, which calls into `lib` if
+/// given, and runs on the `runtime` if any, whose example test a binary without a library keeps
+/// along with the example function of its `observability`.
+fn render_main(
+    description: &str,
+    lib: Option<&str>,
+    runtime: Runtime,
+    observability: Observability,
+) -> String {
+    let tracing = observability != Observability::None;
+    let body = match lib {
+        Some(lib) => format!("{lib}::hello();"),
+        None if tracing => "println!(\"{}\", greet(\"world\"));".to_owned(),
+        None => "println!(\"Hello, world!\");".to_owned(),
+    };
+    let (imports, init_call) = match tracing {
+        true => (
+            "use tracing_subscriber::EnvFilter;\n",
+            "    init_tracing();\n",
+        ),
+        false => ("", ""),
+    };
+    let init = observability.render_init();
+    let main = match runtime.main_attribute() {
+        Some(attribute) => format!("{attribute}\nasync fn main()"),
+        None => "fn main()".to_owned(),
+    };
+    let tests = match lib {
+        Some(_) => String::new(),
+        None => format!(
+            "{}{}",
+            observability.render_example(),
+            runtime.render_tests()
+        ),
+    };
+    format!(
+        r#"//! {description}
+
+use clap::Parser;
+use clap::Subcommand;
+{imports}
+/// The command-line arguments.
+#[derive(Parser)]
+#[command(version, about)]
+struct Args {{
+    #[command(subcommand)]
+    command: Option<Command>,
+}}
+
+/// The subcommands, where running without one says hello.
+#[derive(Subcommand)]
+enum Command {{
+    /// Says hello.
+    Hello,
+}}
+
+{main} {{
+    let args = Args::parse();
+{init_call}    match args.command.unwrap_or(Command::Hello) {{
+        Command::Hello => {{
+            {body}
+        }}
+    }}
+}}
+{init}{tests}"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_planned_files() {
+        let header = "// Copyright 2026 Octocat\n\n";
+        let lib_rs = format!("{header}//! A demo.\n");
+        let manifest =
+            "# Copyright 2026 Octocat\n\n[package]\nname = \"demo-core\"\nversion = \"0.1.0\"\n";
+        assert!(
+            planned_files(
+                Kind::Lib,
+                "demo",
+                "A demo.",
+                "0.1.0",
+                &lib_rs,
+                manifest,
+                Runtime::None,
+                Observability::None
+            )
+            .is_empty()
+        );
+
+        let changes = planned_files(
+            Kind::Bin,
+            "demo",
+            "A demo.",
+            "0.1.0",
+            &lib_rs,
+            manifest,
+            Runtime::AsyncStd,
+            Observability::Tracing,
+        );
+        let files: Vec<&str> = changes.iter().map(FileChange::file).collect();
+        assert_eq!(
+            files,
+            [
+                "template/src/main.rs",
+                "template/src/lib.rs",
+                "template/tests/cli.rs"
+            ]
+        );
+        let FileChange::Write {
+            content: main_rs, ..
+        } = &changes[0]
+        else {
+            panic!("expected the main.rs of the binary");
+        };
+        assert!(main_rs.contains("#[async_std::main]\nasync fn main() {\n"));
+        assert!(main_rs.contains("    #[async_std::test]\n"));
+        assert!(main_rs.contains("enum Command {\n    /// Says hello.\n    Hello,\n}\n"));
+        assert!(main_rs.contains("use tracing_subscriber::EnvFilter;\n"));
+        assert!(main_rs.contains("    let args = Args::parse();\n    init_tracing();\n"));
+        assert!(main_rs.contains("            println!(\"{}\", greet(\"world\"));\n"));
+        assert!(main_rs.contains("\n/// Logs to stderr as `RUST_LOG` filters it"));
+        assert!(main_rs.contains("mod tracing_tests {\n"));
+        let FileChange::Write {
+            content: cli_rs, ..
+        } = &changes[2]
+        else {
+            panic!("expected the smoke test of the binary");
+        };
+        assert!(cli_rs.starts_with("// Copyright 2026 Octocat\n\n//! Smoke tests"));
+        assert_eq!(
+            Kind::Bin.crate_dev_dependencies()[0].1.to_string(),
+            "{ workspace = true }"
+        );
+        assert_eq!(
+            Kind::Bin.workspace_dependencies("demo", "0.1.0")[0]
+                .1
+                .to_string(),
+            "\"2.2\""
+        );
+        let workflow = release_workflow("demo", "# Copyright 2026 Octocat\n\nname: CI\n");
+        assert!(workflow.starts_with("# Copyright 2026 Octocat\n\nname: Release\n"));
+        assert!(workflow.contains("\nenv:\n  BINARY: demo\n"));
+
+        let changes = planned_files(
+            Kind::Workspace,
+            "demo",
+            "A demo.",
+            "0.1.0",
+            &lib_rs,
+            manifest,
+            Runtime::Tokio,
+            Observability::None,
+        );
+        let [
+            FileChange::Write {
+                file: cli_manifest,
+                content: cli_manifest_content,
+            },
+            FileChange::Write {
+                file: cli_main,
+                content: cli_main_content,
+            },
+        ] = &changes[..]
+        else {
+            panic!("expected the manifest and main.rs of the CLI");
+        };
+        assert_eq!(cli_manifest, "demo-cli/Cargo.toml");
+        assert!(cli_manifest_content.starts_with(
+            "# Copyright 2026 Octocat\n\n[package]\nname = \"demo-cli\"\nversion = \"0.1.0\"\n"
+        ));
+        assert!(cli_manifest_content.contains(
+            "demo-core.workspace = true\nclap = { workspace = true }\ntokio = { workspace = true }\n"
+        ));
+        assert_eq!(cli_main, "demo-cli/src/main.rs");
+        assert!(cli_main_content.starts_with("// Copyright 2026 Octocat\n\n//! A demo.\n"));
+        assert!(cli_main_content.contains("#[tokio::main]\nasync fn main() {\n"));
+        assert!(cli_main_content.contains("    demo_core::hello();\n"));
+        assert!(!cli_main_content.contains("mod tests"));
+        assert!(!cli_main_content.contains("tracing"));
+
+        let changes = planned_files(
+            Kind::ProcMacro,
+            "demo",
+            "A demo.",
+            "0.1.0",
+            &lib_rs,
+            manifest,
+            Runtime::None,
+            Observability::None,
+        );
+        let written = |name: &str| {
+            changes.iter().find_map(|change| match change {
+                FileChange::Write { file, content } if file == name => Some(content.as_str()),
+                _ => None,
+            })
+        };
+        let macros_manifest = written("demo-macros/Cargo.toml").unwrap();
+        assert!(macros_manifest.starts_with(
+            "# Copyright 2026 Octocat\n\n[package]\nname = \"demo-macros\"\nversion = \"0.1.0\"\n"
+        ));
+        assert!(macros_manifest.contains("\n[lib]\nproc-macro = true\n"));
+        assert!(macros_manifest.contains("\nquote.workspace = true\nsyn.workspace = true\n"));
+        let macros_rs = written("demo-macros/src/lib.rs").unwrap();
+        assert!(macros_rs.contains("        impl #impl_generics ::demo::Hello for #name"));
+        assert!(written("template/tests/ui.rs").is_some());
+        assert!(
+            written("template/tests/ui/pass/derive.rs")
+                .unwrap()
+                .starts_with("// Copyright 2026 Octocat\n\nuse demo::Hello;\n")
+        );
+        // the error points at the union, below the license header
+        assert_eq!(
+            written("template/tests/ui/fail/union.stderr").unwrap(),
+            "error: `#[derive(Hello)]` does not support unions\n --> tests/ui/fail/union.rs:6:1\n  |\n6 | union Number {\n  | ^^^^^\n"
+        );
+        assert!(
+            Kind::ProcMacro
+                .render_facade("demo-rs")
+                .contains("\npub use demo_rs_macros::Hello;\n")
+        );
+        assert!(Kind::Lib.render_facade("demo").is_empty());
+
+        assert_eq!(Kind::Workspace.crate_name("demo"), "demo-core");
+        assert_eq!(Kind::from_id("BIN"), Some(Kind::Bin));
+        assert_eq!(Kind::from_id("proc-macro"), Some(Kind::ProcMacro));
+        assert_eq!(Kind::from_id("cdylib"), None);
+    }
+}
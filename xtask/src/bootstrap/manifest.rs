@@ -0,0 +1,534 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Updates Cargo manifests with toml_edit, keeping their formatting and comments.
+
+use toml_edit::Array;
+use toml_edit::ArrayOfTables;
+use toml_edit::DocumentMut;
+use toml_edit::Item;
+use toml_edit::Key;
+use toml_edit::Table;
+use toml_edit::Value;
+
+use super::rename::CrateRename;
+use crate::metadata::DEPENDENCY_TABLES;
+
+/// The repository of the template, as its manifests declare it.
+pub const TEMPLATE_REPOSITORY: &str = "https://github.com/fast/template";
+
+/// The structured changes to a manifest.
+#[derive(Clone)]
+pub struct ManifestEdit {
+    /// The members named after the template, renamed in the workspace members and in
+    /// dependencies.
+    pub crates: Vec<CrateRename>,
+    /// The new `[package]` name.
+    pub package_name: Option<String>,
+    /// The URL replacing the template repository as `repository` and `homepage`.
+    pub repository: String,
+    /// Fields set in `[workspace.package]`, or inherited from there in `[package]`.
+    pub package_fields: Vec<(String, Value)>,
+    /// Members added to the workspace after the template crate.
+    pub members: Vec<String>,
+    /// Dependencies added to `[workspace.dependencies]` of a workspace manifest, or to
+    /// `[dependencies]` of a member manifest.
+    pub dependencies: Vec<(String, Value)>,
+    /// Features added to `[features]` of a member manifest, by name with what they enable.
+    pub features: Vec<(String, Value)>,
+    /// Dependencies added to `[dev-dependencies]` of a member manifest.
+    pub dev_dependencies: Vec<(String, Value)>,
+    /// Bench targets added to a member manifest by name, each run by its own harness.
+    pub benches: Vec<String>,
+    /// Members dropped from the workspace along with their directory, also from `exclude`.
+    pub removed_members: Vec<String>,
+    /// The arrays of target tables removed along with their sources, e.g., `bench`.
+    pub removed_targets: Vec<String>,
+}
+
+/// Applies `edit` to the manifest `content`, which is returned as is if it is not valid TOML.
+///
+/// Only values naming the template or its members are changed, so a dependency like
+/// `template-engine` from crates.io or a custom homepage is left alone.
+pub fn update_manifest(content: &str, edit: &ManifestEdit) -> String {
+    let Ok(mut doc) = content.parse::<DocumentMut>() else {
+        return content.to_owned();
+    };
+
+    if let Some(package) = doc.get_mut("package").and_then(Item::as_table_mut) {
+        if let Some(name) = &edit.package_name {
+            set_str(package, "name", name);
+        }
+        set_repository(package, &edit.repository);
+        rename_dependencies(package, &edit.crates);
+    }
+    if let Some(workspace) = doc.get_mut("workspace").and_then(Item::as_table_mut) {
+        for key in ["members", "default-members"] {
+            let Some(members) = workspace.get_mut(key).and_then(Item::as_array_mut) else {
+                continue;
+            };
+            for member in members.iter_mut() {
+                let renamed = edit
+                    .crates
+                    .iter()
+                    .find(|rename| member.as_str() == Some(rename.dir.as_str()));
+                if let Some(rename) = renamed {
+                    replace_value(member, &rename.new_dir);
+                }
+            }
+            if key == "members" {
+                let template = edit
+                    .crates
+                    .iter()
+                    .find(|rename| rename.package == "template");
+                let after = template.map(|rename| rename.new_dir.as_str());
+                add_members(members, after, &edit.members);
+            }
+        }
+        for key in ["members", "default-members", "exclude"] {
+            if let Some(members) = workspace.get_mut(key).and_then(Item::as_array_mut) {
+                remove_members(members, &edit.removed_members);
+            }
+        }
+        if let Some(package) = workspace.get_mut("package").and_then(Item::as_table_mut) {
+            set_repository(package, &edit.repository);
+        }
+        if let Some(dependencies) = workspace
+            .get_mut("dependencies")
+            .and_then(Item::as_table_mut)
+        {
+            rename_dependency(dependencies, &edit.crates);
+        }
+    }
+    rename_dependencies(doc.as_table_mut(), &edit.crates);
+    if let Some(targets) = doc.get_mut("target").and_then(Item::as_table_mut) {
+        for (_, target) in targets.iter_mut() {
+            if let Some(target) = target.as_table_mut() {
+                rename_dependencies(target, &edit.crates);
+            }
+        }
+    }
+
+    for target in &edit.removed_targets {
+        doc.remove(target);
+    }
+    if !edit.package_fields.is_empty() {
+        set_package_fields(&mut doc, &edit.package_fields);
+    }
+    if !edit.dependencies.is_empty() {
+        add_dependencies(&mut doc, &edit.dependencies);
+    }
+    if !edit.features.is_empty() {
+        add_features(&mut doc, &edit.features);
+    }
+    if !edit.dev_dependencies.is_empty() {
+        add_dev_dependencies(&mut doc, &edit.dev_dependencies);
+    }
+    if !edit.benches.is_empty() {
+        add_benches(&mut doc, &edit.benches);
+    }
+    doc.to_string()
+}
+
+/// Inserts `added` into `members` after the member `after`, or at the end, unless already a
+/// member.
+fn add_members(members: &mut Array, after: Option<&str>, added: &[String]) {
+    let mut index = members
+        .iter()
+        .position(|member| after.is_some() && member.as_str() == after)
+        .map_or(members.len(), |i| i + 1);
+    for member in added {
+        if !members.iter().any(|m| m.as_str() == Some(member)) {
+            members.insert(index, member.as_str());
+            index += 1;
+        }
+    }
+}
+
+/// Removes the `removed` members, keeping the formatting of the array.
+fn remove_members(members: &mut Array, removed: &[String]) {
+    let first_decor = members.get(0).map(|member| member.decor().clone());
+    members.retain(|member| !removed.iter().any(|r| member.as_str() == Some(r)));
+    // the first member keeps the decor of the removed one, e.g., without a space after `[`
+    if let (Some(decor), Some(first)) = (first_decor, members.get_mut(0)) {
+        *first.decor_mut() = decor;
+    }
+}
+
+/// Adds `dependencies` to `[workspace.dependencies]` of a workspace manifest, or otherwise to
+/// `[dependencies]`, creating the table if needed and keeping existing declarations.
+fn add_dependencies(doc: &mut DocumentMut, dependencies: &[(String, Value)]) {
+    let table = match doc.get_mut("workspace").and_then(Item::as_table_mut) {
+        Some(workspace) => workspace,
+        None => doc.as_table_mut(),
+    };
+    let Some(table) = table
+        .entry("dependencies")
+        .or_insert(Item::Table(Table::new()))
+        .as_table_mut()
+    else {
+        return;
+    };
+    for (name, dependency) in dependencies {
+        if !table.contains_key(name) {
+            table.insert(name, Item::Value(dependency.clone()));
+        }
+    }
+}
+
+/// Adds `features` to `[features]`, creating the table right before `[dependencies]` if needed
+/// and keeping existing declarations.
+fn add_features(doc: &mut DocumentMut, features: &[(String, Value)]) {
+    let position = doc
+        .get("dependencies")
+        .and_then(Item::as_table)
+        .and_then(Table::position);
+    let Some(table) = doc
+        .entry("features")
+        .or_insert_with(|| {
+            let mut table = Table::new();
+            table.set_position(position.map(|position| position - 1));
+            Item::Table(table)
+        })
+        .as_table_mut()
+    else {
+        return;
+    };
+    for (name, feature) in features {
+        if !table.contains_key(name) {
+            table.insert(name, Item::Value(feature.clone()));
+        }
+    }
+}
+
+/// Adds `dependencies` to `[dev-dependencies]`, creating the table right after `[dependencies]`
+/// if needed and keeping existing declarations.
+fn add_dev_dependencies(doc: &mut DocumentMut, dependencies: &[(String, Value)]) {
+    let position = doc
+        .get("dependencies")
+        .and_then(Item::as_table)
+        .and_then(Table::position);
+    let Some(table) = doc
+        .entry("dev-dependencies")
+        .or_insert_with(|| {
+            let mut table = Table::new();
+            table.set_position(position);
+            Item::Table(table)
+        })
+        .as_table_mut()
+    else {
+        return;
+    };
+    for (name, dependency) in dependencies {
+        if !table.contains_key(name) {
+            table.insert(name, Item::Value(dependency.clone()));
+        }
+    }
+}
+
+/// Adds the `benches` as `[[bench]]` targets without the libtest harness, after the dependency
+/// tables, unless already declared.
+fn add_benches(doc: &mut DocumentMut, benches: &[String]) {
+    let position = ["dev-dependencies", "dependencies"]
+        .iter()
+        .find_map(|key| doc.get(key).and_then(Item::as_table)?.position());
+    let Some(targets) = doc
+        .entry("bench")
+        .or_insert(Item::ArrayOfTables(ArrayOfTables::new()))
+        .as_array_of_tables_mut()
+    else {
+        return;
+    };
+    for name in benches {
+        if targets
+            .iter()
+            .any(|target| target.get("name").and_then(Item::as_str) == Some(name))
+        {
+            continue;
+        }
+        let mut target = Table::new();
+        target.set_position(position);
+        target.insert("name", toml_edit::value(name));
+        target.insert("harness", toml_edit::value(false));
+        targets.push(target);
+    }
+}
+
+fn set_repository(package: &mut Table, repository: &str) {
+    for key in ["repository", "homepage"] {
+        if package.get(key).and_then(Item::as_str) == Some(TEMPLATE_REPOSITORY) {
+            set_str(package, key, repository);
+        }
+    }
+}
+
+/// Renames the dependencies on the `crates` in the dependency tables of `table`.
+fn rename_dependencies(table: &mut Table, crates: &[CrateRename]) {
+    for key in DEPENDENCY_TABLES {
+        if let Some(dependencies) = table.get_mut(key).and_then(Item::as_table_mut) {
+            rename_dependency(dependencies, crates);
+        }
+    }
+}
+
+/// Renames the dependencies on the `crates`, along with the last directory of their paths.
+fn rename_dependency(dependencies: &mut Table, crates: &[CrateRename]) {
+    for rename in crates {
+        let Some(dependency) = dependencies.get_mut(&rename.package) else {
+            continue;
+        };
+        let dir_name = |dir: &str| dir.rsplit('/').next().unwrap_or(dir).to_owned();
+        let (old_name, new_name) = (dir_name(&rename.dir), dir_name(&rename.new_dir));
+        if let Some(path) = dependency.get_mut("path") {
+            if let Some(dir) = path
+                .as_str()
+                .and_then(|p| p.strip_suffix(old_name.as_str()))
+            {
+                if dir.is_empty() || dir.ends_with('/') {
+                    let renamed = format!("{dir}{new_name}");
+                    if let Some(path) = path.as_value_mut() {
+                        replace_value(path, &renamed);
+                    }
+                }
+            }
+        }
+        rename_key(dependencies, &rename.package, &rename.new_package);
+    }
+}
+
+/// Renames the key `old` of `table` in place, keeping the order and formatting of the others.
+fn rename_key(table: &mut Table, old: &str, new: &str) {
+    let items: Vec<(Key, Item)> = table
+        .iter()
+        .map(|(key, item)| (table.key(key).unwrap().clone(), item.clone()))
+        .collect();
+    table.clear();
+    for (key, item) in items {
+        let key = if key.get() == old {
+            Key::new(new).with_leaf_decor(key.leaf_decor().clone())
+        } else {
+            key
+        };
+        table.insert_formatted(&key, item);
+    }
+}
+
+fn set_str(table: &mut Table, key: &str, new: &str) {
+    if let Some(value) = table.get_mut(key).and_then(Item::as_value_mut) {
+        replace_value(value, new);
+    }
+}
+
+/// Replaces `value` with the string `new`, keeping the whitespace and comments around it.
+fn replace_value(value: &mut Value, new: &str) {
+    let decor = value.decor().clone();
+    *value = Value::from(new);
+    *value.decor_mut() = decor;
+}
+
+/// Sets `fields` in the `[workspace.package]` table of a workspace manifest, or inherits them
+/// from there in the `[package]` table of a member manifest, keeping the inherited keys sorted
+/// in a group after the package's own.
+fn set_package_fields(doc: &mut DocumentMut, fields: &[(String, Value)]) {
+    if let Some(package) = doc
+        .get_mut("workspace")
+        .and_then(|workspace| workspace.get_mut("package"))
+        .and_then(Item::as_table_mut)
+    {
+        for (key, value) in fields {
+            package.insert(key, Item::Value(value.clone()));
+        }
+        package.sort_values();
+    } else if let Some(package) = doc.get_mut("package").and_then(Item::as_table_mut) {
+        let is_inherited = |item: &Item| item.get("workspace").is_some();
+        for (key, _) in fields {
+            let mut inherited = Table::new();
+            inherited.insert("workspace", toml_edit::value(true));
+            inherited.set_dotted(true);
+            package.insert(key, Item::Table(inherited));
+        }
+        // reinsert the items to sort the inherited keys, which toml_edit parses as dotted tables
+        let mut items: Vec<(String, Item)> = package
+            .iter()
+            .map(|(key, item)| (key.to_owned(), item.clone()))
+            .collect();
+        items.sort_by(
+            |(k1, v1), (k2, v2)| match (is_inherited(v1), is_inherited(v2)) {
+                (true, true) => k1.cmp(k2),
+                (a, b) => a.cmp(&b),
+            },
+        );
+        package.clear();
+        let mut first_inherited = true;
+        for (key, item) in items {
+            let inherited = is_inherited(&item);
+            package.insert(&key, item);
+            if inherited {
+                // the inherited keys form one group after a blank line, which a dotted key
+                // keeps in the decor of its last part
+                let prefix = if first_inherited { "\n" } else { "" };
+                if let Some(table) = package[key.as_str()].as_table_mut() {
+                    // parsed dotted tables are rendered by their position in the document
+                    table.set_position(None);
+                    if let Some(mut workspace) = table.key_mut("workspace") {
+                        workspace.leaf_decor_mut().set_prefix(prefix);
+                    }
+                }
+                first_inherited = false;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bootstrap::template_renames;
+
+    fn renames(crate_name: &str) -> Vec<CrateRename> {
+        let members = [
+            ("template", "template"),
+            ("template-macros", "crates/template-macros"),
+        ]
+        .map(|(package, dir)| (package.to_owned(), dir.to_owned()));
+        template_renames(&members, "demo", crate_name)
+    }
+
+    #[test]
+    fn test_update_manifest() {
+        let edit = ManifestEdit {
+            crates: renames("demo"),
+            package_name: None,
+            repository: "https://github.com/octocat/demo".to_owned(),
+            package_fields: vec![],
+            members: vec![],
+            dependencies: vec![],
+            features: vec![],
+            dev_dependencies: vec![],
+            benches: vec![],
+            removed_members: vec![],
+            removed_targets: vec![],
+        };
+        let content = r#"[workspace]
+members = ["template", "crates/template-macros", "xtask"] # the crates
+
+[workspace.package]
+homepage = "https://template.example.com"
+repository = "https://github.com/fast/template"
+
+[workspace.dependencies]
+serde = "1"
+template = { path = "template" }
+template-engine = "1"
+template-macros = { path = "crates/template-macros", version = "0.1" }
+"#;
+        assert_eq!(
+            update_manifest(content, &edit),
+            r#"[workspace]
+members = ["demo", "crates/demo-macros", "xtask"] # the crates
+
+[workspace.package]
+homepage = "https://template.example.com"
+repository = "https://github.com/octocat/demo"
+
+[workspace.dependencies]
+serde = "1"
+demo = { path = "demo" }
+template-engine = "1"
+demo-macros = { path = "crates/demo-macros", version = "0.1" }
+"#
+        );
+
+        let edit = ManifestEdit {
+            package_name: Some("dev".to_owned()),
+            ..edit
+        };
+        assert_eq!(
+            update_manifest(
+                "[package]\nname = \"x\"\n\n[target.'cfg(unix)'.dev-dependencies]\ntemplate = { workspace = true }\n",
+                &edit
+            ),
+            "[package]\nname = \"dev\"\n\n[target.'cfg(unix)'.dev-dependencies]\ndemo = { workspace = true }\n"
+        );
+    }
+
+    #[test]
+    fn test_update_manifest_additions() {
+        let core = Value::from_iter([("path", "demo-core"), ("version", "0.1.0")]);
+        let edit = ManifestEdit {
+            crates: renames("demo-core"),
+            package_name: None,
+            repository: "https://github.com/octocat/demo".to_owned(),
+            package_fields: vec![],
+            members: vec!["demo-cli".to_owned()],
+            dependencies: vec![("demo-core".to_owned(), core)],
+            features: vec![],
+            dev_dependencies: vec![],
+            benches: vec![],
+            removed_members: vec![],
+            removed_targets: vec![],
+        };
+        assert_eq!(
+            update_manifest("[workspace]\nmembers = [\"template\", \"xtask\"]\n", &edit),
+            "[workspace]\nmembers = [\"demo-core\", \"demo-cli\", \"xtask\"]\n\n[workspace.dependencies]\ndemo-core = { path = \"demo-core\", version = \"0.1.0\" }\n"
+        );
+
+        let edit = ManifestEdit {
+            members: vec![],
+            dependencies: vec![("clap".to_owned(), Value::from("4"))],
+            features: vec![("serde".to_owned(), Value::from_iter(["dep:serde"]))],
+            dev_dependencies: vec![("assert_cmd".to_owned(), Value::from("2"))],
+            benches: vec!["bench_main".to_owned()],
+            ..edit
+        };
+        assert_eq!(
+            update_manifest(
+                "[package]\nname = \"demo\"\n\n[dependencies]\n\n[lints]\nworkspace = true\n",
+                &edit
+            ),
+            "[package]\nname = \"demo\"\n\n[features]\nserde = [\"dep:serde\"]\n\n[dependencies]\nclap = \"4\"\n\n[dev-dependencies]\nassert_cmd = \"2\"\n\n[[bench]]\nname = \"bench_main\"\nharness = false\n\n[lints]\nworkspace = true\n"
+        );
+    }
+
+    #[test]
+    fn test_update_manifest_removals() {
+        let edit = ManifestEdit {
+            crates: renames("demo"),
+            package_name: None,
+            repository: "https://github.com/octocat/demo".to_owned(),
+            package_fields: vec![],
+            members: vec![],
+            dependencies: vec![],
+            features: vec![],
+            dev_dependencies: vec![],
+            benches: vec![],
+            removed_members: vec!["fuzz".to_owned()],
+            removed_targets: vec!["bench".to_owned()],
+        };
+        assert_eq!(
+            update_manifest(
+                "[workspace]\nmembers = [\"template\", \"xtask\"]\nexclude = [\"fuzz\"]\n",
+                &edit
+            ),
+            "[workspace]\nmembers = [\"demo\", \"xtask\"]\nexclude = []\n"
+        );
+        assert_eq!(
+            update_manifest(
+                "[package]\nname = \"template\"\n\n[[bench]]\nname = \"parse\"\nharness = false\n\n[[example]]\nname = \"hello\"\n",
+                &edit
+            ),
+            "[package]\nname = \"template\"\n\n[[example]]\nname = \"hello\"\n"
+        );
+    }
+}
@@ -0,0 +1,198 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The observability the generated crate can start with: a binary initializes a tracing
+//! subscriber filtered by `RUST_LOG`, and an instrumented example function comes with a test
+//! capturing its span.
+
+use toml_edit::Value;
+
+use super::choice::Choice;
+use super::choice::inherited;
+
+/// The tracing requirement of the generated crate.
+const TRACING_VERSION: &str = "0.1.44";
+
+/// The tracing-subscriber requirement of the generated crate.
+const TRACING_SUBSCRIBER_VERSION: &str = "0.3.22";
+
+/// The instrumented example function, following the placeholder function of the library or the
+/// `main` of a binary, and the test capturing its span.
+const EXAMPLE: &str = r#"
+/// Returns the greeting of `name`, in a span recording it.
+#[tracing::instrument]
+pub fn greet(name: &str) -> String {
+    tracing::info!("greeting");
+    format!("Hello, {name}!")
+}
+
+#[cfg(test)]
+mod tracing_tests {
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    use tracing::Subscriber;
+    use tracing::span;
+    use tracing_subscriber::Layer;
+    use tracing_subscriber::layer::Context;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    /// Records the names of the spans created.
+    #[derive(Clone, Default)]
+    struct Spans(Arc<Mutex<Vec<&'static str>>>);
+
+    impl<S: Subscriber> Layer<S> for Spans {
+        fn on_new_span(&self, attrs: &span::Attributes<'_>, _: &span::Id, _: Context<'_, S>) {
+            self.0.lock().unwrap().push(attrs.metadata().name());
+        }
+    }
+
+    #[test]
+    fn test_greet_span() {
+        let spans = Spans::default();
+        let subscriber = tracing_subscriber::registry().with(spans.clone());
+        let greeting = tracing::subscriber::with_default(subscriber, || super::greet("world"));
+        assert_eq!(greeting, "Hello, world!");
+        assert_eq!(*spans.0.lock().unwrap(), ["greet"]);
+    }
+}
+"#;
+
+/// The initialization of the subscriber, following the `main` of a binary.
+const INIT: &str = r#"
+/// Logs to stderr as `RUST_LOG` filters it, at the info level by default.
+fn init_tracing() {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .init();
+}
+"#;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Observability {
+    None,
+    Tracing,
+}
+
+impl Choice for Observability {
+    const OPTION: &'static str = "--observability";
+
+    /// The supported observability, starting with the default of none.
+    const ALL: &'static [Observability] = &[Observability::None, Observability::Tracing];
+
+    fn id(self) -> &'static str {
+        match self {
+            Observability::None => "none",
+            Observability::Tracing => "tracing",
+        }
+    }
+
+    /// Returns the dependencies on tracing, with the `env-filter` the subscriber of a binary is
+    /// filtered by.
+    fn workspace_dependencies(self) -> Vec<(String, Value)> {
+        match self {
+            Observability::None => vec![],
+            Observability::Tracing => {
+                let mut subscriber = toml_edit::InlineTable::new();
+                subscriber.insert("version", TRACING_SUBSCRIBER_VERSION.into());
+                subscriber.insert("features", Value::from_iter(["env-filter"]));
+                vec![
+                    ("tracing".to_owned(), TRACING_VERSION.into()),
+                    (
+                        "tracing-subscriber".to_owned(),
+                        Value::InlineTable(subscriber),
+                    ),
+                ]
+            }
+        }
+    }
+
+    /// Returns the dependencies of a crate with the example function, inherited from the
+    /// workspace.
+    fn crate_dependencies(self) -> Vec<(String, Value)> {
+        self.inherited(&["tracing"])
+    }
+}
+
+impl Observability {
+    /// Returns the dev-dependencies of a library testing the span of the example function.
+    pub fn library_dev_dependencies(self) -> Vec<(String, Value)> {
+        self.inherited(&["tracing-subscriber"])
+    }
+
+    /// Returns the dependencies of a binary initializing the subscriber.
+    pub fn binary_dependencies(self) -> Vec<(String, Value)> {
+        self.inherited(&["tracing-subscriber"])
+    }
+
+    fn inherited(self, names: &[&str]) -> Vec<(String, Value)> {
+        match self {
+            Observability::None => vec![],
+            Observability::Tracing => names.iter().map(|name| inherited(name)).collect(),
+        }
+    }
+
+    /// Returns the instrumented example function and the test of its span, or an empty string
+    /// without observability.
+    pub fn render_example(self) -> &'static str {
+        match self {
+            Observability::None => "",
+            Observability::Tracing => EXAMPLE,
+        }
+    }
+
+    /// Returns the function initializing the subscriber of a binary, or an empty string without
+    /// observability.
+    pub fn render_init(self) -> &'static str {
+        match self {
+            Observability::None => "",
+            Observability::Tracing => INIT,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_observability_scaffolding() {
+        assert!(Observability::None.workspace_dependencies().is_empty());
+        assert!(Observability::None.crate_dependencies().is_empty());
+        assert!(Observability::None.render_example().is_empty());
+        let dependencies = Observability::Tracing.workspace_dependencies();
+        assert_eq!(
+            dependencies
+                .iter()
+                .map(|(name, dependency)| format!("{name} = {dependency}"))
+                .collect::<Vec<_>>(),
+            [
+                "tracing = \"0.1.44\"",
+                "tracing-subscriber = { version = \"0.3.22\", features = [\"env-filter\"] }"
+            ]
+        );
+        let [(name, dependency)] = &Observability::Tracing.binary_dependencies()[..] else {
+            panic!("expected the tracing-subscriber dependency");
+        };
+        assert_eq!(name, "tracing-subscriber");
+        assert_eq!(dependency.to_string(), "{ workspace = true }");
+        assert!(
+            Observability::Tracing
+                .render_example()
+                .contains("#[tracing::instrument]\npub fn greet(name: &str) -> String {\n")
+        );
+    }
+}
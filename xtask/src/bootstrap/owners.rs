@@ -0,0 +1,147 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The `.github/CODEOWNERS` and `.github/FUNDING.yml` of the project, naming its maintainers and
+//! sponsorship accounts instead of the template organization's.
+
+use super::FileChange;
+
+pub const CODEOWNERS: &str = ".github/CODEOWNERS";
+pub const FUNDING: &str = ".github/FUNDING.yml";
+
+/// The platforms of `FUNDING.yml`, as GitHub documents them.
+pub const FUNDING_PLATFORMS: &[&str] = &[
+    "github",
+    "patreon",
+    "open_collective",
+    "ko_fi",
+    "tidelift",
+    "community_bridge",
+    "liberapay",
+    "issuehunt",
+    "lfx_crowdfunding",
+    "polar",
+    "buy_me_a_coffee",
+    "thanks_dev",
+    "custom",
+];
+
+/// The platforms listing several accounts, or URLs for `custom`, where the others name one.
+pub const LIST_PLATFORMS: &[&str] = &["github", "custom"];
+
+/// Returns the files naming the `maintainers`, as GitHub handles, and the `funding` accounts, by
+/// platform, in place of the template's.
+///
+/// `header` is the license header `FUNDING.yml` starts with, whose `.github/` siblings have one.
+/// Without funding accounts, a `FUNDING.yml` among the workspace `files` is removed.
+pub fn planned_files(
+    files: &[String],
+    maintainers: &[String],
+    funding: &[(String, String)],
+    header: &str,
+) -> Vec<FileChange> {
+    let mut changes = vec![];
+    if !maintainers.is_empty() {
+        let owners: Vec<String> = maintainers.iter().map(|m| format!("@{m}")).collect();
+        changes.push(FileChange::Write {
+            file: CODEOWNERS.to_owned(),
+            content: format!(
+                "# The maintainers are requested to review every pull request.\n* {}\n",
+                owners.join(" ")
+            ),
+        });
+    }
+    if !funding.is_empty() {
+        changes.push(FileChange::Write {
+            file: FUNDING.to_owned(),
+            content: format!("{header}{}", render_funding(funding)),
+        });
+    } else if files.iter().any(|file| file == FUNDING) {
+        changes.push(FileChange::Remove {
+            file: FUNDING.to_owned(),
+        });
+    }
+    changes
+}
+
+/// Renders the `funding` accounts as `FUNDING.yml`, one key per platform in the order they were
+/// first given.
+fn render_funding(funding: &[(String, String)]) -> String {
+    let mut platforms: Vec<(&str, Vec<&str>)> = vec![];
+    for (platform, account) in funding {
+        match platforms.iter_mut().find(|(p, _)| p == platform) {
+            Some((_, accounts)) => accounts.push(account),
+            None => platforms.push((platform, vec![account])),
+        }
+    }
+    // JSON strings are valid YAML, so the accounts and URLs need no escaping of their own
+    let quote = |account: &&str| serde_json::Value::from(*account).to_string();
+    platforms
+        .iter()
+        .map(|(platform, accounts)| {
+            if LIST_PLATFORMS.contains(platform) {
+                let accounts: Vec<String> = accounts.iter().map(quote).collect();
+                format!("{platform}: [{}]\n", accounts.join(", "))
+            } else {
+                format!("{platform}: {}\n", quote(&accounts[0]))
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_planned_files() {
+        let funding = [
+            ("github", "octo"),
+            ("ko_fi", "octo"),
+            ("github", "cat"),
+            ("custom", "https://octo.dev/donate"),
+        ]
+        .map(|(platform, account)| (platform.to_owned(), account.to_owned()));
+        let changes = planned_files(
+            &[],
+            &["octo".to_owned(), "octo-org/maintainers".to_owned()],
+            &funding,
+            "# Copyright 2026 Octocat\n\n",
+        );
+        let [
+            FileChange::Write {
+                file: codeowners,
+                content: owners,
+            },
+            FileChange::Write {
+                file: funding_file,
+                content: funding,
+            },
+        ] = &changes[..]
+        else {
+            panic!("expected CODEOWNERS and FUNDING.yml written");
+        };
+        assert_eq!(codeowners, CODEOWNERS);
+        assert!(owners.ends_with("\n* @octo @octo-org/maintainers\n"));
+        assert_eq!(funding_file, FUNDING);
+        assert_eq!(
+            funding,
+            "# Copyright 2026 Octocat\n\ngithub: [\"octo\", \"cat\"]\nko_fi: \"octo\"\ncustom: [\"https://octo.dev/donate\"]\n"
+        );
+
+        let changes = planned_files(&[FUNDING.to_owned()], &[], &[], "");
+        let described: Vec<String> = changes.iter().map(FileChange::describe).collect();
+        assert_eq!(described, ["remove .github/FUNDING.yml"]);
+    }
+}
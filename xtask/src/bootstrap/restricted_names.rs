@@ -0,0 +1,114 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The names Cargo or crates.io reject for a package, as Cargo's `restricted_names` checks them.
+
+/// The longest package name crates.io accepts.
+pub const MAX_NAME_LENGTH: usize = 64;
+
+/// The Rust keywords, strict and reserved, including those of later editions.
+const KEYWORDS: &[&str] = &[
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// The crates of the standard library, which a dependency of the same name would shadow.
+const STD_CRATES: &[&str] = &[
+    "alloc",
+    "compiler_builtins",
+    "core",
+    "panic_abort",
+    "panic_unwind",
+    "proc_macro",
+    "profiler_builtins",
+    "std",
+    "test",
+    "unwind",
+];
+
+/// The directories of the target directory, which a binary of the same name would collide with.
+const ARTIFACT_DIRS: &[&str] = &["build", "deps", "examples", "incremental"];
+
+/// The device names Windows reserves, even with an extension, so a crate directory cannot be
+/// named after them.
+const WINDOWS_DEVICES: &[&str] = &[
+    "con", "prn", "aux", "nul", "com1", "com2", "com3", "com4", "com5", "com6", "com7", "com8",
+    "com9", "lpt1", "lpt2", "lpt3", "lpt4", "lpt5", "lpt6", "lpt7", "lpt8", "lpt9",
+];
+
+/// Checks that `name`, a valid identifier besides `-`, is allowed as a package and crate
+/// directory name, explaining how to pick another one otherwise.
+pub fn check(name: &str) -> Result<(), String> {
+    let length = name.chars().count();
+    if length > MAX_NAME_LENGTH {
+        return Err(format!(
+            "the name is {length} characters long, but crates.io allows at most {MAX_NAME_LENGTH}"
+        ));
+    }
+
+    let crate_name = name.replace('-', "_");
+    let reason = if KEYWORDS.contains(&crate_name.as_str()) {
+        "is a Rust keyword, which cannot name a crate"
+    } else if STD_CRATES.contains(&crate_name.as_str()) {
+        "is a crate of the standard library, which Cargo refuses to shadow"
+    } else if ARTIFACT_DIRS.contains(&name) {
+        "collides with a directory Cargo creates for build artifacts"
+    } else if is_windows_device(name) {
+        "is a reserved device name on Windows, where the crate directory cannot be created"
+    } else {
+        return Ok(());
+    };
+    Err(format!(
+        "'{name}' {reason}; pick another name, e.g., '{name}-rs'"
+    ))
+}
+
+fn is_windows_device(name: &str) -> bool {
+    let stem = name.split('.').next().unwrap_or(name).to_ascii_lowercase();
+    WINDOWS_DEVICES.contains(&stem.as_str())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        for name in ["my-proj", "tests", "self-rs", "console", "std_ext", "Core"] {
+            assert_eq!(check(name), Ok(()), "{name}");
+        }
+        for name in [
+            "test",
+            "core",
+            "self",
+            "Self",
+            "proc-macro",
+            "deps",
+            "con",
+            "NUL",
+            "lpt1",
+        ] {
+            assert!(check(name).is_err(), "{name}");
+        }
+        assert_eq!(
+            check("self"),
+            Err("'self' is a Rust keyword, which cannot name a crate; pick another name, e.g., 'self-rs'".into())
+        );
+        assert!(check(&"a".repeat(64)).is_ok());
+        assert!(check(&"a".repeat(65)).is_err());
+    }
+}
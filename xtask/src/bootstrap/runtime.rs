@@ -0,0 +1,132 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The async runtimes the generated crate can start with: its `main` runs on the runtime, and an
+//! example test shows how to test async code with it.
+
+use toml_edit::Value;
+
+use super::choice::Choice;
+use super::choice::inherited;
+
+/// The tokio requirement of the generated crate.
+const TOKIO_VERSION: &str = "1.53";
+
+/// The async-std requirement of the generated crate.
+const ASYNC_STD_VERSION: &str = "1.13";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Runtime {
+    None,
+    Tokio,
+    AsyncStd,
+}
+
+impl Choice for Runtime {
+    const OPTION: &'static str = "--async-runtime";
+
+    /// The supported runtimes, starting with the default of none.
+    const ALL: &'static [Runtime] = &[Runtime::None, Runtime::Tokio, Runtime::AsyncStd];
+
+    fn id(self) -> &'static str {
+        match self {
+            Runtime::None => "none",
+            Runtime::Tokio => "tokio",
+            Runtime::AsyncStd => "async-std",
+        }
+    }
+
+    /// Returns the dependency on the runtime, with the features the generated `main` and test
+    /// need.
+    fn workspace_dependencies(self) -> Vec<(String, Value)> {
+        let (features, version) = match self {
+            Runtime::None => return vec![],
+            Runtime::Tokio => (&["macros", "rt-multi-thread"][..], TOKIO_VERSION),
+            Runtime::AsyncStd => (&["attributes"][..], ASYNC_STD_VERSION),
+        };
+        let mut dependency = toml_edit::InlineTable::new();
+        dependency.insert("version", version.into());
+        dependency.insert("features", Value::from_iter(features.iter().copied()));
+        vec![(self.id().to_owned(), Value::InlineTable(dependency))]
+    }
+
+    fn crate_dependencies(self) -> Vec<(String, Value)> {
+        match self {
+            Runtime::None => vec![],
+            Runtime::Tokio | Runtime::AsyncStd => vec![inherited(self.id())],
+        }
+    }
+}
+
+impl Runtime {
+    /// Returns the attribute running an async `main` on the runtime, if any.
+    pub fn main_attribute(self) -> Option<&'static str> {
+        match self {
+            Runtime::None => None,
+            Runtime::Tokio => Some("#[tokio::main]"),
+            Runtime::AsyncStd => Some("#[async_std::main]"),
+        }
+    }
+
+    /// Returns a test module with an async test spawning a task on the runtime, or an empty
+    /// string without one.
+    pub fn render_tests(self) -> String {
+        let (attribute, spawn, join) = match self {
+            Runtime::None => return String::new(),
+            Runtime::Tokio => ("tokio::test", "tokio::spawn", ".await.unwrap()"),
+            Runtime::AsyncStd => ("async_std::test", "async_std::task::spawn", ".await"),
+        };
+        format!(
+            r#"
+#[cfg(test)]
+mod tests {{
+    #[{attribute}]
+    async fn test_runtime() {{
+        let task = {spawn}(async {{ 1 + 1 }});
+        assert_eq!(task{join}, 2);
+    }}
+}}
+"#
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_runtime_scaffolding() {
+        assert!(Runtime::None.workspace_dependencies().is_empty());
+        assert!(Runtime::None.render_tests().is_empty());
+        let [(name, dependency)] = &Runtime::Tokio.workspace_dependencies()[..] else {
+            panic!("expected the tokio dependency");
+        };
+        assert_eq!(name, "tokio");
+        assert_eq!(
+            dependency.to_string(),
+            "{ version = \"1.53\", features = [\"macros\", \"rt-multi-thread\"] }"
+        );
+        let [(name, dependency)] = &Runtime::AsyncStd.crate_dependencies()[..] else {
+            panic!("expected the async-std dependency");
+        };
+        assert_eq!(name, "async-std");
+        assert_eq!(dependency.to_string(), "{ workspace = true }");
+        assert!(
+            Runtime::AsyncStd
+                .render_tests()
+                .contains("    #[async_std::test]\n    async fn test_runtime() {\n        let task = async_std::task::spawn(async { 1 + 1 });\n        assert_eq!(task.await, 2);\n")
+        );
+    }
+}
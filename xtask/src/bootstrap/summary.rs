@@ -0,0 +1,141 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The machine-readable summary of `--format json`, which lets automation check what the
+//! bootstrap did instead of scraping its colored output.
+//!
+//! The summary is a JSON object with the `outcome` of the bootstrap (`success`, `failure`,
+//! `cancelled`, or `dry-run`), the `files` written, symlinked or removed, the directory
+//! `renames`, the `errors` reported, and whatever else the bootstrap records, such as the
+//! `project` answers. It is written to stdout, with the progress moved to stderr, or to a file.
+
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+
+/// The summary being recorded, if `--format json` was given.
+static SUMMARY: Mutex<Option<Summary>> = Mutex::new(None);
+
+struct Summary {
+    /// The file the summary is written to, or `None` for stdout.
+    file: Option<PathBuf>,
+    fields: Map<String, Value>,
+}
+
+impl Summary {
+    fn new(file: Option<PathBuf>) -> Summary {
+        let mut fields = Map::new();
+        for key in ["files", "renames", "errors"] {
+            fields.insert(key.to_owned(), json!([]));
+        }
+        Summary { file, fields }
+    }
+
+    fn set(&mut self, key: &str, value: Value) {
+        self.fields.insert(key.to_owned(), value);
+    }
+
+    fn push(&mut self, key: &str, value: Value) {
+        let entry = self.fields.entry(key).or_insert_with(|| json!([]));
+        if let Some(values) = entry.as_array_mut() {
+            values.push(value);
+        }
+    }
+
+    /// Renders the summary with the given `outcome`, unless one was already set.
+    fn render(mut self, outcome: &str) -> String {
+        self.fields
+            .entry("outcome")
+            .or_insert_with(|| outcome.into());
+        serde_json::to_string_pretty(&Value::Object(self.fields)).unwrap()
+    }
+}
+
+/// Starts recording the summary, which [`finish`] writes to `file` or else stdout.
+pub fn start(file: Option<PathBuf>) {
+    *SUMMARY.lock().unwrap() = Some(Summary::new(file));
+}
+
+/// Returns whether stdout is reserved for the summary, so the progress goes to stderr.
+pub fn on_stdout() -> bool {
+    SUMMARY
+        .lock()
+        .unwrap()
+        .as_ref()
+        .is_some_and(|summary| summary.file.is_none())
+}
+
+/// Sets `key` of the summary to `value`, if it is being recorded.
+pub fn set(key: &str, value: impl Into<Value>) {
+    if let Some(summary) = SUMMARY.lock().unwrap().as_mut() {
+        summary.set(key, value.into());
+    }
+}
+
+/// Appends `value` to the array `key` of the summary, if it is being recorded.
+pub fn push(key: &str, value: impl Into<Value>) {
+    if let Some(summary) = SUMMARY.lock().unwrap().as_mut() {
+        summary.push(key, value.into());
+    }
+}
+
+/// Writes the summary with the given `outcome`, unless one was already set, and stops
+/// recording it.
+pub fn finish(outcome: &str) {
+    let Some(summary) = SUMMARY.lock().unwrap().take() else {
+        return;
+    };
+    let file = summary.file.clone();
+    let content = summary.render(outcome);
+    match file {
+        Some(file) => {
+            if let Err(err) = std::fs::write(&file, format!("{content}\n")) {
+                eprintln!("failed to write the summary to {}: {err}", file.display());
+            }
+        }
+        None => println!("{content}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let mut summary = Summary::new(None);
+        summary.set("project", json!({ "name": "demo" }));
+        summary.push("files", json!({ "file": "Cargo.toml", "action": "write" }));
+        summary.push("errors", "Directory 'demo' already exists".into());
+        let rendered: Value = serde_json::from_str(&summary.render("success")).unwrap();
+        assert_eq!(
+            rendered,
+            json!({
+                "files": [{ "file": "Cargo.toml", "action": "write" }],
+                "renames": [],
+                "errors": ["Directory 'demo' already exists"],
+                "project": { "name": "demo" },
+                "outcome": "success",
+            })
+        );
+
+        let mut summary = Summary::new(None);
+        summary.set("outcome", "cancelled".into());
+        let rendered: Value = serde_json::from_str(&summary.render("success")).unwrap();
+        assert_eq!(rendered["outcome"], "cancelled");
+    }
+}
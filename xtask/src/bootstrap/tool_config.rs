@@ -0,0 +1,200 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Updates the configs of the lint tools with toml_edit, so that the first lint of the
+//! bootstrapped project passes.
+
+use toml_edit::Array;
+use toml_edit::DocumentMut;
+use toml_edit::Item;
+use toml_edit::Value;
+
+/// The GitHub organization of the template, as `deny.toml` allows it as a source.
+const TEMPLATE_ORG: &str = "fast";
+
+const TYPOS_CONFIGS: &[&str] = &["typos.toml", "_typos.toml", ".typos.toml"];
+const TAPLO_CONFIGS: &[&str] = &["taplo.toml", ".taplo.toml"];
+const LICENSERC: &str = "licenserc.toml";
+const DENY_CONFIG: &str = "deny.toml";
+
+/// The structured changes to the tool configs.
+#[derive(Clone)]
+pub struct ToolConfigEdit {
+    /// The member directories renamed by the bootstrap, from and to, which are replaced in the
+    /// path globs of the configs.
+    pub dirs: Vec<(String, String)>,
+    /// The words of the project name and account, which typos must accept.
+    pub words: Vec<String>,
+    /// The SPDX identifiers of the project license, which cargo-deny must allow.
+    pub licenses: Vec<String>,
+    /// The GitHub account replacing the template's organization in the allowed sources.
+    pub github_account: String,
+}
+
+/// Returns whether `file`, relative to the workspace, is a tool config updated by
+/// [`update_tool_config`].
+pub fn is_tool_config(file: &str) -> bool {
+    TYPOS_CONFIGS.contains(&file)
+        || TAPLO_CONFIGS.contains(&file)
+        || file == LICENSERC
+        || file == DENY_CONFIG
+}
+
+/// Applies `edit` to the tool config `file` with the given `content`, which is returned as is if
+/// it is not valid TOML.
+pub fn update_tool_config(file: &str, content: &str, edit: &ToolConfigEdit) -> String {
+    let Ok(mut doc) = content.parse::<DocumentMut>() else {
+        return content.to_owned();
+    };
+    if TYPOS_CONFIGS.contains(&file) {
+        rename_paths(doc.get_mut("files"), "extend-exclude", &edit.dirs);
+        add_typos_words(&mut doc, &edit.words);
+    } else if TAPLO_CONFIGS.contains(&file) {
+        for key in ["include", "exclude"] {
+            rename_paths(Some(doc.as_item_mut()), key, &edit.dirs);
+        }
+    } else if file == LICENSERC {
+        for key in ["includes", "excludes"] {
+            rename_paths(Some(doc.as_item_mut()), key, &edit.dirs);
+        }
+    } else if file == DENY_CONFIG {
+        update_deny(&mut doc, edit);
+    }
+    doc.to_string()
+}
+
+/// Renames the `dirs` in the path globs of the array `key` of `table`.
+fn rename_paths(table: Option<&mut Item>, key: &str, dirs: &[(String, String)]) {
+    let Some(paths) = table
+        .and_then(|table| table.get_mut(key))
+        .and_then(Item::as_array_mut)
+    else {
+        return;
+    };
+    for path in paths.iter_mut() {
+        let renamed = path.as_str().and_then(|path| {
+            dirs.iter().find_map(|(old, new)| {
+                let rest = path.strip_prefix(old.as_str())?;
+                (rest.is_empty() || rest.starts_with('/')).then(|| format!("{new}{rest}"))
+            })
+        });
+        if let Some(renamed) = renamed {
+            let decor = path.decor().clone();
+            *path = Value::from(renamed);
+            *path.decor_mut() = decor;
+        }
+    }
+}
+
+/// Accepts `words` in `[default.extend-words]`, unless typos is already told about them.
+fn add_typos_words(doc: &mut DocumentMut, words: &[String]) {
+    let Some(extend_words) = doc
+        .get_mut("default")
+        .and_then(|default| default.get_mut("extend-words"))
+        .and_then(Item::as_table_like_mut)
+    else {
+        return;
+    };
+    for word in words {
+        if !extend_words.contains_key(word) {
+            extend_words.insert(word, toml_edit::value(word.as_str()));
+        }
+    }
+}
+
+/// Allows the project license in `[licenses]` and the project account in `[sources]`.
+fn update_deny(doc: &mut DocumentMut, edit: &ToolConfigEdit) {
+    if let Some(allow) = doc
+        .get_mut("licenses")
+        .and_then(|licenses| licenses.get_mut("allow"))
+        .and_then(Item::as_array_mut)
+    {
+        for license in &edit.licenses {
+            if !allow
+                .iter()
+                .any(|allowed| allowed.as_str() == Some(license))
+            {
+                allow.push(license.as_str());
+            }
+        }
+    }
+    if let Some(orgs) = doc
+        .get_mut("sources")
+        .and_then(|sources| sources.get_mut("allow-org"))
+        .and_then(|allow_org| allow_org.get_mut("github"))
+        .and_then(Item::as_array_mut)
+    {
+        replace_org(orgs, &edit.github_account);
+    }
+}
+
+fn replace_org(orgs: &mut Array, account: &str) {
+    if orgs.iter().any(|org| org.as_str() == Some(account)) {
+        orgs.retain(|org| org.as_str() != Some(TEMPLATE_ORG));
+        return;
+    }
+    for org in orgs.iter_mut() {
+        if org.as_str() == Some(TEMPLATE_ORG) {
+            let decor = org.decor().clone();
+            *org = Value::from(account);
+            *org.decor_mut() = decor;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_tool_config() {
+        let edit = ToolConfigEdit {
+            dirs: vec![
+                ("template".to_owned(), "my-proj".to_owned()),
+                (
+                    "crates/template-macros".to_owned(),
+                    "crates/my-proj-macros".to_owned(),
+                ),
+            ],
+            words: vec!["my".to_owned(), "proj".to_owned()],
+            licenses: vec!["MIT".to_owned(), "Apache-2.0".to_owned()],
+            github_account: "octocat".to_owned(),
+        };
+        assert_eq!(
+            update_tool_config(
+                "typos.toml",
+                "[default.extend-words]\nproj = \"proj\"\n\n[files]\nextend-exclude = [\"template/fixtures\", \"templates\", \"crates/template-macros/tests\"]\n",
+                &edit
+            ),
+            "[default.extend-words]\nproj = \"proj\"\nmy = \"my\"\n\n[files]\nextend-exclude = [\"my-proj/fixtures\", \"templates\", \"crates/my-proj-macros/tests\"]\n"
+        );
+        assert_eq!(
+            update_tool_config(
+                "taplo.toml",
+                "exclude = [\"target\", \"template/tests/**\"]\n",
+                &edit
+            ),
+            "exclude = [\"target\", \"my-proj/tests/**\"]\n"
+        );
+        assert_eq!(
+            update_tool_config(
+                "deny.toml",
+                "[licenses]\nallow = [\"Apache-2.0\"]\n\n[sources.allow-org]\ngithub = [\"fast\"]\n",
+                &edit
+            ),
+            "[licenses]\nallow = [\"Apache-2.0\", \"MIT\"]\n\n[sources.allow-org]\ngithub = [\"octocat\"]\n"
+        );
+        assert!(!is_tool_config("xtask.toml"));
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The files exercising the public API of the library in a fresh project: an integration test
+//! and an example, which `cargo x build` builds along with the crate.
+//!
+//! They are written as template sources calling `template::hello()`, and renamed like them.
+
+use xtask_bootstrap::rename;
+use xtask_bootstrap::rename::CrateRename;
+
+use super::FileChange;
+
+/// A file using the public API of the template crate.
+pub struct UsageFile {
+    /// The value of `--usage-files`.
+    pub id: &'static str,
+    /// The path of the file, from the workspace root.
+    pub file: &'static str,
+    content: &'static str,
+}
+
+/// The usage files, in the order they are written.
+pub const USAGE_FILES: &[UsageFile] = &[
+    UsageFile {
+        id: "tests",
+        file: "template/tests/integration.rs",
+        content: r#"//! Integration tests exercising the public API of the crate.
+
+#[test]
+fn test_hello() {
+    template::hello();
+}
+"#,
+    },
+    UsageFile {
+        id: "examples",
+        file: "template/examples/basic.rs",
+        content: r#"//! A basic use of the crate, run with `cargo run --example basic`.
+
+fn main() {
+    template::hello();
+}
+"#,
+    },
+];
+
+impl UsageFile {
+    pub fn from_id(id: &str) -> Option<&'static UsageFile> {
+        USAGE_FILES
+            .iter()
+            .find(|file| file.id.eq_ignore_ascii_case(id))
+    }
+}
+
+/// Returns the `selected` usage files, starting with the license `header` of the template crate
+/// and calling it by its new name as the `crates` rename it, except those already among the
+/// workspace `files`.
+pub fn planned_files(
+    selected: &[&UsageFile],
+    files: &[String],
+    header: &str,
+    crates: &[CrateRename],
+) -> Vec<FileChange> {
+    selected
+        .iter()
+        .filter(|usage| !files.iter().any(|file| file == usage.file))
+        .map(|usage| FileChange::Write {
+            file: usage.file.to_owned(),
+            content: format!(
+                "{header}{}",
+                rename::rename_crates(usage.content, crates, true)
+            ),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bootstrap::template_renames;
+
+    #[test]
+    fn test_planned_files() {
+        let crates = template_renames(
+            &[("template".to_owned(), "template".to_owned())],
+            "demo-core",
+            "demo-core",
+        );
+        let selected = [UsageFile::from_id("Examples").unwrap()];
+        let changes = planned_files(&selected, &[], "// Copyright\n\n", &crates);
+        let [FileChange::Write { file, content }] = &changes[..] else {
+            panic!("expected the example");
+        };
+        assert_eq!(file, "template/examples/basic.rs");
+        assert!(content.starts_with("// Copyright\n\n//! A basic use of the crate"));
+        assert!(content.contains("\n    demo_core::hello();\n"));
+
+        let files = ["template/examples/basic.rs".to_owned()];
+        assert!(planned_files(&selected, &files, "", &crates).is_empty());
+        assert!(UsageFile::from_id("benches").is_none());
+    }
+}
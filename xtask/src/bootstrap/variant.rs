@@ -0,0 +1,358 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The named variants of the template under `templates/`, e.g., `templates/async-service/`, each
+//! declaring in its `variant.toml` the files it adds to or replaces in the template, the files it
+//! removes, the placeholders it prompts for, the dependencies its members inherit from
+//! `[workspace.dependencies]`, and the commands run once it is bootstrapped.
+//!
+//! The variant files are bootstrapped like those of the template, so they name the template crate
+//! and its directory, and are then filled with the answers to the placeholders, e.g., `${port}`.
+//! The `templates/` directory itself is not part of the bootstrapped project.
+
+use toml_edit::DocumentMut;
+use toml_edit::Item;
+use toml_edit::Value;
+
+use super::FileChange;
+
+/// The directory of the variants, relative to the workspace root.
+pub const VARIANTS_DIR: &str = "templates/";
+
+/// The manifest of a variant, in its directory.
+pub const VARIANT_MANIFEST: &str = "variant.toml";
+
+/// A variant of the template, as declared by its manifest.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Variant {
+    pub name: String,
+    pub description: String,
+    /// The files of the variant by their path in the workspace, with their content.
+    pub files: Vec<(String, String)>,
+    /// The files of the template the variant removes.
+    pub removed: Vec<String>,
+    pub placeholders: Vec<Placeholder>,
+    /// The dependencies added to `[workspace.dependencies]` by name, with their requirement as
+    /// TOML, e.g., `"0.2"` or `{ version = "1", features = ["full"] }`.
+    pub dependencies: Vec<(String, String)>,
+    /// The shell commands run in the bootstrapped project, before those of a `--config` file.
+    pub post_bootstrap: Vec<String>,
+}
+
+/// A value the variant files are filled with, as `${key}`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Placeholder {
+    pub key: String,
+    pub prompt: String,
+    pub default: Option<String>,
+}
+
+/// Returns the names of the variants among the workspace `files`, sorted.
+pub fn variant_names(files: &[String]) -> Vec<String> {
+    let mut names: Vec<String> = files
+        .iter()
+        .filter_map(|file| {
+            let name = file
+                .strip_prefix(VARIANTS_DIR)?
+                .strip_suffix(VARIANT_MANIFEST)?
+                .strip_suffix('/')?;
+            (!name.contains('/')).then(|| name.to_owned())
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+/// Loads the variant `name`, reading its manifest and files, given by their path relative to the
+/// workspace root, with `read`.
+pub fn load_variant(
+    name: &str,
+    read: impl Fn(&str) -> Result<String, String>,
+) -> Result<Variant, String> {
+    let dir = format!("{VARIANTS_DIR}{name}/");
+    let doc: DocumentMut = read(&format!("{dir}{VARIANT_MANIFEST}"))?
+        .parse()
+        .map_err(|err| format!("invalid {dir}{VARIANT_MANIFEST}: {err}"))?;
+    let mut variant = Variant {
+        name: name.to_owned(),
+        ..Variant::default()
+    };
+    let strings = |item: &Item, key: &str| {
+        item.as_array()
+            .and_then(|array| {
+                array
+                    .iter()
+                    .map(|value| value.as_str().map(str::to_owned))
+                    .collect::<Option<Vec<_>>>()
+            })
+            .ok_or_else(|| format!("'{key}' must be an array of strings"))
+    };
+    for (key, item) in doc.iter() {
+        match key {
+            "description" => {
+                variant.description = item
+                    .as_str()
+                    .ok_or("'description' must be a string")?
+                    .to_owned();
+            }
+            "remove" => {
+                variant.removed = strings(item, key)?;
+                for file in &variant.removed {
+                    check_path(file)?;
+                }
+            }
+            "files" => {
+                let files = item.as_table_like().ok_or("'files' must be a table")?;
+                for (file, source) in files.iter() {
+                    check_path(file)?;
+                    let source = source
+                        .as_str()
+                        .ok_or_else(|| format!("'files.\"{file}\"' must be a path"))?;
+                    check_path(source)?;
+                    variant
+                        .files
+                        .push((file.to_owned(), read(&format!("{dir}{source}"))?));
+                }
+            }
+            "placeholders" => {
+                let placeholders = item
+                    .as_table_like()
+                    .ok_or("'placeholders' must be a table")?;
+                for (key, placeholder) in placeholders.iter() {
+                    let field = |field: &str| {
+                        placeholder
+                            .get(field)
+                            .and_then(Item::as_str)
+                            .map(str::to_owned)
+                    };
+                    let prompt = field("prompt")
+                        .ok_or_else(|| format!("'placeholders.{key}.prompt' must be a string"))?;
+                    variant.placeholders.push(Placeholder {
+                        key: key.to_owned(),
+                        prompt,
+                        default: field("default"),
+                    });
+                }
+            }
+            "dependencies" => {
+                let dependencies = item
+                    .as_table_like()
+                    .ok_or("'dependencies' must be a table")?;
+                for (name, requirement) in dependencies.iter() {
+                    let requirement = requirement.as_value().ok_or_else(|| {
+                        format!("'dependencies.{name}' must be a version or an inline table")
+                    })?;
+                    variant
+                        .dependencies
+                        .push((name.to_owned(), requirement.to_string().trim().to_owned()));
+                }
+            }
+            "hooks" => {
+                if let Some(hooks) = item.get("post_bootstrap") {
+                    variant.post_bootstrap = strings(hooks, "hooks.post_bootstrap")?;
+                }
+            }
+            _ => {
+                return Err(format!(
+                    "unknown key '{key}' in {dir}{VARIANT_MANIFEST}, expected description, remove, [files], [placeholders], [dependencies] or [hooks]"
+                ));
+            }
+        }
+    }
+    Ok(variant)
+}
+
+/// Fails for a path leaving the workspace, or pointing into the variants.
+fn check_path(path: &str) -> Result<(), String> {
+    let escapes = path.starts_with('/') || path.split('/').any(|part| part == "..");
+    if escapes || path.starts_with(VARIANTS_DIR) {
+        return Err(format!("'{path}' must be a relative path in the workspace"));
+    }
+    Ok(())
+}
+
+impl Variant {
+    /// Returns the dependencies added to `[workspace.dependencies]`.
+    pub fn workspace_dependencies(&self) -> Vec<(String, Value)> {
+        self.dependencies
+            .iter()
+            .filter_map(|(name, requirement)| Some((name.clone(), requirement.parse().ok()?)))
+            .collect()
+    }
+
+    /// Fills `${key}` in the variant files with the `values` of the placeholders, by key.
+    pub fn fill(&mut self, values: &[(String, String)]) {
+        for (_, content) in &mut self.files {
+            for (key, value) in values {
+                *content = content.replace(&format!("${{{key}}}"), value);
+            }
+        }
+    }
+}
+
+/// Returns the workspace `files` as the `variant` overlays them, which are bootstrapped instead:
+/// its files replace or join those of the template, and the variants themselves are left out.
+///
+/// The files the variant removes are kept, since the files generated for the project kind are
+/// derived from them.
+pub fn overlaid_files(
+    files: &[(String, String)],
+    variant: Option<&Variant>,
+) -> Vec<(String, String)> {
+    let variant_files = variant.map_or(&[][..], |variant| &variant.files[..]);
+    let mut overlaid: Vec<(String, String)> = files
+        .iter()
+        .filter(|(file, _)| !file.starts_with(VARIANTS_DIR))
+        .map(|(file, content)| {
+            let replaced = variant_files.iter().find(|(name, _)| name == file);
+            (
+                file.clone(),
+                replaced.map_or(content, |(_, new)| new).clone(),
+            )
+        })
+        .collect();
+    for (file, content) in variant_files {
+        if !overlaid.iter().any(|(name, _)| name == file) {
+            overlaid.push((file.clone(), content.clone()));
+        }
+    }
+    overlaid
+}
+
+/// Returns the changes writing the files of the `variant` with their `bootstrapped` content, and
+/// removing the files it removes and the variants among the workspace `files`.
+pub fn planned_files(
+    variant: Option<&Variant>,
+    files: &[String],
+    bootstrapped: impl Fn(&str) -> String,
+) -> Vec<FileChange> {
+    let mut changes: Vec<FileChange> = variant
+        .iter()
+        .flat_map(|variant| &variant.files)
+        .map(|(file, _)| FileChange::Write {
+            file: file.clone(),
+            content: bootstrapped(file),
+        })
+        .collect();
+    let removed = variant.map_or(&[][..], |variant| &variant.removed[..]);
+    changes.extend(
+        files
+            .iter()
+            .filter(|file| file.starts_with(VARIANTS_DIR) || removed.contains(file))
+            .map(|file| FileChange::Remove { file: file.clone() }),
+    );
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(path: &str) -> Result<String, String> {
+        match path {
+            "templates/service/variant.toml" => Ok(r#"description = "A service."
+remove = ["template/src/lib.rs"]
+
+[files]
+"template/src/main.rs" = "main.rs"
+
+[placeholders.port]
+prompt = "Enter the port"
+default = "8080"
+
+[dependencies]
+tokio = { version = "1", features = ["net"] }
+
+[hooks]
+post_bootstrap = ["cargo check"]
+"#
+            .to_owned()),
+            "templates/service/main.rs" => Ok("fn main() { template::serve(${port}); }\n".into()),
+            "templates/escape/variant.toml" => Ok("[files]\n\"../x\" = \"x\"\n".into()),
+            _ => Err(format!("failed to read {path}")),
+        }
+    }
+
+    #[test]
+    fn test_load_variant() {
+        let mut variant = load_variant("service", read).unwrap();
+        assert_eq!(variant.description, "A service.");
+        assert_eq!(variant.removed, ["template/src/lib.rs"]);
+        assert_eq!(
+            variant.placeholders,
+            [Placeholder {
+                key: "port".to_owned(),
+                prompt: "Enter the port".to_owned(),
+                default: Some("8080".to_owned()),
+            }]
+        );
+        assert_eq!(
+            variant.dependencies,
+            [(
+                "tokio".to_owned(),
+                r#"{ version = "1", features = ["net"] }"#.to_owned()
+            )]
+        );
+        assert_eq!(variant.workspace_dependencies().len(), 1);
+        assert_eq!(variant.post_bootstrap, ["cargo check"]);
+        variant.fill(&[("port".to_owned(), "3000".to_owned())]);
+        assert_eq!(
+            variant.files,
+            [(
+                "template/src/main.rs".to_owned(),
+                "fn main() { template::serve(3000); }\n".to_owned()
+            )]
+        );
+
+        assert!(load_variant("escape", read).is_err());
+        assert_eq!(
+            load_variant("missing", read),
+            Err("failed to read templates/missing/variant.toml".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_overlaid_files() {
+        let variant = load_variant("service", read).unwrap();
+        let files = [
+            ("README.md", "# template\n"),
+            ("template/src/lib.rs", "pub fn hello() {}\n"),
+            ("templates/service/variant.toml", ""),
+        ]
+        .map(|(file, content)| (file.to_owned(), content.to_owned()));
+        let overlaid = overlaid_files(&files, Some(&variant));
+        assert_eq!(
+            overlaid
+                .iter()
+                .map(|(file, _)| file.as_str())
+                .collect::<Vec<_>>(),
+            ["README.md", "template/src/lib.rs", "template/src/main.rs"]
+        );
+        assert_eq!(overlaid_files(&files, None).len(), 2);
+
+        let names = files.map(|(file, _)| file);
+        assert_eq!(variant_names(&names), ["service"]);
+        let changes = planned_files(Some(&variant), &names, |file| {
+            format!("bootstrapped {file}")
+        });
+        assert_eq!(
+            changes.iter().map(FileChange::describe).collect::<Vec<_>>(),
+            [
+                "write template/src/main.rs",
+                "remove template/src/lib.rs",
+                "remove templates/service/variant.toml",
+            ]
+        );
+    }
+}
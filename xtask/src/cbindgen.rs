@@ -0,0 +1,99 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use super::ensure_installed;
+use super::find_command;
+use super::history;
+use super::metadata;
+use super::run_command;
+
+/// The cbindgen config of a member, next to its manifest.
+const CONFIG_FILE: &str = "cbindgen.toml";
+
+/// Generates the C header of every workspace member with a `cbindgen.toml`, as
+/// `include/<lib>.h` in its directory.
+///
+/// With `check`, the headers are compared with those generated instead, and the command fails if
+/// one is stale, so that CI catches a C API changed without its header. Without any such member,
+/// there is nothing to do and cbindgen is not installed.
+pub fn cbindgen(check: bool) {
+    let metadata = metadata::cargo_metadata(true);
+    let members: Vec<(String, PathBuf)> = metadata::workspace_packages(&metadata)
+        .into_iter()
+        .filter_map(|package| {
+            let manifest = Path::new(package["manifest_path"].as_str()?);
+            let dir = manifest.parent()?;
+            dir.join(CONFIG_FILE).is_file().then(|| {
+                let name = package["name"].as_str().unwrap_or_default();
+                (name.to_owned(), dir.to_path_buf())
+            })
+        })
+        .collect();
+    if members.is_empty() {
+        println!("No workspace member has a {CONFIG_FILE}.");
+        return;
+    }
+
+    ensure_installed("cbindgen", "cbindgen");
+    let mut stale = vec![];
+    for (name, dir) in members {
+        let header = header_path(&name);
+        let mut cmd = find_command("cbindgen");
+        cmd.current_dir(&dir);
+        cmd.args([
+            "--config",
+            CONFIG_FILE,
+            "--crate",
+            &name,
+            "--output",
+            &header,
+        ]);
+        if !check {
+            run_command(cmd);
+            continue;
+        }
+        cmd.arg("--verify");
+        println!("{cmd:?}");
+        let status = cmd.status().expect("failed to execute cbindgen");
+        if !status.success() {
+            stale.push(dir.join(header));
+        }
+    }
+    if !stale.is_empty() {
+        for header in &stale {
+            eprintln!("{} is stale.", header.display());
+        }
+        eprintln!("\nRegenerate the headers with `cargo x cbindgen`.");
+        history::exit(1);
+    }
+}
+
+/// Returns the path of the header of the package `name`, relative to its directory.
+fn header_path(name: &str) -> String {
+    format!("include/{}.h", name.replace('-', "_"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_header_path() {
+        assert_eq!(header_path("demo"), "include/demo.h");
+        assert_eq!(header_path("demo-ffi"), "include/demo_ffi.h");
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Maintains `CHANGELOG.md` in the [Keep a Changelog](https://keepachangelog.com/en/1.1.0/)
+//! format: changes are listed under `## [Unreleased]` until a release moves them under a section
+//! of its own, and the compare links at the bottom follow.
+
+use std::time::SystemTime;
+
+use super::history;
+use super::workspace_dir;
+
+const UNRELEASED: &str = "## [Unreleased]\n";
+const UNRELEASED_LINK: &str = "[Unreleased]: ";
+
+/// Moves the unreleased changes of the workspace `CHANGELOG.md` under a section for `version`,
+/// released on `date` or else today.
+pub fn release_changelog(version: &str, date: Option<&str>) {
+    let file = workspace_dir().join("CHANGELOG.md");
+    let Ok(changelog) = std::fs::read_to_string(&file) else {
+        eprintln!("{} not found.", file.display());
+        history::exit(1);
+    };
+    let date = date.map_or_else(today, str::to_owned);
+    match release(&changelog, version, &date) {
+        Ok(updated) => {
+            std::fs::write(&file, updated).unwrap();
+            println!("Released {version} in CHANGELOG.md.");
+        }
+        Err(err) => {
+            eprintln!("CHANGELOG.md: {err}");
+            history::exit(1);
+        }
+    }
+}
+
+/// Moves the unreleased changes of `changelog` under a `## [version] - date` section, and points
+/// the `[Unreleased]` link to the changes since the `v{version}` tag and the new version's link
+/// to the changes since the previous release.
+fn release(changelog: &str, version: &str, date: &str) -> Result<String, String> {
+    if changelog.contains(&format!("## [{version}]")) {
+        return Err(format!("version {version} is already released"));
+    }
+    if !changelog.contains(UNRELEASED) {
+        return Err(format!("no `{}` section", UNRELEASED.trim_end()));
+    }
+    let changelog = changelog.replacen(
+        UNRELEASED,
+        &format!("{UNRELEASED}\n## [{version}] - {date}\n"),
+        1,
+    );
+
+    let mut lines: Vec<String> = changelog.lines().map(str::to_owned).collect();
+    let Some(index) = lines
+        .iter()
+        .position(|line| line.starts_with(UNRELEASED_LINK))
+    else {
+        return Err(format!("no `{UNRELEASED_LINK}` link"));
+    };
+    let url = &lines[index][UNRELEASED_LINK.len()..];
+    let (base, previous) = match url.split_once("/compare/") {
+        Some((base, range)) => (base, range.strip_suffix("...HEAD")),
+        None => (
+            url.split_once("/commits/").map_or(url, |(base, _)| base),
+            None,
+        ),
+    };
+    let link = match previous {
+        Some(previous) => format!("[{version}]: {base}/compare/{previous}...v{version}"),
+        None => format!("[{version}]: {base}/releases/tag/v{version}"),
+    };
+    let unreleased = format!("{UNRELEASED_LINK}{base}/compare/v{version}...HEAD");
+    lines[index] = unreleased;
+    lines.insert(index + 1, link);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Returns the current date in UTC, as `YYYY-MM-DD`.
+fn today() -> String {
+    let (year, month, day) = civil_date(current_days());
+    format!("{year}-{month:02}-{day:02}")
+}
+
+/// Returns the number of days since 1970-01-01 in UTC.
+pub fn current_days() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() / 86_400)
+}
+
+/// Returns the Gregorian year, month, and day of the day `days` after 1970-01-01.
+///
+/// Adapted from [`civil_from_days`](https://howardhinnant.github.io/date_algorithms.html).
+pub fn civil_date(days: u64) -> (u64, u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    // the computed year starts in March, so January and February belong to the next one
+    (era * 400 + yoe + u64::from(month <= 2), month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_release() {
+        let changelog = "# Changelog\n\n## [Unreleased]\n\n### Added\n\n- The first feature.\n\n[Unreleased]: https://github.com/octo/demo/commits/HEAD\n";
+        let released = release(changelog, "0.1.0", "2026-10-16").unwrap();
+        assert!(released.ends_with(
+            "## [Unreleased]\n\n## [0.1.0] - 2026-10-16\n\n### Added\n\n- The first feature.\n\n[Unreleased]: https://github.com/octo/demo/compare/v0.1.0...HEAD\n[0.1.0]: https://github.com/octo/demo/releases/tag/v0.1.0\n"
+        ));
+
+        let released = release(&released, "0.2.0", "2026-11-01").unwrap();
+        assert!(released.contains("## [Unreleased]\n\n## [0.2.0] - 2026-11-01\n\n## [0.1.0]"));
+        assert!(released.ends_with(
+            "[Unreleased]: https://github.com/octo/demo/compare/v0.2.0...HEAD\n[0.2.0]: https://github.com/octo/demo/compare/v0.1.0...v0.2.0\n[0.1.0]: https://github.com/octo/demo/releases/tag/v0.1.0\n"
+        ));
+        assert_eq!(
+            release(&released, "0.1.0", "2026-11-02"),
+            Err("version 0.1.0 is already released".to_owned())
+        );
+        assert!(release("# Changelog\n", "0.1.0", "2026-11-02").is_err());
+    }
+
+    #[test]
+    fn test_civil_date() {
+        assert_eq!(civil_date(0), (1970, 1, 1));
+        assert_eq!(civil_date(20_088), (2024, 12, 31));
+        assert_eq!(civil_date(20_089), (2025, 1, 1));
+        assert_eq!(civil_date(20_742), (2026, 10, 16));
+        assert_eq!(civil_date(19_782), (2024, 2, 29));
+    }
+}
@@ -0,0 +1,177 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use toml_edit::Document;
+use toml_edit::Item;
+use toml_edit::Table;
+
+use super::config;
+use super::config::CONFIG_FILE;
+use super::history;
+use super::metadata;
+use super::metadata::DEPENDENCY_TABLES;
+use super::workspace_dir;
+
+/// A dependency of a member manifest not inherited from `[workspace.dependencies]`.
+#[derive(Debug, PartialEq)]
+struct Violation {
+    /// The 1-based line of the dependency in the manifest.
+    line: usize,
+    /// The name of the dependency.
+    name: String,
+    /// The table declaring it, e.g., `dev-dependencies` or `target.'cfg(unix)'.dependencies`.
+    table: String,
+}
+
+/// Checks that every workspace member declares its dependencies with `workspace = true`, so that
+/// their versions, paths and sources live only in `[workspace.dependencies]`.
+///
+/// The xtask and the members it depends on keep their own dependencies, which the bootstrap
+/// cleanup removes from them, and so do the members listed under `[check-deps] exclude` in
+/// `xtask.toml`.
+pub fn check_deps() {
+    let doc = config::load_config();
+    let exclude = config::string_array(
+        doc.get("check-deps").and_then(|check| check.get("exclude")),
+        &format!("'check-deps.exclude' in {CONFIG_FILE}"),
+    )
+    .unwrap_or_else(|err| history::fail(&format!("invalid {CONFIG_FILE}: {err}")));
+
+    let metadata = metadata::cargo_metadata(true);
+    let packages = metadata::workspace_packages(&metadata);
+
+    let mut problems = 0;
+    for package in metadata::non_tooling_members(&packages) {
+        let name = package["name"].as_str().unwrap_or_default();
+        if exclude.iter().any(|excluded| excluded == name) {
+            continue;
+        }
+        let manifest_path = Path::new(package["manifest_path"].as_str().unwrap());
+        let content = std::fs::read_to_string(manifest_path).unwrap();
+        let manifest = manifest_path
+            .strip_prefix(workspace_dir())
+            .unwrap_or(manifest_path)
+            .display();
+        let violations = check_manifest(&content)
+            .unwrap_or_else(|err| history::fail(&format!("invalid {manifest}: {err}")));
+        for violation in violations {
+            println!(
+                "{manifest}:{}: `{}` in [{}] is not inherited from [workspace.dependencies]",
+                violation.line, violation.name, violation.table
+            );
+            problems += 1;
+        }
+    }
+
+    if problems > 0 {
+        eprintln!(
+            "\nFound {problems} dependency(ies) declared outside the workspace; move them to [workspace.dependencies] in Cargo.toml and declare them as `<name>.workspace = true`, or list the member under [check-deps] exclude in {CONFIG_FILE}."
+        );
+        history::exit(1);
+    }
+    println!("Dependencies are inherited from the workspace.");
+}
+
+/// Returns the dependencies of the member manifest `content` not declared with
+/// `workspace = true`, in the order they appear.
+fn check_manifest(content: &str) -> Result<Vec<Violation>, String> {
+    let doc = Document::parse(content).map_err(|err| err.to_string())?;
+    let line = |offset: usize| content[..offset].matches('\n').count() + 1;
+
+    let mut violations = vec![];
+    let mut check_tables = |table: &Table, prefix: &str| {
+        for key in DEPENDENCY_TABLES {
+            let Some(dependencies) = table.get(key).and_then(Item::as_table_like) else {
+                continue;
+            };
+            for (name, dependency) in dependencies.iter() {
+                let inherited = dependency
+                    .get("workspace")
+                    .and_then(Item::as_bool)
+                    .unwrap_or(false);
+                if inherited {
+                    continue;
+                }
+                let span = dependencies
+                    .get_key_value(name)
+                    .and_then(|(key, _)| key.span())
+                    .or_else(|| dependency.span());
+                violations.push(Violation {
+                    line: span.map_or(0, |span| line(span.start)),
+                    name: name.to_owned(),
+                    table: format!("{prefix}{key}"),
+                });
+            }
+        }
+    };
+    check_tables(doc.as_table(), "");
+    if let Some(targets) = doc.get("target").and_then(Item::as_table) {
+        for (target, item) in targets.iter() {
+            if let Some(table) = item.as_table() {
+                check_tables(table, &format!("target.'{target}'."));
+            }
+        }
+    }
+    violations.sort_by_key(|violation| violation.line);
+    Ok(violations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_manifest() {
+        let manifest = r#"[package]
+name = "demo"
+
+[dependencies]
+demo-core.workspace = true
+serde = { workspace = true, optional = true }
+anyhow = "1.0"
+
+[dev-dependencies]
+trybuild = { version = "1.0.116" }
+
+[target.'cfg(unix)'.dependencies]
+libc = { path = "../libc" }
+
+[build-dependencies.cc]
+version = "1.0"
+"#;
+        let violations = check_manifest(manifest).unwrap();
+        let summary: Vec<(usize, &str, &str)> = violations
+            .iter()
+            .map(|v| (v.line, v.name.as_str(), v.table.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (7, "anyhow", "dependencies"),
+                (10, "trybuild", "dev-dependencies"),
+                (13, "libc", "target.'cfg(unix)'.dependencies"),
+                (15, "cc", "build-dependencies"),
+            ]
+        );
+
+        assert!(
+            check_manifest("[dependencies]\nserde.workspace = true\n")
+                .unwrap()
+                .is_empty()
+        );
+        assert!(check_manifest("[dependencies\n").is_err());
+    }
+}
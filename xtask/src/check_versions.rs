@@ -0,0 +1,225 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use semver::Version;
+use semver::VersionReq;
+use toml_edit::Item;
+
+use super::config;
+use super::config::CONFIG_FILE;
+use super::history;
+use super::metadata;
+use super::workspace_dir;
+
+/// How the publishable members are versioned, as `[check-versions] policy` in `xtask.toml`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Policy {
+    /// Every publishable member shares the same version, released together.
+    Unified,
+    /// Each member is versioned on its own.
+    Independent,
+}
+
+/// A workspace member, as reported by `cargo metadata`.
+#[derive(Debug)]
+struct Member {
+    name: String,
+    /// The manifest, relative to the workspace.
+    manifest: String,
+    version: String,
+    rust_version: Option<String>,
+    publishable: bool,
+    /// The name and version requirement of each dependency on another member.
+    dependencies: Vec<(String, String)>,
+}
+
+/// Checks that the members are versioned consistently: every publishable member shares the same
+/// `version` unless `[check-versions] policy = "independent"` is set in `xtask.toml`, every
+/// member declares the same `rust-version`, and the version requirements of the dependencies
+/// between members match the versions of those members.
+pub fn check_versions() {
+    let doc = config::load_config();
+    let policy = doc
+        .get("check-versions")
+        .and_then(|check| check.get("policy"))
+        .and_then(Item::as_str)
+        .unwrap_or("unified");
+    let policy = match policy {
+        "unified" => Policy::Unified,
+        "independent" => Policy::Independent,
+        policy => history::fail(&format!(
+            "invalid {CONFIG_FILE}: unknown versioning policy '{policy}'"
+        )),
+    };
+
+    let metadata = metadata::cargo_metadata(true);
+    let packages = metadata::workspace_packages(&metadata);
+    let names: Vec<&str> = packages
+        .iter()
+        .filter_map(|package| package["name"].as_str())
+        .collect();
+    let members: Vec<Member> = packages
+        .iter()
+        .map(|package| {
+            let manifest = Path::new(package["manifest_path"].as_str().unwrap());
+            let manifest = manifest.strip_prefix(workspace_dir()).unwrap_or(manifest);
+            let dependencies = package["dependencies"].as_array().into_iter().flatten();
+            Member {
+                name: package["name"].as_str().unwrap().to_owned(),
+                manifest: manifest.to_string_lossy().replace('\\', "/"),
+                version: package["version"].as_str().unwrap().to_owned(),
+                rust_version: package["rust_version"].as_str().map(str::to_owned),
+                publishable: metadata::is_publishable(package),
+                dependencies: dependencies
+                    .filter(|dep| dep["path"].is_string())
+                    .filter(|dep| names.contains(&dep["name"].as_str().unwrap_or_default()))
+                    .map(|dep| {
+                        let name = dep["name"].as_str().unwrap().to_owned();
+                        (name, dep["req"].as_str().unwrap_or("*").to_owned())
+                    })
+                    .collect(),
+            }
+        })
+        .collect();
+
+    let problems = version_problems(&members, policy);
+    for problem in &problems {
+        println!("{problem}");
+    }
+    if !problems.is_empty() {
+        eprintln!("\nFound {} version problem(s).", problems.len());
+        history::exit(1);
+    }
+    println!("Versions are consistent.");
+}
+
+/// Returns the version inconsistencies between the `members`, each starting with the manifest
+/// to fix.
+fn version_problems(members: &[Member], policy: Policy) -> Vec<String> {
+    let mut problems = vec![];
+
+    if policy == Policy::Unified {
+        let publishable: Vec<&Member> = members.iter().filter(|m| m.publishable).collect();
+        let versions: Vec<Option<&str>> = publishable
+            .iter()
+            .map(|member| Some(member.version.as_str()))
+            .collect();
+        if let Some(Some(expected)) = most_common(&versions) {
+            for member in publishable.iter().filter(|m| m.version != expected) {
+                problems.push(format!(
+                    "{}: version {} of {} differs from {expected}, which the other publishable members share",
+                    member.manifest, member.version, member.name
+                ));
+            }
+        }
+    }
+
+    let rust_versions: Vec<Option<&str>> = members
+        .iter()
+        .map(|member| member.rust_version.as_deref())
+        .collect();
+    if let Some(expected) = most_common(&rust_versions) {
+        for member in members
+            .iter()
+            .filter(|m| m.rust_version.as_deref() != expected)
+        {
+            let actual = member.rust_version.as_deref().unwrap_or("none");
+            let expected = expected.unwrap_or("none");
+            problems.push(format!(
+                "{}: rust-version {actual} of {} differs from {expected}, which the other members declare",
+                member.manifest, member.name
+            ));
+        }
+    }
+
+    for member in members {
+        for (name, req) in &member.dependencies {
+            let Some(dependency) = members.iter().find(|m| &m.name == name) else {
+                continue;
+            };
+            let matches = match (VersionReq::parse(req), Version::parse(&dependency.version)) {
+                (Ok(req), Ok(version)) => req.matches(&version),
+                _ => false,
+            };
+            if !matches {
+                problems.push(format!(
+                    "{}: the requirement {req} on {name} does not match its version {}",
+                    member.manifest, dependency.version
+                ));
+            }
+        }
+    }
+
+    problems
+}
+
+/// Returns the most common of the `values`, the first one seen on a tie.
+fn most_common<'a>(values: &[Option<&'a str>]) -> Option<Option<&'a str>> {
+    let count = |value: &Option<&str>| values.iter().filter(|other| *other == value).count();
+    values.iter().copied().reduce(|best, value| {
+        if count(&value) > count(&best) {
+            value
+        } else {
+            best
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(name: &str, version: &str, rust_version: Option<&str>) -> Member {
+        Member {
+            name: name.to_owned(),
+            manifest: format!("{name}/Cargo.toml"),
+            version: version.to_owned(),
+            rust_version: rust_version.map(str::to_owned),
+            publishable: true,
+            dependencies: vec![],
+        }
+    }
+
+    #[test]
+    fn test_version_problems() {
+        let mut members = vec![
+            member("demo", "0.2.0", Some("1.85")),
+            member("demo-cli", "0.2.0", Some("1.85")),
+            member("demo-macros", "0.1.0", Some("1.88")),
+            Member {
+                publishable: false,
+                ..member("x", "0.0.0", Some("1.85"))
+            },
+        ];
+        members[1].dependencies = vec![
+            ("demo".to_owned(), "^0.2.0".to_owned()),
+            ("demo-macros".to_owned(), "^0.2.0".to_owned()),
+            ("x".to_owned(), "*".to_owned()),
+        ];
+        assert_eq!(
+            version_problems(&members, Policy::Unified),
+            [
+                "demo-macros/Cargo.toml: version 0.1.0 of demo-macros differs from 0.2.0, which the other publishable members share",
+                "demo-macros/Cargo.toml: rust-version 1.88 of demo-macros differs from 1.85, which the other members declare",
+                "demo-cli/Cargo.toml: the requirement ^0.2.0 on demo-macros does not match its version 0.1.0",
+            ]
+        );
+        assert_eq!(version_problems(&members, Policy::Independent).len(), 2);
+
+        members[2] = member("demo-macros", "0.2.0", Some("1.85"));
+        assert!(version_problems(&members, Policy::Unified).is_empty());
+    }
+}
@@ -0,0 +1,59 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use globset::Glob;
+use globset::GlobSet;
+use globset::GlobSetBuilder;
+use toml_edit::DocumentMut;
+use toml_edit::Item;
+
+use super::history;
+use super::workspace_dir;
+
+/// The xtask configuration file, relative to the workspace.
+pub const CONFIG_FILE: &str = "xtask.toml";
+
+/// Loads `xtask.toml`, or an empty document if the file does not exist.
+pub fn load_config() -> DocumentMut {
+    let file = workspace_dir().join(CONFIG_FILE);
+    if !file.exists() {
+        return DocumentMut::new();
+    }
+    let content = std::fs::read_to_string(&file).unwrap();
+    content
+        .parse()
+        .unwrap_or_else(|err| history::fail(&format!("invalid {CONFIG_FILE}: {err}")))
+}
+
+/// Reads an optional array of strings, where `context` names the value in error messages.
+pub fn string_array(item: Option<&Item>, context: &str) -> Result<Vec<String>, String> {
+    let Some(item) = item else {
+        return Ok(vec![]);
+    };
+    let error = || format!("{context} must be an array of strings");
+    let array = item.as_array().ok_or_else(error)?;
+    array
+        .iter()
+        .map(|value| value.as_str().map(str::to_owned).ok_or_else(error))
+        .collect()
+}
+
+/// Compiles glob patterns, e.g., `**/*.rs`, into a set matching any of them.
+pub fn glob_set(patterns: &[String]) -> Result<GlobSet, String> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(Glob::new(pattern).map_err(|err| err.to_string())?);
+    }
+    builder.build().map_err(|err| err.to_string())
+}
@@ -0,0 +1,148 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Write;
+use std::process::Stdio;
+
+use super::find_command;
+use super::history;
+use super::toolchain;
+use super::workspace_dir;
+
+const RUSTFMT_CONFIGS: &[&str] = &["rustfmt.toml", ".rustfmt.toml"];
+const CLIPPY_CONFIGS: &[&str] = &["clippy.toml", ".clippy.toml"];
+
+/// Checks that every key in the rustfmt and clippy configs is recognized by the toolchain pinned
+/// in `rust-toolchain.toml`, and exits non-zero otherwise.
+///
+/// Nightly-only rustfmt options are reported on a stable toolchain since they are silently
+/// ignored there, and fail the check with `deny_nightly`.
+pub fn check_tool_configs(deny_nightly: bool) {
+    let channel = toolchain::pinned_channel().unwrap_or("stable".to_owned());
+    println!("Checking tool configs against the '{channel}' toolchain...");
+    let mut errors = 0;
+
+    for file in RUSTFMT_CONFIGS {
+        if !workspace_dir().join(file).exists() {
+            continue;
+        }
+        let (unknown, nightly_only) = check_rustfmt_config(&channel, file);
+        for key in unknown {
+            println!("{file}: unknown option `{key}`");
+            errors += 1;
+        }
+        for key in nightly_only {
+            println!("{file}: `{key}` is nightly-only and ignored by the '{channel}' toolchain");
+            if deny_nightly {
+                errors += 1;
+            }
+        }
+    }
+
+    for file in CLIPPY_CONFIGS {
+        if !workspace_dir().join(file).exists() {
+            continue;
+        }
+        if let Err(message) = check_clippy_config(&channel) {
+            println!("{file}: {message}");
+            errors += 1;
+        }
+    }
+
+    if errors > 0 {
+        eprintln!("\nFound {errors} tool config problem(s).");
+        history::exit(1);
+    }
+    println!("Tool configs are valid.");
+}
+
+/// Formats an empty input with the config and collects the options rustfmt warns about.
+fn check_rustfmt_config(channel: &str, file: &str) -> (Vec<String>, Vec<String>) {
+    let mut cmd = find_command("rustfmt");
+    cmd.arg(format!("+{channel}"))
+        .args(["--emit", "stdout", "--config-path", file])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped());
+    let mut child = cmd.spawn().expect("failed to execute rustfmt");
+    child.stdin.take().unwrap().write_all(b"").unwrap();
+    let output = child.wait_with_output().unwrap();
+    parse_rustfmt_warnings(&String::from_utf8_lossy(&output.stderr))
+}
+
+fn parse_rustfmt_warnings(stderr: &str) -> (Vec<String>, Vec<String>) {
+    let mut unknown = vec![];
+    let mut nightly_only = vec![];
+    for line in stderr.lines() {
+        let key = || line.split('`').nth(1).unwrap_or_default().to_owned();
+        if line.contains("Unknown configuration option") {
+            unknown.push(key());
+        } else if line.contains("unstable features are only available in nightly channel") {
+            let key = key();
+            let name = key.split(" = ").next().unwrap_or_default().to_owned();
+            nightly_only.push(name);
+        }
+    }
+    (unknown, nightly_only)
+}
+
+/// Compiles an empty crate with clippy so that it loads and validates the workspace config.
+fn check_clippy_config(channel: &str) -> Result<(), String> {
+    let dir = workspace_dir().join("target/xtask/config-check");
+    std::fs::create_dir_all(&dir).unwrap();
+    let source = dir.join("empty.rs");
+    std::fs::write(&source, "").unwrap();
+
+    let mut cmd = find_command("rustup");
+    cmd.args([
+        "run",
+        channel,
+        "clippy-driver",
+        "--crate-type",
+        "lib",
+        "--emit=metadata",
+    ])
+    .arg("--out-dir")
+    .arg(&dir)
+    .arg(&source)
+    .env("CLIPPY_CONF_DIR", workspace_dir());
+    let output = cmd.output().expect("failed to execute clippy-driver");
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rustfmt_warnings() {
+        let stderr = "\
+Warning: Unknown configuration option `bogus_key`
+Warning: can't set `wrap_comments = true`, unstable features are only available in nightly channel.
+Warning: can't set `group_imports = StdExternalCrate`, unstable features are only available in nightly channel.
+";
+        assert_eq!(
+            parse_rustfmt_warnings(stderr),
+            (
+                vec!["bogus_key".to_owned()],
+                vec!["wrap_comments".to_owned(), "group_imports".to_owned()]
+            )
+        );
+        assert_eq!(parse_rustfmt_warnings(""), (vec![], vec![]));
+    }
+}
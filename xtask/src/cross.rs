@@ -0,0 +1,166 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::Features;
+use super::add_build_args;
+use super::config;
+use super::config::CONFIG_FILE;
+use super::ensure_installed;
+use super::find_cargo;
+use super::find_command;
+use super::history;
+use super::make_target_add_cmd;
+use super::run_command;
+
+/// Builds the workspace tests for each foreign target and, with `run`, executes them emulated.
+///
+/// Tests run under `qemu-<arch>` by default, or inside the `cross` containers with `use_cross`.
+/// Tests listed under `[cross.<target>] skip` in `xtask.toml` are skipped when running.
+pub fn cross_test(targets: &[String], run: bool, use_cross: bool) {
+    for target in targets {
+        let skip = load_skip_list(target);
+        let mut cmd = if use_cross {
+            ensure_installed("cross", "cross");
+            find_command("cross")
+        } else {
+            run_command(make_target_add_cmd(target));
+
+            let mut cmd = find_cargo();
+            if run {
+                configure_qemu(&mut cmd, target);
+            }
+            cmd
+        };
+        cmd.args(["test", "--workspace", "--target", target]);
+        if run {
+            if !skip.is_empty() {
+                cmd.arg("--");
+                for name in &skip {
+                    cmd.args(["--skip", name]);
+                }
+            }
+        } else {
+            cmd.arg("--no-run");
+        }
+        run_command(cmd);
+    }
+}
+
+/// Builds the workspace like `cargo x build` for each target, adding the target to the toolchain
+/// first when missing.
+///
+/// With `use_zigbuild`, `cargo zigbuild` links with zig instead of the system linker, and with
+/// `use_cross`, the build runs inside the `cross` containers, which bring their own toolchain.
+pub fn cross_build(targets: &[String], locked: bool, use_zigbuild: bool, use_cross: bool) {
+    for target in targets {
+        let mut cmd = if use_cross {
+            ensure_installed("cross", "cross");
+            let mut cmd = find_command("cross");
+            cmd.arg("build");
+            cmd
+        } else {
+            run_command(make_target_add_cmd(target));
+            let mut cmd = find_cargo();
+            if use_zigbuild {
+                ensure_installed("cargo-zigbuild", "cargo-zigbuild");
+                which::which("zig")
+                    .unwrap_or_else(|err| history::fail(&format!("zig not found: {err}")));
+                cmd.arg("zigbuild");
+            } else {
+                cmd.arg("build");
+            }
+            cmd
+        };
+        add_build_args(&mut cmd, locked, Features::All);
+        cmd.args(["--target", target]);
+        run_command(cmd);
+    }
+}
+
+fn load_skip_list(target: &str) -> Vec<String> {
+    let doc = config::load_config();
+    let item = doc
+        .get("cross")
+        .and_then(|cross| cross.get(target))
+        .and_then(|target| target.get("skip"));
+    config::string_array(item, &format!("'cross.{target}.skip'"))
+        .unwrap_or_else(|err| history::fail(&format!("invalid {CONFIG_FILE}: {err}")))
+}
+
+/// Points cargo at `qemu-<arch>` as the test runner and at the GNU cross linker, unless the
+/// corresponding `CARGO_TARGET_<TRIPLE>_*` variables are already set.
+fn configure_qemu(cmd: &mut std::process::Command, target: &str) {
+    let prefix = gnu_prefix(target);
+    let var = target_env_var(target);
+
+    let runner = format!("{var}_RUNNER");
+    if std::env::var_os(&runner).is_none() {
+        let qemu = format!("qemu-{}", qemu_arch(target));
+        which::which(&qemu)
+            .unwrap_or_else(|err| history::fail(&format!("{qemu} not found: {err}")));
+        cmd.env(runner, format!("{qemu} -L /usr/{prefix}"));
+    }
+
+    let linker = format!("{var}_LINKER");
+    let gcc = format!("{prefix}-gcc");
+    if std::env::var_os(&linker).is_none() && which::which(&gcc).is_ok() {
+        cmd.env(linker, gcc);
+    }
+}
+
+/// Returns the architecture name used by qemu-user for the target.
+fn qemu_arch(target: &str) -> &str {
+    match target.split('-').next().unwrap_or(target) {
+        "riscv64gc" => "riscv64",
+        "i586" | "i686" => "i386",
+        "powerpc64le" => "ppc64le",
+        "powerpc64" => "ppc64",
+        arch if arch.starts_with("armv7") || arch == "arm" => "arm",
+        arch => arch,
+    }
+}
+
+/// Returns the GNU toolchain prefix for the target, e.g., `aarch64-linux-gnu`.
+fn gnu_prefix(target: &str) -> String {
+    let abi = target.rsplit('-').next().unwrap_or("gnu");
+    format!("{}-linux-{abi}", qemu_arch(target))
+}
+
+fn target_env_var(target: &str) -> String {
+    format!(
+        "CARGO_TARGET_{}",
+        target.to_uppercase().replace(['-', '.'], "_")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_names() {
+        assert_eq!(qemu_arch("aarch64-unknown-linux-gnu"), "aarch64");
+        assert_eq!(qemu_arch("riscv64gc-unknown-linux-gnu"), "riscv64");
+        assert_eq!(qemu_arch("armv7-unknown-linux-gnueabihf"), "arm");
+        assert_eq!(gnu_prefix("aarch64-unknown-linux-gnu"), "aarch64-linux-gnu");
+        assert_eq!(
+            gnu_prefix("armv7-unknown-linux-gnueabihf"),
+            "arm-linux-gnueabihf"
+        );
+        assert_eq!(
+            target_env_var("riscv64gc-unknown-linux-gnu"),
+            "CARGO_TARGET_RISCV64GC_UNKNOWN_LINUX_GNU"
+        );
+    }
+}
@@ -0,0 +1,303 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Write;
+use std::net::TcpListener;
+use std::net::TcpStream;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command as StdCommand;
+use std::sync::Arc;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Duration;
+use std::time::SystemTime;
+
+use serde_json::Value;
+
+use super::find_cargo;
+use super::metadata;
+use super::run_command;
+
+/// The path polled by served pages; it returns the number of completed builds.
+const RELOAD_PATH: &str = "/__xtask_reload";
+
+/// How often sources are checked for changes and pages poll for a rebuild.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Injected into every served HTML page to reload it after a rebuild.
+const RELOAD_SCRIPT: &str = r#"<script>
+(() => {
+  let generation;
+  setInterval(async () => {
+    try {
+      const current = await (await fetch("/__xtask_reload")).text();
+      if (generation === undefined) generation = current;
+      else if (current !== generation) location.reload();
+    } catch (_) {}
+  }, 500);
+})();
+</script>"#;
+
+/// Builds the workspace documentation, and with `serve` serves it on `port` of localhost.
+///
+/// While serving, the members' sources are watched: every change triggers a rebuild, after
+/// which open pages reload themselves.
+pub fn doc(serve: bool, port: u16) {
+    if !serve {
+        run_command(make_doc_cmd());
+        return;
+    }
+
+    let metadata = metadata::cargo_metadata(true);
+    let doc_dir = Path::new(metadata["target_directory"].as_str().unwrap()).join("doc");
+    let sources: Vec<PathBuf> = metadata::workspace_packages(&metadata)
+        .iter()
+        .map(|p| {
+            Path::new(p["manifest_path"].as_str().unwrap())
+                .parent()
+                .unwrap()
+                .to_owned()
+        })
+        .collect();
+    let index = first_crate(&metadata).map(|name| format!("/{name}/index.html"));
+
+    let generation = Arc::new(AtomicU64::new(0));
+    rebuild(&generation);
+    {
+        let generation = generation.clone();
+        std::thread::spawn(move || watch(&sources, &generation));
+    }
+
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .unwrap_or_else(|err| panic!("failed to listen on port {port}: {err}"));
+    println!("Serving docs at http://127.0.0.1:{port}/; press Ctrl-C to stop.");
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else {
+            continue;
+        };
+        let doc_dir = doc_dir.clone();
+        let index = index.clone();
+        let generation = generation.clone();
+        std::thread::spawn(move || {
+            // a client closing the connection early is not worth reporting
+            let _ = handle(stream, &doc_dir, index.as_deref(), &generation);
+        });
+    }
+}
+
+fn make_doc_cmd() -> StdCommand {
+    let mut cmd = find_cargo();
+    cmd.args(["doc", "--workspace", "--all-features", "--no-deps"]);
+    cmd
+}
+
+/// Rebuilds the docs, keeping the previous output if the build fails.
+fn rebuild(generation: &AtomicU64) {
+    let mut cmd = make_doc_cmd();
+    println!("{cmd:?}");
+    match cmd.status() {
+        Ok(status) if status.success() => {
+            generation.fetch_add(1, Ordering::SeqCst);
+        }
+        Ok(status) => eprintln!("cargo doc failed: {status}; waiting for changes"),
+        Err(err) => eprintln!("failed to execute cargo doc: {err}"),
+    }
+}
+
+/// Polls the source directories and rebuilds whenever a file changed.
+fn watch(sources: &[PathBuf], generation: &AtomicU64) {
+    let mut last = latest_modification(sources);
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+        let current = latest_modification(sources);
+        if current != last {
+            last = current;
+            rebuild(generation);
+        }
+    }
+}
+
+fn latest_modification(dirs: &[PathBuf]) -> Option<SystemTime> {
+    fn visit(dir: &Path, latest: &mut Option<SystemTime>) {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let Ok(file_type) = entry.file_type() else {
+                continue;
+            };
+            if file_type.is_dir() {
+                if entry.file_name() != "target" {
+                    visit(&entry.path(), latest);
+                }
+            } else if let Ok(modified) = entry.metadata().and_then(|m| m.modified()) {
+                *latest = (*latest).max(Some(modified));
+            }
+        }
+    }
+
+    let mut latest = None;
+    for dir in dirs {
+        visit(dir, &mut latest);
+    }
+    latest
+}
+
+/// Returns the doc directory name of the first member with a library target.
+fn first_crate(metadata: &Value) -> Option<String> {
+    metadata::workspace_packages(metadata)
+        .iter()
+        .flat_map(|p| p["targets"].as_array().unwrap())
+        .find(|t| t["kind"].as_array().unwrap().iter().any(|k| k == "lib"))
+        .map(|t| t["name"].as_str().unwrap().replace('-', "_"))
+}
+
+fn handle(
+    mut stream: TcpStream,
+    doc_dir: &Path,
+    index: Option<&str>,
+    generation: &AtomicU64,
+) -> std::io::Result<()> {
+    let mut request_line = String::new();
+    BufReader::new(&stream).read_line(&mut request_line)?;
+    let path = request_line.split_whitespace().nth(1).unwrap_or("/");
+    let path = path.split(['?', '#']).next().unwrap();
+
+    if path == RELOAD_PATH {
+        let body = generation.load(Ordering::SeqCst).to_string();
+        return respond(&mut stream, "200 OK", "text/plain", body.as_bytes());
+    }
+    if path == "/"
+        && let Some(index) = index
+    {
+        let header =
+            format!("HTTP/1.1 302 Found\r\nLocation: {index}\r\nContent-Length: 0\r\n\r\n");
+        return stream.write_all(header.as_bytes());
+    }
+
+    let Some(mut file) = resolve_path(doc_dir, path) else {
+        return respond(&mut stream, "400 Bad Request", "text/plain", b"bad request");
+    };
+    if file.is_dir() {
+        file.push("index.html");
+    }
+    match std::fs::read(&file) {
+        Ok(body) => {
+            let content_type = content_type(&file);
+            if content_type.starts_with("text/html") {
+                let html = inject_reload_script(&String::from_utf8_lossy(&body));
+                respond(&mut stream, "200 OK", content_type, html.as_bytes())
+            } else {
+                respond(&mut stream, "200 OK", content_type, &body)
+            }
+        }
+        Err(_) => respond(&mut stream, "404 Not Found", "text/plain", b"not found"),
+    }
+}
+
+fn respond(
+    stream: &mut TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &[u8],
+) -> std::io::Result<()> {
+    let header = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n",
+        body.len()
+    );
+    stream.write_all(header.as_bytes())?;
+    stream.write_all(body)
+}
+
+/// Maps a request path below `root`, rejecting paths that would escape it.
+fn resolve_path(root: &Path, path: &str) -> Option<PathBuf> {
+    let path = decode_percent(path)?;
+    let relative = Path::new(path.trim_start_matches('/'));
+    relative
+        .components()
+        .all(|c| matches!(c, Component::Normal(_) | Component::CurDir))
+        .then(|| root.join(relative))
+}
+
+fn decode_percent(path: &str) -> Option<String> {
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = std::str::from_utf8(bytes.get(i + 1..i + 3)?).ok()?;
+            decoded.push(u8::from_str_radix(hex, 16).ok()?);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).ok()
+}
+
+fn content_type(file: &Path) -> &'static str {
+    match file.extension().and_then(|e| e.to_str()) {
+        Some("html") => "text/html; charset=utf-8",
+        Some("css") => "text/css",
+        Some("js") => "text/javascript",
+        Some("json") => "application/json",
+        Some("svg") => "image/svg+xml",
+        Some("png") => "image/png",
+        Some("ico") => "image/x-icon",
+        Some("woff2") => "font/woff2",
+        Some("txt") | Some("md") => "text/plain; charset=utf-8",
+        _ => "application/octet-stream",
+    }
+}
+
+fn inject_reload_script(html: &str) -> String {
+    match html.rfind("</body>") {
+        Some(end) => format!("{}{RELOAD_SCRIPT}{}", &html[..end], &html[end..]),
+        None => format!("{html}{RELOAD_SCRIPT}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_path() {
+        let root = Path::new("/srv/doc");
+        assert_eq!(
+            resolve_path(root, "/template/index.html"),
+            Some(root.join("template/index.html"))
+        );
+        assert_eq!(
+            resolve_path(root, "/static.files/a%20b.css"),
+            Some(root.join("static.files/a b.css"))
+        );
+        assert_eq!(resolve_path(root, "/../secret"), None);
+        assert_eq!(resolve_path(root, "/%2e%2e/secret"), None);
+        assert_eq!(resolve_path(root, "/bad%zz"), None);
+    }
+
+    #[test]
+    fn test_inject_reload_script() {
+        let html = inject_reload_script("<html><body><p>docs</p></body></html>");
+        assert!(html.starts_with("<html><body><p>docs</p><script>"));
+        assert!(html.ends_with("</script></body></html>"));
+        assert!(html.contains(RELOAD_PATH));
+    }
+}
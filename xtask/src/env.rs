@@ -0,0 +1,127 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+use super::history;
+use super::tasks;
+use super::workspace_dir;
+
+/// Dotenv files loaded from the workspace root, in increasing order of precedence.
+const DOTENV_FILES: &[&str] = &[".env", ".env.local"];
+
+/// Returns the variables loaded from the dotenv files that are applied to every spawned command.
+///
+/// Variables already set in the process environment take precedence over the dotenv files.
+pub fn dotenv_vars() -> &'static BTreeMap<String, String> {
+    static VARS: OnceLock<BTreeMap<String, String>> = OnceLock::new();
+    VARS.get_or_init(|| {
+        let mut vars = BTreeMap::new();
+        for file in DOTENV_FILES {
+            let path = workspace_dir().join(file);
+            if !path.exists() {
+                continue;
+            }
+            let content = std::fs::read_to_string(&path).unwrap();
+            let parsed = parse_dotenv(&content)
+                .unwrap_or_else(|err| history::fail(&format!("invalid {file}: {err}")));
+            vars.extend(parsed);
+        }
+        vars.retain(|key, _| std::env::var_os(key).is_none());
+        vars
+    })
+}
+
+/// Prints the variables xtask adds to the environment of spawned commands.
+///
+/// When `task` is given, its `env` table from `xtask.toml` is merged on top.
+pub fn print_env(task: Option<&str>) {
+    let mut vars = dotenv_vars().clone();
+    if let Some(name) = task {
+        let tasks = tasks::load_tasks();
+        let task = tasks.get(name).unwrap_or_else(|| {
+            history::fail(&format!("task '{name}' is not defined in xtask.toml"))
+        });
+        vars.extend(task.env.clone());
+    }
+    for (key, value) in vars {
+        println!("{key}={value}");
+    }
+}
+
+/// Parses the `KEY=VALUE` lines of a dotenv file.
+///
+/// Blank lines and `#` comments are skipped, an optional `export ` prefix is allowed, and values
+/// may be wrapped in single or double quotes.
+fn parse_dotenv(content: &str) -> Result<Vec<(String, String)>, String> {
+    let mut vars = vec![];
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(format!("line {}: expected KEY=VALUE", index + 1));
+        };
+        let key = key.trim();
+        if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(format!("line {}: invalid variable name '{key}'", index + 1));
+        }
+        let value = value.trim();
+        let value = match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => value
+                .strip_prefix(quote)
+                .and_then(|v| v.strip_suffix(quote))
+                .ok_or(format!("line {}: unterminated quoted value", index + 1))?,
+            _ => value.split(" #").next().unwrap_or_default().trim_end(),
+        };
+        vars.push((key.to_owned(), value.to_owned()));
+    }
+    Ok(vars)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dotenv() {
+        let content = r#"
+# local services
+DATABASE_URL=postgres://localhost/db
+export RUST_LOG = debug
+GREETING="hello # world"
+SINGLE='quoted'
+EMPTY=
+TRAILING=value # comment
+"#;
+        assert_eq!(
+            parse_dotenv(content),
+            Ok(vec![
+                ("DATABASE_URL".into(), "postgres://localhost/db".into()),
+                ("RUST_LOG".into(), "debug".into()),
+                ("GREETING".into(), "hello # world".into()),
+                ("SINGLE".into(), "quoted".into()),
+                ("EMPTY".into(), "".into()),
+                ("TRAILING".into(), "value".into()),
+            ])
+        );
+
+        assert!(parse_dotenv("NO_VALUE").is_err());
+        assert!(parse_dotenv("BAD-NAME=1").is_err());
+        assert!(parse_dotenv("QUOTE=\"open").is_err());
+    }
+}
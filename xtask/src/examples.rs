@@ -0,0 +1,174 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::Command as StdCommand;
+use std::time::Duration;
+use std::time::Instant;
+
+use toml_edit::DocumentMut;
+
+use super::config;
+use super::config::CONFIG_FILE;
+use super::find_cargo;
+use super::history;
+use super::metadata;
+use super::run_command;
+use super::workspace_dir;
+
+/// Seconds an example may run before it is killed, unless configured otherwise.
+const DEFAULT_TIMEOUT_SECS: u64 = 60;
+
+/// The `[examples]` table of `xtask.toml`.
+#[derive(Debug, PartialEq)]
+struct ExamplesConfig {
+    timeout: Duration,
+    exit_codes: BTreeMap<String, i32>,
+}
+
+/// Builds and runs every example of the workspace members, or only those in `names`.
+///
+/// Each example must exit within the timeout with its expected exit code, which is `0` unless
+/// configured under `[examples.exit-codes]` in `xtask.toml`.
+pub fn run_examples(names: &[String], timeout: Option<u64>) {
+    let mut config = parse_config(&config::load_config())
+        .unwrap_or_else(|err| history::fail(&format!("invalid {CONFIG_FILE}: {err}")));
+    if let Some(timeout) = timeout {
+        config.timeout = Duration::from_secs(timeout);
+    }
+
+    let metadata = metadata::cargo_metadata(true);
+    let mut examples = vec![];
+    for package in metadata::workspace_packages(&metadata) {
+        for target in package["targets"].as_array().unwrap() {
+            let is_example = target["kind"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|k| k == "example");
+            let name = target["name"].as_str().unwrap();
+            if is_example && (names.is_empty() || names.iter().any(|n| n == name)) {
+                examples.push(name.to_owned());
+            }
+        }
+    }
+    if let Some(missing) = names.iter().find(|name| !examples.contains(name)) {
+        history::fail(&format!("example '{missing}' not found in the workspace"));
+    }
+    if examples.is_empty() {
+        println!("No examples found.");
+        return;
+    }
+
+    let mut cmd = find_cargo();
+    cmd.args(["build", "--workspace", "--all-features", "--examples"]);
+    run_command(cmd);
+
+    let examples_dir =
+        Path::new(metadata["target_directory"].as_str().unwrap()).join("debug/examples");
+    let mut failures = vec![];
+    for name in &examples {
+        let expected = config.exit_codes.get(name).copied().unwrap_or(0);
+        let exe = examples_dir.join(format!("{name}{}", std::env::consts::EXE_SUFFIX));
+        let mut cmd = StdCommand::new(exe);
+        cmd.current_dir(workspace_dir());
+        println!("Running example '{name}'...");
+        match run_with_timeout(cmd, config.timeout) {
+            Some(code) if code == expected => println!("Example '{name}' exited with {code}."),
+            Some(code) => {
+                failures.push(format!("'{name}' exited with {code}, expected {expected}"))
+            }
+            None => failures.push(format!("'{name}' timed out after {:?}", config.timeout)),
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "examples failed:\n{}",
+        failures.join("\n")
+    );
+    println!("All {} example(s) passed.", examples.len());
+}
+
+/// Runs the command and returns its exit code, or `None` if it was killed after the timeout.
+fn run_with_timeout(mut cmd: StdCommand, timeout: Duration) -> Option<i32> {
+    let mut child = cmd.spawn().expect("failed to execute process");
+    let start = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait().unwrap() {
+            // a process terminated by a signal has no exit code
+            return Some(status.code().unwrap_or(-1));
+        }
+        if start.elapsed() >= timeout {
+            child.kill().unwrap();
+            child.wait().unwrap();
+            return None;
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
+
+fn parse_config(doc: &DocumentMut) -> Result<ExamplesConfig, String> {
+    let mut config = ExamplesConfig {
+        timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+        exit_codes: BTreeMap::new(),
+    };
+    let Some(examples) = doc.get("examples") else {
+        return Ok(config);
+    };
+    if let Some(timeout) = examples.get("timeout") {
+        let timeout = timeout
+            .as_integer()
+            .filter(|t| *t > 0)
+            .ok_or("'examples.timeout' must be a positive integer of seconds")?;
+        config.timeout = Duration::from_secs(timeout as u64);
+    }
+    if let Some(exit_codes) = examples.get("exit-codes") {
+        let error = || "'examples.exit-codes' must be a table of integers".to_owned();
+        let exit_codes = exit_codes.as_table_like().ok_or_else(error)?;
+        for (name, code) in exit_codes.iter() {
+            let code = code.as_integer().ok_or_else(error)?;
+            config.exit_codes.insert(name.to_owned(), code as i32);
+        }
+    }
+    Ok(config)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let parse = |content: &str| parse_config(&content.parse().unwrap());
+
+        assert_eq!(
+            parse(""),
+            Ok(ExamplesConfig {
+                timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECS),
+                exit_codes: BTreeMap::new(),
+            })
+        );
+        assert_eq!(
+            parse("[examples]\ntimeout = 5\n[examples.exit-codes]\nusage = 2\n"),
+            Ok(ExamplesConfig {
+                timeout: Duration::from_secs(5),
+                exit_codes: BTreeMap::from([("usage".to_owned(), 2)]),
+            })
+        );
+        assert!(parse("[examples]\ntimeout = 0\n").is_err());
+        assert!(parse("[examples.exit-codes]\nusage = \"2\"\n").is_err());
+    }
+}
@@ -0,0 +1,312 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+use super::history;
+use super::metadata;
+
+/// The kind of a dependency, as the table declaring it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum DepKind {
+    Normal,
+    Dev,
+    Build,
+}
+
+impl DepKind {
+    /// Returns the label of the edge, none for a normal dependency.
+    fn label(self) -> Option<&'static str> {
+        match self {
+            DepKind::Normal => None,
+            DepKind::Dev => Some("dev"),
+            DepKind::Build => Some("build"),
+        }
+    }
+}
+
+/// The dependency graph of the workspace members.
+#[derive(Debug, Default)]
+struct Graph {
+    members: Vec<String>,
+    /// The external crates the members depend on directly, if requested.
+    externals: BTreeSet<String>,
+    /// The dependencies as `(dependent, dependency, kind)`.
+    edges: BTreeSet<(String, String, DepKind)>,
+}
+
+/// Prints the dependency graph of the workspace members in the DOT or mermaid `format`, with the
+/// external crates they depend on directly if `external` is set.
+///
+/// Dev and build dependencies are drawn as dashed edges. The command fails if members depend on
+/// each other in a cycle, and reports the cycles only closed by dev-dependencies, which Cargo
+/// accepts but which entangle the members when published.
+pub fn graph(format: &str, external: bool) {
+    let metadata = metadata::cargo_metadata(true);
+    let packages = metadata::workspace_packages(&metadata);
+    let mut graph = Graph {
+        members: packages
+            .iter()
+            .map(|package| package["name"].as_str().unwrap().to_owned())
+            .collect(),
+        ..Graph::default()
+    };
+    for package in &packages {
+        let name = package["name"].as_str().unwrap();
+        for dep in package["dependencies"].as_array().into_iter().flatten() {
+            let dep_name = dep["name"].as_str().unwrap().to_owned();
+            let is_member = dep["path"].is_string() && graph.members.contains(&dep_name);
+            if !is_member {
+                if !external {
+                    continue;
+                }
+                graph.externals.insert(dep_name.clone());
+            }
+            let kind = match dep["kind"].as_str() {
+                Some("dev") => DepKind::Dev,
+                Some("build") => DepKind::Build,
+                _ => DepKind::Normal,
+            };
+            graph.edges.insert((name.to_owned(), dep_name, kind));
+        }
+    }
+
+    match format {
+        "mermaid" => print!("{}", render_mermaid(&graph)),
+        _ => print!("{}", render_dot(&graph)),
+    }
+
+    let cycles = find_cycles(&graph, false);
+    for cycle in find_cycles(&graph, true) {
+        if !cycles.iter().any(|other| same_cycle(other, &cycle)) {
+            eprintln!(
+                "note: {} is a cycle closed by dev-dependencies",
+                cycle.join(" -> ")
+            );
+        }
+    }
+    for cycle in &cycles {
+        eprintln!(
+            "error: the members depend on each other in a cycle: {}",
+            cycle.join(" -> ")
+        );
+    }
+    if !cycles.is_empty() {
+        history::exit(1);
+    }
+}
+
+fn render_dot(graph: &Graph) -> String {
+    let mut out = String::from("digraph workspace {\n    node [shape=box];\n");
+    for member in &graph.members {
+        writeln!(out, "    \"{member}\";").unwrap();
+    }
+    for external in &graph.externals {
+        writeln!(out, "    \"{external}\" [shape=ellipse, color=gray];").unwrap();
+    }
+    for (from, to, kind) in &graph.edges {
+        match kind.label() {
+            None => writeln!(out, "    \"{from}\" -> \"{to}\";").unwrap(),
+            Some(label) => writeln!(
+                out,
+                "    \"{from}\" -> \"{to}\" [style=dashed, label=\"{label}\"];"
+            )
+            .unwrap(),
+        }
+    }
+    out.push_str("}\n");
+    out
+}
+
+fn render_mermaid(graph: &Graph) -> String {
+    // mermaid ids cannot contain every character of a crate name, so the nodes are numbered
+    let nodes: BTreeMap<&str, usize> = graph
+        .members
+        .iter()
+        .chain(&graph.externals)
+        .enumerate()
+        .map(|(index, name)| (name.as_str(), index))
+        .collect();
+    let mut out = String::from("flowchart LR\n");
+    for member in &graph.members {
+        writeln!(out, "    n{}[\"{member}\"]", nodes[member.as_str()]).unwrap();
+    }
+    for external in &graph.externals {
+        writeln!(out, "    n{}(\"{external}\")", nodes[external.as_str()]).unwrap();
+    }
+    for (from, to, kind) in &graph.edges {
+        let (from, to) = (nodes[from.as_str()], nodes[to.as_str()]);
+        match kind.label() {
+            None => writeln!(out, "    n{from} --> n{to}").unwrap(),
+            Some(label) => writeln!(out, "    n{from} -.->|{label}| n{to}").unwrap(),
+        }
+    }
+    out
+}
+
+/// Returns one cycle, e.g., `[a, b, a]`, through each group of members depending on each other,
+/// following the dev-dependencies too if `dev` is set.
+fn find_cycles(graph: &Graph, dev: bool) -> Vec<Vec<String>> {
+    let mut adjacency: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (from, to, kind) in &graph.edges {
+        if graph.members.contains(to) && (dev || *kind != DepKind::Dev) {
+            adjacency.entry(from).or_default().push(to);
+        }
+    }
+    let reachable = |start: &str| {
+        let mut seen = BTreeSet::new();
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            for next in adjacency.get(node).into_iter().flatten() {
+                if seen.insert(*next) {
+                    stack.push(next);
+                }
+            }
+        }
+        seen
+    };
+
+    let mut members: Vec<&str> = graph.members.iter().map(String::as_str).collect();
+    members.sort();
+    let reach: BTreeMap<&str, BTreeSet<&str>> = members
+        .iter()
+        .map(|member| (*member, reachable(member)))
+        .collect();
+    let mut visited = BTreeSet::new();
+    let mut cycles = vec![];
+    for start in &members {
+        if visited.contains(start) || !reach[start].contains(start) {
+            continue;
+        }
+        // the members on a cycle through `start`, found as its shortest path back to itself
+        let component: BTreeSet<&str> = members
+            .iter()
+            .copied()
+            .filter(|member| reach[start].contains(member) && reach[member].contains(start))
+            .collect();
+        visited.extend(component.iter().copied());
+        let mut parents: BTreeMap<&str, &str> = BTreeMap::new();
+        let mut queue = VecDeque::from([*start]);
+        'search: while let Some(node) = queue.pop_front() {
+            for next in adjacency.get(node).into_iter().flatten() {
+                if *next == *start {
+                    let mut cycle = vec![*start, node];
+                    let mut current = node;
+                    while current != *start {
+                        current = parents[current];
+                        cycle.push(current);
+                    }
+                    cycle.reverse();
+                    cycles.push(cycle.into_iter().map(str::to_owned).collect());
+                    break 'search;
+                }
+                if component.contains(next) && !parents.contains_key(next) {
+                    parents.insert(next, node);
+                    queue.push_back(next);
+                }
+            }
+        }
+    }
+    cycles
+}
+
+/// Returns whether two cycles go through the same members.
+fn same_cycle(a: &[String], b: &[String]) -> bool {
+    let members = |cycle: &[String]| cycle.iter().cloned().collect::<BTreeSet<_>>();
+    members(a) == members(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(edges: &[(&str, &str, DepKind)]) -> Graph {
+        Graph {
+            members: ["demo", "demo-cli", "demo-macros"]
+                .map(str::to_owned)
+                .to_vec(),
+            externals: BTreeSet::from(["syn".to_owned()]),
+            edges: edges
+                .iter()
+                .map(|(from, to, kind)| (from.to_string(), to.to_string(), *kind))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        let graph = graph(&[
+            ("demo", "demo-macros", DepKind::Normal),
+            ("demo-cli", "demo", DepKind::Normal),
+            ("demo-macros", "demo", DepKind::Dev),
+            ("demo-macros", "syn", DepKind::Normal),
+        ]);
+        assert_eq!(
+            render_dot(&graph),
+            r#"digraph workspace {
+    node [shape=box];
+    "demo";
+    "demo-cli";
+    "demo-macros";
+    "syn" [shape=ellipse, color=gray];
+    "demo" -> "demo-macros";
+    "demo-cli" -> "demo";
+    "demo-macros" -> "demo" [style=dashed, label="dev"];
+    "demo-macros" -> "syn";
+}
+"#
+        );
+        assert_eq!(
+            render_mermaid(&graph),
+            r#"flowchart LR
+    n0["demo"]
+    n1["demo-cli"]
+    n2["demo-macros"]
+    n3("syn")
+    n0 --> n2
+    n1 --> n0
+    n2 -.->|dev| n0
+    n2 --> n3
+"#
+        );
+    }
+
+    #[test]
+    fn test_find_cycles() {
+        let mut graph = graph(&[
+            ("demo", "demo-macros", DepKind::Normal),
+            ("demo-cli", "demo", DepKind::Normal),
+            ("demo-macros", "demo", DepKind::Dev),
+            ("demo-macros", "syn", DepKind::Normal),
+        ]);
+        assert!(find_cycles(&graph, false).is_empty());
+        assert_eq!(
+            find_cycles(&graph, true),
+            [["demo", "demo-macros", "demo"].map(str::to_owned)]
+        );
+
+        graph.edges.insert((
+            "demo-macros".to_owned(),
+            "demo-cli".to_owned(),
+            DepKind::Build,
+        ));
+        assert_eq!(
+            find_cycles(&graph, false),
+            [["demo", "demo-macros", "demo-cli", "demo"].map(str::to_owned)]
+        );
+    }
+}
@@ -0,0 +1,429 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use globset::GlobSet;
+use toml_edit::DocumentMut;
+use toml_edit::Item;
+
+use super::config;
+use super::config::CONFIG_FILE;
+use super::find_command;
+use super::history;
+use super::workspace_dir;
+
+/// hawkeye's config file, which also provides the defaults of the native engine.
+const LICENSERC_FILE: &str = "licenserc.toml";
+
+/// Built-in license headers; `{year}` and `{owner}` are substituted from the properties.
+const BUILTIN_HEADERS: &[(&str, &str)] = &[
+    (
+        "Apache-2.0",
+        r#"Copyright {year} {owner}
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License."#,
+    ),
+    (
+        "MIT",
+        "Copyright {year} {owner}\n\nSPDX-License-Identifier: MIT",
+    ),
+    (
+        "MIT OR Apache-2.0",
+        "Copyright {year} {owner}\n\nSPDX-License-Identifier: MIT OR Apache-2.0",
+    ),
+    (
+        "MPL-2.0",
+        r#"Copyright {year} {owner}
+
+This Source Code Form is subject to the terms of the Mozilla Public
+License, v. 2.0. If a copy of the MPL was not distributed with this
+file, You can obtain one at https://mozilla.org/MPL/2.0/."#,
+    ),
+];
+
+/// The comment syntax used for headers of a kind of file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CommentStyle {
+    /// Each line starts with the prefix, e.g., `//` or `#`.
+    Line(&'static str),
+    /// The header is wrapped in an opening and a closing delimiter.
+    Block(&'static str, &'static str),
+}
+
+impl CommentStyle {
+    fn for_path(path: &str) -> Option<Self> {
+        let extension = Path::new(path).extension()?.to_str()?;
+        let style = match extension {
+            "rs" | "c" | "h" | "cc" | "cpp" | "hpp" | "go" | "java" | "js" | "ts" | "kt"
+            | "proto" | "swift" => Self::Line("//"),
+            "toml" | "yml" | "yaml" | "sh" | "bash" | "py" | "rb" | "ps1" => Self::Line("#"),
+            "sql" | "lua" => Self::Line("--"),
+            "md" | "html" | "xml" | "svg" => Self::Block("<!--", "-->"),
+            "css" => Self::Block("/*", "*/"),
+            _ => return None,
+        };
+        Some(style)
+    }
+
+    fn render(&self, header: &str) -> String {
+        match self {
+            Self::Line(prefix) => header
+                .lines()
+                .map(|line| {
+                    if line.is_empty() {
+                        format!("{prefix}\n")
+                    } else {
+                        format!("{prefix} {line}\n")
+                    }
+                })
+                .collect(),
+            Self::Block(open, close) => format!("{open}\n{header}\n{close}\n"),
+        }
+    }
+
+    /// Returns the length of the comment block at the start of `content`, if any.
+    fn leading_comment_len(&self, content: &str) -> usize {
+        match self {
+            Self::Line(prefix) => content
+                .split_inclusive('\n')
+                .take_while(|line| line.starts_with(prefix))
+                .map(str::len)
+                .sum(),
+            Self::Block(open, close) => {
+                if !content.starts_with(open) {
+                    return 0;
+                }
+                content.find(close).map_or(0, |end| {
+                    let end = end + close.len();
+                    end + usize::from(content[end..].starts_with('\n'))
+                })
+            }
+        }
+    }
+}
+
+/// A set of files sharing the same license header.
+struct HeaderRule {
+    includes: GlobSet,
+    excludes: GlobSet,
+    header: String,
+}
+
+/// Checks the license headers of all files, or adds and fixes them with `fix`.
+///
+/// The backend is selected by `[headers] backend` in `xtask.toml`: `native` (the default) uses
+/// the rules below, and `hawkeye` runs the external hawkeye binary with `licenserc.toml`.
+///
+/// Native rules come from `[[headers.rules]]` in `xtask.toml`, each with `includes`, optional
+/// `excludes`, and either a built-in `license` or a `header-file` path; without any rules, the
+/// includes, excludes, and header of `licenserc.toml` apply. The `{year}` and `{owner}`
+/// placeholders are filled from the `[properties]` of `licenserc.toml`.
+pub fn check_headers(fix: bool) {
+    let Some(rules) = native_rules(fix) else {
+        return;
+    };
+    let mut problems = 0;
+    for path in list_files() {
+        let Some(fixed) = fixed_header(&rules, &path) else {
+            continue;
+        };
+        if fix {
+            std::fs::write(workspace_dir().join(&path), fixed).unwrap();
+            println!("Fixed the license header of {path}.");
+        } else {
+            println!("{path}: missing or outdated license header");
+            problems += 1;
+        }
+    }
+
+    if problems > 0 {
+        eprintln!(
+            "\nFound {problems} file(s) with a bad license header; run `cargo x lint --fix`."
+        );
+        history::exit(1);
+    }
+}
+
+/// Adds the license headers of the configured rules to the `files`, relative to the workspace,
+/// e.g., to the sources of a new member; with the `hawkeye` backend, it fixes all files.
+pub fn add_headers(files: &[String]) {
+    let Some(rules) = native_rules(true) else {
+        return;
+    };
+    for path in files {
+        if let Some(fixed) = fixed_header(&rules, path) {
+            std::fs::write(workspace_dir().join(path), fixed).unwrap();
+        }
+    }
+}
+
+/// Returns the header rules of the native backend, or runs hawkeye, fixing the headers with
+/// `fix`, and returns `None` if it is the backend instead.
+fn native_rules(fix: bool) -> Option<Vec<HeaderRule>> {
+    let doc = config::load_config();
+    let backend = doc
+        .get("headers")
+        .and_then(|headers| headers.get("backend"))
+        .and_then(Item::as_str)
+        .unwrap_or("native");
+    match backend {
+        "native" => {}
+        "hawkeye" => {
+            super::run_command(super::make_hawkeye_cmd(fix));
+            return None;
+        }
+        backend => history::fail(&format!(
+            "invalid {CONFIG_FILE}: unknown headers backend '{backend}'"
+        )),
+    }
+
+    let licenserc = load_licenserc();
+    Some(load_rules(&doc, &licenserc).unwrap_or_else(|err| history::fail(&err)))
+}
+
+/// Returns the content of the file at `path` with the header of the first of the `rules`
+/// including it, or `None` if it already has it or has no header to check.
+fn fixed_header(rules: &[HeaderRule], path: &str) -> Option<String> {
+    let rule = rules
+        .iter()
+        .find(|rule| rule.includes.is_match(path) && !rule.excludes.is_match(path))?;
+    let style = CommentStyle::for_path(path)?;
+    // skip binary or otherwise non-UTF-8 files
+    let content = std::fs::read_to_string(workspace_dir().join(path)).ok()?;
+    apply_header(&content, &style.render(&rule.header), style)
+}
+
+/// Returns `content` with the expected header, or `None` if it already has it.
+///
+/// A leading comment block mentioning "Copyright" is considered an outdated header and
+/// replaced; a shebang line is kept on top.
+fn apply_header(content: &str, header: &str, style: CommentStyle) -> Option<String> {
+    let (shebang, body) = match content.strip_prefix("#!") {
+        Some(_) if !content.starts_with("#![") => {
+            let end = content.find('\n').map_or(content.len(), |i| i + 1);
+            content.split_at(end)
+        }
+        _ => ("", content),
+    };
+    if body.starts_with(header) {
+        return None;
+    }
+
+    let existing = style.leading_comment_len(body);
+    let body = if body[..existing].contains("Copyright") {
+        body[existing..].trim_start_matches('\n')
+    } else {
+        body
+    };
+    let separator = if body.is_empty() { "" } else { "\n" };
+    Some(format!("{shebang}{header}{separator}{body}"))
+}
+
+fn load_licenserc() -> DocumentMut {
+    let file = workspace_dir().join(LICENSERC_FILE);
+    let content = std::fs::read_to_string(&file).unwrap_or_default();
+    content
+        .parse()
+        .unwrap_or_else(|err| history::fail(&format!("invalid {LICENSERC_FILE}: {err}")))
+}
+
+fn load_rules(doc: &DocumentMut, licenserc: &DocumentMut) -> Result<Vec<HeaderRule>, String> {
+    let properties = licenserc.get("properties");
+    let owner = properties
+        .and_then(|p| p.get("copyrightOwner"))
+        .and_then(Item::as_str)
+        .ok_or(format!(
+            "'properties.copyrightOwner' not found in {LICENSERC_FILE}"
+        ))?;
+    let year = properties
+        .and_then(|p| p.get("inceptionYear"))
+        .and_then(|year| match year.as_integer() {
+            Some(year) => Some(year.to_string()),
+            None => year.as_str().map(str::to_owned),
+        })
+        .ok_or(format!(
+            "'properties.inceptionYear' not found in {LICENSERC_FILE}"
+        ))?;
+    let render = |template: &str| template.replace("{year}", &year).replace("{owner}", owner);
+
+    let rules = doc
+        .get("headers")
+        .and_then(|headers| headers.get("rules"))
+        .and_then(Item::as_array_of_tables);
+    let Some(rules) = rules else {
+        // fall back to the hawkeye config
+        let header_path = licenserc
+            .get("headerPath")
+            .and_then(Item::as_str)
+            .unwrap_or("Apache-2.0.txt");
+        let license = header_path.trim_end_matches(".txt");
+        let header = builtin_header(license)
+            .map(str::to_owned)
+            .or_else(|| std::fs::read_to_string(workspace_dir().join(header_path)).ok())
+            .ok_or(format!(
+                "unknown header '{header_path}' in {LICENSERC_FILE}"
+            ))?;
+        let context = |key: &str| format!("'{key}' in {LICENSERC_FILE}");
+        return Ok(vec![HeaderRule {
+            includes: config::glob_set(&config::string_array(
+                licenserc.get("includes"),
+                &context("includes"),
+            )?)?,
+            excludes: config::glob_set(&config::string_array(
+                licenserc.get("excludes"),
+                &context("excludes"),
+            )?)?,
+            header: render(header.trim_end()),
+        }]);
+    };
+
+    rules
+        .iter()
+        .enumerate()
+        .map(|(index, rule)| {
+            let context = |key: &str| format!("'headers.rules[{index}].{key}' in {CONFIG_FILE}");
+            let header = match (
+                rule.get("license").and_then(Item::as_str),
+                rule.get("header-file").and_then(Item::as_str),
+            ) {
+                (Some(license), None) => builtin_header(license)
+                    .ok_or(format!(
+                        "{}: unknown license '{license}'",
+                        context("license")
+                    ))?
+                    .to_owned(),
+                (None, Some(file)) => std::fs::read_to_string(workspace_dir().join(file))
+                    .map_err(|err| format!("{}: {err}", context("header-file")))?,
+                _ => {
+                    return Err(format!(
+                        "{}: exactly one of 'license' and 'header-file' is required",
+                        context("*")
+                    ));
+                }
+            };
+            Ok(HeaderRule {
+                includes: config::glob_set(&config::string_array(
+                    rule.get("includes"),
+                    &context("includes"),
+                )?)?,
+                excludes: config::glob_set(&config::string_array(
+                    rule.get("excludes"),
+                    &context("excludes"),
+                )?)?,
+                header: render(header.trim_end()),
+            })
+        })
+        .collect()
+}
+
+fn builtin_header(license: &str) -> Option<&'static str> {
+    BUILTIN_HEADERS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(license))
+        .map(|(_, header)| *header)
+}
+
+/// Lists tracked and untracked but not ignored files, relative to the workspace.
+fn list_files() -> Vec<String> {
+    let mut cmd = find_command("git");
+    cmd.args([
+        "ls-files",
+        "-z",
+        "--cached",
+        "--others",
+        "--exclude-standard",
+    ]);
+    let output = cmd.output().expect("failed to execute git");
+    assert!(output.status.success(), "{cmd:?} failed: {}", output.status);
+    String::from_utf8_lossy(&output.stdout)
+        .split('\0')
+        .filter(|path| !path.is_empty() && workspace_dir().join(path).is_file())
+        .map(str::to_owned)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comment_style() {
+        assert_eq!(
+            CommentStyle::for_path("src/lib.rs"),
+            Some(CommentStyle::Line("//"))
+        );
+        assert_eq!(
+            CommentStyle::for_path("Cargo.toml"),
+            Some(CommentStyle::Line("#"))
+        );
+        assert_eq!(
+            CommentStyle::for_path("docs/index.md"),
+            Some(CommentStyle::Block("<!--", "-->"))
+        );
+        assert_eq!(CommentStyle::for_path("LICENSE"), None);
+
+        let header = "Copyright 2026 Someone\n\nSPDX-License-Identifier: MIT";
+        assert_eq!(
+            CommentStyle::Line("#").render(header),
+            "# Copyright 2026 Someone\n#\n# SPDX-License-Identifier: MIT\n"
+        );
+        assert_eq!(
+            CommentStyle::Block("<!--", "-->").render(header),
+            "<!--\nCopyright 2026 Someone\n\nSPDX-License-Identifier: MIT\n-->\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_header() {
+        let style = CommentStyle::Line("//");
+        let header = "// Copyright 2026 Someone\n";
+
+        // up to date
+        assert_eq!(
+            apply_header("// Copyright 2026 Someone\n\nfn main() {}\n", header, style),
+            None
+        );
+        // missing
+        assert_eq!(
+            apply_header("//! Crate docs.\n", header, style),
+            Some("// Copyright 2026 Someone\n\n//! Crate docs.\n".into())
+        );
+        // outdated
+        assert_eq!(
+            apply_header(
+                "// Copyright 2025 Other\n// more\n\nfn main() {}\n",
+                header,
+                style
+            ),
+            Some("// Copyright 2026 Someone\n\nfn main() {}\n".into())
+        );
+        // shebang
+        let style = CommentStyle::Line("#");
+        assert_eq!(
+            apply_header("#!/bin/sh\necho hi\n", "# Copyright 2026 Someone\n", style),
+            Some("#!/bin/sh\n# Copyright 2026 Someone\n\necho hi\n".into())
+        );
+    }
+}
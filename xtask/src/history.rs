@@ -0,0 +1,223 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io::Write as _;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::time::Instant;
+use std::time::SystemTime;
+
+use serde_json::Value;
+use serde_json::json;
+
+use super::env;
+use super::workspace_dir;
+
+/// The variable that opts into recording, set in the environment or a dotenv file.
+const HISTORY_VAR: &str = "XTASK_HISTORY";
+
+/// The history file, relative to the workspace.
+const HISTORY_FILE: &str = "target/xtask/history.jsonl";
+
+/// The invocation being recorded, if recording is enabled.
+static INVOCATION: OnceLock<Invocation> = OnceLock::new();
+
+/// Tools that were already installed when a command needed them.
+static CACHE_HITS: AtomicU64 = AtomicU64::new(0);
+
+/// Tools that had to be installed first.
+static CACHE_MISSES: AtomicU64 = AtomicU64::new(0);
+
+struct Invocation {
+    subcommand: String,
+    args: Vec<String>,
+    start: Instant,
+}
+
+/// Starts recording this invocation if `XTASK_HISTORY` is set to `1` or `true`.
+///
+/// A panic records a failed invocation before the default panic message is printed.
+pub fn start(subcommand: &str, args: Vec<String>) {
+    let enabled = std::env::var(HISTORY_VAR)
+        .ok()
+        .or_else(|| env::dotenv_vars().get(HISTORY_VAR).cloned())
+        .is_some_and(|value| value == "1" || value.eq_ignore_ascii_case("true"));
+    if !enabled {
+        return;
+    }
+
+    let invocation = Invocation {
+        subcommand: subcommand.to_owned(),
+        args,
+        start: Instant::now(),
+    };
+    if INVOCATION.set(invocation).is_ok() {
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            finish(false);
+            default_hook(info);
+        }));
+    }
+}
+
+/// Counts whether a tool needed by a command was already installed.
+pub fn record_cache(hit: bool) {
+    let counter = if hit { &CACHE_HITS } else { &CACHE_MISSES };
+    counter.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Appends the record of this invocation to the history file, if recording is enabled.
+pub fn finish(success: bool) {
+    let Some(invocation) = INVOCATION.get() else {
+        return;
+    };
+    let timestamp = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let record = json!({
+        "timestamp": timestamp,
+        "subcommand": invocation.subcommand,
+        "args": invocation.args,
+        "duration_ms": invocation.start.elapsed().as_millis() as u64,
+        "outcome": if success { "success" } else { "failure" },
+        "cache_hits": CACHE_HITS.load(Ordering::Relaxed),
+        "cache_misses": CACHE_MISSES.load(Ordering::Relaxed),
+    });
+
+    // recording must never break the command itself
+    let file = history_file();
+    let _ = std::fs::create_dir_all(file.parent().unwrap());
+    if let Ok(mut file) = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&file)
+    {
+        let _ = writeln!(file, "{record}");
+    }
+}
+
+/// Records a failed invocation and exits with `code`.
+pub fn exit(code: i32) -> ! {
+    finish(false);
+    std::process::exit(code)
+}
+
+/// Prints the error `message` of a failure the project or environment caused, e.g., an invalid
+/// config or a failing command, rather than a bug in xtask, then records it and exits with 1.
+pub fn fail(message: &str) -> ! {
+    eprintln!("error: {message}");
+    exit(1)
+}
+
+/// Prints how often each subcommand ran, how often it failed, and how long it took.
+pub fn print_summary() {
+    let file = history_file();
+    let Ok(content) = std::fs::read_to_string(&file) else {
+        println!("No history recorded; set {HISTORY_VAR}=1 in the environment or `.env.local`.");
+        return;
+    };
+    let records: Vec<Value> = content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect();
+    print!("{}", render_summary(&records));
+}
+
+fn history_file() -> PathBuf {
+    workspace_dir().join(HISTORY_FILE)
+}
+
+#[derive(Default)]
+struct Summary {
+    runs: u64,
+    failures: u64,
+    total_ms: u64,
+    cache_hits: u64,
+    cache_misses: u64,
+}
+
+fn render_summary(records: &[Value]) -> String {
+    let mut summaries: BTreeMap<&str, Summary> = BTreeMap::new();
+    for record in records {
+        let Some(subcommand) = record["subcommand"].as_str() else {
+            continue;
+        };
+        let summary = summaries.entry(subcommand).or_default();
+        summary.runs += 1;
+        summary.failures += u64::from(record["outcome"] == "failure");
+        summary.total_ms += record["duration_ms"].as_u64().unwrap_or(0);
+        summary.cache_hits += record["cache_hits"].as_u64().unwrap_or(0);
+        summary.cache_misses += record["cache_misses"].as_u64().unwrap_or(0);
+    }
+    let mut summaries: Vec<_> = summaries.into_iter().collect();
+    summaries.sort_by_key(|(_, s)| std::cmp::Reverse(s.total_ms));
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "{:<16} {:>6} {:>8} {:>10} {:>10} {:>12}",
+        "SUBCOMMAND", "RUNS", "FAILED", "TOTAL", "MEAN", "CACHE HITS"
+    )
+    .unwrap();
+    for (subcommand, s) in summaries {
+        let seconds = |ms: u64| format!("{:.1}s", ms as f64 / 1000.0);
+        let lookups = s.cache_hits + s.cache_misses;
+        let cache = if lookups == 0 {
+            "-".to_owned()
+        } else {
+            format!("{}/{lookups}", s.cache_hits)
+        };
+        writeln!(
+            out,
+            "{subcommand:<16} {:>6} {:>8} {:>10} {:>10} {cache:>12}",
+            s.runs,
+            s.failures,
+            seconds(s.total_ms),
+            seconds(s.total_ms / s.runs),
+        )
+        .unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_summary() {
+        let records = [
+            r#"{"subcommand":"lint","args":[],"duration_ms":3000,"outcome":"success","cache_hits":3,"cache_misses":1}"#,
+            r#"{"subcommand":"lint","args":["--fix"],"duration_ms":1000,"outcome":"failure","cache_hits":4,"cache_misses":0}"#,
+            r#"{"subcommand":"test","args":[],"duration_ms":9000,"outcome":"success","cache_hits":0,"cache_misses":0}"#,
+        ]
+        .map(|line| serde_json::from_str(line).unwrap());
+        let summary = render_summary(&records);
+        let lines: Vec<&str> = summary.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("SUBCOMMAND"));
+        assert_eq!(
+            lines[1].split_whitespace().collect::<Vec<_>>(),
+            ["test", "1", "0", "9.0s", "9.0s", "-"]
+        );
+        assert_eq!(
+            lines[2].split_whitespace().collect::<Vec<_>>(),
+            ["lint", "2", "1", "4.0s", "2.0s", "7/8"]
+        );
+    }
+}
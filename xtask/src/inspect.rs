@@ -0,0 +1,90 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::PathBuf;
+use std::process::Command as StdCommand;
+
+use super::ensure_installed;
+use super::find_cargo;
+use super::history;
+use super::workspace_dir;
+
+/// Writes the assembly of the function at `path` to `target/xtask/inspect/asm/`.
+pub fn asm(path: &str, package: Option<&str>) {
+    ensure_installed("cargo-asm", "cargo-show-asm");
+    let mut cmd = find_cargo();
+    cmd.args(["asm", "--all-features", "--simplify"]);
+    if let Some(package) = package {
+        cmd.args(["--package", package]);
+    }
+    cmd.arg(path);
+    run_to_file(cmd, output_file("asm", path, "s"));
+}
+
+/// Writes the macro-expanded source of `module` to `target/xtask/inspect/expand/`.
+pub fn expand(module: Option<&str>, package: Option<&str>) {
+    ensure_installed("cargo-expand", "cargo-expand");
+    let mut cmd = find_cargo();
+    cmd.args(["expand", "--all-features"]);
+    if let Some(package) = package {
+        cmd.args(["--package", package]);
+    }
+    if let Some(module) = module {
+        cmd.arg(module);
+    }
+    run_to_file(cmd, output_file("expand", module.unwrap_or("crate"), "rs"));
+}
+
+fn output_file(kind: &str, item: &str, extension: &str) -> PathBuf {
+    let name = item
+        .replace("::", ".")
+        .replace(['<', '>', ' ', '/', '\\'], "_");
+    workspace_dir()
+        .join("target/xtask/inspect")
+        .join(kind)
+        .join(format!("{name}.{extension}"))
+}
+
+fn run_to_file(mut cmd: StdCommand, file: PathBuf) {
+    println!("{cmd:?}");
+    let output = cmd.output().expect("failed to execute process");
+    if !output.status.success() {
+        history::fail(&format!(
+            "command failed: {}\n{}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+    std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+    std::fs::write(&file, output.stdout).unwrap();
+    println!("Wrote {}.", file.display());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_file() {
+        let inspect = workspace_dir().join("target/xtask/inspect");
+        assert_eq!(
+            output_file("asm", "template::hello", "s"),
+            inspect.join("asm/template.hello.s")
+        );
+        assert_eq!(
+            output_file("asm", "<Foo as Bar>::baz", "s"),
+            inspect.join("asm/_Foo_as_Bar_.baz.s")
+        );
+    }
+}
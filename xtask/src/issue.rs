@@ -0,0 +1,143 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::panic::PanicHookInfo;
+
+use toml_edit::DocumentMut;
+
+use super::toolchain;
+use super::workspace_dir;
+
+/// The details of a failure that go into the issue.
+struct Failure<'a> {
+    subcommand: &'a str,
+    args: &'a [String],
+    toolchain: &'a str,
+    message: &'a str,
+    location: &'a str,
+}
+
+/// Prints a prefilled "new issue" URL for the project's GitHub repository after the panic
+/// message of any failure, since the failures the project or environment causes exit through
+/// [`history::fail`] instead of panicking.
+///
+/// [`history::fail`]: super::history::fail
+pub fn install_panic_hook(subcommand: String, args: Vec<String>) {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        default_hook(info);
+        if let Some(url) = issue_url_for_panic(&subcommand, &args, info) {
+            eprintln!("\nThis looks like a bug in xtask; please report it at:\n{url}");
+        }
+    }));
+}
+
+fn issue_url_for_panic(subcommand: &str, args: &[String], info: &PanicHookInfo) -> Option<String> {
+    let payload = info.payload();
+    let message = payload
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown panic payload");
+
+    let repository = github_repository()?;
+    let location = info.location().map(ToString::to_string).unwrap_or_default();
+    let toolchain = toolchain::selected().unwrap_or_else(|| "default".to_owned());
+    let failure = Failure {
+        subcommand,
+        args,
+        toolchain: &toolchain,
+        message,
+        location: &location,
+    };
+    Some(issue_url(&repository, &failure))
+}
+
+/// Returns the `https://github.com/<owner>/<repo>` repository declared in the workspace manifest.
+fn github_repository() -> Option<String> {
+    let content = std::fs::read_to_string(workspace_dir().join("Cargo.toml")).ok()?;
+    let doc = content.parse::<DocumentMut>().ok()?;
+    let repository = doc
+        .get("workspace")
+        .and_then(|w| w.get("package"))
+        .or_else(|| doc.get("package"))?
+        .get("repository")?
+        .as_str()?;
+    let repository = repository.trim_end_matches('/').trim_end_matches(".git");
+    repository
+        .starts_with("https://github.com/")
+        .then(|| repository.to_owned())
+}
+
+fn issue_url(repository: &str, failure: &Failure) -> String {
+    // the xtask package is named after its cargo alias
+    let alias = env!("CARGO_PKG_NAME");
+    let title = format!(
+        "cargo {alias} {} failed: {}",
+        failure.subcommand,
+        failure.message.lines().next().unwrap_or_default()
+    );
+    let body = format!(
+        "**Command:** `cargo {alias} {} {}`\n**OS:** {} ({})\n**Toolchain:** {}\n**Location:** {}\n\n```\n{}\n```\n",
+        failure.subcommand,
+        failure.args.join(" "),
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        failure.toolchain,
+        failure.location,
+        failure.message,
+    );
+    format!(
+        "{repository}/issues/new?title={}&body={}",
+        percent_encode(&title),
+        percent_encode(&body)
+    )
+}
+
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_issue_url() {
+        let failure = Failure {
+            subcommand: "lint",
+            args: &["--fix".to_owned()],
+            toolchain: "stable",
+            message: "invalid xtask.toml: bad key\nmore",
+            location: "xtask/src/main.rs:1:1",
+        };
+        let url = issue_url("https://github.com/fast/demo", &failure);
+        let alias = env!("CARGO_PKG_NAME");
+        assert!(url.starts_with(&format!(
+            "https://github.com/fast/demo/issues/new?title=cargo%20{alias}%20lint%20failed%3A%20invalid%20xtask.toml%3A%20bad%20key&body="
+        )));
+        assert!(url.contains(&format!("%60cargo%20{alias}%20lint%20--fix%60")));
+        assert!(url.contains("Toolchain%3A%2A%2A%20stable"));
+        assert!(!url.contains(' ') && !url.contains('\n'));
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+
+use serde_json::Value;
+
+use super::history;
+use super::metadata;
+use super::workspace_dir;
+
+/// The generated notices file, relative to the workspace.
+const NOTICES_FILE: &str = "THIRD-PARTY-NOTICES";
+
+/// File name prefixes of license texts shipped within a package.
+const LICENSE_FILE_PREFIXES: &[&str] = &["LICENSE", "LICENCE", "COPYING", "NOTICE"];
+
+/// A third-party package shipped with the publishable workspace members.
+struct Notice {
+    name: String,
+    version: String,
+    license: String,
+    repository: Option<String>,
+    texts: Vec<(String, String)>,
+}
+
+/// Generates the notices file, or with `check` fails if the committed file is stale.
+pub fn generate_notices(check: bool) {
+    let metadata = metadata::cargo_metadata(false);
+    let notices = render_notices(&collect_notices(&metadata));
+    let file = workspace_dir().join(NOTICES_FILE);
+
+    if check {
+        let current = std::fs::read_to_string(&file).unwrap_or_default();
+        if current != notices {
+            eprintln!("{NOTICES_FILE} is stale; run `cargo x licenses` to regenerate it.");
+            history::exit(1);
+        }
+        println!("{NOTICES_FILE} is up to date.");
+    } else {
+        std::fs::write(&file, notices).unwrap();
+        println!("Generated {}.", file.display());
+    }
+}
+
+/// Collects the packages reachable through normal dependencies from publishable members.
+fn collect_notices(metadata: &Value) -> Vec<Notice> {
+    let members = metadata::workspace_packages(metadata);
+    let member_ids: BTreeSet<&str> = members.iter().map(|p| p["id"].as_str().unwrap()).collect();
+    let packages: BTreeMap<&str, &Value> = metadata["packages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| (p["id"].as_str().unwrap(), p))
+        .collect();
+    let nodes: BTreeMap<&str, &Value> = metadata["resolve"]["nodes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|n| (n["id"].as_str().unwrap(), n))
+        .collect();
+
+    let mut pending: Vec<&str> = members
+        .iter()
+        .filter(|p| metadata::is_publishable(p))
+        .map(|p| p["id"].as_str().unwrap())
+        .collect();
+    let mut visited = BTreeSet::new();
+    while let Some(id) = pending.pop() {
+        if !visited.insert(id) {
+            continue;
+        }
+        for dep in nodes[id]["deps"].as_array().unwrap() {
+            let is_normal = dep["dep_kinds"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|kind| kind["kind"].is_null());
+            if is_normal {
+                pending.push(dep["pkg"].as_str().unwrap());
+            }
+        }
+    }
+
+    let mut notices: Vec<Notice> = visited
+        .into_iter()
+        .filter(|id| !member_ids.contains(id))
+        .map(|id| {
+            let package = packages[id];
+            let manifest_path = Path::new(package["manifest_path"].as_str().unwrap());
+            Notice {
+                name: package["name"].as_str().unwrap().to_owned(),
+                version: package["version"].as_str().unwrap().to_owned(),
+                license: package["license"].as_str().unwrap_or("UNKNOWN").to_owned(),
+                repository: package["repository"].as_str().map(str::to_owned),
+                texts: read_license_texts(manifest_path.parent().unwrap(), package),
+            }
+        })
+        .collect();
+    notices.sort_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
+    notices
+}
+
+fn read_license_texts(package_dir: &Path, package: &Value) -> Vec<(String, String)> {
+    let mut files = BTreeSet::new();
+    if let Some(license_file) = package["license_file"].as_str() {
+        files.insert(package_dir.join(license_file));
+    }
+    if let Ok(entries) = std::fs::read_dir(package_dir) {
+        for entry in entries.flatten() {
+            let name = entry.file_name().to_string_lossy().to_uppercase();
+            if LICENSE_FILE_PREFIXES.iter().any(|p| name.starts_with(p)) {
+                files.insert(entry.path());
+            }
+        }
+    }
+    files
+        .into_iter()
+        .filter_map(|file| {
+            let text = std::fs::read_to_string(&file).ok()?;
+            let name = file.file_name()?.to_string_lossy().into_owned();
+            Some((name, text))
+        })
+        .collect()
+}
+
+fn render_notices(notices: &[Notice]) -> String {
+    let mut out = String::new();
+    writeln!(out, "THIRD-PARTY SOFTWARE NOTICES").unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "This file is generated by `cargo x licenses`. Do not edit it by hand."
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    if notices.is_empty() {
+        writeln!(
+            out,
+            "This project does not ship any third-party dependencies."
+        )
+        .unwrap();
+        return out;
+    }
+    for notice in notices {
+        writeln!(out, "{}", "=".repeat(80)).unwrap();
+        writeln!(out, "{} {}", notice.name, notice.version).unwrap();
+        writeln!(out, "License: {}", notice.license).unwrap();
+        if let Some(repository) = &notice.repository {
+            writeln!(out, "Repository: {repository}").unwrap();
+        }
+        for (name, text) in &notice.texts {
+            writeln!(out, "\n--- {name} ---\n").unwrap();
+            writeln!(out, "{}", text.trim_end()).unwrap();
+        }
+        writeln!(out).unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_notices() {
+        assert!(render_notices(&[]).contains("does not ship any third-party dependencies"));
+
+        let notices = render_notices(&[Notice {
+            name: "foo".into(),
+            version: "1.0.0".into(),
+            license: "MIT".into(),
+            repository: Some("https://github.com/foo/foo".into()),
+            texts: vec![("LICENSE".into(), "MIT License\n".into())],
+        }]);
+        assert!(
+            notices.contains("foo 1.0.0\nLicense: MIT\nRepository: https://github.com/foo/foo\n")
+        );
+        assert!(notices.contains("\n--- LICENSE ---\n\nMIT License\n"));
+    }
+}
@@ -0,0 +1,169 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use serde_json::Value;
+use serde_json::json;
+
+use super::metadata;
+use super::workspace_dir;
+
+/// A workspace member, as listed by `cargo x list`.
+struct Member {
+    name: String,
+    version: String,
+    publish: bool,
+    rust_version: Option<String>,
+    features: Vec<String>,
+    /// The non-blank lines of its Rust sources.
+    loc: usize,
+}
+
+/// Prints every workspace member with its version, whether it is published, its MSRV, its
+/// features, and the non-blank lines of its Rust sources, as a table or, with `json`, as a JSON
+/// array for scripts.
+pub fn list(json: bool) {
+    let metadata = metadata::cargo_metadata(true);
+    let members: Vec<Member> = metadata::workspace_packages(&metadata)
+        .into_iter()
+        .map(|package| {
+            let manifest = Path::new(package["manifest_path"].as_str().unwrap());
+            let features = package["features"].as_object();
+            Member {
+                name: package["name"].as_str().unwrap().to_owned(),
+                version: package["version"].as_str().unwrap().to_owned(),
+                publish: metadata::is_publishable(package),
+                rust_version: package["rust_version"].as_str().map(str::to_owned),
+                features: features
+                    .into_iter()
+                    .flatten()
+                    .map(|(name, _)| name.clone())
+                    .collect(),
+                loc: count_dir_lines(manifest.parent().unwrap()),
+            }
+        })
+        .collect();
+
+    if json {
+        let members: Vec<Value> = members
+            .iter()
+            .map(|member| {
+                json!({
+                    "name": member.name,
+                    "version": member.version,
+                    "publish": member.publish,
+                    "rust_version": member.rust_version,
+                    "features": member.features,
+                    "loc": member.loc,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&members).unwrap());
+    } else {
+        print!("{}", render_table(&members));
+    }
+}
+
+/// Counts the non-blank lines of the Rust sources under `dir`, except the build output and the
+/// members nested in it.
+fn count_dir_lines(dir: &Path) -> usize {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+    let mut lines = 0;
+    for entry in entries {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            let nested = path.join("Cargo.toml").exists() && path != workspace_dir();
+            if path.file_name().is_some_and(|name| name != "target") && !nested {
+                lines += count_dir_lines(&path);
+            }
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            let content = std::fs::read_to_string(&path).unwrap_or_default();
+            lines += count_lines(&content);
+        }
+    }
+    lines
+}
+
+fn count_lines(content: &str) -> usize {
+    content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .count()
+}
+
+fn render_table(members: &[Member]) -> String {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "{:<24} {:>10} {:>8} {:>8} {:>8}  FEATURES",
+        "CRATE", "VERSION", "PUBLISH", "MSRV", "LOC"
+    )
+    .unwrap();
+    for member in members {
+        let features = match member.features.is_empty() {
+            true => "-".to_owned(),
+            false => member.features.join(", "),
+        };
+        writeln!(
+            out,
+            "{:<24} {:>10} {:>8} {:>8} {:>8}  {features}",
+            member.name,
+            member.version,
+            if member.publish { "yes" } else { "no" },
+            member.rust_version.as_deref().unwrap_or("-"),
+            member.loc,
+        )
+        .unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_table() {
+        assert_eq!(count_lines("fn main() {\n\n    hello();\n  \n}\n"), 3);
+
+        let members = [
+            Member {
+                name: "demo".to_owned(),
+                version: "0.1.0".to_owned(),
+                publish: true,
+                rust_version: Some("1.85.0".to_owned()),
+                features: vec!["default".to_owned(), "std".to_owned()],
+                loc: 120,
+            },
+            Member {
+                name: "x".to_owned(),
+                version: "0.0.0".to_owned(),
+                publish: false,
+                rust_version: None,
+                features: vec![],
+                loc: 8,
+            },
+        ];
+        assert_eq!(
+            render_table(&members),
+            "CRATE                       VERSION  PUBLISH     MSRV      LOC  FEATURES\n\
+             demo                          0.1.0      yes   1.85.0      120  default, std\n\
+             x                             0.0.0       no        -        8  -\n"
+        );
+    }
+}
@@ -0,0 +1,364 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+use std::process::Command as StdCommand;
+
+use serde_json::Value;
+use toml_edit::DocumentMut;
+use toml_edit::Item;
+
+use super::find_cargo;
+use super::find_command;
+use super::history;
+use super::run_command;
+use super::workspace_dir;
+use super::worktree;
+
+/// The lockfile, relative to the workspace.
+const LOCKFILE: &str = "Cargo.lock";
+
+/// A `[[package]]` entry of the lockfile.
+type LockEntry = (String, String);
+
+/// Verifies that the lockfile matches the manifests and has no unreachable entries.
+///
+/// The manifests are resolved with `--locked`, so any change cargo would make to the lockfile
+/// fails the check, as does an entry no workspace member depends on with any feature set, or a
+/// tracked lockfile with uncommitted changes.
+pub fn check_lockfile() {
+    let Some(entries) = read_lockfile() else {
+        eprintln!("{LOCKFILE} not found; run `cargo x lock prune` to generate it.");
+        history::exit(1);
+    };
+
+    let mut cmd = make_metadata_cmd();
+    cmd.arg("--locked");
+    let output = cmd.output().expect("failed to execute cargo metadata");
+    if !output.status.success() {
+        eprintln!("{}", String::from_utf8_lossy(&output.stderr).trim_end());
+        eprintln!("\n{LOCKFILE} is out of sync with the manifests; run `cargo x lock prune`.");
+        history::exit(1);
+    }
+    let metadata: Value =
+        serde_json::from_slice(&output.stdout).expect("failed to parse cargo metadata output");
+
+    let stale = stale_entries(&entries, &resolved_packages(&metadata));
+    if !stale.is_empty() {
+        for (name, version) in &stale {
+            println!("{name} {version}: not reachable from any workspace member");
+        }
+        eprintln!(
+            "\nFound {} unreachable {LOCKFILE} entries; run `cargo x lock prune`.",
+            stale.len()
+        );
+        history::exit(1);
+    }
+
+    // `cargo x` itself runs through `cargo run`, which rewrites a stale lockfile before the
+    // check starts, so a committed lockfile must also match the working tree
+    let mut cmd = find_command("git");
+    cmd.args(["diff", "--quiet", "HEAD", "--", LOCKFILE]);
+    let status = cmd.status().expect("failed to execute git");
+    if status.code() == Some(1) {
+        eprintln!("{LOCKFILE} differs from the committed version; commit the updated lockfile.");
+        history::exit(1);
+    }
+    println!("{LOCKFILE} is in sync with the manifests.");
+}
+
+/// Rewrites the lockfile to match the manifests, dropping unreachable entries.
+///
+/// Existing versions are kept unless `regenerate` is set, in which case the lockfile is
+/// regenerated from scratch with the newest versions compatible with `rust-version`.
+pub fn prune_lockfile(regenerate: bool) {
+    let before = read_lockfile().unwrap_or_default();
+    if regenerate {
+        let mut cmd = find_cargo();
+        cmd.arg("generate-lockfile");
+        run_command(cmd);
+    } else {
+        // resolving without `--locked` writes the minimal set of changes to the lockfile
+        let mut cmd = make_metadata_cmd();
+        cmd.stdout(std::process::Stdio::null());
+        run_command(cmd);
+    }
+    let after = read_lockfile().unwrap_or_default();
+
+    let removed: Vec<_> = before.difference(&after).collect();
+    let added: Vec<_> = after.difference(&before).collect();
+    for (name, version) in &removed {
+        println!("- {name} {version}");
+    }
+    for (name, version) in &added {
+        println!("+ {name} {version}");
+    }
+    println!(
+        "Updated {LOCKFILE}: {} removed, {} added.",
+        removed.len(),
+        added.len()
+    );
+}
+
+/// Prints the dependencies added, removed, or updated since `since`, and fails if an updated
+/// dependency changed its license.
+///
+/// The lockfile must be tracked at `since`; the licenses of the old versions are resolved in a
+/// worktree checked out at that revision.
+pub fn diff_lockfile(since: &str) {
+    let work_dir = workspace_dir().join("target/xtask/lock-diff");
+    let base_dir = work_dir.join("base");
+    worktree::add_worktree(&base_dir, since);
+    let tracked = base_dir.join(LOCKFILE).exists() && is_tracked(&base_dir);
+    let base = tracked.then(|| package_licenses(&base_dir));
+    worktree::remove_worktree(&base_dir);
+    let Some(base) = base else {
+        panic!("{LOCKFILE} is not tracked at '{since}'");
+    };
+    let head = package_licenses(workspace_dir());
+
+    let changes = diff_packages(&base, &head);
+    if changes.is_empty() {
+        println!("No dependency changes since '{since}'.");
+        return;
+    }
+    let mut license_changes = 0;
+    for change in &changes {
+        println!("{}", change.line);
+        license_changes += usize::from(change.license_changed);
+    }
+    if license_changes > 0 {
+        eprintln!(
+            "\nFound {license_changes} dependency license change(s); review them against the policy."
+        );
+        history::exit(1);
+    }
+}
+
+fn is_tracked(dir: &Path) -> bool {
+    let mut cmd = find_command("git");
+    cmd.current_dir(dir)
+        .args(["ls-files", "--error-unmatch", LOCKFILE])
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null());
+    cmd.status().is_ok_and(|status| status.success())
+}
+
+/// Returns the license of every package in the resolved dependency graph of the workspace at
+/// `dir`, by name and version; a missing license field is reported as `unknown`.
+fn package_licenses(dir: &Path) -> BTreeMap<String, BTreeMap<String, String>> {
+    let mut cmd = make_metadata_cmd();
+    cmd.current_dir(dir).arg("--locked");
+    let output = cmd.output().expect("failed to execute cargo metadata");
+    assert!(
+        output.status.success(),
+        "cargo metadata failed in {}: {}\n{}",
+        dir.display(),
+        output.status,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let metadata: Value =
+        serde_json::from_slice(&output.stdout).expect("failed to parse cargo metadata output");
+
+    let mut packages: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
+    for package in metadata["packages"].as_array().unwrap() {
+        let name = package["name"].as_str().unwrap().to_owned();
+        let version = package["version"].as_str().unwrap().to_owned();
+        let license = package["license"].as_str().unwrap_or("unknown").to_owned();
+        packages.entry(name).or_default().insert(version, license);
+    }
+    packages
+}
+
+#[derive(Debug, PartialEq)]
+struct PackageChange {
+    line: String,
+    license_changed: bool,
+}
+
+fn diff_packages(
+    base: &BTreeMap<String, BTreeMap<String, String>>,
+    head: &BTreeMap<String, BTreeMap<String, String>>,
+) -> Vec<PackageChange> {
+    let empty = BTreeMap::new();
+    let names: BTreeSet<&String> = base.keys().chain(head.keys()).collect();
+    let mut changes = vec![];
+    for name in names {
+        let old = base.get(name).unwrap_or(&empty);
+        let new = head.get(name).unwrap_or(&empty);
+        if old.keys().eq(new.keys()) {
+            continue;
+        }
+        let versions = |versions: &BTreeMap<String, String>| {
+            versions.keys().cloned().collect::<Vec<_>>().join(", ")
+        };
+        let licenses = |versions: &BTreeMap<String, String>| {
+            versions.values().cloned().collect::<BTreeSet<_>>()
+        };
+        let join =
+            |licenses: &BTreeSet<String>| licenses.iter().cloned().collect::<Vec<_>>().join(", ");
+        let change = if old.is_empty() {
+            PackageChange {
+                line: format!("+ {name} {} ({})", versions(new), join(&licenses(new))),
+                license_changed: false,
+            }
+        } else if new.is_empty() {
+            PackageChange {
+                line: format!("- {name} {} ({})", versions(old), join(&licenses(old))),
+                license_changed: false,
+            }
+        } else {
+            let (old_licenses, new_licenses) = (licenses(old), licenses(new));
+            let mut line = format!("~ {name} {} -> {}", versions(old), versions(new));
+            let license_changed = old_licenses != new_licenses;
+            if license_changed {
+                write!(
+                    line,
+                    ": license changed from {} to {}",
+                    join(&old_licenses),
+                    join(&new_licenses)
+                )
+                .unwrap();
+            }
+            PackageChange {
+                line,
+                license_changed,
+            }
+        };
+        changes.push(change);
+    }
+    changes
+}
+
+fn make_metadata_cmd() -> StdCommand {
+    let mut cmd = find_cargo();
+    cmd.args(["metadata", "--format-version", "1", "--all-features"]);
+    cmd
+}
+
+fn read_lockfile() -> Option<BTreeSet<LockEntry>> {
+    let content = std::fs::read_to_string(workspace_dir().join(LOCKFILE)).ok()?;
+    let doc = content
+        .parse()
+        .unwrap_or_else(|err| history::fail(&format!("invalid {LOCKFILE}: {err}")));
+    Some(parse_lock_entries(&doc))
+}
+
+/// Returns the name and version of every `[[package]]` in the lockfile.
+fn parse_lock_entries(doc: &DocumentMut) -> BTreeSet<LockEntry> {
+    doc.get("package")
+        .and_then(Item::as_array_of_tables)
+        .into_iter()
+        .flatten()
+        .filter_map(|package| {
+            let name = package.get("name")?.as_str()?;
+            let version = package.get("version")?.as_str()?;
+            Some((name.to_owned(), version.to_owned()))
+        })
+        .collect()
+}
+
+/// Returns the name and version of every package in the resolved dependency graph.
+fn resolved_packages(metadata: &Value) -> BTreeSet<LockEntry> {
+    metadata["packages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|p| {
+            let name = p["name"].as_str().unwrap().to_owned();
+            let version = p["version"].as_str().unwrap().to_owned();
+            (name, version)
+        })
+        .collect()
+}
+
+fn stale_entries(lock: &BTreeSet<LockEntry>, resolved: &BTreeSet<LockEntry>) -> Vec<LockEntry> {
+    lock.difference(resolved).cloned().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stale_entries() {
+        let doc = r#"version = 4
+
+[[package]]
+name = "demo"
+version = "0.1.0"
+
+[[package]]
+name = "old-dep"
+version = "1.2.3"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+"#
+        .parse()
+        .unwrap();
+        let lock = parse_lock_entries(&doc);
+        assert_eq!(lock.len(), 2);
+
+        let resolved = BTreeSet::from([("demo".to_owned(), "0.1.0".to_owned())]);
+        assert_eq!(
+            stale_entries(&lock, &resolved),
+            vec![("old-dep".to_owned(), "1.2.3".to_owned())]
+        );
+        assert!(stale_entries(&resolved, &resolved).is_empty());
+    }
+
+    #[test]
+    fn test_diff_packages() {
+        let packages = |entries: &[(&str, &str, &str)]| {
+            let mut packages: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
+            for (name, version, license) in entries {
+                packages
+                    .entry((*name).to_owned())
+                    .or_default()
+                    .insert((*version).to_owned(), (*license).to_owned());
+            }
+            packages
+        };
+        let base = packages(&[
+            ("bumped", "1.0.0", "MIT"),
+            ("relicensed", "2.0.0", "MIT"),
+            ("removed", "0.1.0", "Apache-2.0"),
+            ("same", "1.0.0", "MIT"),
+        ]);
+        let head = packages(&[
+            ("added", "0.2.0", "MIT OR Apache-2.0"),
+            ("bumped", "1.1.0", "MIT"),
+            ("relicensed", "3.0.0", "BUSL-1.1"),
+            ("same", "1.0.0", "MIT"),
+        ]);
+        let lines: Vec<(String, bool)> = diff_packages(&base, &head)
+            .into_iter()
+            .map(|c| (c.line, c.license_changed))
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                ("+ added 0.2.0 (MIT OR Apache-2.0)".to_owned(), false),
+                ("~ bumped 1.0.0 -> 1.1.0".to_owned(), false),
+                (
+                    "~ relicensed 2.0.0 -> 3.0.0: license changed from MIT to BUSL-1.1".to_owned(),
+                    true
+                ),
+                ("- removed 0.1.0 (Apache-2.0)".to_owned(), false),
+            ]
+        );
+    }
+}
@@ -14,57 +14,330 @@
 
 //! An xtask binary for managing workspace tasks.
 
+use std::ffi::OsString;
 use std::path::Path;
+use std::path::PathBuf;
 use std::process::Command as StdCommand;
 
+use clap::CommandFactory;
+use clap::FromArgMatches;
 use clap::Parser;
 use clap::Subcommand;
 
+mod bench;
 mod bootstrap;
+mod cbindgen;
+mod changelog;
+mod check_deps;
+mod check_versions;
+mod config;
+mod config_check;
+mod cross;
+mod doc;
+mod env;
+mod examples;
+mod graph;
+mod headers;
+mod history;
+mod inspect;
+mod issue;
+mod licenses;
+mod list;
+mod lock;
+mod maturin;
+mod metadata;
+mod msrv;
+mod mutants;
+mod new_crate;
+mod package;
+mod plugin;
+mod readme;
+mod remove_crate;
+mod rename_crate;
+mod scan;
+mod secrets;
+mod size_diff;
+mod smoke;
+mod split;
+mod tasks;
+mod toolchain;
+mod typos;
+mod unsafe_report;
+mod wasm;
+mod worktree;
 
 fn workspace_dir() -> &'static Path {
     Path::new(env!("CARGO_WORKSPACE_DIR"))
 }
 
 #[derive(Parser)]
-#[clap(about = "Run repository tasks.")]
+#[clap(
+    about = "Run repository tasks.",
+    after_help = "Any other subcommand `<name>` runs the `xtask-<name>` executable found in `xtask/plugins/` or on PATH."
+)]
 struct Command {
+    #[arg(
+        long,
+        global = true,
+        help = "The toolchain for spawned cargo commands; defaults to `rust-toolchain.toml`."
+    )]
+    toolchain: Option<String>,
     #[clap(subcommand)]
     sub: SubCommand,
 }
 
 impl Command {
     fn run(self) {
+        if let Some(toolchain) = self.toolchain {
+            toolchain::set_override(toolchain);
+        }
         match self.sub {
+            SubCommand::Asm(cmd) => cmd.run(),
+            SubCommand::Bench(cmd) => cmd.run(),
             SubCommand::Build(cmd) => cmd.run(),
             SubCommand::Bootstrap(cmd) => cmd.run(),
+            SubCommand::Cbindgen(cmd) => cmd.run(),
+            SubCommand::Changelog(cmd) => cmd.run(),
+            SubCommand::CheckConfig(cmd) => cmd.run(),
+            SubCommand::CheckDeps(cmd) => cmd.run(),
+            SubCommand::CheckVersions(cmd) => cmd.run(),
+            SubCommand::Cross(cmd) => cmd.run(),
+            SubCommand::Doc(cmd) => cmd.run(),
+            SubCommand::Env(cmd) => cmd.run(),
+            SubCommand::Examples(cmd) => cmd.run(),
+            SubCommand::Expand(cmd) => cmd.run(),
+            SubCommand::ExportTemplate(cmd) => cmd.run(),
+            SubCommand::Graph(cmd) => cmd.run(),
+            SubCommand::Licenses(cmd) => cmd.run(),
+            SubCommand::History(cmd) => cmd.run(),
+            SubCommand::InitWorkspaceSplit(cmd) => cmd.run(),
             SubCommand::Lint(cmd) => cmd.run(),
+            SubCommand::List(cmd) => cmd.run(),
+            SubCommand::Lock(cmd) => cmd.run(),
+            SubCommand::Maturin(cmd) => cmd.run(),
+            SubCommand::Msrv(cmd) => cmd.run(),
+            SubCommand::Mutants(cmd) => cmd.run(),
+            SubCommand::NewCrate(cmd) => cmd.run(),
+            SubCommand::Readme(cmd) => cmd.run(),
+            SubCommand::RemoveCrate(cmd) => cmd.run(),
+            SubCommand::RenameCrate(cmd) => cmd.run(),
+            SubCommand::RunTask(cmd) => cmd.run(),
+            SubCommand::Scan(cmd) => cmd.run(),
+            SubCommand::Secrets(cmd) => cmd.run(),
+            SubCommand::SizeDiff(cmd) => cmd.run(),
+            SubCommand::Smoke(cmd) => cmd.run(),
+            SubCommand::Tasks(cmd) => cmd.run(),
             SubCommand::Test(cmd) => cmd.run(),
+            SubCommand::Typos(cmd) => cmd.run(),
+            SubCommand::UnsafeReport(cmd) => cmd.run(),
+            SubCommand::VerifyPackage(cmd) => cmd.run(),
+            SubCommand::Wasm(cmd) => cmd.run(),
+            SubCommand::External(args) => plugin::run_plugin(args),
         }
     }
 }
 
 #[derive(Subcommand)]
 enum SubCommand {
+    #[clap(about = "Write the assembly of a function under `target/xtask/inspect/`.")]
+    Asm(CommandAsm),
+    #[clap(about = "Run the workspace benches, optionally comparing against a baseline.")]
+    Bench(CommandBench),
     #[clap(about = "Compile all workspace targets.")]
     Build(CommandBuild),
     #[clap(about = "Bootstrap a new project from this template.")]
-    Bootstrap(CommandBootstrap),
+    Bootstrap(Box<CommandBootstrap>),
+    #[clap(about = "Generate the C headers of the members with a `cbindgen.toml`.")]
+    Cbindgen(CommandCbindgen),
+    #[clap(about = "Maintain `CHANGELOG.md` in the Keep a Changelog format.")]
+    Changelog(CommandChangelog),
+    #[clap(about = "Validate rustfmt and clippy configs against the pinned toolchain.")]
+    CheckConfig(CommandCheckConfig),
+    #[clap(about = "Check that members inherit every dependency from `[workspace.dependencies]`.")]
+    CheckDeps(CommandCheckDeps),
+    #[clap(
+        about = "Check that the member versions, `rust-version`s and requirements on each other agree."
+    )]
+    CheckVersions(CommandCheckVersions),
+    #[clap(about = "Build and run workspace tests for foreign architectures.")]
+    Cross(CommandCross),
+    #[clap(about = "Build the workspace docs, optionally serving them with live reload.")]
+    Doc(CommandDoc),
+    #[clap(about = "Print the environment variables applied to spawned commands.")]
+    Env(CommandEnv),
+    #[clap(about = "Run the workspace examples.")]
+    Examples(CommandExamples),
+    #[clap(about = "Write the macro expansion of a module under `target/xtask/inspect/`.")]
+    Expand(CommandExpand),
+    #[clap(about = "Export the template for `cargo generate`.")]
+    ExportTemplate(CommandExportTemplate),
+    #[clap(
+        about = "Print the dependency graph of the members as DOT or mermaid, failing on cycles."
+    )]
+    Graph(CommandGraph),
+    #[clap(about = "Summarize the invocations recorded with `XTASK_HISTORY=1`.")]
+    History(CommandHistory),
+    #[clap(about = "Split the library into `{name}-core` and `{name}-cli` members.")]
+    InitWorkspaceSplit(CommandInitWorkspaceSplit),
+    #[clap(about = "Generate the third-party license notices.")]
+    Licenses(CommandLicenses),
     #[clap(about = "Run workspace quality checks.")]
     Lint(CommandLint),
+    #[clap(
+        about = "List the workspace members with their version, MSRV, features and lines of code."
+    )]
+    List(CommandList),
+    #[clap(about = "Check or prune `Cargo.lock`.")]
+    Lock(CommandLock),
+    #[clap(about = "Build or publish the Python packages of the members with a `pyproject.toml`.")]
+    Maturin(CommandMaturin),
+    #[clap(about = "Verify or discover the minimum supported Rust version.")]
+    Msrv(CommandMsrv),
+    #[clap(about = "Run mutation testing with cargo-mutants and summarize survivors per crate.")]
+    Mutants(CommandMutants),
+    #[clap(about = "Add a library or binary member in `crates/<name>` to the workspace.")]
+    NewCrate(CommandNewCrate),
+    #[clap(about = "Keep `README.md` in sync with the crate-level docs.")]
+    Readme(CommandReadme),
+    #[clap(about = "Remove a member and its directory from the workspace.")]
+    RemoveCrate(CommandRemoveCrate),
+    #[clap(about = "Rename a member, its directory and the references to it across the workspace.")]
+    RenameCrate(CommandRenameCrate),
+    #[clap(about = "Run a task defined in `xtask.toml`.")]
+    RunTask(CommandRunTask),
+    #[clap(about = "Scan the workspace for leftover template placeholders.")]
+    Scan(CommandScan),
+    #[clap(about = "Scan tracked files and recent history for leaked secrets.")]
+    Secrets(CommandSecrets),
+    #[clap(about = "Compare release artifact sizes against another revision.")]
+    SizeDiff(CommandSizeDiff),
+    #[clap(about = "Run the release binary inside Linux distribution containers.")]
+    Smoke(CommandSmoke),
+    #[clap(about = "List the tasks defined in `xtask.toml`.")]
+    Tasks(CommandTasks),
     #[clap(about = "Run workspace unit tests.")]
     Test(CommandTest),
+    #[clap(about = "Manage the words accepted by the typos checker.")]
+    Typos(CommandTypos),
+    #[clap(about = "Count unsafe code per crate and check it against the committed budget.")]
+    UnsafeReport(CommandUnsafeReport),
+    #[clap(about = "Check the files `cargo package` would publish for each member.")]
+    VerifyPackage(CommandVerifyPackage),
+    #[clap(about = "Build or test the members depending on wasm-bindgen with wasm-pack.")]
+    Wasm(CommandWasm),
+    #[clap(external_subcommand)]
+    External(Vec<OsString>),
+}
+
+#[derive(Parser)]
+struct CommandAsm {
+    #[arg(help = "The path of the function to inspect, e.g., `template::hello`.")]
+    path: String,
+    #[arg(long, short, help = "The package containing the function.")]
+    package: Option<String>,
+}
+
+impl CommandAsm {
+    fn run(self) {
+        inspect::asm(&self.path, self.package.as_deref());
+    }
+}
+
+#[derive(Parser)]
+struct CommandBench {
+    #[arg(
+        long,
+        conflicts_with = "baseline",
+        help = "Compare against the benches built at this revision, e.g., `origin/main`."
+    )]
+    compare: Option<String>,
+    #[arg(
+        long,
+        help = "Compare against measurements saved with `--save-baseline`."
+    )]
+    baseline: Option<PathBuf>,
+    #[arg(long, help = "Save the measurements of the working tree to this file.")]
+    save_baseline: Option<PathBuf>,
+    #[arg(
+        long,
+        default_value_t = 5,
+        value_parser = clap::value_parser!(u64).range(1..),
+        help = "How many times to run the benches on each side."
+    )]
+    runs: u64,
+    #[arg(
+        long,
+        default_value_t = 5.0,
+        help = "The slowdown in percent above which a significant change fails."
+    )]
+    threshold: f64,
+    #[arg(long, help = "Also write the markdown report to this file.")]
+    output: Option<PathBuf>,
+}
+
+impl CommandBench {
+    fn run(self) {
+        let baseline = match (self.compare, self.baseline) {
+            (Some(revision), _) => Some(bench::Baseline::Revision(revision)),
+            (None, Some(file)) => Some(bench::Baseline::File(file)),
+            (None, None) => None,
+        };
+        bench::bench(bench::BenchOptions {
+            baseline,
+            save_baseline: self.save_baseline,
+            runs: self.runs as usize,
+            threshold: self.threshold,
+            output: self.output,
+        });
+    }
 }
 
 #[derive(Parser)]
 struct CommandBuild {
     #[arg(long, help = "Assert that `Cargo.lock` will remain unchanged.")]
     locked: bool,
+    #[arg(
+        long,
+        value_delimiter = ',',
+        help = "Build for these target triples instead of the host, e.g., x86_64-unknown-linux-musl; repeatable or comma-separated."
+    )]
+    target: Vec<String>,
+    #[arg(
+        long,
+        requires = "target",
+        conflicts_with = "use_cross",
+        help = "Build with `cargo zigbuild`, linking with zig, e.g., for linux-musl and windows-gnu targets."
+    )]
+    use_zigbuild: bool,
+    #[arg(
+        long,
+        requires = "target",
+        help = "Build inside the `cross` containers, e.g., for linux-musl and windows-gnu targets."
+    )]
+    use_cross: bool,
+    #[arg(
+        long,
+        conflicts_with_all = ["feature_powerset", "target"],
+        help = "Build each feature on its own with cargo-hack, instead of all features at once."
+    )]
+    each_feature: bool,
+    #[arg(
+        long,
+        conflicts_with = "target",
+        help = "Build every combination of features with cargo-hack, instead of all features at once."
+    )]
+    feature_powerset: bool,
 }
 
 impl CommandBuild {
     fn run(self) {
-        run_command(make_build_cmd(self.locked));
+        if let Some(features) = feature_matrix(self.each_feature, self.feature_powerset) {
+            run_command(make_build_cmd(self.locked, features));
+        } else if self.target.is_empty() {
+            run_command(make_build_cmd(self.locked, Features::All));
+        } else {
+            cross::cross_build(&self.target, self.locked, self.use_zigbuild, self.use_cross);
+        }
     }
 }
 
@@ -72,11 +345,464 @@ impl CommandBuild {
 struct CommandBootstrap {
     #[arg(long, help = "Clean up the bootstrap scaffolding.")]
     cleanup: bool,
+    #[arg(
+        long,
+        exclusive = true,
+        help = "Undo an in-place bootstrap, restoring the template from its journal."
+    )]
+    undo: bool,
+    #[arg(
+        long,
+        conflicts_with = "cleanup",
+        help = "Scan the workspace for leftover template placeholders, like `cargo x scan`."
+    )]
+    scan: bool,
+    #[arg(
+        long,
+        visible_alias = "output",
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "Create the project in this directory, leaving the template checkout untouched."
+    )]
+    out: Option<PathBuf>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "Preview the changes and choose which files or directories to update."
+    )]
+    diff: bool,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "Print the planned changes as unified diffs without writing anything."
+    )]
+    dry_run: bool,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The project name, instead of prompting for it."
+    )]
+    project_name: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The GitHub user or organization owning the project, instead of prompting for it."
+    )]
+    github_account: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The xtask command alias, instead of prompting for it."
+    )]
+    xtask_alias: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan", "xtask_alias"],
+        help = "Strip the xtask harness, leaving a plain Cargo workspace whose CI runs cargo directly."
+    )]
+    no_xtask: bool,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan", "diff"],
+        help = "Skip all prompts, failing if a required input is not given."
+    )]
+    yes: bool,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan", "diff"],
+        help = "Read the answers from a TOML file and skip all prompts."
+    )]
+    config: Option<PathBuf>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "Skip checking whether the project name is available on crates.io."
+    )]
+    offline: bool,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan", "dry_run"],
+        help = "Skip checking that the bootstrapped workspace builds with `cargo check --workspace`."
+    )]
+    no_verify: bool,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan", "out"],
+        help = "Bootstrap in place even if the git working tree has uncommitted changes."
+    )]
+    allow_dirty: bool,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "undo", "scan"],
+        help = "Overwrite a directory in the way of renaming a template directory, instead of asking what to do."
+    )]
+    force: bool,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan", "out", "dry_run"],
+        help = "Commit the bootstrapped project and set its origin remote, instead of prompting for it."
+    )]
+    commit: bool,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan", "dry_run"],
+        help = "Replace the template's git history with a new repository and commit the project."
+    )]
+    reset_history: bool,
+    #[arg(
+        long,
+        default_value = "main",
+        requires = "reset_history",
+        help = "The default branch of the new repository."
+    )]
+    default_branch: String,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan", "dry_run"],
+        help = "Create the GitHub repository with gh or GITHUB_TOKEN and push the project to it."
+    )]
+    create_repo: bool,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The variant of the template under `templates/` to bootstrap, as name or name@git-ref to read it from another revision, or none, instead of prompting for it."
+    )]
+    template: Option<String>,
+    #[arg(
+        long = "placeholder",
+        value_name = "KEY=VALUE",
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The value of a placeholder of the template variant, instead of prompting for it; may be repeated."
+    )]
+    placeholders: Vec<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The project kind: lib, bin with a clap CLI, or a workspace of `{name}-core` and `{name}-cli`, or proc-macro for a `{name}-macros` derive macro re-exported by `{name}`, instead of prompting for it."
+    )]
+    kind: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The Rust edition: 2024 or 2021, instead of prompting for it."
+    )]
+    edition: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The minimum supported Rust version, as `rust-version`, instead of prompting for it."
+    )]
+    rust_version: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The CI system: github, gitlab, or none, instead of prompting for it."
+    )]
+    ci: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The async runtime the generated crate starts with: none, tokio, or async-std, instead of prompting for it."
+    )]
+    async_runtime: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The error handling of the library: none, or thiserror for an `Error` enum and `Result` alias in `src/error.rs`, instead of prompting for it."
+    )]
+    error_handling: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The feature flags of the library: none, or serde for an optional `serde` feature gating the serialization of an example type, instead of prompting for them."
+    )]
+    feature_flags: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The observability the generated crate starts with: none, or tracing for a subscriber filtered by RUST_LOG and an instrumented example, instead of prompting for it."
+    )]
+    observability: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The benchmarks the generated crate starts with: none, or criterion for a `benches/bench_main.rs` harness run by `cargo x bench`, instead of prompting for it."
+    )]
+    benchmarks: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The files using the library's public API to write, comma-separated: tests for `tests/integration.rs` and examples for `examples/basic.rs`, or none, instead of prompting for them."
+    )]
+    usage_files: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The optional components to remove, comma-separated: benches, examples, fuzz, docs, or release, instead of prompting for them."
+    )]
+    without: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The README badges to leave out, comma-separated: crates, docs, msrv, license, or ci, instead of prompting for them."
+    )]
+    without_badges: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The license: apache-2.0, mit, mit-or-apache-2.0, or mpl-2.0, instead of prompting for it."
+    )]
+    license: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The copyright holder of the license headers, instead of prompting for it."
+    )]
+    copyright_holder: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The community health files to write, comma-separated: contributing, code-of-conduct, security, or none, instead of prompting for them."
+    )]
+    community_files: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The email or URL where conduct violations are reported, instead of prompting for it."
+    )]
+    maintainer_contact: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The email where vulnerabilities are reported, instead of prompting for it."
+    )]
+    security_email: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The GitHub handles of the code owners, comma-separated, instead of prompting for them."
+    )]
+    maintainers: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The sponsorship accounts of `.github/FUNDING.yml` as platform:account, comma-separated, instead of prompting for them."
+    )]
+    funding: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The one-line project description, instead of prompting for it."
+    )]
+    description: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The homepage URL of the package, instead of prompting for it (the GitHub repository by default)."
+    )]
+    homepage: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The documentation URL of the package, instead of prompting for it (docs.rs by default)."
+    )]
+    documentation: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The comma-separated package authors, instead of prompting for them."
+    )]
+    authors: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The comma-separated crates.io keywords, instead of prompting for them."
+    )]
+    keywords: Option<String>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "scan"],
+        help = "The comma-separated crates.io category slugs, instead of prompting for them."
+    )]
+    categories: Option<String>,
+    #[arg(
+        long,
+        default_value = "text",
+        value_parser = ["text", "json"],
+        conflicts_with = "scan",
+        help = "The output format: text, or json to print a summary of every action on stdout, with the progress on stderr."
+    )]
+    format: String,
+    #[arg(
+        long,
+        conflicts_with = "scan",
+        help = "Write the JSON summary to this file, implying --format json but keeping the progress on stdout."
+    )]
+    summary_file: Option<PathBuf>,
+    #[arg(
+        long,
+        conflicts_with_all = ["cleanup", "undo", "scan", "dry_run"],
+        help = "Append the log of every answer, file change, and command to this file instead of `bootstrap.log` at the project root."
+    )]
+    log_file: Option<PathBuf>,
+    #[arg(
+        long,
+        value_parser = ["en", "zh-CN"],
+        conflicts_with = "scan",
+        help = "The language of the prompts and messages, instead of the one of the LANG locale."
+    )]
+    lang: Option<String>,
 }
 
 impl CommandBootstrap {
     fn run(self) {
-        bootstrap::bootstrap(self.cleanup);
+        if self.scan {
+            scan::scan_placeholders();
+        } else {
+            bootstrap::bootstrap(bootstrap::BootstrapOptions {
+                cleanup: self.cleanup,
+                undo: self.undo,
+                allow_dirty: self.allow_dirty,
+                force: self.force,
+                commit: self.commit,
+                reset_history: self.reset_history,
+                default_branch: self.default_branch,
+                create_repo: self.create_repo,
+                out: self.out,
+                diff: self.diff,
+                dry_run: self.dry_run,
+                project_name: self.project_name,
+                github_account: self.github_account,
+                xtask_alias: self.xtask_alias,
+                no_xtask: self.no_xtask,
+                yes: self.yes,
+                config: self.config,
+                offline: self.offline,
+                no_verify: self.no_verify,
+                template: self.template,
+                placeholders: self.placeholders,
+                kind: self.kind,
+                edition: self.edition,
+                rust_version: self.rust_version,
+                ci: self.ci,
+                async_runtime: self.async_runtime,
+                error_handling: self.error_handling,
+                feature_flags: self.feature_flags,
+                observability: self.observability,
+                benchmarks: self.benchmarks,
+                usage_files: self.usage_files,
+                without: self.without,
+                without_badges: self.without_badges,
+                license: self.license,
+                copyright_holder: self.copyright_holder,
+                community_files: self.community_files,
+                maintainer_contact: self.maintainer_contact,
+                security_email: self.security_email,
+                maintainers: self.maintainers,
+                funding: self.funding,
+                description: self.description,
+                homepage: self.homepage,
+                documentation: self.documentation,
+                authors: self.authors,
+                keywords: self.keywords,
+                categories: self.categories,
+                format: self.format,
+                summary_file: self.summary_file,
+                log_file: self.log_file,
+                lang: self.lang,
+            });
+        }
+    }
+}
+
+#[derive(Parser)]
+struct CommandCheckConfig {
+    #[arg(
+        long,
+        help = "Fail on options that the pinned stable toolchain ignores."
+    )]
+    deny_nightly: bool,
+}
+
+impl CommandCheckConfig {
+    fn run(self) {
+        config_check::check_tool_configs(self.deny_nightly);
+    }
+}
+
+#[derive(Parser)]
+struct CommandCheckDeps {}
+
+impl CommandCheckDeps {
+    fn run(self) {
+        check_deps::check_deps();
+    }
+}
+
+#[derive(Parser)]
+struct CommandCheckVersions {}
+
+impl CommandCheckVersions {
+    fn run(self) {
+        check_versions::check_versions();
+    }
+}
+
+#[derive(Parser)]
+struct CommandCross {
+    #[arg(
+        long,
+        required = true,
+        value_delimiter = ',',
+        help = "Comma-separated target triples, e.g., aarch64-unknown-linux-gnu."
+    )]
+    target: Vec<String>,
+    #[arg(
+        long,
+        help = "Run the tests under emulation instead of only building them."
+    )]
+    run: bool,
+    #[arg(
+        long,
+        help = "Use `cross` instead of the local qemu-user and cross linker."
+    )]
+    use_cross: bool,
+}
+
+impl CommandCross {
+    fn run(self) {
+        cross::cross_test(&self.target, self.run, self.use_cross);
+    }
+}
+
+#[derive(Parser)]
+struct CommandDoc {
+    #[arg(
+        long,
+        help = "Serve the docs on localhost and rebuild them on source changes."
+    )]
+    serve: bool,
+    #[arg(long, default_value_t = 8000, help = "The port to serve the docs on.")]
+    port: u16,
+}
+
+impl CommandDoc {
+    fn run(self) {
+        doc::doc(self.serve, self.port);
+    }
+}
+
+#[derive(Parser)]
+struct CommandEnv {
+    #[arg(
+        long,
+        help = "Include the `env` table of the given task from `xtask.toml`."
+    )]
+    task: Option<String>,
+}
+
+impl CommandEnv {
+    fn run(self) {
+        env::print_env(self.task.as_deref());
     }
 }
 
@@ -84,11 +810,756 @@ impl CommandBootstrap {
 struct CommandTest {
     #[arg(long, help = "Run tests serially and do not capture output.")]
     no_capture: bool,
+    #[arg(
+        long,
+        help = "Also compile and run the Rust code blocks in `README.md`."
+    )]
+    readme: bool,
+    #[arg(
+        long,
+        conflicts_with = "feature_powerset",
+        help = "Test each feature on its own with cargo-hack, along with no features and the default ones."
+    )]
+    each_feature: bool,
+    #[arg(long, help = "Test every combination of features with cargo-hack.")]
+    feature_powerset: bool,
 }
 
 impl CommandTest {
     fn run(self) {
-        run_command(make_test_cmd(self.no_capture, &[]));
+        if let Some(features) = feature_matrix(self.each_feature, self.feature_powerset) {
+            run_command(make_test_cmd(self.no_capture, features));
+            if self.readme {
+                readme::test_readme();
+            }
+            return;
+        }
+        run_command(make_test_cmd(self.no_capture, Features::Default));
+        // a crate enabling features by default, like `std`, is also tested without them, and
+        // one with optional features, like `serde`, with them
+        let metadata = metadata::cargo_metadata(true);
+        let packages = metadata::workspace_packages(&metadata);
+        if packages
+            .iter()
+            .any(|package| metadata::has_default_features(package))
+        {
+            run_command(make_test_cmd(self.no_capture, Features::NoDefault));
+        }
+        if packages
+            .iter()
+            .any(|package| metadata::has_optional_features(package))
+        {
+            run_command(make_test_cmd(self.no_capture, Features::All));
+        }
+        if self.readme {
+            readme::test_readme();
+        }
+    }
+}
+
+#[derive(Parser)]
+struct CommandExamples {
+    #[clap(subcommand)]
+    sub: ExamplesSubCommand,
+}
+
+#[derive(Subcommand)]
+enum ExamplesSubCommand {
+    #[clap(about = "Run examples and check their exit codes within a timeout.")]
+    Run(CommandExamplesRun),
+}
+
+impl CommandExamples {
+    fn run(self) {
+        match self.sub {
+            ExamplesSubCommand::Run(cmd) => cmd.run(),
+        }
+    }
+}
+
+#[derive(Parser)]
+struct CommandExamplesRun {
+    #[arg(help = "The examples to run; defaults to all examples in the workspace.")]
+    names: Vec<String>,
+    #[arg(long, help = "Seconds each example may run; overrides `xtask.toml`.")]
+    timeout: Option<u64>,
+}
+
+impl CommandExamplesRun {
+    fn run(self) {
+        examples::run_examples(&self.names, self.timeout);
+    }
+}
+
+#[derive(Parser)]
+struct CommandExpand {
+    #[arg(help = "The module to expand; defaults to the whole crate.")]
+    module: Option<String>,
+    #[arg(long, short, help = "The package containing the module.")]
+    package: Option<String>,
+}
+
+impl CommandExpand {
+    fn run(self) {
+        inspect::expand(self.module.as_deref(), self.package.as_deref());
+    }
+}
+
+#[derive(Parser)]
+struct CommandExportTemplate {
+    #[arg(
+        long,
+        help = "The directory to export to, replaced if it holds an earlier export; defaults to `target/cargo-generate`."
+    )]
+    out: Option<PathBuf>,
+}
+
+impl CommandExportTemplate {
+    fn run(self) {
+        let out = self
+            .out
+            .unwrap_or_else(|| workspace_dir().join("target/cargo-generate"));
+        bootstrap::export_template(&out);
+    }
+}
+
+#[derive(Parser)]
+struct CommandGraph {
+    #[arg(
+        long,
+        default_value = "dot",
+        value_parser = ["dot", "mermaid"],
+        help = "The output format: dot for Graphviz, or mermaid for Markdown renderers like GitHub's."
+    )]
+    format: String,
+    #[arg(
+        long,
+        help = "Also draw the external crates the members depend on directly."
+    )]
+    external: bool,
+}
+
+impl CommandGraph {
+    fn run(self) {
+        graph::graph(&self.format, self.external);
+    }
+}
+
+#[derive(Parser)]
+struct CommandHistory {}
+
+impl CommandHistory {
+    fn run(self) {
+        history::print_summary();
+    }
+}
+
+#[derive(Parser)]
+struct CommandInitWorkspaceSplit {
+    #[arg(
+        long,
+        help = "The base name of the members; defaults to the library's package name."
+    )]
+    name: Option<String>,
+}
+
+impl CommandInitWorkspaceSplit {
+    fn run(self) {
+        split::split_workspace(self.name.as_deref());
+    }
+}
+
+#[derive(Parser)]
+struct CommandLicenses {
+    #[arg(
+        long,
+        help = "Fail if `THIRD-PARTY-NOTICES` is stale instead of writing it."
+    )]
+    check: bool,
+}
+
+impl CommandLicenses {
+    fn run(self) {
+        licenses::generate_notices(self.check);
+    }
+}
+
+#[derive(Parser)]
+struct CommandList {
+    #[arg(
+        long,
+        default_value = "text",
+        value_parser = ["text", "json"],
+        help = "The output format: text, or json to print the members as a JSON array for scripts."
+    )]
+    format: String,
+}
+
+impl CommandList {
+    fn run(self) {
+        list::list(self.format == "json");
+    }
+}
+
+#[derive(Parser)]
+struct CommandLock {
+    #[clap(subcommand)]
+    sub: LockSubCommand,
+}
+
+#[derive(Subcommand)]
+enum LockSubCommand {
+    #[clap(about = "Check that `Cargo.lock` is in sync and has no unreachable entries.")]
+    Check(CommandLockCheck),
+    #[clap(about = "Rewrite `Cargo.lock` to match the manifests.")]
+    Prune(CommandLockPrune),
+    #[clap(about = "Show dependency changes in `Cargo.lock`, failing on license changes.")]
+    Diff(CommandLockDiff),
+}
+
+impl CommandLock {
+    fn run(self) {
+        match self.sub {
+            LockSubCommand::Check(cmd) => cmd.run(),
+            LockSubCommand::Prune(cmd) => cmd.run(),
+            LockSubCommand::Diff(cmd) => cmd.run(),
+        }
+    }
+}
+
+#[derive(Parser)]
+struct CommandLockCheck {}
+
+impl CommandLockCheck {
+    fn run(self) {
+        lock::check_lockfile();
+    }
+}
+
+#[derive(Parser)]
+struct CommandLockPrune {
+    #[arg(
+        long,
+        help = "Regenerate the lockfile from scratch instead of keeping the locked versions."
+    )]
+    regenerate: bool,
+}
+
+impl CommandLockPrune {
+    fn run(self) {
+        lock::prune_lockfile(self.regenerate);
+    }
+}
+
+#[derive(Parser)]
+struct CommandLockDiff {
+    #[arg(
+        long,
+        default_value = "origin/main",
+        help = "The base revision to compare against."
+    )]
+    since: String,
+}
+
+impl CommandLockDiff {
+    fn run(self) {
+        lock::diff_lockfile(&self.since);
+    }
+}
+
+#[derive(Parser)]
+struct CommandMaturin {
+    #[clap(subcommand)]
+    sub: MaturinSubCommand,
+}
+
+#[derive(Subcommand)]
+enum MaturinSubCommand {
+    #[clap(about = "Build the Python packages and install them into the current virtualenv.")]
+    Develop(CommandMaturinDevelop),
+    #[clap(about = "Build the wheels of the Python packages into `target/wheels/`.")]
+    Build(CommandMaturinBuild),
+    #[clap(about = "Build the wheels of the Python packages and upload them to PyPI.")]
+    Publish(CommandMaturinPublish),
+}
+
+impl CommandMaturin {
+    fn run(self) {
+        match self.sub {
+            MaturinSubCommand::Develop(cmd) => cmd.run(),
+            MaturinSubCommand::Build(cmd) => cmd.run(),
+            MaturinSubCommand::Publish(cmd) => cmd.run(),
+        }
+    }
+}
+
+#[derive(Parser)]
+struct CommandMaturinDevelop {
+    #[arg(long, help = "Build with optimizations.")]
+    release: bool,
+}
+
+impl CommandMaturinDevelop {
+    fn run(self) {
+        let mut args = vec!["develop"];
+        if self.release {
+            args.push("--release");
+        }
+        maturin::run_maturin(&args);
+    }
+}
+
+#[derive(Parser)]
+struct CommandMaturinBuild {
+    #[arg(long, help = "Build with optimizations.")]
+    release: bool,
+    #[arg(long, help = "Also build the source distribution.")]
+    sdist: bool,
+}
+
+impl CommandMaturinBuild {
+    fn run(self) {
+        let mut args = vec!["build"];
+        if self.release {
+            args.push("--release");
+        }
+        if self.sdist {
+            args.push("--sdist");
+        }
+        maturin::run_maturin(&args);
+    }
+}
+
+#[derive(Parser)]
+struct CommandMaturinPublish {
+    #[arg(
+        long,
+        help = "Upload to this package index instead of PyPI, e.g., `testpypi`."
+    )]
+    repository: Option<String>,
+}
+
+impl CommandMaturinPublish {
+    fn run(self) {
+        let mut args = vec!["publish"];
+        if let Some(repository) = &self.repository {
+            args.extend(["--repository", repository]);
+        }
+        maturin::run_maturin(&args);
+    }
+}
+
+#[derive(Parser)]
+struct CommandMsrv {
+    #[clap(subcommand)]
+    sub: MsrvSubCommand,
+}
+
+#[derive(Subcommand)]
+enum MsrvSubCommand {
+    #[clap(about = "Check that the workspace builds with the declared `rust-version`.")]
+    Verify(CommandMsrvVerify),
+    #[clap(about = "Bisect Rust releases to find the oldest one the workspace builds with.")]
+    Find(CommandMsrvFind),
+}
+
+impl CommandMsrv {
+    fn run(self) {
+        match self.sub {
+            MsrvSubCommand::Verify(cmd) => cmd.run(),
+            MsrvSubCommand::Find(cmd) => cmd.run(),
+        }
+    }
+}
+
+#[derive(Parser)]
+struct CommandMsrvVerify {}
+
+impl CommandMsrvVerify {
+    fn run(self) {
+        msrv::verify_msrv();
+    }
+}
+
+#[derive(Parser)]
+struct CommandMsrvFind {
+    #[arg(long, help = "The oldest Rust version to consider, e.g., 1.70.0.")]
+    min: Option<String>,
+    #[arg(
+        long,
+        help = "Write the result to `rust-version` in the workspace manifest."
+    )]
+    write: bool,
+}
+
+impl CommandMsrvFind {
+    fn run(self) {
+        msrv::find_msrv(self.min, self.write);
+    }
+}
+
+#[derive(Parser)]
+struct CommandMutants {
+    #[arg(
+        long,
+        help = "Only mutate code changed since this revision, e.g., `origin/main`."
+    )]
+    in_diff: Option<String>,
+    #[arg(
+        long,
+        short,
+        help = "Only mutate these packages; defaults to all members."
+    )]
+    package: Vec<String>,
+    #[arg(
+        long,
+        help = "The seconds each test run may take before it counts as a timeout."
+    )]
+    timeout: Option<u64>,
+}
+
+impl CommandMutants {
+    fn run(self) {
+        mutants::mutants(mutants::MutantsOptions {
+            in_diff: self.in_diff,
+            packages: self.package,
+            timeout: self.timeout,
+        });
+    }
+}
+
+#[derive(Parser)]
+struct CommandNewCrate {
+    #[arg(help = "The package name of the member, also its directory under `crates/`.")]
+    name: String,
+    #[arg(
+        long,
+        default_value = "lib",
+        value_parser = ["lib", "bin"],
+        help = "Whether the member is a library or a binary."
+    )]
+    kind: String,
+}
+
+impl CommandNewCrate {
+    fn run(self) {
+        new_crate::new_crate(&self.name, self.kind == "bin");
+    }
+}
+
+#[derive(Parser)]
+struct CommandRemoveCrate {
+    #[arg(help = "The package name of the member.")]
+    name: String,
+    #[arg(
+        long,
+        help = "Remove the member even if other members still depend on it."
+    )]
+    force: bool,
+}
+
+impl CommandRemoveCrate {
+    fn run(self) {
+        remove_crate::remove_crate(&self.name, self.force);
+    }
+}
+
+#[derive(Parser)]
+struct CommandRenameCrate {
+    #[arg(help = "The package name of the member.")]
+    old: String,
+    #[arg(help = "The new package name.")]
+    new: String,
+}
+
+impl CommandRenameCrate {
+    fn run(self) {
+        rename_crate::rename_crate(&self.old, &self.new);
+    }
+}
+
+#[derive(Parser)]
+struct CommandCbindgen {
+    #[arg(long, help = "Fail if a header is stale instead of writing it.")]
+    check: bool,
+}
+
+impl CommandCbindgen {
+    fn run(self) {
+        cbindgen::cbindgen(self.check);
+    }
+}
+
+#[derive(Parser)]
+struct CommandChangelog {
+    #[clap(subcommand)]
+    sub: ChangelogSubCommand,
+}
+
+#[derive(Subcommand)]
+enum ChangelogSubCommand {
+    #[clap(about = "Move the unreleased changes under a section for the released version.")]
+    Release(CommandChangelogRelease),
+}
+
+impl CommandChangelog {
+    fn run(self) {
+        match self.sub {
+            ChangelogSubCommand::Release(cmd) => {
+                changelog::release_changelog(&cmd.version, cmd.date.as_deref())
+            }
+        }
+    }
+}
+
+#[derive(Parser)]
+struct CommandChangelogRelease {
+    #[arg(help = "The released version, e.g., `0.2.0`, tagged as `v0.2.0`.")]
+    version: String,
+    #[arg(long, help = "The release date, as `YYYY-MM-DD`, instead of today.")]
+    date: Option<String>,
+}
+
+#[derive(Parser)]
+struct CommandReadme {
+    #[clap(subcommand)]
+    sub: ReadmeSubCommand,
+}
+
+#[derive(Subcommand)]
+enum ReadmeSubCommand {
+    #[clap(about = "Render the crate-level docs into the marked section of `README.md`.")]
+    Sync(CommandReadmeSync),
+    #[clap(about = "Fail if `README.md` is out of sync with the crate-level docs.")]
+    Check(CommandReadmeSync),
+}
+
+impl CommandReadme {
+    fn run(self) {
+        match self.sub {
+            ReadmeSubCommand::Sync(cmd) => readme::sync_readme(cmd.package.as_deref(), false),
+            ReadmeSubCommand::Check(cmd) => readme::sync_readme(cmd.package.as_deref(), true),
+        }
+    }
+}
+
+#[derive(Parser)]
+struct CommandReadmeSync {
+    #[arg(long, short, help = "The package whose crate-level docs to render.")]
+    package: Option<String>,
+}
+
+#[derive(Parser)]
+struct CommandRunTask {
+    #[arg(help = "The name of the task to run.")]
+    name: String,
+}
+
+impl CommandRunTask {
+    fn run(self) {
+        tasks::run_task(&self.name);
+    }
+}
+
+#[derive(Parser)]
+struct CommandScan {}
+
+impl CommandScan {
+    fn run(self) {
+        scan::scan_placeholders();
+    }
+}
+
+#[derive(Parser)]
+struct CommandSecrets {
+    #[arg(
+        long,
+        default_value_t = 50,
+        help = "Number of recent commits to scan; 0 scans the working tree only."
+    )]
+    history: usize,
+}
+
+impl CommandSecrets {
+    fn run(self) {
+        secrets::scan_secrets(self.history);
+    }
+}
+
+#[derive(Parser)]
+struct CommandSizeDiff {
+    #[arg(
+        long,
+        default_value = "origin/main",
+        help = "The base revision to compare against."
+    )]
+    since: String,
+    #[arg(
+        long,
+        help = "Also write the markdown report to this file, e.g., for a PR comment."
+    )]
+    output: Option<PathBuf>,
+}
+
+impl CommandSizeDiff {
+    fn run(self) {
+        size_diff::size_diff(&self.since, self.output);
+    }
+}
+
+#[derive(Parser)]
+struct CommandSmoke {
+    #[arg(
+        long,
+        value_delimiter = ',',
+        default_value = "ubuntu:24.04,alpine:3.20",
+        help = "Comma-separated container images; Alpine images get a musl build."
+    )]
+    image: Vec<String>,
+    #[arg(
+        long,
+        help = "The binary to test; defaults to the only one in the workspace."
+    )]
+    bin: Option<String>,
+    #[arg(
+        long,
+        default_value = "{bin} --version",
+        help = "The shell command to run in each container; `{bin}` expands to the binary name."
+    )]
+    command: String,
+}
+
+impl CommandSmoke {
+    fn run(self) {
+        smoke::smoke_test(&self.image, self.bin, &self.command);
+    }
+}
+
+#[derive(Parser)]
+struct CommandTasks {}
+
+impl CommandTasks {
+    fn run(self) {
+        tasks::list_tasks();
+    }
+}
+
+#[derive(Parser)]
+struct CommandTypos {
+    #[clap(subcommand)]
+    sub: TyposSubCommand,
+}
+
+#[derive(Subcommand)]
+enum TyposSubCommand {
+    #[clap(about = "Accept a word that typos reports as a false positive.")]
+    AddWord(CommandTyposAddWord),
+    #[clap(about = "List the accepted words and identifiers.")]
+    List(CommandTyposList),
+}
+
+impl CommandTypos {
+    fn run(self) {
+        match self.sub {
+            TyposSubCommand::AddWord(cmd) => typos::add_word(&cmd.word, cmd.identifier),
+            TyposSubCommand::List(_) => typos::list_words(),
+        }
+    }
+}
+
+#[derive(Parser)]
+struct CommandTyposAddWord {
+    #[arg(help = "The word to accept.")]
+    word: String,
+    #[arg(long, help = "Accept the word as a whole identifier only.")]
+    identifier: bool,
+}
+
+#[derive(Parser)]
+struct CommandTyposList {}
+
+#[derive(Parser)]
+struct CommandUnsafeReport {
+    #[arg(long, help = "Write the current counts to `unsafe-baseline.toml`.")]
+    update: bool,
+}
+
+impl CommandUnsafeReport {
+    fn run(self) {
+        unsafe_report::unsafe_report(self.update);
+    }
+}
+
+#[derive(Parser)]
+struct CommandVerifyPackage {}
+
+impl CommandVerifyPackage {
+    fn run(self) {
+        package::verify_packages();
+    }
+}
+
+#[derive(Parser)]
+struct CommandWasm {
+    #[clap(subcommand)]
+    sub: WasmSubCommand,
+}
+
+#[derive(Subcommand)]
+enum WasmSubCommand {
+    #[clap(about = "Build the npm packages of the WebAssembly members into their `pkg/`.")]
+    Build(CommandWasmBuild),
+    #[clap(about = "Run the tests of the WebAssembly members in a headless browser.")]
+    Test(CommandWasmTest),
+}
+
+impl CommandWasm {
+    fn run(self) {
+        match self.sub {
+            WasmSubCommand::Build(cmd) => cmd.run(),
+            WasmSubCommand::Test(cmd) => cmd.run(),
+        }
+    }
+}
+
+#[derive(Parser)]
+struct CommandWasmBuild {
+    #[arg(long, help = "Build without optimizations.")]
+    dev: bool,
+    #[arg(
+        long,
+        default_value = "bundler",
+        value_parser = ["bundler", "web", "nodejs", "no-modules", "deno"],
+        help = "The JavaScript environment the packages target."
+    )]
+    target: String,
+}
+
+impl CommandWasmBuild {
+    fn run(self) {
+        let mut args = vec!["build", "--target", &self.target];
+        if self.dev {
+            args.push("--dev");
+        }
+        wasm::run_wasm_pack(&args);
+    }
+}
+
+#[derive(Parser)]
+struct CommandWasmTest {
+    #[arg(
+        long,
+        default_value = "firefox",
+        value_parser = ["firefox", "chrome", "safari"],
+        help = "The browser to run the tests in."
+    )]
+    browser: String,
+}
+
+impl CommandWasmTest {
+    fn run(self) {
+        let browser = format!("--{}", self.browser);
+        wasm::run_wasm_pack(&["test", "--headless", &browser]);
     }
 }
 
@@ -97,6 +1568,11 @@ impl CommandTest {
 struct CommandLint {
     #[arg(long, help = "Automatically apply available lint and format fixes.")]
     fix: bool,
+    #[arg(
+        long,
+        help = "Additionally run the checks that require a nightly toolchain."
+    )]
+    nightly: bool,
 }
 
 impl CommandLint {
@@ -105,7 +1581,14 @@ impl CommandLint {
         run_command(make_format_cmd(self.fix));
         run_command(make_taplo_cmd(self.fix));
         run_command(make_typos_cmd());
-        run_command(make_hawkeye_cmd(self.fix));
+        headers::check_headers(self.fix);
+        check_deps::check_deps();
+        check_versions::check_versions();
+        if self.nightly {
+            run_command(make_nightly_format_cmd());
+            run_command(make_nightly_doc_cmd());
+            run_command(make_nightly_check_cfg_cmd());
+        }
     }
 }
 
@@ -113,17 +1596,38 @@ fn find_command(cmd: &str) -> StdCommand {
     match which::which(cmd) {
         Ok(exe) => {
             let mut cmd = StdCommand::new(exe);
-            cmd.current_dir(workspace_dir());
+            cmd.current_dir(workspace_dir()).envs(env::dotenv_vars());
             cmd
         }
         Err(err) => {
-            panic!("{cmd} not found: {err}");
+            history::fail(&format!("{cmd} not found: {err}"));
         }
     }
 }
 
+/// Returns a cargo command that runs with the selected toolchain; see [`toolchain::selected`].
+fn find_cargo() -> StdCommand {
+    let mut cmd = find_command("cargo");
+    if let Some(toolchain) = toolchain::selected() {
+        cmd.arg(format!("+{toolchain}"));
+    }
+    cmd
+}
+
+/// Returns a `rustup target add` command for the selected toolchain.
+fn make_target_add_cmd(target: &str) -> StdCommand {
+    let mut cmd = find_command("rustup");
+    cmd.args(["target", "add", target]);
+    if let Some(toolchain) = toolchain::selected() {
+        cmd.args(["--toolchain", &toolchain]);
+    }
+    cmd
+}
+
 fn ensure_installed(bin: &str, crate_name: &str) {
-    if which::which(bin).is_err() {
+    let installed = which::which(bin).is_ok();
+    history::record_cache(installed);
+    if !installed {
         let mut cmd = find_command("cargo");
         cmd.args(["install", crate_name]);
         run_command(cmd);
@@ -133,32 +1637,88 @@ fn ensure_installed(bin: &str, crate_name: &str) {
 fn run_command(mut cmd: StdCommand) {
     println!("{cmd:?}");
     let status = cmd.status().expect("failed to execute process");
-    assert!(status.success(), "command failed: {status}");
+    if !status.success() {
+        history::fail(&format!("command failed: {status}"));
+    }
 }
 
-fn make_build_cmd(locked: bool) -> StdCommand {
-    let mut cmd = find_command("cargo");
-    cmd.args([
-        "build",
-        "--workspace",
-        "--all-features",
-        "--tests",
-        "--examples",
-        "--benches",
-        "--bins",
-    ]);
+fn make_build_cmd(locked: bool, features: Features) -> StdCommand {
+    let mut cmd = find_cargo();
+    if features.is_matrix() {
+        cmd.arg("hack");
+    }
+    cmd.arg("build");
+    add_build_args(&mut cmd, locked, features);
+    cmd
+}
+
+/// Adds the arguments of `cargo x build` to a build command, such as `cargo zigbuild`.
+fn add_build_args(cmd: &mut StdCommand, locked: bool, features: Features) {
+    cmd.arg("--workspace");
+    features.add_args(cmd);
+    cmd.args(["--tests", "--examples", "--benches", "--bins"]);
     if locked {
         cmd.arg("--locked");
     }
-    cmd
 }
 
-fn make_test_cmd(no_capture: bool, features: &[&str]) -> StdCommand {
-    let mut cmd = find_command("cargo");
-    cmd.args(["test", "--workspace", "--no-default-features"]);
-    if !features.is_empty() {
-        cmd.args(["--features", features.join(",").as_str()]);
+/// The features the workspace is built or tested with by a pass of `cargo x build` or `cargo x
+/// test`.
+#[derive(Clone, Copy)]
+enum Features {
+    Default,
+    NoDefault,
+    All,
+    /// Each feature on its own, as cargo-hack's `--each-feature`.
+    EachFeature,
+    /// Every combination of features, as cargo-hack's `--feature-powerset`.
+    Powerset,
+}
+
+impl Features {
+    /// Returns whether the pass runs through cargo-hack.
+    fn is_matrix(self) -> bool {
+        matches!(self, Features::EachFeature | Features::Powerset)
+    }
+
+    fn add_args(self, cmd: &mut StdCommand) {
+        match self {
+            Features::Default => {}
+            Features::NoDefault => {
+                cmd.arg("--no-default-features");
+            }
+            Features::All => {
+                cmd.arg("--all-features");
+            }
+            Features::EachFeature => {
+                cmd.arg("--each-feature");
+            }
+            Features::Powerset => {
+                cmd.arg("--feature-powerset");
+            }
+        }
+    }
+}
+
+/// Returns the feature matrix asked for with `--each-feature` or `--feature-powerset`,
+/// installing cargo-hack to run it.
+fn feature_matrix(each_feature: bool, feature_powerset: bool) -> Option<Features> {
+    let features = match (each_feature, feature_powerset) {
+        (true, _) => Features::EachFeature,
+        (_, true) => Features::Powerset,
+        _ => return None,
+    };
+    ensure_installed("cargo-hack", "cargo-hack");
+    Some(features)
+}
+
+fn make_test_cmd(no_capture: bool, features: Features) -> StdCommand {
+    let mut cmd = find_cargo();
+    if features.is_matrix() {
+        cmd.arg("hack");
     }
+    cmd.args(["test", "--workspace"]);
+    features.add_args(&mut cmd);
     if no_capture {
         cmd.args(["--", "--nocapture"]);
     }
@@ -174,6 +1734,47 @@ fn make_format_cmd(fix: bool) -> StdCommand {
     cmd
 }
 
+fn make_nightly_format_cmd() -> StdCommand {
+    let mut cmd = find_command("cargo");
+    cmd.args([
+        "+nightly",
+        "fmt",
+        "--all",
+        "--check",
+        "--",
+        "--config",
+        "error_on_unformatted=true",
+    ]);
+    cmd
+}
+
+fn make_nightly_doc_cmd() -> StdCommand {
+    let mut cmd = find_command("cargo");
+    cmd.args([
+        "+nightly",
+        "doc",
+        "--workspace",
+        "--all-features",
+        "--no-deps",
+    ]);
+    // the same cfg docs.rs sets, which enables `doc_cfg` in the crates
+    cmd.env("RUSTDOCFLAGS", "--cfg docsrs -D warnings");
+    cmd
+}
+
+fn make_nightly_check_cfg_cmd() -> StdCommand {
+    let mut cmd = find_command("cargo");
+    cmd.args([
+        "+nightly",
+        "check",
+        "--workspace",
+        "--all-features",
+        "--all-targets",
+    ]);
+    cmd.env("RUSTFLAGS", "-D unexpected_cfgs");
+    cmd
+}
+
 fn make_clippy_cmd(fix: bool) -> StdCommand {
     let mut cmd = find_command("cargo");
     cmd.args([
@@ -220,6 +1821,16 @@ fn make_taplo_cmd(fix: bool) -> StdCommand {
 }
 
 fn main() {
-    let cmd = Command::parse();
-    cmd.run()
+    let matches = Command::command().get_matches();
+    let subcommand = matches.subcommand_name().unwrap_or_default().to_owned();
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(index) = args.iter().position(|arg| *arg == subcommand) {
+        args.remove(index);
+    }
+    issue::install_panic_hook(subcommand.clone(), args.clone());
+    history::start(&subcommand, args);
+
+    let cmd = Command::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+    cmd.run();
+    history::finish(true);
 }
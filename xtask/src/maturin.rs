@@ -0,0 +1,53 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use super::ensure_installed;
+use super::find_command;
+use super::history;
+use super::metadata;
+use super::run_command;
+
+/// The Python project of a member, next to its manifest.
+const PYPROJECT_FILE: &str = "pyproject.toml";
+
+/// Runs maturin with `args`, e.g., `develop --release`, for every workspace member with a
+/// `pyproject.toml`, in its directory.
+pub fn run_maturin(args: &[&str]) {
+    let metadata = metadata::cargo_metadata(true);
+    let dirs: Vec<PathBuf> = metadata::workspace_packages(&metadata)
+        .into_iter()
+        .filter_map(|package| {
+            let manifest = Path::new(package["manifest_path"].as_str()?);
+            let dir = manifest.parent()?;
+            dir.join(PYPROJECT_FILE)
+                .is_file()
+                .then(|| dir.to_path_buf())
+        })
+        .collect();
+    if dirs.is_empty() {
+        eprintln!("No workspace member has a {PYPROJECT_FILE} to build with maturin.");
+        history::exit(1);
+    }
+
+    ensure_installed("maturin", "maturin");
+    for dir in dirs {
+        let mut cmd = find_command("maturin");
+        cmd.current_dir(dir);
+        cmd.args(args);
+        run_command(cmd);
+    }
+}
@@ -0,0 +1,156 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use serde_json::Value;
+
+use super::find_cargo;
+
+/// The dependency tables of a manifest, which may also be nested under `target.<cfg>`.
+pub const DEPENDENCY_TABLES: &[&str] = &["dependencies", "dev-dependencies", "build-dependencies"];
+
+/// Runs `cargo metadata` for the workspace and returns the parsed output.
+///
+/// When `no_deps` is true, only workspace members are reported and no dependency graph is
+/// resolved.
+pub fn cargo_metadata(no_deps: bool) -> Value {
+    let mut cmd = find_cargo();
+    cmd.args(["metadata", "--format-version", "1"]);
+    if no_deps {
+        cmd.arg("--no-deps");
+    }
+    let output = cmd.output().expect("failed to execute cargo metadata");
+    assert!(
+        output.status.success(),
+        "cargo metadata failed: {}\n{}",
+        output.status,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_slice(&output.stdout).expect("failed to parse cargo metadata output")
+}
+
+/// Returns the packages of the workspace members.
+pub fn workspace_packages(metadata: &Value) -> Vec<&Value> {
+    let members = metadata["workspace_members"].as_array().unwrap();
+    metadata["packages"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|package| members.contains(&package["id"]))
+        .collect()
+}
+
+/// Returns the workspace `packages` other than the xtask and the members it depends on by path,
+/// like the rename engine, which the member commands leave alone.
+pub fn non_tooling_members<'a>(packages: &[&'a Value]) -> Vec<&'a Value> {
+    let xtask = env!("CARGO_PKG_NAME");
+    let tooling: Vec<&str> = packages
+        .iter()
+        .filter(|package| package["name"] == xtask)
+        .flat_map(|package| package["dependencies"].as_array().into_iter().flatten())
+        .filter(|dep| dep["path"].is_string())
+        .filter_map(|dep| dep["name"].as_str())
+        .chain([xtask])
+        .collect();
+    packages
+        .iter()
+        .copied()
+        .filter(|package| !tooling.iter().any(|name| package["name"] == *name))
+        .collect()
+}
+
+/// Returns whether the package enables features by default, e.g., the `std` feature of a
+/// `#![no_std]` library.
+pub fn has_default_features(package: &Value) -> bool {
+    package["features"]["default"]
+        .as_array()
+        .is_some_and(|features| !features.is_empty())
+}
+
+/// Returns whether the package has features its default features leave out, e.g., an optional
+/// `serde` feature.
+pub fn has_optional_features(package: &Value) -> bool {
+    let Some(features) = package["features"].as_object() else {
+        return false;
+    };
+    // the features enabled by default, including those the default ones enable
+    let mut enabled = vec!["default"];
+    let mut index = 0;
+    while let Some(name) = enabled.get(index) {
+        let implied = features.get(*name).and_then(Value::as_array);
+        for feature in implied.into_iter().flatten() {
+            let feature = feature.as_str().unwrap_or_default();
+            if features.contains_key(feature) && !enabled.contains(&feature) {
+                enabled.push(feature);
+            }
+        }
+        index += 1;
+    }
+    features
+        .keys()
+        .any(|name| !enabled.contains(&name.as_str()))
+}
+
+/// Returns whether the package can be published, i.e., it does not set `publish = false`.
+pub fn is_publishable(package: &Value) -> bool {
+    package["publish"]
+        .as_array()
+        .is_none_or(|registries| !registries.is_empty())
+}
+
+/// Collects the Rust sources under `dir`, except in the directories named in `skipped`, e.g.,
+/// the build output in `target`.
+pub fn collect_sources(dir: &Path, skipped: &[&str], files: &mut Vec<PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries {
+        let entry = entry.unwrap();
+        let path = entry.path();
+        let file_type = entry.file_type().unwrap();
+        if file_type.is_dir() {
+            if !skipped.contains(&entry.file_name().to_str().unwrap_or("")) {
+                collect_sources(&path, skipped, files);
+            }
+        } else if file_type.is_file() && path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_features() {
+        let package = json!({ "features": {} });
+        assert!(!has_default_features(&package));
+        assert!(!has_optional_features(&package));
+
+        let package = json!({
+            "features": { "default": ["std"], "std": ["alloc"], "alloc": [] }
+        });
+        assert!(has_default_features(&package));
+        assert!(!has_optional_features(&package));
+
+        let package = json!({ "features": { "serde": ["dep:serde"] } });
+        assert!(!has_default_features(&package));
+        assert!(has_optional_features(&package));
+    }
+}
@@ -0,0 +1,165 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::process::Stdio;
+
+use toml_edit::DocumentMut;
+
+use super::find_command;
+use super::history;
+use super::run_command;
+use super::workspace_dir;
+
+/// Verifies that the workspace builds with the declared `rust-version`.
+pub fn verify_msrv() {
+    let rust_version = read_workspace_package_field("rust-version")
+        .unwrap_or_else(|| history::fail("`rust-version` not found in [workspace.package]"));
+    println!("Verifying MSRV {rust_version}...");
+    assert!(
+        check_with_toolchain(&rust_version),
+        "the workspace does not build with its declared MSRV {rust_version}"
+    );
+    println!("The workspace builds with Rust {rust_version}.");
+}
+
+/// Bisects stable Rust releases to find the oldest one the workspace builds with.
+///
+/// The search starts from `min` if given, otherwise from the first release supporting the
+/// workspace edition. With `write`, the result is written to `[workspace.package]`.
+pub fn find_msrv(min: Option<String>, write: bool) {
+    let lower = match min {
+        Some(min) => parse_minor(&min)
+            .unwrap_or_else(|| history::fail(&format!("invalid Rust version: {min}"))),
+        None => {
+            let edition = read_workspace_package_field("edition").unwrap_or("2015".to_owned());
+            edition_minor(&edition)
+        }
+    };
+    let upper = stable_minor();
+    println!("Bisecting Rust 1.{lower}.0 to 1.{upper}.0...");
+
+    let found = bisect(lower, upper, |minor| {
+        let version = format!("1.{minor}.0");
+        let ok = check_with_toolchain(&version);
+        println!("Rust {version}: {}", if ok { "OK" } else { "FAILED" });
+        ok
+    });
+    let Some(minor) = found else {
+        panic!("the workspace does not build with any Rust release up to 1.{upper}.0");
+    };
+    let msrv = format!("1.{minor}.0");
+    println!("MSRV: {msrv}");
+
+    if write {
+        let file = workspace_dir().join("Cargo.toml");
+        let content = std::fs::read_to_string(&file).unwrap();
+        let mut doc = content.parse::<DocumentMut>().unwrap();
+        doc["workspace"]["package"]["rust-version"] = toml_edit::value(msrv.as_str());
+        std::fs::write(&file, doc.to_string()).unwrap();
+        println!("Updated `rust-version` in {}.", file.display());
+    }
+}
+
+fn read_workspace_package_field(field: &str) -> Option<String> {
+    let content = std::fs::read_to_string(workspace_dir().join("Cargo.toml")).unwrap();
+    let doc = content.parse::<DocumentMut>().unwrap();
+    let value = doc.get("workspace")?.get("package")?.get(field)?.as_str()?;
+    Some(value.to_owned())
+}
+
+/// Returns the minor version of the first release supporting the given edition.
+fn edition_minor(edition: &str) -> u32 {
+    match edition {
+        "2024" => 85,
+        "2021" => 56,
+        "2018" => 31,
+        _ => 0,
+    }
+}
+
+fn parse_minor(version: &str) -> Option<u32> {
+    let mut parts = version.trim().split('.');
+    if parts.next()? != "1" {
+        return None;
+    }
+    parts.next()?.parse().ok()
+}
+
+fn stable_minor() -> u32 {
+    let mut cmd = find_command("rustc");
+    cmd.args(["+stable", "--version"]);
+    let output = cmd.output().expect("failed to execute rustc");
+    assert!(output.status.success(), "{cmd:?} failed: {}", output.status);
+    // e.g., "rustc 1.85.0 (4d91de4e4 2025-02-17)"
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout
+        .split_whitespace()
+        .nth(1)
+        .and_then(parse_minor)
+        .unwrap_or_else(|| panic!("unexpected rustc version output: {stdout}"))
+}
+
+fn check_with_toolchain(version: &str) -> bool {
+    let mut install = find_command("rustup");
+    install.args(["toolchain", "install", version, "--profile", "minimal"]);
+    run_command(install);
+
+    let mut cmd = find_command("cargo");
+    cmd.arg(format!("+{version}"))
+        .args(["check", "--workspace", "--all-features", "--all-targets"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    println!("{cmd:?}");
+    cmd.status().expect("failed to execute process").success()
+}
+
+/// Returns the smallest value in `lower..=upper` for which `passes` holds, assuming that once it
+/// holds it keeps holding for larger values.
+fn bisect(lower: u32, upper: u32, mut passes: impl FnMut(u32) -> bool) -> Option<u32> {
+    if lower > upper || !passes(upper) {
+        return None;
+    }
+    let (mut lo, mut hi) = (lower, upper);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if passes(mid) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    Some(lo)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_minor() {
+        assert_eq!(parse_minor("1.85.0"), Some(85));
+        assert_eq!(parse_minor("1.85"), Some(85));
+        assert_eq!(parse_minor("2.0.0"), None);
+        assert_eq!(parse_minor("stable"), None);
+    }
+
+    #[test]
+    fn test_bisect() {
+        assert_eq!(bisect(56, 99, |minor| minor >= 70), Some(70));
+        assert_eq!(bisect(56, 99, |_| true), Some(56));
+        assert_eq!(bisect(56, 99, |minor| minor >= 99), Some(99));
+        assert_eq!(bisect(56, 99, |_| false), None);
+        assert_eq!(bisect(90, 85, |_| true), None);
+    }
+}
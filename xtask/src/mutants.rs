@@ -0,0 +1,208 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use super::ensure_installed;
+use super::find_cargo;
+use super::find_command;
+use super::history;
+use super::metadata;
+use super::workspace_dir;
+
+/// Where cargo-mutants writes `mutants.out/`, relative to the workspace.
+const OUTPUT_DIR: &str = "target/xtask/mutants";
+
+/// The outcome files of cargo-mutants and how they are labeled in the summary.
+const OUTCOMES: &[(&str, &str)] = &[
+    ("caught.txt", "caught"),
+    ("missed.txt", "missed"),
+    ("timeout.txt", "timeout"),
+    ("unviable.txt", "unviable"),
+];
+
+/// How to scope and bound a mutation testing run.
+pub struct MutantsOptions {
+    pub in_diff: Option<String>,
+    pub packages: Vec<String>,
+    pub timeout: Option<u64>,
+}
+
+/// Runs cargo-mutants over the workspace members, except xtask, and prints the outcomes per
+/// crate.
+///
+/// With `in_diff`, only code changed since that revision is mutated, which keeps pull request
+/// runs short; `timeout` bounds each test run in seconds.
+pub fn mutants(options: MutantsOptions) {
+    ensure_installed("cargo-mutants", "cargo-mutants");
+    let output_dir = workspace_dir().join(OUTPUT_DIR);
+    std::fs::create_dir_all(&output_dir).unwrap();
+
+    let mut cmd = find_cargo();
+    cmd.args(["mutants", "--exclude", "xtask/**", "--output"])
+        .arg(&output_dir);
+    if options.packages.is_empty() {
+        cmd.arg("--workspace");
+    }
+    for package in &options.packages {
+        cmd.args(["--package", package]);
+    }
+    if let Some(timeout) = options.timeout {
+        cmd.args(["--timeout", &timeout.to_string()]);
+    }
+    if let Some(base) = &options.in_diff {
+        let diff_file = output_dir.join("in-diff.patch");
+        let mut diff = find_command("git");
+        diff.args(["diff", &format!("{base}...HEAD")]);
+        let output = diff.output().expect("failed to execute git");
+        assert!(
+            output.status.success(),
+            "{diff:?} failed: {}",
+            output.status
+        );
+        std::fs::write(&diff_file, output.stdout).unwrap();
+        cmd.arg("--in-diff").arg(&diff_file);
+    }
+
+    println!("{cmd:?}");
+    let status = cmd.status().expect("failed to execute cargo mutants");
+
+    let results_dir = output_dir.join("mutants.out");
+    let metadata = metadata::cargo_metadata(true);
+    let crate_dirs: Vec<(String, String)> = metadata::workspace_packages(&metadata)
+        .iter()
+        .map(|p| {
+            let manifest = Path::new(p["manifest_path"].as_str().unwrap());
+            let dir = manifest.parent().unwrap();
+            let relative = dir.strip_prefix(workspace_dir()).unwrap_or(dir);
+            let name = p["name"].as_str().unwrap().to_owned();
+            (relative.to_string_lossy().into_owned(), name)
+        })
+        .collect();
+    let mut outcomes = vec![];
+    for (file, label) in OUTCOMES {
+        let content = std::fs::read_to_string(results_dir.join(file)).unwrap_or_default();
+        outcomes.extend(content.lines().map(|line| (*label, line.to_owned())));
+    }
+    print!("{}", render_summary(&crate_dirs, &outcomes));
+    println!("Full results are in {}.", results_dir.display());
+
+    if !status.success() {
+        // cargo-mutants exits non-zero when mutants survived or timed out
+        history::exit(status.code().unwrap_or(1));
+    }
+}
+
+/// Returns the crate owning `file`, a path relative to the workspace, by the longest matching
+/// crate directory.
+fn owning_crate<'a>(crate_dirs: &'a [(String, String)], file: &str) -> Option<&'a str> {
+    crate_dirs
+        .iter()
+        .filter(|(dir, _)| dir.is_empty() || file.starts_with(&format!("{dir}/")))
+        .max_by_key(|(dir, _)| dir.len())
+        .map(|(_, name)| name.as_str())
+}
+
+/// Renders the outcome counts per crate, followed by the surviving mutants.
+fn render_summary(crate_dirs: &[(String, String)], outcomes: &[(&str, String)]) -> String {
+    let mut counts: BTreeMap<&str, BTreeMap<&str, usize>> = BTreeMap::new();
+    let mut missed: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for (label, line) in outcomes {
+        let file = line.split(':').next().unwrap_or_default();
+        let name = owning_crate(crate_dirs, file).unwrap_or("(unknown)");
+        *counts.entry(name).or_default().entry(label).or_default() += 1;
+        if *label == "missed" {
+            missed.entry(name).or_default().push(line);
+        }
+    }
+
+    let mut out = String::new();
+    if counts.is_empty() {
+        writeln!(out, "No mutants were tested.").unwrap();
+        return out;
+    }
+    writeln!(
+        out,
+        "{:<24} {:>8} {:>8} {:>8} {:>8}",
+        "CRATE", "CAUGHT", "MISSED", "TIMEOUT", "UNVIABLE"
+    )
+    .unwrap();
+    for (name, counts) in &counts {
+        let count = |label: &str| counts.get(label).copied().unwrap_or(0);
+        writeln!(
+            out,
+            "{name:<24} {:>8} {:>8} {:>8} {:>8}",
+            count("caught"),
+            count("missed"),
+            count("timeout"),
+            count("unviable"),
+        )
+        .unwrap();
+    }
+    for (name, lines) in missed {
+        writeln!(out, "\nSurviving mutants in {name}:").unwrap();
+        for line in lines {
+            writeln!(out, "  {line}").unwrap();
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_summary() {
+        let crate_dirs = vec![
+            ("demo".to_owned(), "demo".to_owned()),
+            ("demo-cli".to_owned(), "demo-cli".to_owned()),
+        ];
+        assert_eq!(
+            owning_crate(&crate_dirs, "demo-cli/src/main.rs"),
+            Some("demo-cli")
+        );
+        assert_eq!(owning_crate(&crate_dirs, "other/src/lib.rs"), None);
+
+        let outcomes = vec![
+            (
+                "caught",
+                "demo/src/lib.rs:3:5: replace add -> i32 with 0".to_owned(),
+            ),
+            (
+                "missed",
+                "demo/src/lib.rs:9:5: replace is_even -> bool with true".to_owned(),
+            ),
+            (
+                "timeout",
+                "demo-cli/src/main.rs:4:5: replace main with ()".to_owned(),
+            ),
+        ];
+        let summary = render_summary(&crate_dirs, &outcomes);
+        let lines: Vec<&str> = summary.lines().collect();
+        assert_eq!(
+            lines[1].split_whitespace().collect::<Vec<_>>(),
+            ["demo", "1", "1", "0", "0"]
+        );
+        assert_eq!(
+            lines[2].split_whitespace().collect::<Vec<_>>(),
+            ["demo-cli", "0", "0", "1", "0"]
+        );
+        assert!(summary.ends_with(
+            "Surviving mutants in demo:\n  demo/src/lib.rs:9:5: replace is_even -> bool with true\n"
+        ));
+    }
+}
@@ -0,0 +1,261 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use toml_edit::Array;
+use toml_edit::DocumentMut;
+use toml_edit::InlineTable;
+use toml_edit::Item;
+use toml_edit::Table;
+use toml_edit::value;
+
+use super::headers;
+use super::history;
+use super::metadata;
+use super::workspace_dir;
+
+/// The directory new members are created in.
+const CRATES_DIR: &str = "crates";
+
+/// The column width of the workspace manifest, as configured for taplo, past which the members
+/// are listed one per line.
+const COLUMN_WIDTH: usize = 80;
+
+/// The manifest of a new member; `{name}`, `{version}` and `{description}` are substituted.
+const MEMBER_MANIFEST: &str = r#"[package]
+name = "{name}"
+version = "{version}"
+description = "{description}"
+
+edition.workspace = true
+homepage.workspace = true
+license.workspace = true
+readme.workspace = true
+repository.workspace = true
+rust-version.workspace = true
+
+[package.metadata.docs.rs]
+all-features = true
+rustdoc-args = ["--cfg", "docsrs"]
+
+[dependencies]
+
+[lints]
+workspace = true
+"#;
+
+/// The sources of a new library member; `{description}` is substituted.
+const LIB_RS: &str = r#"//! {description}
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![deny(missing_docs)]
+"#;
+
+/// The sources of a new binary member; `{description}` is substituted.
+const MAIN_RS: &str = r#"//! {description}
+
+fn main() {
+    println!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+}
+"#;
+
+/// Creates the member `name` in `crates/<name>`, a library or with `bin` a binary, at the version
+/// of the published members, and adds the license headers to its files.
+///
+/// The member is added to `[workspace.members]`, and a library also to
+/// `[workspace.dependencies]`, so that the other members depend on it with `<name>.workspace =
+/// true`.
+pub fn new_crate(name: &str, bin: bool) {
+    if let Err(err) = validate_name(name) {
+        eprintln!("Invalid crate name '{name}': {err}.");
+        history::exit(1);
+    }
+    let dir = format!("{CRATES_DIR}/{name}");
+    let root = workspace_dir();
+    if root.join(&dir).exists() {
+        eprintln!("{dir} already exists.");
+        history::exit(1);
+    }
+
+    let metadata = metadata::cargo_metadata(true);
+    let packages = metadata::workspace_packages(&metadata);
+    if packages.iter().any(|package| package["name"] == name) {
+        eprintln!("The workspace already has a member named {name}.");
+        history::exit(1);
+    }
+    // the published members share a version, which the new one starts at
+    let version = packages
+        .iter()
+        .find(|package| package["publish"].is_null())
+        .and_then(|package| package["version"].as_str())
+        .unwrap_or("0.0.1");
+
+    let (source, description) = match bin {
+        true => ("src/main.rs", format!("The {name} command-line tool.")),
+        false => ("src/lib.rs", format!("The {name} library.")),
+    };
+    let manifest = render_template(MEMBER_MANIFEST, name, version, &description);
+    let code = render_template(
+        if bin { MAIN_RS } else { LIB_RS },
+        name,
+        version,
+        &description,
+    );
+    std::fs::create_dir_all(root.join(&dir).join("src")).unwrap();
+    let files = [
+        (format!("{dir}/Cargo.toml"), manifest),
+        (format!("{dir}/{source}"), code),
+    ];
+    for (file, content) in &files {
+        std::fs::write(root.join(file), content).unwrap();
+    }
+    let files = files.map(|(file, _)| file);
+    headers::add_headers(&files);
+    println!("Created {dir}.");
+
+    let manifest_path = root.join("Cargo.toml");
+    let mut workspace: DocumentMut = std::fs::read_to_string(&manifest_path)
+        .unwrap()
+        .parse()
+        .unwrap_or_else(|err| history::fail(&format!("invalid workspace Cargo.toml: {err}")));
+    update_workspace_manifest(&mut workspace, name, &dir, version, !bin);
+    std::fs::write(&manifest_path, workspace.to_string()).unwrap();
+    println!("Added {name} to the workspace.");
+}
+
+/// Checks that `name` is a valid package name, which cargo would otherwise reject only once the
+/// member is added.
+pub fn validate_name(name: &str) -> Result<(), String> {
+    let Some(first) = name.chars().next() else {
+        return Err("it is empty".to_owned());
+    };
+    if !first.is_ascii_alphabetic() {
+        return Err("it must start with an ASCII letter".to_owned());
+    }
+    match name
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && *c != '-' && *c != '_')
+    {
+        Some(c) => Err(format!("'{c}' is not an ASCII letter, digit, '-' or '_'")),
+        None => Ok(()),
+    }
+}
+
+fn render_template(template: &str, name: &str, version: &str, description: &str) -> String {
+    template
+        .replace("{name}", name)
+        .replace("{version}", version)
+        .replace("{description}", description)
+}
+
+/// Adds the member in `dir` to the workspace members and, with `dependency`, to the workspace
+/// dependencies.
+fn update_workspace_manifest(
+    doc: &mut DocumentMut,
+    name: &str,
+    dir: &str,
+    version: &str,
+    dependency: bool,
+) {
+    let workspace = doc["workspace"].as_table_mut().unwrap();
+    let members = workspace
+        .entry("members")
+        .or_insert(value(Array::new()))
+        .as_array_mut()
+        .unwrap();
+    // a glob of the crates directory already includes the member
+    let glob = format!("{CRATES_DIR}/*");
+    if !members.iter().any(|member| member.as_str() == Some(&glob)) {
+        members.push(dir);
+        let expanded = members.iter().any(|member| {
+            let prefix = member.decor().prefix().and_then(|prefix| prefix.as_str());
+            prefix.is_some_and(|prefix| prefix.contains('\n'))
+        });
+        let values: Vec<String> = members
+            .iter()
+            .map(|member| member.to_string().trim().to_owned())
+            .collect();
+        if expanded || format!("members = [{}]", values.join(", ")).len() > COLUMN_WIDTH {
+            expand_array(members);
+        }
+    }
+
+    if dependency {
+        let dependencies = workspace
+            .entry("dependencies")
+            .or_insert(Item::Table(Table::new()))
+            .as_table_mut()
+            .unwrap();
+        let mut dependency = InlineTable::new();
+        dependency.insert("path", dir.into());
+        dependency.insert("version", version.into());
+        dependencies.insert(name, value(dependency));
+    }
+}
+
+/// Lists the values of `array` one per line, as taplo formats an array too long for a line.
+fn expand_array(array: &mut Array) {
+    for item in array.iter_mut() {
+        item.decor_mut().set_prefix("\n  ");
+        item.decor_mut().set_suffix("");
+    }
+    array.set_trailing("\n");
+    array.set_trailing_comma(true);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_name() {
+        assert!(validate_name("demo-utils").is_ok());
+        assert!(validate_name("demo_2").is_ok());
+        assert!(validate_name("").is_err());
+        assert!(validate_name("2demo").is_err());
+        assert!(validate_name("demo/utils").is_err());
+    }
+
+    #[test]
+    fn test_update_workspace_manifest() {
+        let mut doc: DocumentMut = "[workspace]\nmembers = [\"demo\", \"xtask\"]\n"
+            .parse()
+            .unwrap();
+        update_workspace_manifest(&mut doc, "demo-utils", "crates/demo-utils", "0.1.0", true);
+        assert_eq!(
+            doc.to_string(),
+            "[workspace]\nmembers = [\"demo\", \"xtask\", \"crates/demo-utils\"]\n\n[workspace.dependencies]\ndemo-utils = { path = \"crates/demo-utils\", version = \"0.1.0\" }\n"
+        );
+
+        update_workspace_manifest(
+            &mut doc,
+            "demo-command-line-tools",
+            "crates/demo-command-line-tools",
+            "0.1.0",
+            false,
+        );
+        assert_eq!(
+            doc["workspace"]["members"].to_string(),
+            " [\n  \"demo\",\n  \"xtask\",\n  \"crates/demo-utils\",\n  \"crates/demo-command-line-tools\",\n]"
+        );
+        assert!(
+            doc["workspace"]["dependencies"]
+                .get("demo-command-line-tools")
+                .is_none()
+        );
+
+        let mut doc: DocumentMut = "[workspace]\nmembers = [\"crates/*\"]\n".parse().unwrap();
+        update_workspace_manifest(&mut doc, "demo", "crates/demo", "0.1.0", false);
+        assert_eq!(doc.to_string(), "[workspace]\nmembers = [\"crates/*\"]\n");
+    }
+}
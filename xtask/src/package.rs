@@ -0,0 +1,167 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use globset::GlobSet;
+use toml_edit::DocumentMut;
+
+use super::config;
+use super::config::CONFIG_FILE;
+use super::find_cargo;
+use super::history;
+use super::metadata;
+
+/// Patterns each of which must match at least one packaged file, unless configured otherwise.
+const DEFAULT_REQUIRED: &[&str] = &["README*", "LICENSE*"];
+
+/// Patterns no packaged file may match, unless configured otherwise.
+const DEFAULT_FORBIDDEN: &[&str] = &[
+    "**/fixtures/**",
+    "**/testdata/**",
+    ".github/**",
+    ".gitlab-ci.yml",
+    ".cargo/**",
+    ".env*",
+    "xtask.toml",
+];
+
+/// The `[verify-package]` table of `xtask.toml`.
+struct PackageRules {
+    required: Vec<String>,
+    forbidden: Vec<String>,
+}
+
+/// Checks the files `cargo package` would ship for every publishable member.
+///
+/// Every `required` pattern must match a packaged file and no packaged file may match a
+/// `forbidden` pattern; both lists can be overridden under `[verify-package]` in `xtask.toml`.
+pub fn verify_packages() {
+    let rules = parse_rules(&config::load_config())
+        .unwrap_or_else(|err| history::fail(&format!("invalid {CONFIG_FILE}: {err}")));
+    let forbidden = config::glob_set(&rules.forbidden).unwrap_or_else(|err| {
+        history::fail(&format!(
+            "invalid {CONFIG_FILE}: 'verify-package.forbidden': {err}"
+        ))
+    });
+
+    let metadata = metadata::cargo_metadata(true);
+    let mut failed = false;
+    for package in metadata::workspace_packages(&metadata) {
+        if !metadata::is_publishable(package) {
+            continue;
+        }
+        let name = package["name"].as_str().unwrap();
+        let files = list_package(name);
+        let diff = diff_package(&files, &rules.required, &forbidden);
+        if diff.is_empty() {
+            println!(
+                "Package '{name}' ships {} file(s) as expected.",
+                files.len()
+            );
+        } else {
+            println!("Package '{name}' has unexpected contents:");
+            for line in diff {
+                println!("  {line}");
+            }
+            failed = true;
+        }
+    }
+
+    if failed {
+        eprintln!("\nPackaged files differ from the expectations; adjust `include`/`exclude`.");
+        history::exit(1);
+    }
+}
+
+/// Returns the files `cargo package` would include for the package.
+fn list_package(name: &str) -> Vec<String> {
+    let mut cmd = find_cargo();
+    cmd.args(["package", "--list", "--allow-dirty", "-p", name]);
+    let output = cmd.output().expect("failed to execute cargo package");
+    assert!(
+        output.status.success(),
+        "cargo package failed for '{name}': {}\n{}",
+        output.status,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Returns `+ <file>` for every forbidden file and `- <pattern>` for every missing requirement.
+fn diff_package(files: &[String], required: &[String], forbidden: &GlobSet) -> Vec<String> {
+    let mut diff: Vec<String> = files
+        .iter()
+        .filter(|file| forbidden.is_match(file))
+        .map(|file| format!("+ {file}"))
+        .collect();
+    for pattern in required {
+        let glob = config::glob_set(std::slice::from_ref(pattern)).unwrap_or_else(|err| {
+            history::fail(&format!("invalid {CONFIG_FILE}: '{pattern}': {err}"))
+        });
+        if !files.iter().any(|file| glob.is_match(file)) {
+            diff.push(format!("- {pattern}"));
+        }
+    }
+    diff
+}
+
+fn parse_rules(doc: &DocumentMut) -> Result<PackageRules, String> {
+    let section = doc.get("verify-package");
+    let list = |key: &str, default: &[&str]| match section.and_then(|s| s.get(key)) {
+        Some(item) => config::string_array(Some(item), &format!("'verify-package.{key}'")),
+        None => Ok(default.iter().map(|p| (*p).to_owned()).collect()),
+    };
+    Ok(PackageRules {
+        required: list("required", DEFAULT_REQUIRED)?,
+        forbidden: list("forbidden", DEFAULT_FORBIDDEN)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_package() {
+        let rules = parse_rules(&DocumentMut::new()).unwrap();
+        let forbidden = config::glob_set(&rules.forbidden).unwrap();
+        let files = |names: &[&str]| names.iter().map(|n| (*n).to_owned()).collect::<Vec<_>>();
+
+        let clean = files(&["Cargo.toml", "LICENSE", "README.md", "src/lib.rs"]);
+        assert!(diff_package(&clean, &rules.required, &forbidden).is_empty());
+
+        let dirty = files(&[
+            ".github/workflows/ci.yml",
+            "Cargo.toml",
+            "README.md",
+            "src/lib.rs",
+            "tests/fixtures/input.txt",
+        ]);
+        assert_eq!(
+            diff_package(&dirty, &rules.required, &forbidden),
+            vec![
+                "+ .github/workflows/ci.yml",
+                "+ tests/fixtures/input.txt",
+                "- LICENSE*",
+            ]
+        );
+
+        let doc = "[verify-package]\nrequired = [\"CHANGELOG.md\"]\n"
+            .parse()
+            .unwrap();
+        assert_eq!(parse_rules(&doc).unwrap().required, vec!["CHANGELOG.md"]);
+    }
+}
@@ -0,0 +1,66 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::process::Command as StdCommand;
+
+use super::env;
+use super::history;
+use super::run_command;
+use super::workspace_dir;
+
+/// Prefix of the executables that provide external subcommands.
+const PLUGIN_PREFIX: &str = "xtask-";
+
+/// Directory, relative to the workspace, searched for plugins before `PATH`.
+const PLUGIN_DIR: &str = "xtask/plugins";
+
+/// Runs an external subcommand `cargo x <name> [args...]` by delegating to an `xtask-<name>`
+/// executable.
+///
+/// The executable is looked up under `xtask/plugins/` first and then on `PATH`. It runs in the
+/// workspace root with the following environment variables set:
+///
+/// * `XTASK_WORKSPACE_DIR`: the absolute path of the workspace root;
+/// * `XTASK_PLUGIN_NAME`: the name of the invoked subcommand.
+pub fn run_plugin(args: Vec<OsString>) {
+    let mut args = args.into_iter();
+    let name = args.next().expect("external subcommand must have a name");
+    let name = name
+        .to_str()
+        .unwrap_or_else(|| history::fail(&format!("invalid subcommand name: {name:?}")));
+
+    let exe = find_plugin(name).unwrap_or_else(|| {
+        panic!(
+            "unrecognized subcommand '{name}': no '{PLUGIN_PREFIX}{name}' executable found in '{PLUGIN_DIR}' or PATH"
+        )
+    });
+
+    let mut cmd = StdCommand::new(exe);
+    cmd.args(args)
+        .current_dir(workspace_dir())
+        .envs(env::dotenv_vars())
+        .env("XTASK_WORKSPACE_DIR", workspace_dir())
+        .env("XTASK_PLUGIN_NAME", name);
+    run_command(cmd);
+}
+
+fn find_plugin(name: &str) -> Option<PathBuf> {
+    let binary_name = format!("{PLUGIN_PREFIX}{name}");
+    let plugin_dir = workspace_dir().join(PLUGIN_DIR);
+    which::which_in(&binary_name, Some(&plugin_dir), workspace_dir())
+        .or_else(|_| which::which(&binary_name))
+        .ok()
+}
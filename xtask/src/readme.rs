@@ -0,0 +1,363 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use super::find_cargo;
+use super::history;
+use super::metadata;
+use super::run_command;
+use super::workspace_dir;
+
+/// Markers delimiting the README section rendered from the crate-level docs.
+const SYNC_START: &str = "<!-- cargo-rdme start -->";
+const SYNC_END: &str = "<!-- cargo-rdme end -->";
+
+/// Rustdoc code block attributes, which imply a Rust code block.
+const RUSTDOC_ATTRIBUTES: &[&str] = &[
+    "ignore",
+    "no_run",
+    "should_panic",
+    "compile_fail",
+    "test_harness",
+    "edition2015",
+    "edition2018",
+    "edition2021",
+    "edition2024",
+];
+
+/// A fenced Rust code block in a markdown file.
+#[derive(Debug, PartialEq)]
+struct CodeBlock {
+    /// The 1-based line number of the opening fence.
+    line: usize,
+    /// The rustdoc attributes after `rust`, e.g., `no_run` or `ignore`.
+    attributes: Vec<String>,
+    code: String,
+}
+
+/// Compiles and runs the Rust code blocks of `README.md` as doctests against the publishable
+/// library crates, skeptic-style.
+///
+/// The blocks are copied into a generated crate under `target/xtask/readme-tests/`, so the
+/// `no_run`, `ignore`, `should_panic`, and `compile_fail` attributes behave as in rustdoc.
+pub fn test_readme() {
+    let readme = std::fs::read_to_string(workspace_dir().join("README.md")).unwrap();
+    let blocks = extract_rust_blocks(&readme);
+    if blocks.is_empty() {
+        println!("No Rust code blocks found in README.md.");
+        return;
+    }
+
+    let metadata = metadata::cargo_metadata(true);
+    let mut dependencies = String::new();
+    let mut edition = "2021".to_owned();
+    for package in metadata::workspace_packages(&metadata) {
+        let has_lib = package["targets"].as_array().unwrap().iter().any(|target| {
+            target["kind"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|k| k == "lib")
+        });
+        if !has_lib || !metadata::is_publishable(package) {
+            continue;
+        }
+        let manifest_path = Path::new(package["manifest_path"].as_str().unwrap());
+        let dir = manifest_path.parent().unwrap().display().to_string();
+        writeln!(
+            dependencies,
+            "{} = {{ path = {dir:?} }}",
+            package["name"].as_str().unwrap()
+        )
+        .unwrap();
+        edition = package["edition"].as_str().unwrap().to_owned();
+    }
+
+    let dir = workspace_dir().join("target/xtask/readme-tests");
+    std::fs::create_dir_all(dir.join("src")).unwrap();
+    let manifest = format!(
+        "[package]\nname = \"readme-tests\"\nedition = \"{edition}\"\npublish = false\n\n[workspace]\n\n[dependencies]\n{dependencies}"
+    );
+    std::fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+    std::fs::write(dir.join("src/lib.rs"), render_doctests(&blocks)).unwrap();
+    // resolve the same dependency versions as the workspace
+    let lockfile = workspace_dir().join("Cargo.lock");
+    if lockfile.exists() {
+        std::fs::copy(lockfile, dir.join("Cargo.lock")).unwrap();
+    }
+
+    let mut cmd = find_cargo();
+    cmd.args(["test", "--doc", "--manifest-path"])
+        .arg(dir.join("Cargo.toml"))
+        .env("CARGO_TARGET_DIR", workspace_dir().join("target"));
+    run_command(cmd);
+}
+
+/// Renders the crate-level docs of `package`, or of the only publishable library, into the
+/// marked section of `README.md`; with `check`, fails if the section is out of date instead.
+pub fn sync_readme(package: Option<&str>, check: bool) {
+    let lib_path = find_lib_path(package);
+    let source = std::fs::read_to_string(&lib_path).unwrap();
+    let docs = render_crate_docs(&source);
+
+    let file = workspace_dir().join("README.md");
+    let readme = std::fs::read_to_string(&file).unwrap();
+    let updated = replace_section(&readme, &docs).unwrap_or_else(|err| panic!("README.md: {err}"));
+
+    if check {
+        if updated != readme {
+            eprintln!(
+                "README.md is out of sync with {}; run `cargo x readme sync`.",
+                lib_path.display()
+            );
+            history::exit(1);
+        }
+        println!("README.md is in sync with {}.", lib_path.display());
+    } else {
+        std::fs::write(&file, updated).unwrap();
+        println!("Synced README.md from {}.", lib_path.display());
+    }
+}
+
+fn find_lib_path(package: Option<&str>) -> PathBuf {
+    let metadata = metadata::cargo_metadata(true);
+    let mut libs = vec![];
+    for member in metadata::workspace_packages(&metadata) {
+        let name = member["name"].as_str().unwrap();
+        let selected = match package {
+            Some(package) => name == package,
+            None => metadata::is_publishable(member),
+        };
+        if !selected {
+            continue;
+        }
+        for target in member["targets"].as_array().unwrap() {
+            if target["kind"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|k| k == "lib")
+            {
+                libs.push(PathBuf::from(target["src_path"].as_str().unwrap()));
+            }
+        }
+    }
+    match libs.as_slice() {
+        [lib] => lib.clone(),
+        [] => panic!("no library target found; pass one with --package"),
+        _ => panic!("multiple library targets found; pass one with --package"),
+    }
+}
+
+/// Extracts the `//!` docs of a source file as markdown, marking implicit Rust code blocks and
+/// dropping their hidden `# ` lines.
+fn render_crate_docs(source: &str) -> String {
+    let mut out = String::new();
+    let mut in_rust_block = false;
+    let mut fence = String::new();
+    for line in source.lines() {
+        let Some(doc) = line.trim_start().strip_prefix("//!") else {
+            continue;
+        };
+        let doc = doc.strip_prefix(' ').unwrap_or(doc);
+        let trimmed = doc.trim_start();
+        let fence_len = trimmed.chars().take_while(|&c| c == '`').count();
+
+        if fence.is_empty() && fence_len >= 3 {
+            fence = "`".repeat(fence_len);
+            let info = trimmed[fence_len..].trim();
+            let first = info.split(',').next().unwrap_or_default().trim();
+            in_rust_block =
+                first.is_empty() || first == "rust" || RUSTDOC_ATTRIBUTES.contains(&first);
+            if in_rust_block {
+                writeln!(out, "{fence}rust").unwrap();
+            } else {
+                writeln!(out, "{doc}").unwrap();
+            }
+        } else if !fence.is_empty() && trimmed.trim_end() == fence {
+            writeln!(out, "{fence}").unwrap();
+            fence.clear();
+            in_rust_block = false;
+        } else if in_rust_block && (trimmed == "#" || trimmed.starts_with("# ")) {
+            continue;
+        } else {
+            writeln!(out, "{doc}").unwrap();
+        }
+    }
+    out
+}
+
+/// Replaces the content between the sync markers of `readme` with `docs`.
+fn replace_section(readme: &str, docs: &str) -> Result<String, String> {
+    let missing = || format!("the '{SYNC_START}' and '{SYNC_END}' markers are required");
+    let start = readme.find(SYNC_START).ok_or_else(missing)? + SYNC_START.len();
+    let end = readme[start..].find(SYNC_END).ok_or_else(missing)? + start;
+    Ok(format!(
+        "{}\n\n{}\n{}",
+        &readme[..start],
+        docs.trim(),
+        &readme[end..]
+    ))
+}
+
+fn extract_rust_blocks(markdown: &str) -> Vec<CodeBlock> {
+    let mut blocks = vec![];
+    let mut current: Option<(String, CodeBlock)> = None;
+    for (index, line) in markdown.lines().enumerate() {
+        let trimmed = line.trim_start();
+        match current.take() {
+            None => {
+                let fence_len = trimmed.chars().take_while(|&c| c == '`').count();
+                if fence_len < 3 {
+                    continue;
+                }
+                let mut info = trimmed[fence_len..].split(',').map(str::trim);
+                if info.next() == Some("rust") {
+                    let block = CodeBlock {
+                        line: index + 1,
+                        attributes: info.map(str::to_owned).collect(),
+                        code: String::new(),
+                    };
+                    current = Some(("`".repeat(fence_len), block));
+                }
+            }
+            Some((fence, mut block)) => {
+                if trimmed.starts_with(&fence) && trimmed.trim_end() == fence {
+                    blocks.push(block);
+                } else {
+                    block.code.push_str(line);
+                    block.code.push('\n');
+                    current = Some((fence, block));
+                }
+            }
+        }
+    }
+    blocks
+}
+
+fn render_doctests(blocks: &[CodeBlock]) -> String {
+    let mut out =
+        String::from("//! Doctests generated from README.md by `cargo x test --readme`.\n");
+    for block in blocks {
+        let mut info = vec!["rust".to_owned()];
+        info.extend(block.attributes.iter().cloned());
+        writeln!(out, "\n/// README.md line {}", block.line).unwrap();
+        writeln!(out, "///\n/// ```{}", info.join(",")).unwrap();
+        for line in block.code.lines() {
+            writeln!(out, "/// {line}").unwrap();
+        }
+        writeln!(out, "/// ```").unwrap();
+        writeln!(out, "pub mod readme_line_{} {{}}", block.line).unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_rust_blocks() {
+        let markdown = "\
+# Title
+
+```rust
+template::hello();
+```
+
+```
+not rust
+```
+
+```rust,no_run
+fn main() {}
+```
+
+````rust
+```
+````
+";
+        assert_eq!(
+            extract_rust_blocks(markdown),
+            vec![
+                CodeBlock {
+                    line: 3,
+                    attributes: vec![],
+                    code: "template::hello();\n".into(),
+                },
+                CodeBlock {
+                    line: 11,
+                    attributes: vec!["no_run".into()],
+                    code: "fn main() {}\n".into(),
+                },
+                CodeBlock {
+                    line: 15,
+                    attributes: vec![],
+                    code: "```\n".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_crate_docs() {
+        let source = "\
+// Copyright header
+
+//! A template library.
+//!
+//! ```
+//! # fn main() {
+//! template::hello();
+//! # }
+//! ```
+//!
+//! ```text
+//! # not hidden
+//! ```
+
+#![deny(missing_docs)]
+";
+        assert_eq!(
+            render_crate_docs(source),
+            "A template library.\n\n```rust\ntemplate::hello();\n```\n\n```text\n# not hidden\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme =
+            "# Title\n\n<!-- cargo-rdme start -->\nstale\n<!-- cargo-rdme end -->\n\n## License\n";
+        assert_eq!(
+            replace_section(readme, "Fresh docs.\n"),
+            Ok("# Title\n\n<!-- cargo-rdme start -->\n\nFresh docs.\n<!-- cargo-rdme end -->\n\n## License\n".into())
+        );
+        assert!(replace_section("# Title\n", "docs").is_err());
+    }
+
+    #[test]
+    fn test_render_doctests() {
+        let doctests = render_doctests(&[CodeBlock {
+            line: 11,
+            attributes: vec!["no_run".into()],
+            code: "fn main() {}\n".into(),
+        }]);
+        assert!(doctests.ends_with(
+            "/// README.md line 11\n///\n/// ```rust,no_run\n/// fn main() {}\n/// ```\npub mod readme_line_11 {}\n"
+        ));
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use toml_edit::Array;
+use toml_edit::DocumentMut;
+use toml_edit::Item;
+
+use super::history;
+use super::metadata;
+use super::workspace_dir;
+
+/// Removes the workspace member `name` and its directory, and drops it from the workspace
+/// members and dependencies.
+///
+/// Other members still depending on it would no longer build, so they are listed and nothing is
+/// removed, unless `force` removes the member anyway, leaving them to be fixed by hand.
+pub fn remove_crate(name: &str, force: bool) {
+    let metadata = metadata::cargo_metadata(true);
+    let packages = metadata::workspace_packages(&metadata);
+    let Some(package) = packages.iter().find(|package| package["name"] == name) else {
+        eprintln!("The workspace has no member named {name}.");
+        history::exit(1);
+    };
+    if name == env!("CARGO_PKG_NAME") {
+        eprintln!("The xtask cannot remove itself.");
+        history::exit(1);
+    }
+
+    let root = workspace_dir();
+    let manifest = Path::new(package["manifest_path"].as_str().unwrap());
+    let dir = manifest.parent().unwrap();
+    let relative_dir = dir
+        .strip_prefix(root)
+        .unwrap_or(dir)
+        .to_string_lossy()
+        .replace('\\', "/");
+
+    let dependents: Vec<(&str, &str)> = packages
+        .iter()
+        .filter(|package| package["name"] != name)
+        .filter(|package| {
+            package["dependencies"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .any(|dep| dep["name"] == name && dep["path"].is_string())
+        })
+        .map(|package| {
+            (
+                package["name"].as_str().unwrap(),
+                package["manifest_path"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    for (dependent, manifest_path) in &dependents {
+        println!("{dependent} depends on {name} in {manifest_path}");
+    }
+    if !dependents.is_empty() {
+        if !force {
+            eprintln!(
+                "\n{} member(s) still depend on {name}; drop the dependencies first, or pass --force to remove it anyway.",
+                dependents.len()
+            );
+            history::exit(1);
+        }
+        eprintln!(
+            "\nThe member(s) above still depend on {name} and no longer build until the dependency is dropped."
+        );
+    }
+
+    let manifest_path = root.join("Cargo.toml");
+    let mut workspace: DocumentMut = std::fs::read_to_string(&manifest_path)
+        .unwrap()
+        .parse()
+        .unwrap_or_else(|err| history::fail(&format!("invalid workspace Cargo.toml: {err}")));
+    update_workspace_manifest(&mut workspace, name, &relative_dir);
+    std::fs::write(&manifest_path, workspace.to_string()).unwrap();
+    println!("Removed {name} from the workspace.");
+
+    std::fs::remove_dir_all(dir).unwrap();
+    println!("Deleted {relative_dir}.");
+}
+
+/// Drops the member in `dir` from the workspace members and the workspace dependency `name`, or
+/// any other one on its path.
+pub fn update_workspace_manifest(doc: &mut DocumentMut, name: &str, dir: &str) {
+    let Some(workspace) = doc.get_mut("workspace").and_then(Item::as_table_mut) else {
+        return;
+    };
+    for key in ["members", "default-members", "exclude"] {
+        if let Some(members) = workspace.get_mut(key).and_then(Item::as_array_mut) {
+            remove_value(members, dir);
+        }
+    }
+    if let Some(dependencies) = workspace
+        .get_mut("dependencies")
+        .and_then(Item::as_table_like_mut)
+    {
+        let on_path = |dependency: &Item| {
+            let path = dependency.get("path").and_then(Item::as_str);
+            path.is_some_and(|path| path.trim_end_matches('/') == dir)
+        };
+        let keys: Vec<String> = dependencies
+            .iter()
+            .filter(|(key, dependency)| *key == name || on_path(dependency))
+            .map(|(key, _)| key.to_owned())
+            .collect();
+        for key in keys {
+            dependencies.remove(&key);
+        }
+    }
+}
+
+/// Removes `value` from `array`, keeping its formatting.
+fn remove_value(array: &mut Array, value: &str) {
+    let first_decor = array.get(0).map(|item| item.decor().clone());
+    array.retain(|item| item.as_str().map(|item| item.trim_end_matches('/')) != Some(value));
+    // the first value keeps the decor of the removed one, e.g., without a space after `[`
+    if let (Some(decor), Some(first)) = (first_decor, array.get_mut(0)) {
+        *first.decor_mut() = decor;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_workspace_manifest() {
+        let mut doc: DocumentMut = r#"[workspace]
+members = ["crates/demo-utils", "demo", "xtask"]
+
+[workspace.dependencies]
+demo = { path = "demo", version = "0.1.0" }
+demo-utils = { path = "crates/demo-utils", version = "0.1.0" }
+"#
+        .parse()
+        .unwrap();
+        update_workspace_manifest(&mut doc, "demo-utils", "crates/demo-utils");
+        assert_eq!(
+            doc.to_string(),
+            r#"[workspace]
+members = ["demo", "xtask"]
+
+[workspace.dependencies]
+demo = { path = "demo", version = "0.1.0" }
+"#
+        );
+
+        let mut doc: DocumentMut = r#"[workspace]
+members = [
+  "demo",
+  "crates/utils",
+  "xtask",
+]
+
+[workspace.dependencies]
+demo-utils = { path = "crates/utils/" }
+"#
+        .parse()
+        .unwrap();
+        update_workspace_manifest(&mut doc, "utils", "crates/utils");
+        assert_eq!(
+            doc.to_string(),
+            "[workspace]\nmembers = [\n  \"demo\",\n  \"xtask\",\n]\n\n[workspace.dependencies]\n"
+        );
+    }
+}
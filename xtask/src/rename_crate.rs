@@ -0,0 +1,286 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+
+use toml_edit::DocumentMut;
+use toml_edit::Item;
+use toml_edit::Table;
+use toml_edit::Value;
+use xtask_bootstrap::rename;
+use xtask_bootstrap::rename::CrateRename;
+
+use super::find_cargo;
+use super::history;
+use super::metadata;
+use super::metadata::DEPENDENCY_TABLES;
+use super::new_crate;
+use super::workspace_dir;
+
+/// Renames the workspace member `old` to `new` with the rename engine of the bootstrap, along
+/// with the members prefixed with its name, e.g., `old-macros` to `new-macros`.
+///
+/// Every spelling of the names is replaced where it starts a word in the manifests and Rust
+/// sources of the members, and in the workflows, so that `use old::hello` becomes `use
+/// new::hello` in the dependents. A member directory named after its package is renamed too,
+/// along with the paths to it, and `Cargo.lock` is updated for the new names.
+pub fn rename_crate(old: &str, new: &str) {
+    if let Err(err) = new_crate::validate_name(new) {
+        eprintln!("Invalid crate name '{new}': {err}.");
+        history::exit(1);
+    }
+
+    let root = workspace_dir();
+    let metadata = metadata::cargo_metadata(true);
+    let packages = metadata::workspace_packages(&metadata);
+    let members: Vec<(String, String)> = metadata::non_tooling_members(&packages)
+        .into_iter()
+        .map(|package| {
+            let manifest = Path::new(package["manifest_path"].as_str().unwrap());
+            let dir = manifest.parent().unwrap();
+            let dir = dir.strip_prefix(root).unwrap_or(dir);
+            let name = package["name"].as_str().unwrap().to_owned();
+            (name, dir.to_string_lossy().replace('\\', "/"))
+        })
+        .collect();
+    if !members.iter().any(|(name, _)| name == old) {
+        if packages.iter().any(|package| package["name"] == old) {
+            eprintln!("The xtask cannot rename {old}, which it depends on.");
+        } else {
+            eprintln!("The workspace has no member named {old}.");
+        }
+        history::exit(1);
+    }
+    let renames = rename::prefixed_renames(&members, old, new, new);
+    for rename in &renames {
+        if packages
+            .iter()
+            .any(|package| package["name"] == rename.new_package)
+        {
+            eprintln!(
+                "The workspace already has a member named {}.",
+                rename.new_package
+            );
+            history::exit(1);
+        }
+        if rename.renames_dir() && root.join(&rename.new_dir).exists() {
+            eprintln!("{} already exists.", rename.new_dir);
+            history::exit(1);
+        }
+    }
+
+    for file in renamed_files(&members) {
+        let path = root.join(&file);
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        let mut updated = rename::rename_crates(&content, &renames, file.ends_with(".rs"));
+        if file.ends_with("Cargo.toml") {
+            let dir = Path::new(&file).parent().unwrap_or(Path::new(""));
+            updated = update_manifest(&updated, dir, &renames);
+        }
+        if updated != content {
+            std::fs::write(&path, updated).unwrap();
+            println!("Updated {file}.");
+        }
+    }
+    for rename in renames.iter().filter(|rename| rename.renames_dir()) {
+        std::fs::rename(root.join(&rename.dir), root.join(&rename.new_dir)).unwrap();
+        println!("Moved {} to {}.", rename.dir, rename.new_dir);
+    }
+    for rename in &renames {
+        println!("Renamed {} to {}.", rename.package, rename.new_package);
+    }
+
+    if root.join("Cargo.lock").exists() {
+        let mut cmd = find_cargo();
+        cmd.args(["update", "--workspace", "--offline"]);
+        match cmd.status() {
+            Ok(status) if status.success() => {}
+            Ok(status) => {
+                eprintln!("{cmd:?} failed: {status}; run `cargo update --workspace` later")
+            }
+            Err(err) => eprintln!("{cmd:?} failed: {err}; run `cargo update --workspace` later"),
+        }
+    }
+}
+
+/// Returns the files naming the crates, relative to the workspace: the workspace manifest, the
+/// workflows, and the manifests and Rust sources of the `members`.
+fn renamed_files(members: &[(String, String)]) -> Vec<String> {
+    let root = workspace_dir();
+    let mut files = vec!["Cargo.toml".to_owned()];
+    if let Ok(entries) = std::fs::read_dir(root.join(".github/workflows")) {
+        for entry in entries {
+            let name = entry.unwrap().file_name().to_string_lossy().into_owned();
+            if name.ends_with(".yml") || name.ends_with(".yaml") {
+                files.push(format!(".github/workflows/{name}"));
+            }
+        }
+    }
+    for (_, dir) in members {
+        if dir.is_empty() {
+            continue;
+        }
+        files.push(format!("{dir}/Cargo.toml"));
+        let mut sources = vec![];
+        metadata::collect_sources(&root.join(dir), &["target"], &mut sources);
+        files.extend(sources.into_iter().map(|path| {
+            let path = path.strip_prefix(root).unwrap_or(&path);
+            path.to_string_lossy().replace('\\', "/")
+        }));
+    }
+    files.sort();
+    files.dedup();
+    files
+}
+
+/// Points the workspace members and the `path` dependencies of the manifest `content` in the
+/// directory `dir`, relative to the workspace, to the renamed directories, and its docs.rs
+/// `documentation` to the renamed crate.
+///
+/// The word-based rename already covers a path like `"demo"`, but not one like `"crates/demo"`
+/// or `"../demo"`, where the name does not start a word.
+fn update_manifest(content: &str, dir: &Path, renames: &[CrateRename]) -> String {
+    let Ok(mut doc) = content.parse::<DocumentMut>() else {
+        return content.to_owned();
+    };
+    let package = doc.get_mut("package").and_then(Item::as_table_mut);
+    rename_documentation(package, renames);
+    let workspace = doc.get_mut("workspace").and_then(Item::as_table_mut);
+    let package = workspace.and_then(|workspace| workspace.get_mut("package"));
+    rename_documentation(package.and_then(Item::as_table_mut), renames);
+    let renamed = |value: &mut Value| {
+        let Some(path) = value.as_str() else {
+            return;
+        };
+        let target = normalize(&dir.join(path));
+        let rename = renames
+            .iter()
+            .find(|rename| rename.renames_dir() && target == Path::new(&rename.dir));
+        if let Some(rename) = rename {
+            let (parent, _) = path
+                .trim_end_matches('/')
+                .rsplit_once('/')
+                .unwrap_or(("", ""));
+            let name = rename.new_dir.rsplit('/').next().unwrap_or(&rename.new_dir);
+            let new_path = match parent {
+                "" => name.to_owned(),
+                parent => format!("{parent}/{name}"),
+            };
+            let decor = value.decor().clone();
+            *value = Value::from(new_path);
+            *value.decor_mut() = decor;
+        }
+    };
+
+    if let Some(workspace) = doc.get_mut("workspace").and_then(Item::as_table_mut) {
+        for key in ["members", "default-members", "exclude"] {
+            if let Some(members) = workspace.get_mut(key).and_then(Item::as_array_mut) {
+                members.iter_mut().for_each(renamed);
+            }
+        }
+        rename_dependency_paths(workspace, &renamed);
+    }
+    rename_dependency_paths(doc.as_table_mut(), &renamed);
+    if let Some(targets) = doc.get_mut("target").and_then(Item::as_table_mut) {
+        for (_, target) in targets.iter_mut() {
+            if let Some(target) = target.as_table_mut() {
+                rename_dependency_paths(target, &renamed);
+            }
+        }
+    }
+    doc.to_string()
+}
+
+/// Points the `documentation` of the `package` table at docs.rs to the renamed crate.
+fn rename_documentation(package: Option<&mut Table>, renames: &[CrateRename]) {
+    let documentation = package.and_then(|package| package.get_mut("documentation"));
+    let Some(value) = documentation.and_then(Item::as_value_mut) else {
+        return;
+    };
+    let rename = renames
+        .iter()
+        .find(|rename| value.as_str() == Some(&format!("https://docs.rs/{}", rename.package)));
+    if let Some(rename) = rename {
+        let decor = value.decor().clone();
+        *value = Value::from(format!("https://docs.rs/{}", rename.new_package));
+        *value.decor_mut() = decor;
+    }
+}
+
+/// Applies `renamed` to the `path` of every dependency in the dependency tables of `table`.
+fn rename_dependency_paths(table: &mut Table, renamed: &impl Fn(&mut Value)) {
+    for key in DEPENDENCY_TABLES {
+        let Some(dependencies) = table.get_mut(key).and_then(Item::as_table_like_mut) else {
+            continue;
+        };
+        for (_, dependency) in dependencies.iter_mut() {
+            let path = match dependency {
+                Item::Value(Value::InlineTable(inline)) => inline.get_mut("path"),
+                Item::Table(table) => table.get_mut("path").and_then(Item::as_value_mut),
+                _ => None,
+            };
+            if let Some(path) = path {
+                renamed(path);
+            }
+        }
+    }
+}
+
+/// Resolves the `.` and `..` components of `path` without touching the file system.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_update_manifest() {
+        let members = [("demo", "crates/demo"), ("demo-cli", "demo-cli")]
+            .map(|(package, dir)| (package.to_owned(), dir.to_owned()));
+        let renames = rename::prefixed_renames(&members, "demo", "acme", "acme");
+
+        let workspace = "[workspace]\nmembers = [\"crates/demo\", \"demo-cli\"]\n\n[workspace.dependencies]\ndemo = { path = \"crates/demo\" }\n";
+        let renamed = update_manifest(
+            &rename::rename_crates(workspace, &renames, false),
+            Path::new(""),
+            &renames,
+        );
+        assert_eq!(
+            renamed,
+            "[workspace]\nmembers = [\"crates/acme\", \"acme-cli\"]\n\n[workspace.dependencies]\nacme = { path = \"crates/acme\" }\n"
+        );
+
+        let member = "[package]\ndocumentation = \"https://docs.rs/demo\"\n\n[dependencies]\ndemo = { path = \"../crates/demo\" }\n\n[dev-dependencies.other]\npath = \"../other\"\n";
+        assert_eq!(
+            update_manifest(member, Path::new("demo-cli"), &renames),
+            "[package]\ndocumentation = \"https://docs.rs/acme\"\n\n[dependencies]\ndemo = { path = \"../crates/acme\" }\n\n[dev-dependencies.other]\npath = \"../other\"\n"
+        );
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use super::history;
+use super::workspace_dir;
+
+/// Placeholders the bootstrap rewrites wherever they occur, by the value they stand for.
+pub const PLACEHOLDERS: &[&str] = &["fast/template", "${projectName}"];
+
+/// The copyright holder of the template, which must not survive a bootstrap either, though the
+/// bootstrap rewrites it along with the copyright notices rather than as a placeholder.
+const TEMPLATE_HOLDER: &str = "FastLabs";
+
+/// The journal of an in-place bootstrap, as the `xtask-bootstrap` engine names it, which keeps the
+/// template files to undo the bootstrap until the cleanup removes it along with the engine.
+pub const JOURNAL_FILE: &str = ".bootstrap-journal.json";
+
+/// Directories that never contain project sources.
+const SKIPPED_DIRS: &[&str] = &[".git", "target"];
+
+/// The xtask sources, relative to the workspace, which define the placeholders themselves and are
+/// left alone by the bootstrap.
+pub const TOOLING_DIRS: &[&str] = &["xtask/src/", "xtask-bootstrap/src/"];
+
+/// Scans the workspace for leftover template placeholders and exits non-zero if any is found.
+pub fn scan_placeholders() {
+    let leftovers = leftover_placeholders(workspace_dir());
+    for (file, line_number, line) in &leftovers {
+        println!("{file}:{line_number}: {line}");
+    }
+
+    if !leftovers.is_empty() {
+        eprintln!(
+            "\nFound {} leftover placeholder(s); the bootstrap is incomplete.",
+            leftovers.len()
+        );
+        history::exit(1);
+    }
+    println!("No leftover placeholders found.");
+}
+
+/// Returns the file, relative to `root`, the 1-based line number and the trimmed content of every
+/// line under `root` containing a placeholder, except in the xtask sources and in the journal of
+/// an in-place bootstrap, which keeps the template files to undo it.
+pub fn leftover_placeholders(root: &Path) -> Vec<(String, usize, String)> {
+    let mut files = vec![];
+    collect_files(root, &mut files);
+    files.sort();
+
+    let mut leftovers = vec![];
+    for file in files {
+        let relative = file.strip_prefix(root).unwrap_or(&file);
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        if relative == JOURNAL_FILE || TOOLING_DIRS.iter().any(|dir| relative.starts_with(dir)) {
+            continue;
+        }
+        // skip binary or otherwise non-UTF-8 files
+        let Ok(content) = std::fs::read_to_string(&file) else {
+            continue;
+        };
+        for (line_number, line) in find_placeholders(&content) {
+            leftovers.push((relative.clone(), line_number, line.trim().to_owned()));
+        }
+    }
+    leftovers
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let entries = std::fs::read_dir(dir)
+        .unwrap_or_else(|err| panic!("failed to read directory {}: {err}", dir.display()));
+    for entry in entries {
+        let entry = entry.unwrap();
+        let path = entry.path();
+        let file_type = entry.file_type().unwrap();
+        if file_type.is_dir() {
+            if !SKIPPED_DIRS
+                .iter()
+                .any(|skipped| entry.file_name() == *skipped)
+            {
+                collect_files(&path, files);
+            }
+        } else if file_type.is_file() {
+            files.push(path);
+        }
+    }
+}
+
+/// Returns the 1-based line number and content of every line containing a placeholder.
+fn find_placeholders(content: &str) -> Vec<(usize, &str)> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| {
+            line.contains(TEMPLATE_HOLDER) || PLACEHOLDERS.iter().any(|p| line.contains(p))
+        })
+        .map(|(index, line)| (index + 1, line))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_placeholders() {
+        let content = "[package]\nrepository = \"https://github.com/fast/template\"\nname = \"my-project\"\n# Copyright FastLabs Developers\n[crates-url]: https://crates.io/crates/${projectName}\n";
+        assert_eq!(
+            find_placeholders(content),
+            vec![
+                (2, "repository = \"https://github.com/fast/template\""),
+                (4, "# Copyright FastLabs Developers"),
+                (5, "[crates-url]: https://crates.io/crates/${projectName}"),
+            ]
+        );
+
+        assert!(find_placeholders("name = \"my-project\"\n").is_empty());
+    }
+}
@@ -0,0 +1,263 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+
+use super::find_command;
+use super::history;
+use super::workspace_dir;
+
+/// The allowlist file, relative to the workspace.
+///
+/// Each non-comment line is either a literal value that is not a secret, or `path:<prefix>` to
+/// skip every file under the given path.
+const ALLOWLIST_FILE: &str = ".secrets-allowlist";
+
+/// Tokens at least this long are checked for high entropy.
+const MIN_ENTROPY_TOKEN_LEN: usize = 20;
+
+/// Shannon entropy, in bits per character, above which a token is considered random.
+const ENTROPY_THRESHOLD: f64 = 4.5;
+
+/// Known credential formats: the kind, the token prefix, and the exact token length.
+const KNOWN_PATTERNS: &[(&str, &str, usize)] = &[
+    ("AWS access key", "AKIA", 20),
+    ("AWS temporary access key", "ASIA", 20),
+    ("GitHub personal access token", "ghp_", 40),
+    ("GitHub OAuth token", "gho_", 40),
+    ("GitHub user-to-server token", "ghu_", 40),
+    ("GitHub server-to-server token", "ghs_", 40),
+    ("GitHub refresh token", "ghr_", 40),
+    ("GitHub fine-grained token", "github_pat_", 93),
+];
+
+#[derive(Debug, PartialEq)]
+struct Finding {
+    kind: &'static str,
+    value: String,
+}
+
+#[derive(Default)]
+struct Allowlist {
+    values: BTreeSet<String>,
+    paths: Vec<String>,
+}
+
+impl Allowlist {
+    fn load() -> Self {
+        let file = workspace_dir().join(ALLOWLIST_FILE);
+        if !file.exists() {
+            return Self::default();
+        }
+        let content = std::fs::read_to_string(file).unwrap();
+        Self::parse(&content)
+    }
+
+    fn parse(content: &str) -> Self {
+        let mut allowlist = Self::default();
+        for line in content.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            match line.strip_prefix("path:") {
+                Some(path) => allowlist.paths.push(path.trim().to_owned()),
+                None => {
+                    allowlist.values.insert(line.to_owned());
+                }
+            }
+        }
+        allowlist
+    }
+
+    fn allows_path(&self, path: &str) -> bool {
+        self.paths.iter().any(|prefix| path.starts_with(prefix))
+    }
+
+    fn allows_value(&self, value: &str) -> bool {
+        self.values.contains(value)
+    }
+}
+
+/// Scans tracked files and the last `history` commits for credentials and exits non-zero if any
+/// is found.
+pub fn scan_secrets(history: usize) {
+    let allowlist = Allowlist::load();
+    let mut count = 0;
+
+    for path in git_output(&["ls-files", "-z"]).split('\0') {
+        if path.is_empty() || path == ALLOWLIST_FILE || allowlist.allows_path(path) {
+            continue;
+        }
+        // skip binary or otherwise non-UTF-8 files
+        let Ok(content) = std::fs::read_to_string(workspace_dir().join(path)) else {
+            continue;
+        };
+        for (index, line) in content.lines().enumerate() {
+            count += report(&allowlist, &format!("{path}:{}", index + 1), line);
+        }
+    }
+
+    if history > 0 {
+        let log = git_output(&[
+            "log",
+            "-p",
+            "--no-color",
+            "--no-ext-diff",
+            "--format=commit %h",
+            &format!("-{history}"),
+        ]);
+        let mut commit = "";
+        let mut path = "";
+        for line in log.lines() {
+            if let Some(hash) = line.strip_prefix("commit ") {
+                commit = hash;
+            } else if let Some(file) = line.strip_prefix("+++ b/") {
+                path = file;
+            } else if let Some(added) = line.strip_prefix('+') {
+                if path == ALLOWLIST_FILE || allowlist.allows_path(path) {
+                    continue;
+                }
+                count += report(&allowlist, &format!("{commit}:{path}"), added);
+            }
+        }
+    }
+
+    if count > 0 {
+        eprintln!(
+            "\nFound {count} potential secret(s). Remove them, or add false positives to {ALLOWLIST_FILE}."
+        );
+        history::exit(1);
+    }
+    println!("No secrets found.");
+}
+
+fn report(allowlist: &Allowlist, location: &str, line: &str) -> usize {
+    let mut count = 0;
+    for finding in find_secrets(line) {
+        if !allowlist.allows_value(&finding.value) {
+            println!("{location}: {}: {}", finding.kind, redact(&finding.value));
+            count += 1;
+        }
+    }
+    count
+}
+
+fn git_output(args: &[&str]) -> String {
+    let mut cmd = find_command("git");
+    cmd.args(args);
+    let output = cmd.output().expect("failed to execute git");
+    assert!(output.status.success(), "{cmd:?} failed: {}", output.status);
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn find_secrets(line: &str) -> Vec<Finding> {
+    let mut findings = vec![];
+    // split so that this line does not match itself
+    if line.contains("-----BEGIN") && line.contains(concat!("PRIVATE KEY", "-----")) {
+        findings.push(Finding {
+            kind: "private key",
+            value: line.trim().to_owned(),
+        });
+    }
+
+    let tokens = line.split(|c: char| !(c.is_ascii_alphanumeric() || "_+/-".contains(c)));
+    for token in tokens {
+        let known = KNOWN_PATTERNS.iter().find(|(_, prefix, len)| {
+            token.len() == *len
+                && token.starts_with(prefix)
+                && token[prefix.len()..]
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        });
+        if let Some((kind, _, _)) = known {
+            findings.push(Finding {
+                kind,
+                value: token.to_owned(),
+            });
+        } else if token.len() >= MIN_ENTROPY_TOKEN_LEN && shannon_entropy(token) > ENTROPY_THRESHOLD
+        {
+            findings.push(Finding {
+                kind: "high-entropy string",
+                value: token.to_owned(),
+            });
+        }
+    }
+    findings
+}
+
+fn shannon_entropy(token: &str) -> f64 {
+    let mut counts = [0usize; 256];
+    for byte in token.bytes() {
+        counts[byte as usize] += 1;
+    }
+    let len = token.len() as f64;
+    counts
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / len;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+fn redact(value: &str) -> String {
+    let visible: String = value.chars().take(4).collect();
+    format!("{visible}***")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_secrets() {
+        // assembled at runtime so that this file does not trip the scanner itself
+        let aws = format!("AKIA{}", "Z7Q2PLMX4KD9WT3B");
+        let github = format!("ghp_{}", "a".repeat(36));
+        let random = ["kF9xQ2mZ8vL", "1pR7tW4yB6n", "C3hJ5gD0sA"].concat();
+
+        let line = format!("aws_access_key_id = \"{aws}\"");
+        assert_eq!(
+            find_secrets(&line),
+            vec![Finding {
+                kind: "AWS access key",
+                value: aws,
+            }]
+        );
+
+        let line = format!("GITHUB_TOKEN={github}");
+        assert_eq!(find_secrets(&line)[0].kind, "GitHub personal access token");
+
+        let line = format!("let key = \"{random}\";");
+        assert_eq!(find_secrets(&line)[0].kind, "high-entropy string");
+
+        let line = format!("-----BEGIN RSA {}-----", "PRIVATE KEY");
+        assert_eq!(find_secrets(&line)[0].kind, "private key");
+
+        assert!(find_secrets("fn make_build_cmd(locked: bool) -> StdCommand {").is_empty());
+        assert!(
+            find_secrets("https://github.com/octocat/demo/actions?query=workflow%3ACI").is_empty()
+        );
+    }
+
+    #[test]
+    fn test_allowlist() {
+        let allowlist = Allowlist::parse("# comment\nnot-a-secret\npath: tests/fixtures/\n");
+        assert!(allowlist.allows_value("not-a-secret"));
+        assert!(!allowlist.allows_value("another"));
+        assert!(allowlist.allows_path("tests/fixtures/keys.pem"));
+        assert!(!allowlist.allows_path("src/lib.rs"));
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use super::find_cargo;
+use super::metadata;
+use super::run_command;
+use super::workspace_dir;
+use super::worktree;
+
+/// Builds the release artifacts at `since` and at the working tree, and prints a markdown table
+/// comparing their sizes, also written to `output` if given.
+pub fn size_diff(since: &str, output: Option<PathBuf>) {
+    let work_dir = workspace_dir().join("target/xtask/size-diff");
+    let base_dir = work_dir.join("base");
+    let base_target_dir = work_dir.join("target");
+    worktree::add_worktree(&base_dir, since);
+
+    let mut cmd = make_release_build_cmd(&base_dir);
+    cmd.env("CARGO_TARGET_DIR", &base_target_dir);
+    run_command(cmd);
+    let base = artifact_sizes(&base_dir, &base_target_dir.join("release"));
+    worktree::remove_worktree(&base_dir);
+
+    run_command(make_release_build_cmd(workspace_dir()));
+    let head = artifact_sizes(workspace_dir(), &workspace_dir().join("target/release"));
+
+    let report = render_report(since, &base, &head);
+    println!("{report}");
+    if let Some(output) = output {
+        std::fs::write(&output, report).unwrap();
+        println!("Wrote the size report to {}.", output.display());
+    }
+}
+
+fn make_release_build_cmd(dir: &Path) -> std::process::Command {
+    let mut cmd = find_cargo();
+    cmd.current_dir(dir)
+        .args(["build", "--release", "--workspace", "--lib", "--bins"]);
+    cmd
+}
+
+/// Returns the size in bytes of every library and binary built from the workspace members.
+fn artifact_sizes(workspace: &Path, release_dir: &Path) -> BTreeMap<String, u64> {
+    let mut cmd = find_cargo();
+    cmd.current_dir(workspace)
+        .args(["metadata", "--format-version", "1", "--no-deps"]);
+    let output = cmd.output().expect("failed to execute cargo metadata");
+    assert!(output.status.success(), "{cmd:?} failed: {}", output.status);
+    let metadata = serde_json::from_slice(&output.stdout).unwrap();
+
+    let mut names = BTreeSet::new();
+    for package in metadata::workspace_packages(&metadata) {
+        for target in package["targets"].as_array().unwrap() {
+            let name = target["name"].as_str().unwrap();
+            for kind in target["kind"].as_array().unwrap() {
+                match kind.as_str().unwrap() {
+                    "bin" => {
+                        names.insert(format!("{name}{}", std::env::consts::EXE_SUFFIX));
+                    }
+                    "lib" | "rlib" => {
+                        names.insert(format!("lib{}.rlib", name.replace('-', "_")));
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let size = std::fs::metadata(release_dir.join(&name)).ok()?.len();
+            Some((name, size))
+        })
+        .collect()
+}
+
+fn render_report(
+    since: &str,
+    base: &BTreeMap<String, u64>,
+    head: &BTreeMap<String, u64>,
+) -> String {
+    let mut out = String::new();
+    writeln!(out, "### Release artifact sizes compared to `{since}`").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "| Artifact | Base | Head | Diff |").unwrap();
+    writeln!(out, "|:---|---:|---:|---:|").unwrap();
+    let names: BTreeSet<&String> = base.keys().chain(head.keys()).collect();
+    for name in names {
+        let format_size = |size: Option<&u64>| size.map_or("-".to_owned(), |s| s.to_string());
+        let diff = match (base.get(name), head.get(name)) {
+            (Some(&base), Some(&head)) => {
+                let delta = head as i64 - base as i64;
+                if base == 0 {
+                    format!("{delta:+}")
+                } else {
+                    let percent = delta as f64 * 100.0 / base as f64;
+                    format!("{delta:+} ({percent:+.2}%)")
+                }
+            }
+            (None, Some(_)) => "added".to_owned(),
+            (Some(_), None) => "removed".to_owned(),
+            (None, None) => unreachable!(),
+        };
+        writeln!(
+            out,
+            "| `{name}` | {} | {} | {diff} |",
+            format_size(base.get(name)),
+            format_size(head.get(name)),
+        )
+        .unwrap();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_report() {
+        let base = BTreeMap::from([("app".to_owned(), 1000), ("libold.rlib".to_owned(), 10)]);
+        let head = BTreeMap::from([("app".to_owned(), 1100), ("libnew.rlib".to_owned(), 20)]);
+        let report = render_report("origin/main", &base, &head);
+        assert!(report.starts_with("### Release artifact sizes compared to `origin/main`\n"));
+        assert!(report.contains("| `app` | 1000 | 1100 | +100 (+10.00%) |\n"));
+        assert!(report.contains("| `libnew.rlib` | - | 20 | added |\n"));
+        assert!(report.contains("| `libold.rlib` | 10 | - | removed |\n"));
+    }
+}
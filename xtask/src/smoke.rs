@@ -0,0 +1,110 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::find_cargo;
+use super::find_command;
+use super::make_target_add_cmd;
+use super::metadata;
+use super::run_command;
+use super::workspace_dir;
+
+/// Builds the release binary for each image's libc and runs `command` inside a container of it.
+///
+/// `{bin}` in `command` is replaced with the binary name. When `bin` is omitted, the only binary
+/// target of the publishable workspace members is used.
+pub fn smoke_test(images: &[String], bin: Option<String>, command: &str) {
+    let bin = bin.unwrap_or_else(default_bin);
+    let command = command.replace("{bin}", &bin);
+
+    for image in images {
+        let target = image_target(image);
+        println!("Smoke testing '{bin}' ({target}) on {image}...");
+
+        run_command(make_target_add_cmd(&target));
+
+        let mut cmd = find_cargo();
+        cmd.args(["build", "--release", "--bin", &bin, "--target", &target]);
+        run_command(cmd);
+
+        let exe = workspace_dir()
+            .join("target")
+            .join(&target)
+            .join("release")
+            .join(&bin);
+        let mut cmd = find_command("docker");
+        cmd.args(["run", "--rm", "-v"])
+            .arg(format!("{}:/usr/local/bin/{bin}:ro", exe.display()))
+            .args([image, "sh", "-c", &command]);
+        run_command(cmd);
+    }
+}
+
+fn default_bin() -> String {
+    let metadata = metadata::cargo_metadata(true);
+    let bins: Vec<String> = metadata::workspace_packages(&metadata)
+        .into_iter()
+        .filter(|package| metadata::is_publishable(package))
+        .flat_map(|package| package["targets"].as_array().unwrap())
+        .filter(|target| {
+            target["kind"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .any(|k| k == "bin")
+        })
+        .map(|target| target["name"].as_str().unwrap().to_owned())
+        .collect();
+    match bins.as_slice() {
+        [bin] => bin.clone(),
+        [] => panic!("no binary target found in the publishable workspace members"),
+        _ => panic!("multiple binary targets found, pass one with --bin: {bins:?}"),
+    }
+}
+
+/// Returns the target triple whose libc matches the image, e.g., musl for Alpine.
+fn image_target(image: &str) -> String {
+    let arch = std::env::consts::ARCH;
+    let name = image.rsplit('/').next().unwrap_or(image);
+    if name.starts_with("alpine") {
+        format!("{arch}-unknown-linux-musl")
+    } else {
+        format!("{arch}-unknown-linux-gnu")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_image_target() {
+        let arch = std::env::consts::ARCH;
+        assert_eq!(
+            image_target("alpine:3.20"),
+            format!("{arch}-unknown-linux-musl")
+        );
+        assert_eq!(
+            image_target("docker.io/library/alpine"),
+            format!("{arch}-unknown-linux-musl")
+        );
+        assert_eq!(
+            image_target("ubuntu:24.04"),
+            format!("{arch}-unknown-linux-gnu")
+        );
+        assert_eq!(
+            image_target("debian:12-slim"),
+            format!("{arch}-unknown-linux-gnu")
+        );
+    }
+}
@@ -0,0 +1,312 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use toml_edit::Array;
+use toml_edit::ArrayOfTables;
+use toml_edit::DocumentMut;
+use toml_edit::InlineTable;
+use toml_edit::Item;
+use toml_edit::Table;
+use toml_edit::value;
+
+use super::config;
+use super::history;
+use super::workspace_dir;
+
+/// Keys inherited from `[workspace.package]` by the generated CLI member.
+const INHERITED_KEYS: &[&str] = &[
+    "edition",
+    "homepage",
+    "license",
+    "readme",
+    "repository",
+    "rust-version",
+];
+
+/// Splits the single library member into `{name}-core`, holding the existing code, and a new
+/// `{name}-cli` binary depending on it.
+///
+/// The library directory is moved, the workspace members and dependencies are rewritten, and
+/// paths to the old directory in the GitHub workflows are updated, with list entries duplicated
+/// for the CLI member so path filters keep triggering.
+pub fn split_workspace(name: Option<&str>) {
+    let root = workspace_dir();
+    let manifest_path = root.join("Cargo.toml");
+    let mut workspace: DocumentMut = std::fs::read_to_string(&manifest_path)
+        .unwrap()
+        .parse()
+        .unwrap_or_else(|err| history::fail(&format!("invalid workspace Cargo.toml: {err}")));
+
+    let members = config::string_array(
+        workspace.get("workspace").and_then(|w| w.get("members")),
+        "'workspace.members' in Cargo.toml",
+    )
+    .unwrap_or_else(|err| history::fail(&err));
+    let libraries: Vec<&String> = members.iter().filter(|m| *m != "xtask").collect();
+    let [old_dir] = libraries[..] else {
+        panic!("expected exactly one member besides xtask, found {libraries:?}");
+    };
+
+    let old_manifest_path = root.join(old_dir).join("Cargo.toml");
+    let old_manifest = std::fs::read_to_string(&old_manifest_path).unwrap();
+    let mut core_manifest: DocumentMut = old_manifest.parse().unwrap_or_else(|err| {
+        history::fail(&format!("invalid {}: {err}", old_manifest_path.display()))
+    });
+    let package_name = core_manifest["package"]["name"]
+        .as_str()
+        .unwrap()
+        .to_owned();
+    let version = core_manifest["package"]["version"]
+        .as_str()
+        .unwrap_or("0.0.1")
+        .to_owned();
+    let name = name.unwrap_or(&package_name);
+    let core = format!("{name}-core");
+    let cli = format!("{name}-cli");
+    for dir in [&core, &cli] {
+        assert!(!root.join(dir).exists(), "{dir} already exists");
+    }
+
+    let lib_rs = std::fs::read_to_string(root.join(old_dir).join("src/lib.rs")).unwrap_or_default();
+    let rust_header = leading_header(&lib_rs, "//");
+    let toml_header = leading_header(&old_manifest, "#");
+
+    std::fs::rename(root.join(old_dir), root.join(&core)).unwrap();
+    core_manifest["package"]["name"] = value(core.as_str());
+    std::fs::write(
+        root.join(&core).join("Cargo.toml"),
+        core_manifest.to_string(),
+    )
+    .unwrap();
+    println!("Moved {old_dir} to {core}.");
+
+    let cli_src = root.join(&cli).join("src");
+    std::fs::create_dir_all(&cli_src).unwrap();
+    std::fs::write(
+        root.join(&cli).join("Cargo.toml"),
+        format!(
+            "{toml_header}{}",
+            render_cli_manifest(name, &core, &version)
+        ),
+    )
+    .unwrap();
+    std::fs::write(
+        cli_src.join("main.rs"),
+        format!("{rust_header}{}", render_cli_main(name)),
+    )
+    .unwrap();
+    println!("Created {cli}.");
+
+    update_workspace_manifest(&mut workspace, old_dir, &core, &cli, &version);
+    std::fs::write(&manifest_path, workspace.to_string()).unwrap();
+    println!("Updated the workspace members.");
+
+    let workflows = root.join(".github/workflows");
+    if let Ok(entries) = std::fs::read_dir(&workflows) {
+        for entry in entries {
+            let path = entry.unwrap().path();
+            update_file(&path, |content| {
+                update_workflow(content, old_dir, &core, &cli)
+            });
+        }
+    }
+}
+
+fn update_file(path: &Path, update: impl FnOnce(&str) -> String) {
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return;
+    };
+    let updated = update(&content);
+    if updated != content {
+        std::fs::write(path, updated).unwrap();
+        println!("Updated {}.", path.display());
+    }
+}
+
+/// Returns the license header comment at the start of `content`, followed by a blank line.
+pub fn leading_header(content: &str, prefix: &str) -> String {
+    let header: String = content
+        .split_inclusive('\n')
+        .take_while(|line| line.starts_with(prefix) && !line.starts_with("//!"))
+        .collect();
+    if header.is_empty() {
+        header
+    } else {
+        header + "\n"
+    }
+}
+
+pub fn render_cli_manifest(name: &str, core: &str, version: &str) -> String {
+    let mut doc = DocumentMut::new();
+    let mut package = Table::new();
+    package.insert("name", value(format!("{name}-cli")));
+    package.insert("version", value(version));
+    package.insert("publish", value(false));
+    for key in INHERITED_KEYS {
+        package.insert(key, inherit_workspace());
+    }
+    doc.insert("package", Item::Table(package));
+
+    let mut bin = Table::new();
+    bin.insert("name", value(name));
+    bin.insert("path", value("src/main.rs"));
+    let mut bins = ArrayOfTables::new();
+    bins.push(bin);
+    doc.insert("bin", Item::ArrayOfTables(bins));
+
+    let mut dependencies = Table::new();
+    dependencies.insert(core, inherit_workspace());
+    doc.insert("dependencies", Item::Table(dependencies));
+
+    let mut lints = Table::new();
+    lints.insert("workspace", value(true));
+    doc.insert("lints", Item::Table(lints));
+    doc.to_string()
+}
+
+/// Returns `workspace = true` as a dotted key, e.g., `edition.workspace = true`.
+fn inherit_workspace() -> Item {
+    let mut table = Table::new();
+    table.set_dotted(true);
+    table.insert("workspace", value(true));
+    Item::Table(table)
+}
+
+fn render_cli_main(name: &str) -> String {
+    format!(
+        r#"//! The command-line interface of {name}.
+
+fn main() {{
+    println!("{{}} {{}}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
+}}
+"#
+    )
+}
+
+/// Replaces the library member with the core and CLI members, and declares the core crate as a
+/// workspace dependency.
+fn update_workspace_manifest(
+    doc: &mut DocumentMut,
+    old_dir: &str,
+    core: &str,
+    cli: &str,
+    version: &str,
+) {
+    let workspace = doc["workspace"].as_table_mut().unwrap();
+    let members = workspace["members"].as_array_mut().unwrap();
+    let mut updated = Array::new();
+    for member in members.iter() {
+        match member.as_str() {
+            Some(member) if member == old_dir => {
+                updated.push(core);
+                updated.push(cli);
+            }
+            Some(member) => updated.push(member),
+            None => {}
+        }
+    }
+    *members = updated;
+
+    let dependencies = workspace
+        .entry("dependencies")
+        .or_insert(Item::Table(Table::new()))
+        .as_table_mut()
+        .unwrap();
+    let mut dependency = InlineTable::new();
+    dependency.insert("path", core.into());
+    dependency.insert("version", version.into());
+    dependencies.insert(core, value(dependency));
+}
+
+/// Points paths under `old_dir` to `core`, duplicating YAML list entries for `cli`.
+fn update_workflow(content: &str, old_dir: &str, core: &str, cli: &str) -> String {
+    let old = format!("{old_dir}/");
+    let mut out = String::new();
+    for line in content.split_inclusive('\n') {
+        if !line.contains(&old) {
+            out.push_str(line);
+            continue;
+        }
+        out.push_str(&line.replace(&old, &format!("{core}/")));
+        if line.trim_start().starts_with("- ") {
+            out.push_str(&line.replace(&old, &format!("{cli}/")));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_cli_manifest() {
+        assert_eq!(
+            render_cli_manifest("demo", "demo-core", "0.1.0"),
+            r#"[package]
+name = "demo-cli"
+version = "0.1.0"
+publish = false
+edition.workspace = true
+homepage.workspace = true
+license.workspace = true
+readme.workspace = true
+repository.workspace = true
+rust-version.workspace = true
+
+[[bin]]
+name = "demo"
+path = "src/main.rs"
+
+[dependencies]
+demo-core.workspace = true
+
+[lints]
+workspace = true
+"#
+        );
+    }
+
+    #[test]
+    fn test_update_workspace_manifest() {
+        let mut doc: DocumentMut = "[workspace]\nmembers = [\"demo\", \"xtask\"]\n"
+            .parse()
+            .unwrap();
+        update_workspace_manifest(&mut doc, "demo", "demo-core", "demo-cli", "0.1.0");
+        assert_eq!(
+            doc.to_string(),
+            "[workspace]\nmembers = [\"demo-core\", \"demo-cli\", \"xtask\"]\n\n[workspace.dependencies]\ndemo-core = { path = \"demo-core\", version = \"0.1.0\" }\n"
+        );
+    }
+
+    #[test]
+    fn test_update_workflow() {
+        let content = "on:\n  push:\n    paths:\n      - 'demo/**'\n      - 'Cargo.toml'\njobs:\n  run: cargo test --manifest-path demo/Cargo.toml\n";
+        assert_eq!(
+            update_workflow(content, "demo", "demo-core", "demo-cli"),
+            "on:\n  push:\n    paths:\n      - 'demo-core/**'\n      - 'demo-cli/**'\n      - 'Cargo.toml'\njobs:\n  run: cargo test --manifest-path demo-core/Cargo.toml\n"
+        );
+    }
+
+    #[test]
+    fn test_leading_header() {
+        assert_eq!(
+            leading_header("// Copyright\n// Licensed\n\n//! Docs.\n", "//"),
+            "// Copyright\n// Licensed\n\n"
+        );
+        assert_eq!(leading_header("//! Docs.\n", "//"), "");
+    }
+}
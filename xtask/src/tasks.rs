@@ -0,0 +1,243 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::process::Command as StdCommand;
+
+use toml_edit::DocumentMut;
+use toml_edit::Item;
+
+use super::config;
+use super::config::CONFIG_FILE;
+use super::find_command;
+use super::history;
+use super::run_command;
+
+/// A named task defined under `[tasks.<name>]` in `xtask.toml`.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Task {
+    pub description: Option<String>,
+    pub depends: Vec<String>,
+    pub commands: Vec<String>,
+    pub env: BTreeMap<String, String>,
+}
+
+/// Prints every task defined in `xtask.toml`.
+pub fn list_tasks() {
+    let tasks = load_tasks();
+    if tasks.is_empty() {
+        println!("No tasks defined in {CONFIG_FILE}.");
+        return;
+    }
+    let width = tasks.keys().map(String::len).max().unwrap_or_default();
+    for (name, task) in &tasks {
+        let description = task.description.as_deref().unwrap_or_default();
+        if task.depends.is_empty() {
+            println!("{name:<width$}  {description}");
+        } else {
+            let depends = task.depends.join(", ");
+            println!("{name:<width$}  {description} (depends on: {depends})");
+        }
+    }
+}
+
+/// Runs the named task after all of its dependencies, each task at most once.
+pub fn run_task(name: &str) {
+    let tasks = load_tasks();
+    let order = resolve_order(&tasks, name).unwrap_or_else(|err| history::fail(&err));
+    for name in order {
+        println!("Running task '{name}'...");
+        let task = &tasks[name];
+        for command in &task.commands {
+            let mut cmd = make_shell_cmd(command);
+            cmd.envs(&task.env);
+            run_command(cmd);
+        }
+    }
+}
+
+pub fn load_tasks() -> BTreeMap<String, Task> {
+    parse_tasks(&config::load_config())
+        .unwrap_or_else(|err| history::fail(&format!("invalid {CONFIG_FILE}: {err}")))
+}
+
+fn parse_tasks(doc: &DocumentMut) -> Result<BTreeMap<String, Task>, String> {
+    let Some(tasks) = doc.get("tasks") else {
+        return Ok(BTreeMap::new());
+    };
+    let tasks = tasks
+        .as_table_like()
+        .ok_or("'tasks' must be a table".to_owned())?;
+
+    let mut result = BTreeMap::new();
+    for (name, item) in tasks.iter() {
+        let table = item
+            .as_table_like()
+            .ok_or(format!("task '{name}' must be a table"))?;
+        let description = match table.get("description") {
+            Some(item) => Some(
+                item.as_str()
+                    .ok_or(format!("task '{name}': 'description' must be a string"))?
+                    .to_owned(),
+            ),
+            None => None,
+        };
+        let task = Task {
+            description,
+            depends: config::string_array(
+                table.get("depends"),
+                &format!("task '{name}': 'depends'"),
+            )?,
+            commands: config::string_array(
+                table.get("commands"),
+                &format!("task '{name}': 'commands'"),
+            )?,
+            env: parse_string_table(table.get("env"), name, "env")?,
+        };
+        result.insert(name.to_owned(), task);
+    }
+    Ok(result)
+}
+
+fn parse_string_table(
+    item: Option<&Item>,
+    task: &str,
+    key: &str,
+) -> Result<BTreeMap<String, String>, String> {
+    let Some(item) = item else {
+        return Ok(BTreeMap::new());
+    };
+    let error = || format!("task '{task}': '{key}' must be a table of strings");
+    let table = item.as_table_like().ok_or_else(error)?;
+    table
+        .iter()
+        .map(|(k, v)| Ok((k.to_owned(), v.as_str().ok_or_else(error)?.to_owned())))
+        .collect()
+}
+
+/// Returns the tasks to run for `name` in dependency order.
+fn resolve_order<'a>(
+    tasks: &'a BTreeMap<String, Task>,
+    name: &str,
+) -> Result<Vec<&'a str>, String> {
+    fn visit<'a>(
+        tasks: &'a BTreeMap<String, Task>,
+        name: &str,
+        stack: &mut Vec<&'a str>,
+        order: &mut Vec<&'a str>,
+    ) -> Result<(), String> {
+        let (name, task) = tasks
+            .get_key_value(name)
+            .ok_or(format!("task '{name}' is not defined in {CONFIG_FILE}"))?;
+        if order.contains(&name.as_str()) {
+            return Ok(());
+        }
+        if stack.contains(&name.as_str()) {
+            let cycle = stack.join(" -> ");
+            return Err(format!("task dependency cycle: {cycle} -> {name}"));
+        }
+        stack.push(name);
+        for dependency in &task.depends {
+            visit(tasks, dependency, stack, order)?;
+        }
+        stack.pop();
+        order.push(name);
+        Ok(())
+    }
+
+    let mut order = vec![];
+    visit(tasks, name, &mut vec![], &mut order)?;
+    Ok(order)
+}
+
+/// Returns a command running `command` with the platform shell.
+pub fn make_shell_cmd(command: &str) -> StdCommand {
+    if cfg!(windows) {
+        let mut cmd = find_command("cmd");
+        cmd.args(["/C", command]);
+        cmd
+    } else {
+        let mut cmd = find_command("sh");
+        cmd.args(["-c", command]);
+        cmd
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(content: &str) -> Result<BTreeMap<String, Task>, String> {
+        parse_tasks(&content.parse().unwrap())
+    }
+
+    #[test]
+    fn test_parse_tasks() {
+        let tasks = parse(
+            r#"
+[tasks.lint]
+commands = ["cargo x lint"]
+
+[tasks.ci]
+description = "Run the CI pipeline locally."
+depends = ["lint"]
+commands = ["cargo x test", "cargo x build --locked"]
+env = { RUST_BACKTRACE = "1" }
+"#,
+        )
+        .unwrap();
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks["lint"].commands, vec!["cargo x lint"]);
+        assert_eq!(
+            tasks["ci"],
+            Task {
+                description: Some("Run the CI pipeline locally.".into()),
+                depends: vec!["lint".into()],
+                commands: vec!["cargo x test".into(), "cargo x build --locked".into()],
+                env: BTreeMap::from([("RUST_BACKTRACE".into(), "1".into())]),
+            }
+        );
+
+        assert!(parse("").unwrap().is_empty());
+        assert!(parse("tasks = 1").is_err());
+        assert!(parse("[tasks.ci]\ncommands = \"cargo x test\"").is_err());
+        assert!(parse("[tasks.ci]\ndepends = [1]").is_err());
+        assert!(parse("[tasks.ci]\nenv = { A = 1 }").is_err());
+    }
+
+    #[test]
+    fn test_resolve_order() {
+        let tasks = parse(
+            r#"
+[tasks.a]
+depends = ["b", "c"]
+[tasks.b]
+depends = ["c"]
+[tasks.c]
+[tasks.d]
+depends = ["e"]
+[tasks.e]
+depends = ["d"]
+[tasks.f]
+depends = ["missing"]
+"#,
+        )
+        .unwrap();
+        assert_eq!(resolve_order(&tasks, "a"), Ok(vec!["c", "b", "a"]));
+        assert_eq!(resolve_order(&tasks, "c"), Ok(vec!["c"]));
+        assert!(resolve_order(&tasks, "d").unwrap_err().contains("cycle"));
+        assert!(resolve_order(&tasks, "f").is_err());
+        assert!(resolve_order(&tasks, "missing").is_err());
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::OnceLock;
+
+use toml_edit::DocumentMut;
+
+use super::workspace_dir;
+
+static OVERRIDE: OnceLock<String> = OnceLock::new();
+
+/// Overrides the toolchain for all spawned cargo commands, e.g., from `--toolchain`.
+pub fn set_override(toolchain: String) {
+    OVERRIDE
+        .set(toolchain)
+        .expect("toolchain override must be set only once");
+}
+
+/// Returns the toolchain spawned cargo commands run with: the override if set, otherwise the
+/// channel pinned in `rust-toolchain.toml`, or `None` to use the default toolchain.
+pub fn selected() -> Option<String> {
+    OVERRIDE.get().cloned().or_else(pinned_channel)
+}
+
+/// Returns the channel from `rust-toolchain.toml`, if any.
+pub fn pinned_channel() -> Option<String> {
+    let file = workspace_dir().join("rust-toolchain.toml");
+    let content = std::fs::read_to_string(file).ok()?;
+    parse_channel(&content)
+}
+
+fn parse_channel(content: &str) -> Option<String> {
+    let doc = content.parse::<DocumentMut>().ok()?;
+    let channel = doc.get("toolchain")?.get("channel")?.as_str()?;
+    Some(channel.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_channel() {
+        assert_eq!(
+            parse_channel("[toolchain]\nchannel = \"stable\"\ncomponents = [\"clippy\"]\n"),
+            Some("stable".into())
+        );
+        assert_eq!(
+            parse_channel("[toolchain]\nchannel = \"nightly-2026-01-01\"\n"),
+            Some("nightly-2026-01-01".into())
+        );
+        assert_eq!(
+            parse_channel("[toolchain]\ncomponents = [\"clippy\"]\n"),
+            None
+        );
+        assert_eq!(parse_channel(""), None);
+    }
+}
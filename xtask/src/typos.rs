@@ -0,0 +1,117 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use toml_edit::DocumentMut;
+use toml_edit::Table;
+
+use super::history;
+use super::workspace_dir;
+
+const TYPOS_FILE: &str = "typos.toml";
+
+/// Accepts `word` in the typos dictionary, as an identifier if `identifier` is true.
+pub fn add_word(word: &str, identifier: bool) {
+    let file = workspace_dir().join(TYPOS_FILE);
+    let content = std::fs::read_to_string(&file).unwrap_or_default();
+    let mut doc = content
+        .parse::<DocumentMut>()
+        .unwrap_or_else(|err| history::fail(&format!("invalid {TYPOS_FILE}: {err}")));
+
+    let key = table_key(identifier);
+    match insert_word(&mut doc, key, word) {
+        Ok(true) => {
+            std::fs::write(&file, doc.to_string()).unwrap();
+            println!("Added '{word}' to [default.{key}] in {TYPOS_FILE}.");
+        }
+        Ok(false) => println!("'{word}' is already in [default.{key}] of {TYPOS_FILE}."),
+        Err(err) => history::fail(&format!("invalid {TYPOS_FILE}: {err}")),
+    }
+}
+
+/// Prints the words and identifiers accepted by the typos dictionary.
+pub fn list_words() {
+    let file = workspace_dir().join(TYPOS_FILE);
+    let content = std::fs::read_to_string(&file).unwrap_or_default();
+    let doc = content
+        .parse::<DocumentMut>()
+        .unwrap_or_else(|err| history::fail(&format!("invalid {TYPOS_FILE}: {err}")));
+    for identifier in [false, true] {
+        let key = table_key(identifier);
+        let table = doc
+            .get("default")
+            .and_then(|default| default.get(key))
+            .and_then(|table| table.as_table_like());
+        println!("[default.{key}]");
+        for (word, correction) in table.iter().flat_map(|table| table.iter()) {
+            match correction.as_str() {
+                Some(correction) if correction != word => println!("  {word} -> {correction}"),
+                _ => println!("  {word}"),
+            }
+        }
+    }
+}
+
+fn table_key(identifier: bool) -> &'static str {
+    if identifier {
+        "extend-identifiers"
+    } else {
+        "extend-words"
+    }
+}
+
+/// Inserts `word = "word"` into `[default.<key>]`, returning whether the document changed.
+fn insert_word(doc: &mut DocumentMut, key: &str, word: &str) -> Result<bool, String> {
+    let default = doc
+        .entry("default")
+        .or_insert_with(|| {
+            let mut table = Table::new();
+            table.set_implicit(true);
+            table.into()
+        })
+        .as_table_mut()
+        .ok_or("'default' must be a table")?;
+    let words = default
+        .entry(key)
+        .or_insert_with(|| Table::new().into())
+        .as_table_mut()
+        .ok_or(format!("'default.{key}' must be a table"))?;
+    if words.contains_key(word) {
+        return Ok(false);
+    }
+    words.insert(word, toml_edit::value(word));
+    words.sort_values();
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_word() {
+        let mut doc = "[default.extend-words]\nteh = \"teh\"\n\n[files]\nextend-exclude = []\n"
+            .parse::<DocumentMut>()
+            .unwrap();
+        assert_eq!(insert_word(&mut doc, "extend-words", "abd"), Ok(true));
+        assert_eq!(insert_word(&mut doc, "extend-words", "teh"), Ok(false));
+        assert_eq!(insert_word(&mut doc, "extend-identifiers", "Fo0"), Ok(true));
+        assert_eq!(
+            doc.to_string(),
+            "[default.extend-words]\nabd = \"abd\"\nteh = \"teh\"\n\n[default.extend-identifiers]\nFo0 = \"Fo0\"\n\n[files]\nextend-exclude = []\n"
+        );
+
+        let mut doc = "default = 1\n".parse::<DocumentMut>().unwrap();
+        assert!(insert_word(&mut doc, "extend-words", "abd").is_err());
+    }
+}
@@ -0,0 +1,258 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use syn::visit::Visit;
+use toml_edit::DocumentMut;
+use toml_edit::Item;
+use toml_edit::Table;
+use toml_edit::value;
+
+use super::history;
+use super::metadata;
+use super::workspace_dir;
+
+/// The committed unsafe budget, relative to the workspace.
+const BASELINE_FILE: &str = "unsafe-baseline.toml";
+
+/// The kinds of unsafe code counted, in report order.
+const KINDS: &[&str] = &["blocks", "functions", "impls", "traits"];
+
+/// The number of unsafe items of each kind, by kind.
+type Counts = BTreeMap<&'static str, u64>;
+
+/// Counts the unsafe code of every workspace member and compares it against the baseline.
+///
+/// The command fails if any count exceeds `unsafe-baseline.toml`, where a missing crate or file
+/// means a budget of zero; with `update`, the baseline is rewritten to the current counts.
+/// Code inside macro invocations is not counted.
+pub fn unsafe_report(update: bool) {
+    let metadata = metadata::cargo_metadata(true);
+    let mut current: BTreeMap<String, Counts> = BTreeMap::new();
+    for package in metadata::workspace_packages(&metadata) {
+        let name = package["name"].as_str().unwrap().to_owned();
+        let manifest = Path::new(package["manifest_path"].as_str().unwrap());
+        let mut files = vec![];
+        collect_rust_files(manifest.parent().unwrap(), &mut files);
+        let mut counts = Counts::new();
+        for file in files {
+            let content = std::fs::read_to_string(&file).unwrap();
+            let syntax = syn::parse_file(&content)
+                .unwrap_or_else(|err| panic!("failed to parse {}: {err}", file.display()));
+            for (kind, count) in count_unsafe(&syntax) {
+                *counts.entry(kind).or_default() += count;
+            }
+        }
+        current.insert(name, counts);
+    }
+
+    let baseline_path = workspace_dir().join(BASELINE_FILE);
+    if update {
+        std::fs::write(&baseline_path, render_baseline(&current)).unwrap();
+        println!("Updated {BASELINE_FILE}.");
+        return;
+    }
+
+    let baseline = match std::fs::read_to_string(&baseline_path) {
+        Ok(content) => parse_baseline(&content)
+            .unwrap_or_else(|err| history::fail(&format!("invalid {BASELINE_FILE}: {err}"))),
+        Err(_) => BTreeMap::new(),
+    };
+    let (report, exceeded) = render_report(&baseline, &current);
+    print!("{report}");
+    if exceeded {
+        eprintln!(
+            "\nNew unsafe code exceeds {BASELINE_FILE}; justify it and run `cargo x unsafe-report --update`."
+        );
+        history::exit(1);
+    }
+}
+
+fn collect_rust_files(dir: &Path, files: &mut Vec<PathBuf>) {
+    let entries = std::fs::read_dir(dir)
+        .unwrap_or_else(|err| panic!("failed to read directory {}: {err}", dir.display()));
+    for entry in entries {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            if path.file_name().is_some_and(|name| name != "target") {
+                collect_rust_files(&path, files);
+            }
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+}
+
+#[derive(Default)]
+struct UnsafeVisitor {
+    counts: Counts,
+}
+
+impl UnsafeVisitor {
+    fn count(&mut self, kind: &'static str) {
+        *self.counts.entry(kind).or_default() += 1;
+    }
+}
+
+impl<'ast> Visit<'ast> for UnsafeVisitor {
+    fn visit_expr_unsafe(&mut self, node: &'ast syn::ExprUnsafe) {
+        self.count("blocks");
+        syn::visit::visit_expr_unsafe(self, node);
+    }
+
+    fn visit_signature(&mut self, node: &'ast syn::Signature) {
+        if node.unsafety.is_some() {
+            self.count("functions");
+        }
+        syn::visit::visit_signature(self, node);
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast syn::ItemImpl) {
+        if node.unsafety.is_some() {
+            self.count("impls");
+        }
+        syn::visit::visit_item_impl(self, node);
+    }
+
+    fn visit_item_trait(&mut self, node: &'ast syn::ItemTrait) {
+        if node.unsafety.is_some() {
+            self.count("traits");
+        }
+        syn::visit::visit_item_trait(self, node);
+    }
+}
+
+fn count_unsafe(file: &syn::File) -> Counts {
+    let mut visitor = UnsafeVisitor::default();
+    visitor.visit_file(file);
+    visitor.counts
+}
+
+fn parse_baseline(content: &str) -> Result<BTreeMap<String, Counts>, String> {
+    let doc: DocumentMut = content.parse().map_err(|err| format!("{err}"))?;
+    let mut baseline = BTreeMap::new();
+    for (name, table) in doc.iter() {
+        let mut counts = Counts::new();
+        for kind in KINDS {
+            if let Some(count) = table.get(kind) {
+                let count = count
+                    .as_integer()
+                    .filter(|c| *c >= 0)
+                    .ok_or_else(|| format!("'{name}.{kind}' must be a non-negative integer"))?;
+                counts.insert(kind, count as u64);
+            }
+        }
+        baseline.insert(name.to_owned(), counts);
+    }
+    Ok(baseline)
+}
+
+fn render_baseline(current: &BTreeMap<String, Counts>) -> String {
+    let mut doc = DocumentMut::new();
+    for (name, counts) in current {
+        let mut table = Table::new();
+        for kind in KINDS {
+            table.insert(kind, value(counts.get(kind).copied().unwrap_or(0) as i64));
+        }
+        doc.insert(name, Item::Table(table));
+    }
+    format!("# The unsafe code budget per crate, checked by `cargo x unsafe-report`.\n\n{doc}")
+}
+
+/// Returns the report and whether any count exceeds its baseline.
+fn render_report(
+    baseline: &BTreeMap<String, Counts>,
+    current: &BTreeMap<String, Counts>,
+) -> (String, bool) {
+    let mut out = String::new();
+    let mut exceeded = false;
+    writeln!(
+        out,
+        "{:<24} {:>10} {:>10} {:>10} {:>10}",
+        "CRATE", "BLOCKS", "FUNCTIONS", "IMPLS", "TRAITS"
+    )
+    .unwrap();
+    let empty = Counts::new();
+    for (name, counts) in current {
+        let budget = baseline.get(name).unwrap_or(&empty);
+        let cells: Vec<String> = KINDS
+            .iter()
+            .map(|kind| {
+                let count = counts.get(kind).copied().unwrap_or(0);
+                let allowed = budget.get(kind).copied().unwrap_or(0);
+                if count > allowed {
+                    exceeded = true;
+                    format!("{count} (+{})", count - allowed)
+                } else {
+                    count.to_string()
+                }
+            })
+            .collect();
+        writeln!(
+            out,
+            "{name:<24} {:>10} {:>10} {:>10} {:>10}",
+            cells[0], cells[1], cells[2], cells[3]
+        )
+        .unwrap();
+    }
+    (out, exceeded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_unsafe() {
+        let file = syn::parse_file(
+            r#"
+            unsafe fn raw() {}
+            unsafe trait Marker {}
+            unsafe impl Marker for u8 {}
+            struct S;
+            impl S {
+                unsafe fn method(&self) {}
+            }
+            fn safe() {
+                unsafe { raw() };
+                let _ = || unsafe { raw() };
+            }
+            "#,
+        )
+        .unwrap();
+        let counts = count_unsafe(&file);
+        assert_eq!(
+            counts,
+            Counts::from([("blocks", 2), ("functions", 2), ("impls", 1), ("traits", 1)])
+        );
+    }
+
+    #[test]
+    fn test_render_report() {
+        let current = BTreeMap::from([
+            ("demo".to_owned(), Counts::from([("blocks", 3)])),
+            ("safe".to_owned(), Counts::new()),
+        ]);
+        let baseline = parse_baseline(&render_baseline(&current)).unwrap();
+        assert!(!render_report(&baseline, &current).1);
+
+        let (report, exceeded) = render_report(&BTreeMap::new(), &current);
+        assert!(exceeded);
+        assert!(report.contains("3 (+3)"));
+    }
+}
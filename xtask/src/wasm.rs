@@ -0,0 +1,61 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use super::ensure_installed;
+use super::find_command;
+use super::make_target_add_cmd;
+use super::metadata;
+use super::run_command;
+
+/// The target the WebAssembly members are built for.
+const WASM_TARGET: &str = "wasm32-unknown-unknown";
+
+/// Runs wasm-pack with `args`, e.g., `test --headless --firefox`, for every workspace member
+/// depending on wasm-bindgen, in its directory, once the WebAssembly target is installed.
+///
+/// Without any such member, there is nothing to do and neither the target nor wasm-pack is
+/// installed, so that the CI can run `cargo x wasm test` for every project.
+pub fn run_wasm_pack(args: &[&str]) {
+    let metadata = metadata::cargo_metadata(true);
+    let dirs: Vec<PathBuf> = metadata::workspace_packages(&metadata)
+        .into_iter()
+        .filter(|package| {
+            package["dependencies"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .any(|dep| dep["name"] == "wasm-bindgen" && dep["kind"].is_null())
+        })
+        .filter_map(|package| {
+            let manifest = Path::new(package["manifest_path"].as_str()?);
+            Some(manifest.parent()?.to_path_buf())
+        })
+        .collect();
+    if dirs.is_empty() {
+        println!("No workspace member depends on wasm-bindgen.");
+        return;
+    }
+
+    run_command(make_target_add_cmd(WASM_TARGET));
+    ensure_installed("wasm-pack", "wasm-pack");
+    for dir in dirs {
+        let mut cmd = find_command("wasm-pack");
+        cmd.current_dir(dir);
+        cmd.args(args);
+        run_command(cmd);
+    }
+}
@@ -0,0 +1,44 @@
+// Copyright 2026 FastLabs Developers
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use super::find_command;
+use super::run_command;
+use super::workspace_dir;
+
+/// Checks out `revision` into a detached worktree at `dir`, replacing any previous one.
+pub fn add_worktree(dir: &Path, revision: &str) {
+    if dir.exists() {
+        remove_worktree(dir);
+    }
+
+    let mut cmd = find_command("git");
+    cmd.args(["worktree", "add", "--detach"])
+        .arg(dir)
+        .arg(revision);
+    run_command(cmd);
+
+    // build both revisions against the same dependency versions when the lockfile is untracked
+    let lockfile = workspace_dir().join("Cargo.lock");
+    if lockfile.exists() && !dir.join("Cargo.lock").exists() {
+        std::fs::copy(&lockfile, dir.join("Cargo.lock")).unwrap();
+    }
+}
+
+pub fn remove_worktree(dir: &Path) {
+    let mut cmd = find_command("git");
+    cmd.args(["worktree", "remove", "--force"]).arg(dir);
+    run_command(cmd);
+}